@@ -0,0 +1,82 @@
+/// Per-decision search budgets for MCTS
+///
+/// A budget scales the iteration count and time limit of one `select_action` call,
+/// so important decisions get a deeper search and forced ones get none.
+use crate::agents::ForwardSimulation;
+use crate::battle::Battle;
+use crate::game::{game::Game, game_state::GameState};
+
+/// Decides how much of the configured search a decision gets
+pub trait SearchBudget<S: ForwardSimulation> {
+    /// Multiplier on the iterations and time limit for the decision at `state`,
+    /// `actions` being its legal actions (0 skips the search)
+    fn scale(&self, state: &S, actions: &[S::Action]) -> f32;
+}
+
+/// Every decision gets the full configured search
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformBudget;
+
+impl<S: ForwardSimulation> SearchBudget<S> for UniformBudget {
+    fn scale(&self, _state: &S, _actions: &[S::Action]) -> f32 {
+        1.0
+    }
+}
+
+/// More search for decisions that shape the run, none for forced moves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveBudget {
+    /// Scale when there is a single legal action
+    pub single_action: f32,
+    /// Scale for picking a card reward
+    pub card_reward: f32,
+    /// Scale for turns of a boss battle
+    pub boss: f32,
+}
+
+impl Default for AdaptiveBudget {
+    fn default() -> Self {
+        AdaptiveBudget { single_action: 0.0, card_reward: 2.0, boss: 2.0 }
+    }
+}
+
+impl SearchBudget<Battle> for AdaptiveBudget {
+    /// A battle does not know whether it is a boss fight, only forced moves are scaled
+    fn scale(&self, _battle: &Battle, actions: &[<Battle as ForwardSimulation>::Action]) -> f32 {
+        if actions.len() <= 1 { self.single_action } else { 1.0 }
+    }
+}
+
+impl SearchBudget<Game> for AdaptiveBudget {
+    fn scale(&self, game: &Game, actions: &[<Game as ForwardSimulation>::Action]) -> f32 {
+        if actions.len() <= 1 {
+            return self.single_action;
+        }
+
+        match game.get_game_state() {
+            GameState::CardRewardSelection(_) => self.card_reward,
+            GameState::InBattle if game.current_battle_is_boss => self.boss,
+            _ => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::battle_action::BattleAction;
+    use crate::battle_builder::BattleBuilder;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    #[test]
+    fn test_adaptive_budget_skips_forced_battle_moves() {
+        let battle = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        let budget = AdaptiveBudget::default();
+
+        assert_eq!(budget.scale(&battle, &[BattleAction::EndTurn]), 0.0);
+        assert_eq!(budget.scale(&battle, &battle.list_available_actions()), 1.0);
+        assert_eq!(UniformBudget.scale(&battle, &[BattleAction::EndTurn]), 1.0);
+    }
+}
@@ -1,5 +1,26 @@
 use std::hash::Hash;
 
+/// Progressive widening: a chance node visited `n` times may have up to
+/// `ceil(constant * n^alpha)` sampled outcomes, further visits revisit the outcomes it has
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressiveWidening {
+    pub constant: f32,
+    pub alpha: f32,
+}
+
+impl Default for ProgressiveWidening {
+    fn default() -> Self {
+        ProgressiveWidening { constant: 1.0, alpha: 0.5 }
+    }
+}
+
+impl ProgressiveWidening {
+    /// Outcomes allowed after the given number of visits (at least one)
+    pub fn max_outcomes(&self, visits: usize) -> usize {
+        ((self.constant * (visits.max(1) as f32).powf(self.alpha)).ceil() as usize).max(1)
+    }
+}
+
 /// Chance node: stochastic outcome of taking an action
 #[derive(Clone)]
 pub struct MCTSChanceNode<A: Clone + PartialEq + Eq + Hash> {
@@ -11,6 +32,14 @@ pub struct MCTSChanceNode<A: Clone + PartialEq + Eq + Hash> {
     pub total_reward: f32,
     /// Children decision nodes (one per sampled outcome)
     pub children: Vec<usize>,
+    /// Weight of each child: how often it was sampled, or its probability when the outcomes are enumerated
+    pub outcome_weights: Vec<f32>,
+    /// Whether the children are the exact outcome distribution of the action
+    pub enumerated: bool,
+    /// Probability of each enumerated outcome by state hash, kept when outcome nodes are evicted
+    pub outcome_probabilities: Vec<(u64, f32)>,
+    /// Whether the action was found to always lead to the same state
+    pub deterministic: bool,
     /// Parent decision node ID
     pub parent: usize,
 }
@@ -22,6 +51,10 @@ impl<A: Clone + PartialEq + Eq + Hash> MCTSChanceNode<A> {
             visits: 0,
             total_reward: 0.0,
             children: Vec::new(),
+            outcome_weights: Vec::new(),
+            enumerated: false,
+            outcome_probabilities: Vec::new(),
+            deterministic: false,
             parent,
         }
     }
@@ -50,4 +83,48 @@ impl<A: Clone + PartialEq + Eq + Hash> MCTSChanceNode<A> {
     pub fn is_fully_expanded(&self, samples_per_action: usize) -> bool {
         self.children.len() >= samples_per_action
     }
+
+    /// Add `weight` to an outcome, adding the outcome if it is new
+    pub fn add_outcome(&mut self, child_id: usize, weight: f32) {
+        match self.children.iter().position(|&id| id == child_id) {
+            Some(index) => self.outcome_weights[index] += weight,
+            None => {
+                self.children.push(child_id);
+                self.outcome_weights.push(weight);
+            }
+        }
+    }
+
+    /// Probability of the enumerated outcome with the given state hash
+    pub fn outcome_probability(&self, hash: u64) -> Option<f32> {
+        self.outcome_probabilities.iter()
+            .find(|&&(outcome_hash, _)| outcome_hash == hash)
+            .map(|&(_, probability)| probability)
+    }
+
+    /// Number of times the action was sampled
+    pub fn samples(&self) -> usize {
+        if self.enumerated {
+            0
+        } else {
+            self.outcome_weights.iter().sum::<f32>() as usize
+        }
+    }
+
+    /// Pick a child with probability proportional to its weight
+    pub fn sample_child(&self, rng: &mut impl rand::Rng) -> Option<usize> {
+        let total: f32 = self.outcome_weights.iter().sum();
+        if total <= 0.0 {
+            return self.children.first().copied();
+        }
+
+        let mut remaining = rng.random::<f32>() * total;
+        for (&child_id, &weight) in self.children.iter().zip(&self.outcome_weights) {
+            if remaining < weight {
+                return Some(child_id);
+            }
+            remaining -= weight;
+        }
+        self.children.last().copied()
+    }
 }
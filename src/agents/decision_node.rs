@@ -1,4 +1,12 @@
 use crate::agents::ForwardSimulation;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Hash identifying a state in the transposition table
+pub fn state_hash<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
 
 /// Decision node: player chooses action from this state
 #[derive(Clone)]
@@ -13,22 +21,38 @@ pub struct MCTSDecisionNode<S: ForwardSimulation> {
     pub parent: Option<usize>,
     /// Actions that have been tried (corresponds to children indices)
     pub tried_actions: Vec<S::Action>,
-    /// The state at this decision node
-    pub state: S,
+    /// The state at this decision node, None when only the root stores its state
+    /// (the state is then reached again by replaying the actions from the root)
+    pub state: Option<S>,
+    /// Hash of the state, the key of this node in the transposition table
+    pub state_hash: u64,
 }
 
 impl<S: ForwardSimulation> MCTSDecisionNode<S> {
     pub fn new(parent: Option<usize>, state: S) -> Self {
+        let mut node = Self::without_state(parent, &state);
+        node.state = Some(state);
+        node
+    }
+
+    /// A node that only remembers the hash of its state
+    pub fn without_state(parent: Option<usize>, state: &S) -> Self {
         MCTSDecisionNode {
             visits: 0,
             total_reward: 0.0,
             children: Vec::new(),
             parent,
             tried_actions: Vec::new(),
-            state,
+            state: None,
+            state_hash: state_hash(state),
         }
     }
 
+    /// Whether this node stands for the state: same hash, and same state when it is stored
+    pub fn matches(&self, state: &S, hash: u64) -> bool {
+        self.state_hash == hash && self.state.as_ref().is_none_or(|stored| stored == state)
+    }
+
     /// Get average reward (Q-value)
     pub fn avg_reward(&self) -> f32 {
         if self.visits == 0 {
@@ -0,0 +1,19 @@
+/// Trait for states with information hidden from the player
+///
+/// Searching the true state lets an agent use what the player cannot know (the draw pile order
+/// for one). Information set search instead works on what the player observes and plays its
+/// iterations on determinizations: states consistent with the observation, hidden parts re-sampled.
+use crate::agents::ForwardSimulation;
+use std::hash::Hash;
+
+pub trait Determinize: ForwardSimulation {
+    /// What the player sees of a state
+    type Observation: Hash;
+
+    /// The state as the player sees it, without its hidden information,
+    /// so two states the player cannot tell apart have the same observation
+    fn observation(&self) -> Self::Observation;
+
+    /// A state with the same observation, the hidden information sampled at random
+    fn determinize(&self, rng: &mut impl rand::Rng) -> Self;
+}
@@ -0,0 +1,225 @@
+/// State evaluators for MCTS
+///
+/// An evaluator scores a state, higher being better. MCTS uses it for terminal states,
+/// rollouts cut short and the rollout depth limit, so the objective being optimised
+/// (win rate, HP preservation, speed...) is chosen by picking an evaluator and its weights.
+use crate::agents::ForwardSimulation;
+use crate::battle::{Battle, counters::Counter};
+use crate::game::{game::Game, game_state::GameState};
+
+/// Scores a state, higher is better
+pub trait Evaluator<S> {
+    fn evaluate(&self, state: &S) -> f32;
+
+    /// Get the name of this evaluator (for logging/identification)
+    fn name(&self) -> &str;
+}
+
+/// Use the state's own `ForwardSimulation::evaluate`, the default heuristic for each state type
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultEvaluator;
+
+impl<S: ForwardSimulation> Evaluator<S> for DefaultEvaluator {
+    fn evaluate(&self, state: &S) -> f32 {
+        state.evaluate()
+    }
+
+    fn name(&self) -> &str {
+        "Default"
+    }
+}
+
+/// Weights of the battle heuristic
+/// A defeat always scores 0. A victory scores `victory + victory_hp * hp_ratio`,
+/// an unfinished battle `player_hp * hp_ratio - enemy_hp * enemy_hp_ratio`.
+/// Both then get `potions` per potion kept and lose `turns` per player turn taken.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BattleWeights {
+    pub victory: f32,
+    pub victory_hp: f32,
+    pub player_hp: f32,
+    pub enemy_hp: f32,
+    pub potions: f32,
+    pub turns: f32,
+}
+
+impl Default for BattleWeights {
+    /// Victory in [1.2, 1.7], unfinished battles in [-1, 1]
+    fn default() -> Self {
+        BattleWeights { victory: 1.2, victory_hp: 0.5, player_hp: 1.0, enemy_hp: 1.0, potions: 0.0, turns: 0.0 }
+    }
+}
+
+impl BattleWeights {
+    /// Winning matters, the HP it costs barely does
+    pub fn win_rate() -> Self {
+        BattleWeights { victory_hp: 0.1, player_hp: 0.5, ..Self::default() }
+    }
+
+    /// Winning with as much HP and as many potions left as possible
+    pub fn hp_preservation() -> Self {
+        BattleWeights { victory_hp: 1.0, player_hp: 1.5, potions: 0.05, ..Self::default() }
+    }
+
+    /// Winning in as few turns as possible
+    pub fn speed() -> Self {
+        BattleWeights { turns: 0.02, ..Self::default() }
+    }
+}
+
+/// Weighted battle heuristic, `BattleEvaluator::default()` is what `Battle::evaluate` uses
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BattleEvaluator {
+    pub weights: BattleWeights,
+}
+
+impl BattleEvaluator {
+    pub fn new(weights: BattleWeights) -> Self {
+        BattleEvaluator { weights }
+    }
+}
+
+fn ratio(current: f32, max: f32) -> f32 {
+    if max > 0.0 { current / max } else { 0.0 }
+}
+
+impl Evaluator<Battle> for BattleEvaluator {
+    fn evaluate(&self, battle: &Battle) -> f32 {
+        let player = &battle.get_player().battle_info;
+        if !player.is_alive() {
+            return 0.0;
+        }
+
+        let weights = &self.weights;
+        let hp_ratio = ratio(player.get_hp() as f32, player.get_max_hp() as f32);
+        let enemies = battle.get_enemies();
+        let score = if enemies.iter().all(|enemy| !enemy.battle_info.is_alive()) {
+            weights.victory + weights.victory_hp * hp_ratio
+        } else {
+            let enemy_hp: f32 = enemies.iter().map(|enemy| enemy.battle_info.get_hp() as f32).sum();
+            let enemy_max_hp: f32 = enemies.iter().map(|enemy| enemy.battle_info.get_max_hp() as f32).sum();
+            weights.player_hp * hp_ratio - weights.enemy_hp * ratio(enemy_hp, enemy_max_hp)
+        };
+
+        let potions = battle.get_potions().potion_count() as f32;
+        let turns = battle.get_counters().this_combat(Counter::PlayerTurns) as f32;
+        score + weights.potions * potions - weights.turns * turns
+    }
+
+    fn name(&self) -> &str {
+        "Battle"
+    }
+}
+
+/// Weights of the run heuristic: each component is normalised to [0, 1] first
+/// (HP ratio, gold up to 500, deck size close to 15, relics up to 10, potions up to 3)
+/// Beating the boss adds `boss_victory`, dying scores 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameWeights {
+    pub boss_victory: f32,
+    pub hp: f32,
+    pub gold: f32,
+    pub deck: f32,
+    pub relics: f32,
+    pub potions: f32,
+}
+
+impl Default for GameWeights {
+    fn default() -> Self {
+        GameWeights { boss_victory: 50.0, hp: 0.4, gold: 0.2, deck: 0.15, relics: 0.15, potions: 0.1 }
+    }
+}
+
+/// Weighted run heuristic, `GameEvaluator::default()` is what `Game::evaluate` uses
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GameEvaluator {
+    pub weights: GameWeights,
+}
+
+impl GameEvaluator {
+    pub fn new(weights: GameWeights) -> Self {
+        GameEvaluator { weights }
+    }
+
+    /// Score of a run still in progress
+    pub fn state_score(&self, game: &Game) -> f32 {
+        let weights = &self.weights;
+        let hp_score = ratio(game.player_hp as f32, game.player_max_hp as f32);
+        let gold_score = (game.gold as f32 / 500.0).min(1.0);
+        let deck_size = game.deck.size() as f32;
+        let deck_score = 1.0 - ((deck_size - 15.0).abs() / 15.0).min(1.0);
+        let relic_score = (game.get_relics().len() as f32 / 10.0).min(1.0);
+        let potion_score = (game.potions.potion_count() as f32 / 3.0).min(1.0);
+
+        weights.hp * hp_score
+            + weights.gold * gold_score
+            + weights.deck * deck_score
+            + weights.relics * relic_score
+            + weights.potions * potion_score
+    }
+}
+
+impl Evaluator<Game> for GameEvaluator {
+    fn evaluate(&self, game: &Game) -> f32 {
+        if !game.is_player_alive() {
+            return 0.0;
+        }
+
+        if matches!(game.get_game_state(), GameState::BossBeaten) {
+            return self.weights.boss_victory + self.state_score(game);
+        }
+
+        self.state_score(game)
+    }
+
+    fn name(&self) -> &str {
+        "Game"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle_builder::BattleBuilder;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    fn battle() -> Battle {
+        BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build()
+    }
+
+    #[test]
+    fn test_default_weights_match_battle_evaluate() {
+        let battle = battle();
+        assert_eq!(BattleEvaluator::default().evaluate(&battle), battle.evaluate());
+        assert_eq!(DefaultEvaluator.evaluate(&battle), battle.evaluate());
+    }
+
+    #[test]
+    fn test_speed_weights_penalise_turns() {
+        let mut battle = battle();
+        let speed = BattleEvaluator::new(BattleWeights::speed());
+        let before = speed.evaluate(&battle);
+
+        // Same HP on both sides, one more turn taken
+        let mut rng = rand::rng();
+        let global_info = crate::game::global_info::GlobalInfo { ascention: 0, current_floor: 1 };
+        battle.get_player_mut().battle_info.gain_block(1000);
+        battle.end_turn(&mut rng, &global_info);
+
+        assert!(speed.evaluate(&battle) < before);
+        assert_eq!(BattleEvaluator::default().evaluate(&battle), battle.evaluate());
+    }
+
+    #[test]
+    fn test_victory_scores_above_unfinished_battles() {
+        let mut battle = battle();
+        let evaluator = BattleEvaluator::new(BattleWeights::hp_preservation());
+        let unfinished = evaluator.evaluate(&battle);
+
+        let hp = battle.get_enemies()[0].battle_info.get_hp();
+        battle.get_enemies_mut()[0].battle_info.take_damage(hp);
+        assert!(evaluator.evaluate(&battle) > unfinished);
+    }
+}
@@ -19,6 +19,13 @@ pub trait ForwardSimulation: Clone + PartialEq + Eq + Hash {
     /// Returns Err if the action is invalid or cannot be executed
     fn eval_action(&mut self, action: Self::Action, rng: &mut impl rand::Rng) -> Result<(), GameError>;
 
+    /// Every state `action` can lead to, with its probability, when the simulation can list them
+    /// and there are at most `max_outcomes`
+    /// None (the default) means the outcomes are only known by sampling `eval_action`
+    fn outcome_distribution(&self, _action: &Self::Action, _max_outcomes: usize, _rng: &mut impl rand::Rng) -> Option<Vec<(Self, f64)>> {
+        None
+    }
+
     /// Check if the current state is terminal (game/battle over)
     fn is_terminal(&self) -> bool;
 
@@ -0,0 +1,343 @@
+/// Agents playing a whole run
+///
+/// - `RandomGameAgent`: uniformly random over every decision, the baseline to beat.
+/// - `RuleBasedGameAgent`: simple heuristics for paths, rewards, rest sites and shops.
+/// - `WithBattleAgent`: any game agent for the decisions between battles and any battle
+///   `Agent` (e.g. `MCTS<Battle>`) for the battles, so meta-game and battle strategies mix freely.
+use crate::agents::ForwardSimulation;
+use crate::battle::Battle;
+use crate::game::{action::{GameAction, RestSiteAction}, card::{Card, CardClass, Rarity}, card_enum::CardEnum, game::Game, game_state::{CardFromDeckTo, GameState}};
+use crate::map::NodeType;
+use super::random::RandomAgent;
+use super::traits::{Agent, GameAgent};
+
+/// Picks uniformly among the legal actions, battles included
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomGameAgent;
+
+impl RandomGameAgent {
+    pub fn new() -> Self {
+        RandomGameAgent
+    }
+}
+
+impl GameAgent for RandomGameAgent {
+    fn select_action(&mut self, game: &Game, rng: &mut impl rand::Rng) -> GameAction {
+        let actions = ForwardSimulation::list_available_actions(game);
+        if actions.is_empty() {
+            panic!("No available actions - game should be over");
+        }
+
+        actions[rng.random_range(0..actions.len())].clone()
+    }
+
+    fn name(&self) -> &str {
+        "RandomGame"
+    }
+}
+
+/// Heuristic meta-game play: claims every reward, takes the best card offered, rests below
+/// `rest_below` HP and otherwise upgrades, removes a basic card at shops and follows a preferred
+/// order of rooms on the map. Battles are played randomly, wrap it in `WithBattleAgent` to do better.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleBasedGameAgent {
+    /// Fraction of max HP below which the agent rests and heads for safe rooms
+    pub rest_below: f32,
+    /// Rooms from most to least wanted while healthy
+    pub path_preference: Vec<NodeType>,
+    /// Rooms from most to least wanted below `rest_below` HP
+    pub low_hp_path_preference: Vec<NodeType>,
+}
+
+impl Default for RuleBasedGameAgent {
+    fn default() -> Self {
+        RuleBasedGameAgent {
+            rest_below: 0.5,
+            path_preference: vec![NodeType::Treasure, NodeType::Combat, NodeType::Event, NodeType::Elite, NodeType::Shop, NodeType::RestSite],
+            low_hp_path_preference: vec![NodeType::RestSite, NodeType::Treasure, NodeType::Shop, NodeType::Event, NodeType::Combat, NodeType::Elite],
+        }
+    }
+}
+
+impl RuleBasedGameAgent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn is_low_hp(&self, game: &Game) -> bool {
+        (game.get_player_hp() as f32) < self.rest_below * game.get_player_max_hp() as f32
+    }
+
+    /// The path leading to the most preferred room, the leftmost one on ties
+    fn choose_path(&self, game: &Game, actions: &[GameAction]) -> Option<GameAction> {
+        let preference = if self.is_low_hp(game) { &self.low_hp_path_preference } else { &self.path_preference };
+        let neighbors = game.get_map().get_neighbors(game.current_node_position);
+
+        actions.iter()
+            .min_by_key(|action| {
+                let GameAction::ChoosePath(path) = action else { return usize::MAX };
+                game.choose_node_from_path(&neighbors, *path).ok()
+                    .and_then(|node_id| game.get_map().get_node(node_id))
+                    .and_then(|node| preference.iter().position(|node_type| *node_type == node.node_type))
+                    .unwrap_or(preference.len())
+            })
+            .cloned()
+    }
+}
+
+/// Rough worth of a card in the deck: rarer is better, Strikes and Defends are worth little and curses less
+fn card_value(card: &Card) -> i32 {
+    let rarity = match card.get_card_class() {
+        CardClass::Curse | CardClass::Status => return -1,
+        CardClass::IronClad(rarity, _) | CardClass::Colorless(rarity, _) => *rarity,
+    };
+    if matches!(card.get_card_enum(), CardEnum::Strike | CardEnum::Defend) {
+        return 0;
+    }
+    match rarity {
+        Rarity::Basic | Rarity::Special => 1,
+        Rarity::Common => 2,
+        Rarity::Uncommon => 3,
+        Rarity::Rare => 4,
+    }
+}
+
+/// The listed action picking the card of highest (or lowest) value, the first one on ties
+fn pick_card<'a>(actions: &[GameAction], card_of: impl Fn(&GameAction) -> Option<&'a Card>, highest: bool) -> Option<GameAction> {
+    let mut best: Option<(&GameAction, i32)> = None;
+    for action in actions {
+        let Some(card) = card_of(action) else { continue };
+        let value = if highest { card_value(card) } else { -card_value(card) };
+        if best.is_none_or(|(_, best_value)| value > best_value) {
+            best = Some((action, value));
+        }
+    }
+    best.map(|(action, _)| action.clone())
+}
+
+impl GameAgent for RuleBasedGameAgent {
+    fn select_action(&mut self, game: &Game, rng: &mut impl rand::Rng) -> GameAction {
+        let actions = ForwardSimulation::list_available_actions(game);
+        if actions.is_empty() {
+            panic!("No available actions - game should be over");
+        }
+
+        let chosen = match game.get_game_state() {
+            GameState::InBattle => game.get_battle()
+                .map(|battle| GameAction::Battle(RandomAgent::new().select_action(battle, rng))),
+            GameState::OnMap => self.choose_path(game, &actions),
+            // Gold, potion, relic and card choices first, skipping only what is left
+            GameState::Reward(_) => actions.iter().find(|action| **action != GameAction::Skip).cloned(),
+            GameState::CardRewardSelection(cards) => pick_card(&actions, |action| match action {
+                GameAction::SelectCardReward(index) => cards.get(*index),
+                _ => None,
+            }, true),
+            // Rest when low on HP, upgrade otherwise, whichever of the two is listed
+            GameState::RestSite => {
+                let preferred = if self.is_low_hp(game) {
+                    [RestSiteAction::Rest, RestSiteAction::Upgrade]
+                } else {
+                    [RestSiteAction::Upgrade, RestSiteAction::Rest]
+                };
+                preferred.into_iter()
+                    .map(GameAction::RestSiteChoice)
+                    .find(|action| actions.contains(action))
+            }
+            GameState::SelectingCardFromDeck(purpose) => pick_card(&actions, |action| match action {
+                GameAction::SelectCardFromDeck(index) => game.deck.get_cards().get(*index),
+                _ => None,
+            }, *purpose == CardFromDeckTo::Upgrade),
+            // Only uncommon or rare cards are worth the gold, otherwise leave the shop
+            GameState::Shop(shop) => pick_card(&actions, |action| match action {
+                GameAction::BuyCard(index) => shop.get_card(*index).filter(|card| card_value(card) >= 3),
+                _ => None,
+            }, true).or(Some(GameAction::Skip)),
+            GameState::InEvent(..) | GameState::BossBeaten => None,
+        };
+
+        // Events and anything not covered above take the first listed option
+        chosen.unwrap_or_else(|| actions[0].clone())
+    }
+
+    fn name(&self) -> &str {
+        "RuleBasedGame"
+    }
+}
+
+/// Plays battles with a battle `Agent` and everything else with a `GameAgent`
+pub struct WithBattleAgent<G: GameAgent, B: Agent> {
+    pub game_agent: G,
+    pub battle_agent: B,
+    name: String,
+    /// Whether the last decision was in a battle, to reset the battle agent when a new battle starts
+    in_battle: bool,
+}
+
+impl<G: GameAgent, B: Agent> WithBattleAgent<G, B> {
+    pub fn new(game_agent: G, battle_agent: B) -> Self {
+        let name = format!("{}+{}", game_agent.name(), battle_agent.name());
+        WithBattleAgent { game_agent, battle_agent, name, in_battle: false }
+    }
+
+    fn current_battle(game: &Game) -> Option<&Battle> {
+        match game.get_game_state() {
+            GameState::InBattle => game.get_battle(),
+            _ => None,
+        }
+    }
+}
+
+impl<G: GameAgent, B: Agent> GameAgent for WithBattleAgent<G, B> {
+    fn select_action(&mut self, game: &Game, rng: &mut impl rand::Rng) -> GameAction {
+        match Self::current_battle(game) {
+            Some(battle) => {
+                if !self.in_battle {
+                    self.battle_agent.reset();
+                    self.in_battle = true;
+                }
+                GameAction::Battle(self.battle_agent.select_action(battle, rng))
+            }
+            None => {
+                self.in_battle = false;
+                self.game_agent.select_action(game, rng)
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn reset(&mut self) {
+        self.game_agent.reset();
+        self.battle_agent.reset();
+        self.in_battle = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::MCTS;
+    use crate::cards::ironclad::{starter_deck::starter_deck, strike::strike, bludgeon::bludgeon, shrug_it_off::shrug_it_off};
+    use crate::game::global_info::GlobalInfo;
+    use crate::map::debug_map;
+
+    /// Start -> Combat, Event, Elite, RestSite or Shop
+    fn game() -> Game {
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        Game::new(starter_deck(), global_info, debug_map(), 80, 80)
+    }
+
+    fn chosen_room(game: &Game, action: &GameAction) -> NodeType {
+        let GameAction::ChoosePath(path) = action else { panic!("Expected a path choice, got {:?}", action) };
+        let neighbors = game.get_map().get_neighbors(game.current_node_position);
+        let node_id = game.choose_node_from_path(&neighbors, *path).unwrap();
+        game.get_map().get_node(node_id).unwrap().node_type.clone()
+    }
+
+    #[test]
+    fn test_random_game_agent_selects_valid_action() {
+        let game = game();
+        let mut agent = RandomGameAgent::new();
+
+        let action = agent.select_action(&game, &mut rand::rng());
+        assert!(ForwardSimulation::list_available_actions(&game).contains(&action));
+        assert_eq!(agent.name(), "RandomGame");
+    }
+
+    #[test]
+    fn test_rule_based_paths_depend_on_hp() {
+        let mut game = game();
+        let mut agent = RuleBasedGameAgent::new();
+        let mut rng = rand::rng();
+
+        let action = agent.select_action(&game, &mut rng);
+        assert_eq!(chosen_room(&game, &action), NodeType::Combat);
+
+        game.set_player_hp(30);
+        let action = agent.select_action(&game, &mut rng);
+        assert_eq!(chosen_room(&game, &action), NodeType::RestSite);
+    }
+
+    #[test]
+    fn test_rule_based_rests_below_half_hp() {
+        let mut game = game();
+        game.set_game_state(GameState::RestSite);
+        let mut agent = RuleBasedGameAgent::new();
+        let mut rng = rand::rng();
+
+        assert_eq!(agent.select_action(&game, &mut rng), GameAction::RestSiteChoice(RestSiteAction::Upgrade));
+        game.set_player_hp(39);
+        assert_eq!(agent.select_action(&game, &mut rng), GameAction::RestSiteChoice(RestSiteAction::Rest));
+
+        // Upgrade is not listed when no card can be upgraded
+        game.set_player_hp(80);
+        game.deck = crate::game::deck::Deck::new(vec![strike().upgrade()]);
+        assert_eq!(agent.select_action(&game, &mut rng), GameAction::RestSiteChoice(RestSiteAction::Rest));
+    }
+
+    #[test]
+    fn test_rule_based_takes_the_best_card_reward() {
+        let mut game = game();
+        game.set_game_state(GameState::CardRewardSelection(vec![strike(), bludgeon(), shrug_it_off()]));
+        let mut agent = RuleBasedGameAgent::new();
+
+        // Bludgeon is rare, Shrug It Off common
+        assert_eq!(agent.select_action(&game, &mut rand::rng()), GameAction::SelectCardReward(1));
+    }
+
+    #[test]
+    fn test_with_battle_agent_delegates_battles() {
+        let mut game = game();
+        let mut agent = WithBattleAgent::new(RuleBasedGameAgent::new(), MCTS::<Battle>::new(20, 1.41).with_rollout_cutoff(3));
+        let mut rng = rand::rng();
+        assert_eq!(agent.name(), "RuleBasedGame+MCTS-Expectimax");
+
+        // The rule-based agent walks into the combat room, then MCTS plays the battle
+        let action = agent.select_action(&game, &mut rng);
+        game.eval_action(action, &mut rng).unwrap();
+        assert_eq!(game.get_game_state(), &GameState::InBattle);
+
+        let action = agent.select_action(&game, &mut rng);
+        assert!(matches!(action, GameAction::Battle(_)));
+        assert!(ForwardSimulation::list_available_actions(&game).contains(&action));
+    }
+
+    #[test]
+    fn test_rule_based_shop_choices_are_listed() {
+        use crate::game::shop::ShopState;
+
+        let mut rng = rand::rng();
+        let mut agent = RuleBasedGameAgent::new();
+        for gold in [0, 60, 150, 1000] {
+            for _ in 0..10 {
+                let mut game = game();
+                game.gold = gold;
+                game.set_game_state(GameState::Shop(ShopState::new_with_colorless(5, &mut rng)));
+
+                let action = agent.select_action(&game, &mut rng);
+                assert!(ForwardSimulation::list_available_actions(&game).contains(&action), "{:?} is not listed", action);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_based_choices_are_listed_through_a_run() {
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let mut game = Game::new(starter_deck(), global_info, crate::map::test_map_large(), 80, 80);
+        let mut agent = RuleBasedGameAgent::new();
+        let mut rng = rand::rng();
+
+        for _ in 0..500 {
+            let actions = ForwardSimulation::list_available_actions(&game);
+            // The test map ends without a boss, nothing is left to choose after its last room
+            if ForwardSimulation::is_terminal(&game) || actions.is_empty() {
+                break;
+            }
+            let action = agent.select_action(&game, &mut rng);
+            assert!(actions.contains(&action), "{:?} is not in {:?}", action, actions);
+            game.eval_action(action, &mut rng).unwrap();
+        }
+    }
+}
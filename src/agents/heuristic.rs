@@ -0,0 +1,363 @@
+/// Rule-based battle agent
+///
+/// Reads the card effects and the enemy intents instead of simulating, so it is deterministic
+/// and cheap enough to drive MCTS rollouts while still playing like a sensible beginner.
+/// On its turn it takes the first rule that applies:
+/// 1. Drink potions in elite fights, or when the incoming damage would kill.
+/// 2. Play an attack that kills an enemy, the cheapest one first.
+/// 3. Block when the unblocked incoming damage reaches `block_threshold`, or when Body Slam
+///    is in hand (it hits for the block).
+/// 4. Play powers.
+/// 5. Apply Vulnerable before the other attacks.
+/// 6. Play the attack dealing the most damage, Whirlwind only with full energy.
+/// 7. Play whatever else can be played, then end the turn.
+///
+/// Card selections (discard, exhaust, choices...) take the first option offered.
+use crate::battle::{Battle, battle_action::BattleAction, battle_state::BattleState, target::Entity};
+use crate::enemies::enemy_enum::EnemyEnum;
+use crate::game::{card::Card, card_enum::CardEnum, card_type::CardType, effect::{BattleEffect, TargetSelector}};
+use super::rollout_policy::RolloutPolicy;
+use super::traits::Agent;
+use rand::RngCore;
+
+/// Heuristic battle agent, see the module documentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeuristicAgent {
+    /// Unblocked incoming damage from which block cards are played before attacks
+    pub block_threshold: u32,
+}
+
+impl Default for HeuristicAgent {
+    fn default() -> Self {
+        HeuristicAgent { block_threshold: 5 }
+    }
+}
+
+/// A card play on offer with its card
+struct Play<'a> {
+    action: &'a BattleAction,
+    card: &'a Card,
+    target: Entity,
+    cost: u32,
+}
+
+impl HeuristicAgent {
+    /// Create a heuristic agent with the default block threshold
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder pattern method to set the unblocked damage from which the agent blocks
+    pub fn with_block_threshold(mut self, block_threshold: u32) -> Self {
+        self.block_threshold = block_threshold;
+        self
+    }
+
+    /// Pick one of `actions`, the legal actions of `battle` (or a subset of them)
+    pub fn choose_action(&self, battle: &Battle, actions: &[BattleAction]) -> BattleAction {
+        assert!(!actions.is_empty(), "No available actions - battle should be over");
+        if battle.get_battle_state() != BattleState::PlayerTurn {
+            return actions[0].clone();
+        }
+
+        let hand = battle.get_hand();
+        let plays: Vec<Play> = actions.iter()
+            .filter_map(|action| match action {
+                BattleAction::PlayCard(index, target) => hand.get(*index).map(|card| Play {
+                    action,
+                    card,
+                    target: *target,
+                    cost: battle.get_modified_cost(card),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let unblocked = incoming_damage(battle).saturating_sub(battle.get_player().get_block());
+
+        // 1. Potions
+        if (is_elite_fight(battle) || unblocked >= battle.get_current_hp())
+            && let Some(potion) = actions.iter().find(|action| matches!(action, BattleAction::UsePotion(..))) {
+            return potion.clone();
+        }
+
+        // 2. Lethal
+        let lethal = plays.iter()
+            .filter(|play| play.card.get_card_type() == CardType::Attack && self.worth_playing(battle, play))
+            .filter(|play| {
+                damage_by_enemy(battle, play.card, play.target).iter().enumerate()
+                    .any(|(index, damage)| *damage > 0 && *damage >= effective_hp(battle, index))
+            })
+            .min_by_key(|play| play.cost);
+        if let Some(play) = lethal {
+            return play.action.clone();
+        }
+
+        // 3. Block
+        let body_slam_in_hand = hand.iter().any(|card| card.get_card_enum() == CardEnum::BodySlam);
+        if (unblocked >= self.block_threshold.max(1) || body_slam_in_hand)
+            && let Some(play) = best_by(&plays, |play| block_gain(battle, play.card)) {
+            return play.action.clone();
+        }
+
+        // 4. Powers
+        if let Some(play) = plays.iter().find(|play| play.card.get_card_type() == CardType::Power) {
+            return play.action.clone();
+        }
+
+        // 5. Vulnerable, when there are other attacks to follow it up
+        let attacks: Vec<&Play> = plays.iter()
+            .filter(|play| play.card.get_card_type() == CardType::Attack && self.worth_playing(battle, play))
+            .collect();
+        let vulnerable = attacks.iter().find(|play| {
+            applies_vulnerable(play.card)
+                && matches!(play.target, Entity::Enemy(index) if battle.get_enemies()[index].get_vulnerable() == 0)
+                && attacks.iter().any(|other| !std::ptr::eq(other.card, play.card))
+        });
+        if let Some(play) = vulnerable {
+            return play.action.clone();
+        }
+
+        // 6. Attacks
+        let attack = attacks.iter()
+            .map(|play| (play, damage_by_enemy(battle, play.card, play.target).iter().sum::<u32>()))
+            .filter(|(_, damage)| *damage > 0)
+            .max_by(|(a, a_damage), (b, b_damage)| a_damage.cmp(b_damage).then(b.cost.cmp(&a.cost)));
+        if let Some((play, _)) = attack {
+            return play.action.clone();
+        }
+
+        // 7. Anything else that helps, curses and statuses stay in hand
+        let other = plays.iter().find(|play| {
+            !matches!(play.card.get_card_type(), CardType::Curse | CardType::Status) && self.worth_playing(battle, play)
+        });
+        match other {
+            Some(play) => play.action.clone(),
+            None => actions.iter().find(|action| **action == BattleAction::EndTurn).unwrap_or(&actions[0]).clone(),
+        }
+    }
+
+    /// Card-specific rules: Whirlwind and other X-cost cards only with full energy, Body Slam only with block
+    fn worth_playing(&self, battle: &Battle, play: &Play) -> bool {
+        if play.card.is_x_cost() {
+            let energy = battle.get_player().get_energy();
+            return energy > 0 && energy >= battle.energy_per_turn();
+        }
+        if play.card.get_card_enum() == CardEnum::BodySlam {
+            return battle.get_player().get_block() > 0;
+        }
+        true
+    }
+}
+
+/// The play with the highest positive score, the first one on ties
+fn best_by<'a, 'b>(plays: &'b [Play<'a>], score: impl Fn(&Play) -> u32) -> Option<&'b Play<'a>> {
+    let mut best: Option<(&Play, u32)> = None;
+    for play in plays {
+        let value = score(play);
+        if value > 0 && best.is_none_or(|(_, best_value)| value > best_value) {
+            best = Some((play, value));
+        }
+    }
+    best.map(|(play, _)| play)
+}
+
+/// Elite fights are where potions pay off (no boss is implemented yet)
+fn is_elite_fight(battle: &Battle) -> bool {
+    battle.get_enemies().iter().any(|enemy| {
+        enemy.battle_info.is_alive()
+            && matches!(enemy.enemy, EnemyEnum::GremlinNob(_) | EnemyEnum::Lagavulin(_) | EnemyEnum::Sentry(_))
+    })
+}
+
+/// Damage the living enemies' intents will deal to the player, before block
+fn incoming_damage(battle: &Battle) -> u32 {
+    let mut total = 0;
+    for (index, enemy) in battle.get_enemies().iter().enumerate() {
+        if !enemy.battle_info.is_alive() {
+            continue;
+        }
+        let Some((_, effects)) = battle.get_enemy_move_and_effects(index) else { continue };
+        for effect in effects {
+            if let BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier } = effect {
+                total += battle.calculate_incoming_damage_with_multiplier(Entity::Enemy(index), Entity::Player, *amount, *strength_multiplier) * num_attacks;
+            }
+        }
+    }
+    total
+}
+
+/// HP plus block of a living enemy, 0 for dead ones
+fn effective_hp(battle: &Battle, index: usize) -> u32 {
+    let enemy = &battle.get_enemies()[index];
+    if enemy.battle_info.is_alive() {
+        enemy.get_current_hp() + enemy.battle_info.get_block()
+    } else {
+        0
+    }
+}
+
+/// Estimated attack damage of playing `card` on `target`, for each enemy
+fn damage_by_enemy(battle: &Battle, card: &Card, target: Entity) -> Vec<u32> {
+    let mut damage = vec![0; battle.get_enemies().len()];
+    for effect in card.get_effects() {
+        add_damage(battle, effect, target, 1, &mut damage);
+    }
+    damage
+}
+
+fn add_damage(battle: &Battle, effect: &BattleEffect, target: Entity, times: u32, damage: &mut [u32]) {
+    match effect {
+        BattleEffect::Targeted { selector: TargetSelector::AllEnemies, effect } => {
+            for (index, enemy) in battle.get_enemies().iter().enumerate() {
+                if enemy.battle_info.is_alive() {
+                    add_damage(battle, effect, Entity::Enemy(index), times, damage);
+                }
+            }
+        }
+        BattleEffect::Targeted { selector: TargetSelector::ChosenTarget, effect } => add_damage(battle, effect, target, times, damage),
+        BattleEffect::RepeatX { effect, extra } => {
+            let repeats = battle.get_player().get_energy() + extra;
+            add_damage(battle, effect, target, times * repeats, damage);
+        }
+        BattleEffect::XAmount { effect, extra } => {
+            let amount = battle.get_player().get_energy() + extra;
+            add_damage(battle, &effect.as_ref().clone().with_amount(amount), target, times, damage);
+        }
+        _ => {
+            let Entity::Enemy(index) = target else { return };
+            let hit = |base: u32, strength_multiplier: u32| {
+                battle.calculate_incoming_damage_with_multiplier(Entity::Player, target, base, strength_multiplier)
+            };
+            let amount = match effect {
+                BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier } => hit(*amount, *strength_multiplier) * num_attacks,
+                BattleEffect::AttackToTargetWithBlock => hit(battle.get_player().get_block(), 1),
+                BattleEffect::AttackToTargetWithScaling { base_damage, .. } => hit(*base_damage, 1),
+                BattleEffect::PerfectedStrike { base_damage, damage_per_strike } => {
+                    hit(base_damage + damage_per_strike * battle.count_strike_cards_in_deck(), 1)
+                }
+                _ => 0,
+            };
+            if let Some(total) = damage.get_mut(index) {
+                *total += amount * times;
+            }
+        }
+    }
+}
+
+/// Estimated block the player gains from playing `card`
+fn block_gain(battle: &Battle, card: &Card) -> u32 {
+    let dexterity = battle.get_player().get_dexterity();
+    card.get_effects().iter()
+        .map(|effect| match effect {
+            BattleEffect::GainDefense { amount } => (*amount as i32 + dexterity).max(0) as u32,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn applies_vulnerable(card: &Card) -> bool {
+    card.get_effects().iter().any(|effect| match effect {
+        BattleEffect::ApplyVulnerable { .. } => true,
+        BattleEffect::Targeted { effect, .. } => matches!(**effect, BattleEffect::ApplyVulnerable { .. }),
+        _ => false,
+    })
+}
+
+impl Agent for HeuristicAgent {
+    fn select_action(&mut self, battle: &Battle, _rng: &mut impl rand::Rng) -> BattleAction {
+        self.choose_action(battle, &battle.list_available_actions())
+    }
+
+    fn name(&self) -> &str {
+        "Heuristic"
+    }
+}
+
+impl RolloutPolicy<Battle> for HeuristicAgent {
+    fn select_action(&mut self, state: &Battle, actions: &[BattleAction], _rng: &mut dyn RngCore) -> BattleAction {
+        self.choose_action(state, actions)
+    }
+
+    fn name(&self) -> &str {
+        "Heuristic"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::MCTS;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::{bash::bash, body_slam::body_slam, defend::defend, strike::strike, whirlwind::whirlwind};
+    use crate::enemies::{cultist::Cultist, jaw_worm::JawWorm};
+
+    /// A battle whose whole deck (at most 5 cards) is the opening hand. The Jaw Worm opens with
+    /// an 11 damage Chomp, the Cultist with Incantation, which does not attack.
+    fn battle(enemy: EnemyEnum, cards: Vec<Card>) -> Battle {
+        BattleBuilder::new().with_deck(cards).add_enemy(enemy).build()
+    }
+
+    fn jaw_worm(hp: u32) -> EnemyEnum {
+        EnemyEnum::JawWorm(JawWorm::new(hp, false))
+    }
+
+    fn cultist() -> EnemyEnum {
+        EnemyEnum::Cultist(Cultist::new(50, 3))
+    }
+
+    fn chosen_card(battle: &Battle) -> Option<CardEnum> {
+        match HeuristicAgent::new().choose_action(battle, &battle.list_available_actions()) {
+            BattleAction::PlayCard(index, _) => Some(battle.get_hand()[index].get_card_enum()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_blocks_against_big_attacks_and_attacks_otherwise() {
+        let threatened = battle(jaw_worm(40), vec![strike(), defend()]);
+        assert_eq!(chosen_card(&threatened), Some(CardEnum::Defend));
+
+        let safe = battle(cultist(), vec![strike(), defend()]);
+        assert_eq!(chosen_card(&safe), Some(CardEnum::Strike));
+    }
+
+    #[test]
+    fn test_lethal_comes_before_block() {
+        let battle = battle(jaw_worm(6), vec![defend(), strike()]);
+        assert_eq!(chosen_card(&battle), Some(CardEnum::Strike));
+    }
+
+    #[test]
+    fn test_applies_vulnerable_before_attacking() {
+        let battle = battle(cultist(), vec![strike(), bash()]);
+        assert_eq!(chosen_card(&battle), Some(CardEnum::Bash));
+    }
+
+    #[test]
+    fn test_body_slam_waits_for_block() {
+        let battle = battle(cultist(), vec![body_slam(), defend()]);
+        assert_eq!(chosen_card(&battle), Some(CardEnum::Defend));
+    }
+
+    #[test]
+    fn test_whirlwind_only_with_full_energy() {
+        let mut battle = battle(cultist(), vec![whirlwind(), defend()]);
+        assert_eq!(chosen_card(&battle), Some(CardEnum::Whirlwind));
+
+        let defend_index = battle.get_hand().iter().position(|card| card.get_card_enum() == CardEnum::Defend).unwrap();
+        battle.eval_action(BattleAction::PlayCard(defend_index, Entity::Player), &mut rand::rng()).unwrap();
+        assert_eq!(chosen_card(&battle), None);
+        assert!(battle.list_available_actions().iter().any(|action| matches!(action, BattleAction::PlayCard(..))));
+    }
+
+    #[test]
+    fn test_heuristic_drives_mcts_rollouts() {
+        let battle = battle(jaw_worm(40), vec![strike(), strike(), defend(), defend(), bash()]);
+        let mut agent = MCTS::new(20, 1.41).with_rollout_policy(HeuristicAgent::new());
+
+        let action = Agent::select_action(&mut agent, &battle, &mut rand::rng());
+        assert!(battle.list_available_actions().contains(&action));
+        assert_eq!(Agent::name(&HeuristicAgent::new()), "Heuristic");
+    }
+}
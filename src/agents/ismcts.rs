@@ -0,0 +1,291 @@
+/// Information set Monte Carlo Tree Search (single observer)
+///
+/// `MCTS` searches the true state, so it plays as if it knew the draw pile order. ISMCTS plays
+/// every iteration on a fresh determinization of the root (`Determinize::determinize`) and keys
+/// its nodes by what the player observes (`Determinize::observation`), so the statistics of all
+/// determinizations are gathered in the same nodes and the chosen action only depends on what the
+/// player can know. Use it for agent strength numbers meant to compare with human play.
+///
+/// An action is not legal in every determinization of a node, so its UCT exploration term counts
+/// the visits where it was available rather than all visits of the node.
+use super::decision_node::state_hash;
+use super::determinize::Determinize;
+use super::evaluator::Evaluator;
+use super::mcts::MCTS;
+use super::rollout_policy::RolloutPolicy;
+use super::traits::Agent;
+use std::collections::HashMap;
+
+/// The best action with (action, visits, q_value) for every root action, as `MCTS::select_action` returns
+type SearchResult<A> = (A, Vec<(A, usize, f32)>);
+
+/// Statistics of an action taken from an information set
+struct ActionEdge<A> {
+    action: A,
+    visits: usize,
+    total_reward: f32,
+    /// Visits to the node in which the action was legal
+    availability: usize,
+}
+
+impl<A> ActionEdge<A> {
+    fn avg_reward(&self) -> f32 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.total_reward / self.visits as f32
+        }
+    }
+}
+
+/// Node standing for every state with the same observation
+struct InformationSetNode<A> {
+    visits: usize,
+    edges: Vec<ActionEdge<A>>,
+}
+
+/// MCTS over information sets, see the module documentation
+pub struct ISMCTS<S: Determinize> {
+    /// Number of iterations (determinizations searched) per action selection
+    pub iterations: usize,
+    /// UCT exploration constant (typically sqrt(2) ≈ 1.41)
+    pub exploration_constant: f32,
+
+    /// Plays the rollouts, with its rollout policy, evaluator and rollout settings
+    rollouts: MCTS<S>,
+
+    nodes: Vec<InformationSetNode<S::Action>>,
+    /// Maps the hash of an observation to its node ID
+    node_ids: HashMap<u64, usize>,
+}
+
+impl<S: Determinize> ISMCTS<S> {
+    /// Create a new ISMCTS agent
+    ///
+    /// # Arguments
+    /// * `iterations` - Number of iterations per action selection (e.g., 1000)
+    /// * `exploration_constant` - UCT exploration parameter (typically 1.41)
+    pub fn new(iterations: usize, exploration_constant: f32) -> Self {
+        ISMCTS {
+            iterations,
+            exploration_constant,
+            rollouts: MCTS::new(0, exploration_constant),
+            nodes: Vec::new(),
+            node_ids: HashMap::new(),
+        }
+    }
+
+    /// Builder pattern method to set the rollout policy (uniformly random by default)
+    pub fn with_rollout_policy(mut self, policy: impl RolloutPolicy<S> + Send + 'static) -> Self {
+        self.rollouts = self.rollouts.with_rollout_policy(policy);
+        self
+    }
+
+    /// Builder pattern method to score rollouts with the evaluator after `depth` steps
+    pub fn with_rollout_cutoff(mut self, depth: usize) -> Self {
+        self.rollouts = self.rollouts.with_rollout_cutoff(depth);
+        self
+    }
+
+    /// Builder pattern method to set the evaluator scoring terminal states and cut rollouts
+    pub fn with_evaluator(mut self, evaluator: impl Evaluator<S> + Send + 'static) -> Self {
+        self.rollouts = self.rollouts.with_evaluator(evaluator);
+        self
+    }
+
+    /// Number of information set nodes in the tree
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Drop the whole tree
+    pub fn reset(&mut self) {
+        self.nodes.clear();
+        self.node_ids.clear();
+    }
+
+    /// Run ISMCTS on a fresh tree and return both the best action and statistics for all explored actions
+    /// Returns (best_action, Vec<(action, visits, q_value)>)
+    pub fn select_action(&mut self, root_state: &S, rng: &mut impl rand::Rng) -> SearchResult<S::Action> {
+        self.reset();
+        let (root_id, _) = self.node_for(root_state);
+
+        for _ in 0..self.iterations {
+            self.run_iteration(root_id, root_state, rng);
+        }
+
+        let stats: Vec<(S::Action, usize, f32)> = self.nodes[root_id].edges.iter()
+            .map(|edge| (edge.action.clone(), edge.visits, edge.avg_reward()))
+            .collect();
+        let best_action = stats.iter()
+            .max_by_key(|(_, visits, _)| *visits)
+            .map(|(action, _, _)| action.clone())
+            .unwrap_or_else(|| root_state.list_available_actions()[0].clone());
+
+        (best_action, stats)
+    }
+
+    /// The node of the state's observation, and whether it was just created
+    fn node_for(&mut self, state: &S) -> (usize, bool) {
+        let hash = state_hash(&state.observation());
+        if let Some(&node_id) = self.node_ids.get(&hash) {
+            return (node_id, false);
+        }
+
+        let node_id = self.nodes.len();
+        self.nodes.push(InformationSetNode { visits: 0, edges: Vec::new() });
+        self.node_ids.insert(hash, node_id);
+        (node_id, true)
+    }
+
+    /// One iteration on a new determinization: select down the tree, expand one node, roll out, backpropagate
+    fn run_iteration(&mut self, root_id: usize, root_state: &S, rng: &mut impl rand::Rng) {
+        let mut state = root_state.determinize(rng);
+        let mut node_id = root_id;
+        // Track path: (node_id, edge index)
+        let mut path: Vec<(usize, usize)> = Vec::new();
+
+        while !state.is_terminal() {
+            let available_actions = state.list_available_actions();
+            if available_actions.is_empty() {
+                break;
+            }
+
+            let edge_index = self.select_edge(node_id, &available_actions);
+            path.push((node_id, edge_index));
+
+            let action = self.nodes[node_id].edges[edge_index].action.clone();
+            if let Err(error) = state.eval_action(action.clone(), rng) {
+                panic!(
+                    "ISMCTS Bug: eval_action failed for an action from list_available_actions()\n\
+                     State Type: {}\n\
+                     Failed Action: {:?}\n\
+                     Error: {:?}",
+                    std::any::type_name::<S>(),
+                    action,
+                    error
+                );
+            }
+
+            // A new node is a leaf: the rollout starts from it
+            let (child_id, is_new) = self.node_for(&state);
+            if is_new {
+                break;
+            }
+            node_id = child_id;
+        }
+
+        let reward = self.rollouts.rollout(state, rng);
+        for (node_id, edge_index) in path {
+            let node = &mut self.nodes[node_id];
+            node.visits += 1;
+            node.edges[edge_index].visits += 1;
+            node.edges[edge_index].total_reward += reward;
+        }
+    }
+
+    /// Pick the action to take from a node among the actions legal in this determinization:
+    /// an untried one first, otherwise the best by UCT with availability counts
+    /// Returns the index of its edge
+    fn select_edge(&mut self, node_id: usize, available_actions: &[S::Action]) -> usize {
+        let node = &mut self.nodes[node_id];
+        for edge in node.edges.iter_mut() {
+            if available_actions.contains(&edge.action) {
+                edge.availability += 1;
+            }
+        }
+
+        if let Some(action) = available_actions.iter().find(|action| node.edges.iter().all(|edge| edge.action != **action)) {
+            node.edges.push(ActionEdge { action: action.clone(), visits: 0, total_reward: 0.0, availability: 1 });
+            return node.edges.len() - 1;
+        }
+
+        let exploration_constant = self.exploration_constant;
+        let uct = |edge: &ActionEdge<S::Action>| {
+            if edge.visits == 0 {
+                return f32::INFINITY;
+            }
+            let exploration = exploration_constant * ((edge.availability as f32).ln() / edge.visits as f32).sqrt();
+            edge.avg_reward() + exploration
+        };
+
+        node.edges.iter()
+            .enumerate()
+            .filter(|(_, edge)| available_actions.contains(&edge.action))
+            .max_by(|(_, a), (_, b)| uct(a).partial_cmp(&uct(b)).expect("UCT values should be comparable"))
+            .map(|(index, _)| index)
+            .expect("Some action is available")
+    }
+}
+
+impl Agent for ISMCTS<crate::battle::Battle> {
+    fn select_action(&mut self, state: &crate::battle::Battle, rng: &mut impl rand::Rng) -> crate::battle::battle_action::BattleAction {
+        let (action, _stats) = ISMCTS::select_action(self, state, rng);
+        action
+    }
+
+    fn name(&self) -> &str {
+        "ISMCTS"
+    }
+
+    fn reset(&mut self) {
+        ISMCTS::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::Battle;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::starter_deck::starter_deck;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    fn battle() -> Battle {
+        BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build()
+    }
+
+    #[test]
+    fn test_ismcts_selects_valid_action() {
+        let state = battle();
+        let mut agent = ISMCTS::new(50, 1.41).with_rollout_cutoff(3);
+        let mut rng = rand::rng();
+
+        let (action, stats) = agent.select_action(&state, &mut rng);
+        assert!(state.list_available_actions().contains(&action));
+        assert_eq!(stats.iter().map(|(_, visits, _)| visits).sum::<usize>(), 50);
+        assert_eq!(agent.name(), "ISMCTS");
+    }
+
+    #[test]
+    fn test_determinizations_share_nodes() {
+        let state = battle();
+        let mut agent = ISMCTS::<Battle>::new(0, 1.41);
+        let mut rng = rand::rng();
+
+        let (root_id, _) = agent.node_for(&state);
+        for _ in 0..10 {
+            assert_eq!(agent.node_for(&state.determinize(&mut rng)), (root_id, false));
+        }
+        assert_eq!(agent.node_count(), 1);
+    }
+
+    #[test]
+    fn test_availability_counts_visits_where_the_action_was_legal() {
+        let mut agent = ISMCTS::<Battle>::new(0, 1.41);
+        let (node_id, _) = agent.node_for(&battle());
+        let end_turn = crate::battle::battle_action::BattleAction::EndTurn;
+        let play = crate::battle::battle_action::BattleAction::PlayCard(0, crate::battle::target::Entity::Enemy(0));
+
+        assert_eq!(agent.select_edge(node_id, &[end_turn.clone(), play.clone()]), 0);
+        assert_eq!(agent.select_edge(node_id, &[end_turn.clone(), play.clone()]), 1);
+        agent.select_edge(node_id, std::slice::from_ref(&end_turn));
+
+        let availability: Vec<usize> = agent.nodes[node_id].edges.iter().map(|edge| edge.availability).collect();
+        // PlayCard was only legal since it was added
+        assert_eq!(availability, vec![3, 1]);
+    }
+}
@@ -7,11 +7,29 @@
 /// Tree structure:
 /// Decision Node (state) -> [Chance Node (action1), Chance Node (action2), ...]
 /// Chance Node (action) -> [Decision Node (outcome1), Decision Node (outcome2), ...]
+///
+/// Chance nodes sample a fixed number of outcomes, or more as they get visited with progressive widening,
+/// and can opt into no longer sampling actions that always lead to the same state and into enumerating
+/// small outcome distributions exactly.
 use crate::agents::ForwardSimulation;
 use super::traits::Agent;
-use super::decision_node::MCTSDecisionNode;
-use super::chance_node::MCTSChanceNode;
-use std::collections::HashMap;
+use super::decision_node::{state_hash, MCTSDecisionNode};
+use super::chance_node::{MCTSChanceNode, ProgressiveWidening};
+use super::rollout_policy::{RandomRollout, RolloutPolicy};
+use super::evaluator::{DefaultEvaluator, Evaluator};
+use super::budget::{SearchBudget, UniformBudget};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Which decision nodes keep a copy of their state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateStorage {
+    /// Every node stores its state, transpositions are checked against the stored state
+    AllNodes,
+    /// Only the root stores its state, the others keep its hash and are reached by replaying
+    /// actions from the root (much less memory for large states like `Game`)
+    RootOnly,
+}
 
 /// MCTS agent with integrated tree structure
 pub struct MCTS<S: ForwardSimulation> {
@@ -21,16 +39,50 @@ pub struct MCTS<S: ForwardSimulation> {
     pub exploration_constant: f32,
     /// Maximum depth for rollout simulations (None = until terminal)
     pub max_rollout_depth: Option<usize>,
-    /// Number of samples per chance node to estimate outcomes
+    /// Outcomes sampled per chance node when progressive widening is off
     pub samples_per_action: usize,
+    /// Outcomes a chance node may sample as it gets visited (None = `samples_per_action`)
+    pub widening: Option<ProgressiveWidening>,
+    /// Samples after which an action that always led to the same state is treated as
+    /// deterministic and no longer sampled (None = always sample)
+    pub deterministic_after: Option<usize>,
+    /// Largest outcome distribution enumerated exactly with `ForwardSimulation::outcome_distribution`
+    /// instead of sampled (None = always sample; needs `StateStorage::AllNodes`)
+    pub exact_outcomes: Option<usize>,
+    /// Rollout steps after which the rollout stops and the state is scored by the evaluator
+    /// (None = roll out until terminal or the depth limit, Some(0) = score new leaves directly)
+    pub rollout_cutoff: Option<usize>,
+
+    /// Picks the actions played during rollouts
+    rollout_policy: Box<dyn RolloutPolicy<S> + Send>,
+    /// Scores terminal states and rollouts that were cut short
+    evaluator: Box<dyn Evaluator<S> + Send>,
+
+    /// Which decision nodes store their state
+    pub state_storage: StateStorage,
+    /// Most decision and chance nodes kept, the least visited are evicted beyond it (None = unbounded)
+    pub max_nodes: Option<usize>,
+    /// Fraction of `max_nodes` an eviction brings the tree down to, so the tree is only
+    /// pruned once per batch of new nodes rather than every iteration
+    pub node_low_water: f32,
+    /// Whether `select_action` first drops everything outside the subtree of its root state
+    pub reroot_on_select: bool,
+    /// Wall-clock time per action selection, the search stops at this or `iterations`,
+    /// whichever comes first (None = iterations only)
+    pub time_limit: Option<Duration>,
+    /// Stop as soon as no other action can catch up with the most visited one in the remaining budget
+    pub early_stop: bool,
+
+    /// Scales the iterations and time limit per decision
+    budget: Box<dyn SearchBudget<S> + Send>,
 
     /// Tree structure
     decision_nodes: Vec<MCTSDecisionNode<S>>,
     chance_nodes: Vec<MCTSChanceNode<S::Action>>,
 
-    /// Global transposition table: maps any state to its decision node ID
+    /// Global transposition table: maps the hash of any state to its decision node ID
     /// Allows reuse of nodes across different parts of the tree and different root states
-    global_transposition_table: HashMap<S, usize>,
+    global_transposition_table: HashMap<u64, usize>,
 }
 
 impl<S: ForwardSimulation> MCTS<S> {
@@ -44,39 +96,168 @@ impl<S: ForwardSimulation> MCTS<S> {
             iterations,
             exploration_constant,
             max_rollout_depth: Some(100),
-            samples_per_action: 3, // Sample each action 3 times to estimate distribution
+            samples_per_action: 3,
+            widening: None,
+            deterministic_after: None,
+            exact_outcomes: None,
+            rollout_cutoff: None,
+            rollout_policy: Box::new(RandomRollout),
+            evaluator: Box::new(DefaultEvaluator),
+            state_storage: StateStorage::AllNodes,
+            max_nodes: None,
+            node_low_water: 0.75,
+            reroot_on_select: false,
+            time_limit: None,
+            early_stop: false,
+            budget: Box::new(UniformBudget),
             decision_nodes: Vec::new(), // Start empty, roots created dynamically
             chance_nodes: Vec::new(),
             global_transposition_table: HashMap::new(),
         }
     }
 
+    /// Builder pattern method to set how the number of outcomes per chance node grows with its visits
+    pub fn with_progressive_widening(mut self, constant: f32, alpha: f32) -> Self {
+        self.widening = Some(ProgressiveWidening { constant, alpha });
+        self
+    }
+
+    /// Builder pattern method to stop sampling an action that led to the same state `samples` times in a row
+    pub fn with_deterministic_after(mut self, samples: usize) -> Self {
+        self.deterministic_after = Some(samples);
+        self
+    }
+
+    /// Builder pattern method to sample a fixed number of outcomes per chance node instead of widening
+    pub fn with_fixed_samples(mut self, samples_per_action: usize) -> Self {
+        self.widening = None;
+        self.samples_per_action = samples_per_action;
+        self
+    }
+
+    /// Builder pattern method to enumerate outcome distributions of at most `max_outcomes` outcomes
+    pub fn with_exact_outcomes(mut self, max_outcomes: usize) -> Self {
+        self.exact_outcomes = Some(max_outcomes);
+        self
+    }
+
+    /// Builder pattern method to set the rollout policy (uniformly random by default)
+    pub fn with_rollout_policy(mut self, policy: impl RolloutPolicy<S> + Send + 'static) -> Self {
+        self.rollout_policy = Box::new(policy);
+        self
+    }
+
+    /// Builder pattern method to stop rollouts after `depth` steps and score the state statically
+    pub fn with_rollout_cutoff(mut self, depth: usize) -> Self {
+        self.rollout_cutoff = Some(depth);
+        self
+    }
+
+    /// Builder pattern method to set the state evaluator (the state's own `evaluate` by default)
+    pub fn with_evaluator(mut self, evaluator: impl Evaluator<S> + Send + 'static) -> Self {
+        self.evaluator = Box::new(evaluator);
+        self
+    }
+
+    /// Builder pattern method to set which nodes store their state
+    pub fn with_state_storage(mut self, state_storage: StateStorage) -> Self {
+        self.state_storage = state_storage;
+        self
+    }
+
+    /// Builder pattern method to bound the number of nodes in the tree
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Builder pattern method to drop everything outside the subtree of the root state on each `select_action`
+    pub fn with_reroot_on_select(mut self) -> Self {
+        self.reroot_on_select = true;
+        self
+    }
+
+    /// Builder pattern method to set the fraction of `max_nodes` left after an eviction
+    pub fn with_node_low_water(mut self, fraction: f32) -> Self {
+        self.node_low_water = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Builder pattern method to search for a wall-clock duration per action selection
+    /// Iterations still cap the search, use `usize::MAX` iterations for a time-only budget
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Builder pattern method to stop once the most visited action can no longer be overtaken
+    pub fn with_early_stop(mut self) -> Self {
+        self.early_stop = true;
+        self
+    }
+
+    /// Builder pattern method to scale the search per decision
+    pub fn with_budget(mut self, budget: impl SearchBudget<S> + Send + 'static) -> Self {
+        self.budget = Box::new(budget);
+        self
+    }
+
+    /// Number of decision and chance nodes in the tree
+    pub fn node_count(&self) -> usize {
+        self.decision_nodes.len() + self.chance_nodes.len()
+    }
+
+    /// Drop the whole tree
+    pub fn reset(&mut self) {
+        self.decision_nodes.clear();
+        self.chance_nodes.clear();
+        self.global_transposition_table.clear();
+    }
+
+    pub fn evaluator_name(&self) -> &str {
+        self.evaluator.name()
+    }
+
+    pub fn rollout_policy_name(&self) -> &str {
+        self.rollout_policy.name()
+    }
+
     /// Run MCTS and return both the best action and statistics for all explored actions
     /// Returns (best_action, Vec<(action, visits, q_value)>)
     pub fn select_action(&mut self, root_state: &S, rng: &mut impl rand::Rng)
         -> (S::Action, Vec<(S::Action, usize, f32)>) {
-        // Check if this state already exists in global transposition table
-        let root_id = if let Some(&existing_id) = self.global_transposition_table.get(root_state) {
-            // Reuse existing decision node as root
-            existing_id
-        } else {
-            // Create new decision node for this root state
-            let new_id = self.decision_nodes.len();
-            self.decision_nodes.push(MCTSDecisionNode::new(None, root_state.clone()));
-            self.global_transposition_table.insert(root_state.clone(), new_id);
-            new_id
-        };
+        if self.reroot_on_select {
+            self.reroot(root_state);
+        }
+        let mut root_id = self.root_node(root_state);
+
+        let scale = self.budget.scale(root_state, &root_state.list_available_actions()).max(0.0);
+        let iterations = (self.iterations as f64 * scale as f64) as usize;
+        let time_limit = self.time_limit.map(|limit| limit.mul_f32(scale));
+        let start = Instant::now();
 
         // Run MCTS iterations
-        for _ in 0..self.iterations {
+        for done in 1..=iterations {
+            if time_limit.is_some_and(|limit| start.elapsed() >= limit) {
+                break;
+            }
+
             // Clone state for this iteration
             let state = root_state.clone();
 
             // Run one iteration: select, expand, simulate, backpropagate
-            let reward = self.run_iteration(root_id, state, rng);
+            self.run_iteration(root_id, state, rng);
+
+            if let Some(max_nodes) = self.max_nodes {
+                root_id = self.enforce_node_budget(root_id, max_nodes);
+            }
 
-            // Backpropagation is handled within run_iteration
-            let _ = reward; // Suppress unused warning
+            if self.early_stop {
+                let remaining = remaining_iterations(iterations - done, time_limit, start.elapsed(), done);
+                if self.best_action_is_decided(root_id, remaining) {
+                    break;
+                }
+            }
         }
 
         // Select best action and get statistics
@@ -86,13 +267,171 @@ impl<S: ForwardSimulation> MCTS<S> {
         (best_action, stats)
     }
 
+    /// Whether the most visited root action stays the most visited whatever the next
+    /// `remaining` iterations do (untried actions count as 0 visits)
+    fn best_action_is_decided(&self, root_id: usize, remaining: usize) -> bool {
+        let mut visits: Vec<usize> = self.decision_nodes[root_id].children.iter()
+            .map(|&child_id| self.chance_nodes[child_id].visits)
+            .collect();
+        visits.sort_unstable_by(|a, b| b.cmp(a));
+
+        match visits.as_slice() {
+            [] => false,
+            [best] => *best > remaining,
+            [best, second, ..] => best - second > remaining,
+        }
+    }
+
+    /// The decision node for a root state, reusing it from the global transposition table when present
+    pub(super) fn root_node(&mut self, root_state: &S) -> usize {
+        let hash = state_hash(root_state);
+        if let Some(existing_id) = self.find_node(root_state, hash) {
+            // The root always keeps its state
+            let root = &mut self.decision_nodes[existing_id];
+            if root.state.is_none() {
+                root.state = Some(root_state.clone());
+            }
+            return existing_id;
+        }
+
+        let new_id = self.decision_nodes.len();
+        self.decision_nodes.push(MCTSDecisionNode::new(None, root_state.clone()));
+        self.global_transposition_table.insert(hash, new_id);
+        new_id
+    }
+
+    /// The decision node of a state in the transposition table
+    fn find_node(&self, state: &S, hash: u64) -> Option<usize> {
+        self.global_transposition_table.get(&hash)
+            .copied()
+            .filter(|&id| self.decision_nodes[id].matches(state, hash))
+    }
+
+    /// Keep only the subtree under the node of `state` and make it the root, so the search
+    /// done for earlier moves is reused for the one actually reached
+    /// Returns false, with the tree dropped, when the state is not in the tree
+    pub fn reroot(&mut self, state: &S) -> bool {
+        match self.find_node(state, state_hash(state)) {
+            Some(node_id) => {
+                self.retain_reachable(node_id, &HashSet::new());
+                true
+            }
+            None => {
+                self.reset();
+                false
+            }
+        }
+    }
+
+    /// Evict the least visited decision nodes (and what is only reachable through them) once the
+    /// tree is over budget, in one batch that brings it down to the low-water mark
+    /// Every evicted decision node removes at least itself, so one pass always reaches the mark
+    /// Returns the new id of the root
+    fn enforce_node_budget(&mut self, root_id: usize, max_nodes: usize) -> usize {
+        if self.node_count() <= max_nodes {
+            return root_id;
+        }
+
+        let low_water = (max_nodes as f32 * self.node_low_water) as usize;
+        let mut candidates: Vec<usize> = (0..self.decision_nodes.len())
+            .filter(|&id| id != root_id)
+            .collect();
+        let excess = (self.node_count() - low_water).min(candidates.len());
+        if excess == 0 {
+            return root_id;
+        }
+
+        // Least visited first, the most recently created first among equals
+        let key = |&id: &usize| (self.decision_nodes[id].visits, std::cmp::Reverse(id));
+        if excess < candidates.len() {
+            candidates.select_nth_unstable_by_key(excess - 1, key);
+        }
+        let evicted: HashSet<usize> = candidates.into_iter().take(excess).collect();
+        self.retain_reachable(root_id, &evicted)
+    }
+
+    /// Drop every node not reachable from `root_id` without going through an evicted decision node,
+    /// then renumber the remaining nodes (in their current order) and rebuild the transposition table
+    /// Returns the new id of the root
+    fn retain_reachable(&mut self, root_id: usize, evicted: &HashSet<usize>) -> usize {
+        let mut keep_decision = vec![false; self.decision_nodes.len()];
+        let mut keep_chance = vec![false; self.chance_nodes.len()];
+        let mut queue = VecDeque::from([root_id]);
+        keep_decision[root_id] = true;
+        while let Some(decision_id) = queue.pop_front() {
+            for &chance_id in &self.decision_nodes[decision_id].children {
+                keep_chance[chance_id] = true;
+                for &child_id in &self.chance_nodes[chance_id].children {
+                    if !keep_decision[child_id] && !evicted.contains(&child_id) {
+                        keep_decision[child_id] = true;
+                        queue.push_back(child_id);
+                    }
+                }
+            }
+        }
+
+        let decision_ids = renumber(&keep_decision);
+        let chance_ids = renumber(&keep_chance);
+
+        let decision_nodes = std::mem::take(&mut self.decision_nodes);
+        self.decision_nodes = decision_nodes.into_iter()
+            .zip(&keep_decision)
+            .filter(|(_, keep)| **keep)
+            .map(|(mut node, _)| {
+                node.children = node.children.iter().map(|&id| chance_ids[id].expect("chance child is reachable")).collect();
+                node.parent = node.parent.and_then(|id| chance_ids[id]);
+                node
+            })
+            .collect();
+
+        let chance_nodes = std::mem::take(&mut self.chance_nodes);
+        self.chance_nodes = chance_nodes.into_iter()
+            .zip(&keep_chance)
+            .filter(|(_, keep)| **keep)
+            .map(|(mut node, _)| {
+                (node.children, node.outcome_weights) = node.children.iter()
+                    .zip(&node.outcome_weights)
+                    .filter_map(|(&id, &weight)| decision_ids[id].map(|id| (id, weight)))
+                    .unzip();
+                node.parent = decision_ids[node.parent].expect("parent of a reachable chance node is kept");
+                node
+            })
+            .collect();
+
+        let new_root_id = decision_ids[root_id].expect("root is kept");
+        self.decision_nodes[new_root_id].parent = None;
+
+        self.global_transposition_table = self.decision_nodes.iter()
+            .enumerate()
+            .map(|(id, node)| (node.state_hash, id))
+            .collect();
+
+        new_root_id
+    }
+
     /// Run a single MCTS iteration
     fn run_iteration(
         &mut self,
         root_id: usize,
-        mut state: S,
+        state: S,
         rng: &mut impl rand::Rng,
     ) -> f32 {
+        let (path, leaf_state) = self.select_leaf(root_id, state, rng);
+        // Terminal leaves are scored right away by the rollout
+        let reward = self.rollout(leaf_state, rng);
+        self.backpropagate(&path, reward);
+        reward
+    }
+
+    /// Selection and expansion: walk down the tree with UCT from the root until a terminal state,
+    /// a decision node not visited yet or an outcome progressive widening does not admit yet
+    /// Returns the path taken, as (is_decision_node, node_id), and the state reached
+    pub(super) fn select_leaf(
+        &mut self,
+        root_id: usize,
+        mut state: S,
+        rng: &mut impl rand::Rng,
+    ) -> (Vec<(bool, usize)>, S) {
         // Track path: (is_decision_node, node_id)
         let mut path: Vec<(bool, usize)> = vec![(true, root_id)];
         let mut at_decision = true;
@@ -101,10 +440,7 @@ impl<S: ForwardSimulation> MCTS<S> {
         // Phase 1: Selection - traverse tree using UCT
         loop {
             if state.is_terminal() {
-                // Terminal state - evaluate and backpropagate
-                let reward = state.evaluate();
-                self.backpropagate(&path, reward);
-                return reward;
+                return (path, state);
             }
 
             if at_decision {
@@ -112,184 +448,240 @@ impl<S: ForwardSimulation> MCTS<S> {
                 let available_actions = state.list_available_actions();
                 let decision_node = &self.decision_nodes[current_id];
 
-                if !decision_node.is_fully_expanded(&available_actions) {
-                    // Expand a new chance node (action)
-                    if let Some(action) = decision_node.get_untried_action(&available_actions) {
-                        let chance_id = self.expand_decision_node(current_id, action.clone());
-                        path.push((false, chance_id));
-
-                        // Execute action and continue from resulting decision node
-                        let action_clone = action.clone();
-                        if let Ok(_) = state.eval_action(action, rng) {
-                            let (outcome_id, is_new_node) = self.add_decision_node(chance_id, &state);
-                            path.push((true, outcome_id));
-
-                            // Phase 2: Rollout - only if we created a new decision node
-                            if is_new_node {
-                                let reward = self.rollout(state, rng);
-                                self.backpropagate(&path, reward);
-                                return reward;
-                            }
-
-                            // Continue traversal if reusing existing node
-                            at_decision = true;
-                            current_id = outcome_id;
-                            continue;
-                        } else {
-                            // Action failed - this should never happen for actions from list_available_actions
-                            let action_for_error = action_clone.clone();
-                            let error = state.eval_action(action_clone, rng).unwrap_err();
-                            panic!(
-                                "MCTS Bug: eval_action failed for an action from list_available_actions()\n\
-                                 State Type: {}\n\
-                                 Failed Action: {:?}\n\
-                                 Error: {:?}\n\
-                                 Available Actions: {:?}\n\
-                                 This indicates list_available_actions() returned an invalid action.",
-                                std::any::type_name::<S>(),
-                                action_for_error,
-                                error,
-                                available_actions
-                            );
+                let chance_id = match decision_node.get_untried_action(&available_actions) {
+                    // Expand a new chance node (action), its first outcome is sampled below
+                    Some(action) => self.expand_decision_node(current_id, action),
+                    // Fully expanded: select best chance node (action) by UCT
+                    None => {
+                        if decision_node.children.is_empty() {
+                            // No children - this should never happen if fully expanded
+                            panic!("Decision node is fully expanded but has no children");
                         }
-                    }
-                }
-
-                // Fully expanded: select best chance node (action) by UCT
-                let decision_node = &self.decision_nodes[current_id];
-                if decision_node.children.is_empty() {
-                    // No children - this should never happen if fully expanded
-                    panic!("Decision node is fully expanded but has no children");
-                }
 
-                let parent_visits = decision_node.visits;
-                let best_child_id = *decision_node.children.iter()
-                    .max_by(|&&a, &&b| {
-                        let a_uct = self.chance_nodes[a].uct_value(parent_visits, self.exploration_constant);
-                        let b_uct = self.chance_nodes[b].uct_value(parent_visits, self.exploration_constant);
-                        a_uct.partial_cmp(&b_uct).expect("UCT values should be comparable")
-                    })
-                    .expect("Decision node should have at least one child");
+                        let parent_visits = decision_node.visits;
+                        *decision_node.children.iter()
+                            .max_by(|&&a, &&b| {
+                                let a_uct = self.chance_uct(a, parent_visits);
+                                let b_uct = self.chance_uct(b, parent_visits);
+                                a_uct.partial_cmp(&b_uct).expect("UCT values should be comparable")
+                            })
+                            .expect("Decision node should have at least one child")
+                    }
+                };
 
-                path.push((false, best_child_id));
+                path.push((false, chance_id));
                 at_decision = false;
-                current_id = best_child_id;
+                current_id = chance_id;
             } else {
-                // At a chance node: sample outcome
-                let chance_node = &self.chance_nodes[current_id];
-                let action = chance_node.action.clone();
-                let is_fully_expanded = chance_node.is_fully_expanded(self.samples_per_action);
-
-                if !is_fully_expanded {
-                    // Sample a new outcome by executing the action
-                    let action_clone = action.clone();
-                    if let Ok(_) = state.eval_action(action, rng) {
-                        let (outcome_id, is_new_node) = self.add_decision_node(current_id, &state);
-                        path.push((true, outcome_id));
-
-                        // Phase 2: Rollout - only if we created a new decision node
-                        if is_new_node {
-                            let reward = self.rollout(state, rng);
-                            self.backpropagate(&path, reward);
-                            return reward;
-                        }
+                // At a chance node: pick an outcome
+                let (outcome_id, outcome_state) = self.select_outcome(current_id, &state, rng);
+                state = outcome_state;
+                // An outcome progressive widening does not admit yet is played out from the chance node
+                let Some(outcome_id) = outcome_id else {
+                    return (path, state);
+                };
+                path.push((true, outcome_id));
 
-                        // Continue traversal if reusing existing node
-                        at_decision = true;
-                        current_id = outcome_id;
-                        continue;
-                    } else {
-                        // Action failed - this should never happen for actions from list_available_actions
-                        let error = state.eval_action(action_clone, rng).unwrap_err();
-                        panic!(
-                            "MCTS Bug: eval_action failed for an action from list_available_actions()\n\
-                             State Type: {}\n\
-                             Failed Action: {:?}\n\
-                             Error: {:?}\n\
-                             This indicates list_available_actions() returned an invalid action.",
-                            std::any::type_name::<S>(),
-                            chance_node.action,
-                            error
-                        );
-                    }
+                // A decision node not visited yet is a leaf: the rollout starts from it
+                if self.decision_nodes[outcome_id].visits == 0 {
+                    return (path, state);
                 }
 
-                // Fully expanded: use simulator to sample a fresh outcome
-                // Get the parent decision node's state state
-                let parent_decision_id = chance_node.parent;
-                let parent_state = &self.decision_nodes[parent_decision_id].state;
-
-                // Clone the parent state state and execute the action to get a fresh outcome
-                let mut fresh_state = parent_state.clone();
-                if let Ok(_) = fresh_state.eval_action(chance_node.action.clone(), rng) {
-                    let (outcome_id, is_new_node) = self.add_decision_node(current_id, &fresh_state);
-                    path.push((true, outcome_id));
-                    state = fresh_state;
-
-                    // Phase 2: Rollout - only if we created a new decision node
-                    if is_new_node {
-                        let reward = self.rollout(state, rng);
-                        self.backpropagate(&path, reward);
-                        return reward;
-                    }
+                at_decision = true;
+                current_id = outcome_id;
+            }
+        }
+    }
 
-                    // Continue traversal if reusing existing node
-                    at_decision = true;
-                    current_id = outcome_id;
-                } else {
-                    // Action failed - this should never happen for actions from list_available_actions
-                    let error = fresh_state.eval_action(chance_node.action.clone(), rng).unwrap_err();
-                    panic!(
-                        "MCTS Bug: eval_action failed for an action from list_available_actions()\n\
-                         State Type: {}\n\
-                         Failed Action: {:?}\n\
-                         Error: {:?}\n\
-                         This occurred during fresh outcome sampling from a fully expanded chance node.",
-                        std::any::type_name::<S>(),
-                        chance_node.action,
-                        error
-                    );
+    /// Pick the outcome of a chance node for this visit, `state` being the state of its parent
+    /// Returns the decision node of the outcome (None when the visit stops at the chance node) and its state
+    ///
+    /// - A small outcome distribution is enumerated on the first visit (when `exact_outcomes` allows it),
+    ///   its outcomes are then visited in proportion to their probabilities.
+    /// - Otherwise a new outcome is sampled with the simulator while progressive widening allows one more,
+    ///   and the outcomes sampled so far are revisited in proportion to how often they came up.
+    ///   Without stored states (`StateStorage::RootOnly`) a revisit samples the action again: an outcome
+    ///   the chance node does not have yet is played out from the chance node instead of being added.
+    /// - An action that always led to the same state is not sampled again.
+    fn select_outcome(&mut self, chance_id: usize, state: &S, rng: &mut impl rand::Rng) -> (Option<usize>, S) {
+        let chance_node = &self.chance_nodes[chance_id];
+        if chance_node.children.len() < chance_node.outcome_probabilities.len().max(1) {
+            self.enumerate_outcomes(chance_id, state, rng);
+        }
+
+        let chance_node = &self.chance_nodes[chance_id];
+        let widen = chance_node.children.is_empty()
+            || (!chance_node.enumerated
+                && !chance_node.deterministic
+                && chance_node.children.len() < self.max_outcomes(chance_node.visits + 1));
+        if !widen {
+            let stored = chance_node.sample_child(rng)
+                .and_then(|child_id| self.decision_nodes[child_id].state.clone().map(|child_state| (child_id, child_state)));
+            if let Some((child_id, child_state)) = stored {
+                return (Some(child_id), child_state);
+            }
+        }
+
+        // Sample an outcome by executing the action
+        let action = chance_node.action.clone();
+        let mut next_state = state.clone();
+        if let Err(error) = next_state.eval_action(action.clone(), rng) {
+            // Action failed - this should never happen for actions from list_available_actions
+            panic!(
+                "MCTS Bug: eval_action failed for an action from list_available_actions()\n\
+                 State Type: {}\n\
+                 Failed Action: {:?}\n\
+                 Error: {:?}\n\
+                 Available Actions: {:?}\n\
+                 This indicates list_available_actions() returned an invalid action.",
+                std::any::type_name::<S>(),
+                action,
+                error,
+                state.list_available_actions()
+            );
+        }
+
+        let hash = state_hash(&next_state);
+        let known = chance_node.children.iter().any(|&child_id| self.decision_nodes[child_id].matches(&next_state, hash));
+        if !widen && !known {
+            return (None, next_state);
+        }
+
+        let weight = if chance_node.enumerated { chance_node.outcome_probability(hash).unwrap_or(0.0) } else { 1.0 };
+        let (outcome_id, _) = self.add_decision_node(chance_id, &next_state, weight);
+        let chance_node = &mut self.chance_nodes[chance_id];
+        chance_node.deterministic = !chance_node.enumerated
+            && chance_node.children.len() == 1
+            && self.deterministic_after.is_some_and(|samples| chance_node.samples() >= samples);
+        (Some(outcome_id), next_state)
+    }
+
+    /// List every outcome of a chance node with its probability, when the simulation can
+    /// and the outcome states can be stored
+    /// Once enumerated, only the outcomes whose nodes were evicted are added back, with their probability
+    fn enumerate_outcomes(&mut self, chance_id: usize, state: &S, rng: &mut impl rand::Rng) {
+        let Some(max_outcomes) = self.exact_outcomes else {
+            return;
+        };
+        if self.state_storage != StateStorage::AllNodes {
+            return;
+        }
+
+        let action = self.chance_nodes[chance_id].action.clone();
+        let Some(outcomes) = state.outcome_distribution(&action, max_outcomes, rng) else {
+            return;
+        };
+        if outcomes.is_empty() || outcomes.len() > max_outcomes {
+            return;
+        }
+
+        if self.chance_nodes[chance_id].enumerated {
+            for (outcome, _) in outcomes {
+                let hash = state_hash(&outcome);
+                let chance_node = &self.chance_nodes[chance_id];
+                let kept = chance_node.children.iter().any(|&child_id| self.decision_nodes[child_id].matches(&outcome, hash));
+                if let Some(probability) = chance_node.outcome_probability(hash).filter(|_| !kept) {
+                    self.add_decision_node(chance_id, &outcome, probability);
                 }
             }
+            return;
+        }
+
+        for (outcome, probability) in outcomes {
+            let (child_id, _) = self.add_decision_node(chance_id, &outcome, probability as f32);
+            let chance_node = &mut self.chance_nodes[chance_id];
+            let hash = self.decision_nodes[child_id].state_hash;
+            match chance_node.outcome_probabilities.iter_mut().find(|(outcome_hash, _)| *outcome_hash == hash) {
+                Some((_, total)) => *total += probability as f32,
+                None => chance_node.outcome_probabilities.push((hash, probability as f32)),
+            }
+        }
+        self.chance_nodes[chance_id].enumerated = true;
+    }
+
+    /// Outcomes a chance node may have after the given number of visits
+    fn max_outcomes(&self, visits: usize) -> usize {
+        match self.widening {
+            Some(widening) => widening.max_outcomes(visits),
+            None => self.samples_per_action,
         }
     }
 
-    /// Perform a random rollout from the current state state
-    /// Returns the reward from the terminal state
-    fn rollout(&self, mut state: S, rng: &mut impl rand::Rng) -> f32 {
+    /// Value of a chance node: the mean reward through it, or for enumerated outcomes the
+    /// probability-weighted mean of the values of the outcomes visited so far
+    fn chance_value(&self, chance_id: usize) -> f32 {
+        let chance_node = &self.chance_nodes[chance_id];
+        if !chance_node.enumerated {
+            return chance_node.avg_reward();
+        }
+
+        let mut weighted_value = 0.0;
+        let mut visited_probability = 0.0;
+        for (&child_id, &probability) in chance_node.children.iter().zip(&chance_node.outcome_weights) {
+            let child = &self.decision_nodes[child_id];
+            if child.visits > 0 {
+                weighted_value += probability * child.avg_reward();
+                visited_probability += probability;
+            }
+        }
+
+        if visited_probability > 0.0 {
+            weighted_value / visited_probability
+        } else {
+            chance_node.avg_reward()
+        }
+    }
+
+    /// UCT value of a chance node, with `chance_value` as the exploitation term
+    fn chance_uct(&self, chance_id: usize, parent_visits: usize) -> f32 {
+        let chance_node = &self.chance_nodes[chance_id];
+        if chance_node.visits == 0 {
+            return f32::INFINITY; // Prioritize unvisited nodes
+        }
+        let exploration = self.exploration_constant * ((parent_visits as f32).ln() / chance_node.visits as f32).sqrt();
+        self.chance_value(chance_id) + exploration
+    }
+
+    /// Play out the state with the rollout policy
+    /// Returns the reward of the state the rollout ended in
+    pub(super) fn rollout(&mut self, mut state: S, rng: &mut impl rand::Rng) -> f32 {
         let mut depth = 0;
         let max_depth = self.max_rollout_depth.unwrap_or(100);
 
         loop {
             // Terminal state - evaluate
             if state.is_terminal() {
-                return state.evaluate();
+                return self.evaluator.evaluate(&state);
             }
 
             // Safety limit to prevent infinite rollouts
             if depth >= max_depth * 2 {
-                return state.evaluate();
+                return self.evaluator.evaluate(&state);
+            }
+
+            // Static evaluation instead of playing further
+            if self.rollout_cutoff.is_some_and(|cutoff| depth >= cutoff) {
+                return self.evaluator.evaluate(&state);
             }
 
             // Get available actions
             let available_actions = state.list_available_actions();
 
             if available_actions.is_empty() {
-                return state.evaluate();
+                return self.evaluator.evaluate(&state);
             }
 
-            // Select a random action
-            let random_action = available_actions[rng.gen_range(0..available_actions.len())].clone();
+            let action = self.rollout_policy.select_action(&state, &available_actions, rng);
 
             // Execute the action
-            match state.eval_action(random_action, rng) {
+            match state.eval_action(action, rng) {
                 Ok(_) => {
                     depth += 1;
                     continue;
                 }
                 Err(_) => {
                     // Action failed - evaluate current state
-                    return state.evaluate();
+                    return self.evaluator.evaluate(&state);
                 }
             }
         }
@@ -312,47 +704,63 @@ impl<S: ForwardSimulation> MCTS<S> {
         chance_id
     }
 
-    /// Add or reuse a decision node as child of a chance node
+    /// Add or reuse a decision node as child of a chance node, adding `weight` to the outcome
     /// Uses global transposition table to detect duplicate states across entire tree
     /// Returns (node_id, is_new) where is_new is true if a new node was created
-    fn add_decision_node(&mut self, chance_node_id: usize, state: &S) -> (usize, bool) {
+    fn add_decision_node(&mut self, chance_node_id: usize, state: &S, weight: f32) -> (usize, bool) {
         // Check global transposition table for existing state
-        if let Some(&existing_node_id) = self.global_transposition_table.get(state) {
+        let hash = state_hash(state);
+        if let Some(existing_node_id) = self.find_node(state, hash) {
             // Reuse existing decision node
-            // Add to chance node's children if not already there
-            let chance_node = &mut self.chance_nodes[chance_node_id];
-            if !chance_node.children.contains(&existing_node_id) {
-                chance_node.children.push(existing_node_id);
-            }
+            self.chance_nodes[chance_node_id].add_outcome(existing_node_id, weight);
             return (existing_node_id, false);
         }
 
         // Create new decision node
         let decision_id = self.decision_nodes.len();
-        self.decision_nodes.push(MCTSDecisionNode::new(Some(chance_node_id), state.clone()));
+        let node = match self.state_storage {
+            StateStorage::AllNodes => MCTSDecisionNode::new(Some(chance_node_id), state.clone()),
+            StateStorage::RootOnly => MCTSDecisionNode::without_state(Some(chance_node_id), state),
+        };
+        self.decision_nodes.push(node);
 
         // Add to chance node's children and global transposition table
-        self.chance_nodes[chance_node_id].children.push(decision_id);
-        self.global_transposition_table.insert(state.clone(), decision_id);
+        self.chance_nodes[chance_node_id].add_outcome(decision_id, weight);
+        self.global_transposition_table.insert(hash, decision_id);
 
         (decision_id, true)
     }
 
     /// Backpropagate reward up the tree
     fn backpropagate(&mut self, path: &[(bool, usize)], reward: f32) {
+        self.update_path(path, 1, reward);
+    }
+
+    /// Count a visit whose rollout is still running as a loss, so that other threads
+    /// searching the same tree are steered elsewhere (tree parallelism)
+    pub(super) fn add_virtual_loss(&mut self, path: &[(bool, usize)], virtual_loss: f32) {
+        self.update_path(path, 1, -virtual_loss);
+    }
+
+    /// Replace a virtual loss added on the path by the real reward, the visit was already counted
+    pub(super) fn backpropagate_virtual_loss(&mut self, path: &[(bool, usize)], reward: f32, virtual_loss: f32) {
+        self.update_path(path, 0, reward + virtual_loss);
+    }
+
+    fn update_path(&mut self, path: &[(bool, usize)], visits: usize, reward: f32) {
         for &(is_decision, node_id) in path.iter() {
             if is_decision {
-                self.decision_nodes[node_id].visits += 1;
+                self.decision_nodes[node_id].visits += visits;
                 self.decision_nodes[node_id].total_reward += reward;
             } else {
-                self.chance_nodes[node_id].visits += 1;
+                self.chance_nodes[node_id].visits += visits;
                 self.chance_nodes[node_id].total_reward += reward;
             }
         }
     }
 
     /// Select the best action based on visit counts of chance nodes
-    fn select_best_action(&self, root_id: usize, state: &S) -> S::Action {
+    pub(super) fn select_best_action(&self, root_id: usize, state: &S) -> S::Action {
         let root = &self.decision_nodes[root_id];
 
         if root.children.is_empty() {
@@ -379,7 +787,7 @@ impl<S: ForwardSimulation> MCTS<S> {
             .map(|&child_id| {
                 let chance_node = &self.chance_nodes[child_id];
                 let q_value = if chance_node.visits > 0 {
-                    chance_node.total_reward / chance_node.visits as f32
+                    self.chance_value(child_id)
                 } else {
                     0.0
                 };
@@ -390,6 +798,30 @@ impl<S: ForwardSimulation> MCTS<S> {
 
 }
 
+/// Iterations left in the budget, estimating those the time limit allows from the pace so far
+fn remaining_iterations(remaining_count: usize, time_limit: Option<Duration>, elapsed: Duration, done: usize) -> usize {
+    match time_limit {
+        Some(limit) => {
+            let per_iteration = elapsed.as_secs_f64() / done as f64;
+            let time_left = limit.saturating_sub(elapsed).as_secs_f64();
+            let by_time = if per_iteration > 0.0 { (time_left / per_iteration).ceil() as usize } else { usize::MAX };
+            remaining_count.min(by_time)
+        }
+        None => remaining_count,
+    }
+}
+
+/// New ids for the kept entries, in their current order
+fn renumber(keep: &[bool]) -> Vec<Option<usize>> {
+    let mut next_id = 0;
+    keep.iter()
+        .map(|&kept| kept.then(|| {
+            next_id += 1;
+            next_id - 1
+        }))
+        .collect()
+}
+
 // Backward-compatible Agent implementation for Battle
 impl Agent for MCTS<crate::battle::Battle> {
     fn select_action(&mut self, state: &crate::battle::Battle, rng: &mut impl rand::Rng) -> crate::battle::battle_action::BattleAction {
@@ -401,6 +833,10 @@ impl Agent for MCTS<crate::battle::Battle> {
     fn name(&self) -> &str {
         "MCTS-Expectimax"
     }
+
+    fn reset(&mut self) {
+        MCTS::reset(self);
+    }
 }
 
 #[cfg(test)]
@@ -410,6 +846,7 @@ mod tests {
     use crate::enemies::jaw_worm::JawWorm;
     use crate::enemies::enemy_enum::EnemyEnum;
     use crate::battle::Battle;
+    use crate::battle::battle_action::BattleAction;
 
     #[test]
     fn test_mcts_agent_selects_valid_action() {
@@ -459,6 +896,331 @@ mod tests {
         assert_eq!(agent.name(), "MCTS-Expectimax");
     }
 
+    #[test]
+    fn test_mcts_with_greedy_rollout_and_cutoff() {
+        use crate::agents::rollout_policy::{GreedyRollout, NoPotionRollout};
+
+        let state = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+
+        let mut agent = MCTS::<Battle>::new(30, 1.41)
+            .with_rollout_policy(NoPotionRollout::new(GreedyRollout::new()))
+            .with_rollout_cutoff(5);
+        assert_eq!(agent.rollout_policy_name(), "No-Potion");
+        assert_eq!(agent.rollout_cutoff, Some(5));
+
+        let mut rng = rand::rng();
+        let (action, stats) = agent.select_action(&state, &mut rng);
+        assert!(state.list_available_actions().contains(&action));
+        assert_eq!(stats.iter().map(|(_, visits, _)| visits).sum::<usize>(), 30);
+    }
+
+    #[test]
+    fn test_zero_cutoff_scores_leaves_without_rollout() {
+        let state = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        let mut agent = MCTS::<Battle>::new(1, 1.41).with_rollout_cutoff(0);
+        let mut rng = rand::rng();
+
+        // With no rollout the reward is the static evaluation of the leaf itself
+        let reward = agent.rollout(state.clone(), &mut rng);
+        assert_eq!(reward, crate::agents::ForwardSimulation::evaluate(&state));
+    }
+
+    #[test]
+    fn test_custom_evaluator_scores_leaves() {
+        use crate::agents::{BattleEvaluator, BattleWeights};
+
+        let state = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        let weights = BattleWeights { player_hp: 0.0, enemy_hp: 0.0, potions: 0.0, turns: 1.0, ..BattleWeights::default() };
+        let mut agent = MCTS::<Battle>::new(1, 1.41)
+            .with_evaluator(BattleEvaluator::new(weights))
+            .with_rollout_cutoff(0);
+        assert_eq!(agent.evaluator_name(), "Battle");
+
+        // Only the turn penalty is left: the opening turn costs 1
+        let mut rng = rand::rng();
+        assert_eq!(agent.rollout(state, &mut rng), -1.0);
+    }
+
+    fn searched_agent(agent: MCTS<Battle>) -> (MCTS<Battle>, Battle) {
+        let state = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        let mut agent = agent;
+        let mut rng = rand::rng();
+        agent.select_action(&state, &mut rng);
+        (agent, state)
+    }
+
+    #[test]
+    fn test_reroot_keeps_only_the_subtree() {
+        let (mut agent, state) = searched_agent(MCTS::<Battle>::new(100, 1.41).with_rollout_cutoff(2));
+        let root_id = agent.root_node(&state);
+        let best_chance = *agent.decision_nodes[root_id].children.iter()
+            .max_by_key(|&&id| agent.chance_nodes[id].visits)
+            .unwrap();
+        let child_id = agent.chance_nodes[best_chance].children[0];
+        let child_state = agent.decision_nodes[child_id].state.clone().unwrap();
+        let child_visits = agent.decision_nodes[child_id].visits;
+        let node_count = agent.node_count();
+
+        assert!(agent.reroot(&child_state));
+        assert!(agent.node_count() < node_count);
+        let new_root = agent.root_node(&child_state);
+        assert_eq!(agent.decision_nodes[new_root].visits, child_visits);
+        assert_eq!(agent.decision_nodes[new_root].parent, None);
+        // The old root is gone and every kept node is still in the transposition table
+        assert!(agent.find_node(&state, state_hash(&state)).is_none());
+        for (id, node) in agent.decision_nodes.iter().enumerate() {
+            assert_eq!(agent.global_transposition_table[&node.state_hash], id);
+        }
+    }
+
+    #[test]
+    fn test_reroot_on_unknown_state_drops_the_tree() {
+        let (mut agent, _) = searched_agent(MCTS::<Battle>::new(20, 1.41).with_rollout_cutoff(2));
+        let other = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(12, false)))
+            .build();
+
+        assert!(!agent.reroot(&other));
+        assert_eq!(agent.node_count(), 0);
+    }
+
+    #[test]
+    fn test_node_budget_evicts_least_visited_nodes() {
+        let (agent, state) = searched_agent(MCTS::<Battle>::new(300, 1.41).with_rollout_cutoff(2).with_max_nodes(60));
+        assert!(agent.node_count() <= 60);
+
+        // The root survives eviction with all of its visits
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+        assert_eq!(agent.decision_nodes[root_id].visits, 300);
+        for chance in &agent.chance_nodes {
+            assert!(chance.children.iter().all(|&id| id < agent.decision_nodes.len()));
+        }
+    }
+
+    #[test]
+    fn test_eviction_goes_down_to_the_low_water_mark() {
+        let (mut agent, state) = searched_agent(MCTS::<Battle>::new(200, 1.41).with_rollout_cutoff(2).with_node_low_water(0.5));
+        let max_nodes = agent.node_count() - 1;
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+
+        let root_id = agent.enforce_node_budget(root_id, max_nodes);
+        assert!(agent.node_count() <= max_nodes / 2);
+        assert_eq!(agent.decision_nodes[root_id].visits, 200);
+
+        // Back under budget, so nothing more is evicted until the tree grows past it again
+        let count = agent.node_count();
+        agent.enforce_node_budget(root_id, max_nodes);
+        assert_eq!(agent.node_count(), count);
+    }
+
+    #[test]
+    fn test_root_only_storage_keeps_states_at_the_root() {
+        let (agent, state) = searched_agent(MCTS::<Battle>::new(50, 1.41)
+            .with_rollout_cutoff(2)
+            .with_state_storage(StateStorage::RootOnly));
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+
+        assert!(agent.decision_nodes.len() > 1);
+        for (id, node) in agent.decision_nodes.iter().enumerate() {
+            assert_eq!(node.state.is_some(), id == root_id);
+        }
+    }
+
+    #[test]
+    fn test_reset_drops_the_tree() {
+        let (mut agent, _) = searched_agent(MCTS::<Battle>::new(20, 1.41).with_rollout_cutoff(2));
+        assert!(agent.node_count() > 0);
+        Agent::reset(&mut agent);
+        assert_eq!(agent.node_count(), 0);
+    }
+
+    #[test]
+    fn test_time_limit_bounds_the_search() {
+        let start = Instant::now();
+        let (agent, state) = searched_agent(MCTS::<Battle>::new(usize::MAX, 1.41)
+            .with_rollout_cutoff(2)
+            .with_time_limit(Duration::from_millis(20)));
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+        assert!(agent.decision_nodes[root_id].visits > 0);
+    }
+
+    #[test]
+    fn test_early_stop_once_the_best_action_is_decided() {
+        let (agent, state) = searched_agent(MCTS::<Battle>::new(1000, 0.05)
+            .with_rollout_cutoff(0)
+            .with_early_stop());
+
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+        assert!(agent.decision_nodes[root_id].visits < 1000);
+    }
+
+    #[test]
+    fn test_best_action_is_decided_by_visit_lead() {
+        let (mut agent, state) = searched_agent(MCTS::<Battle>::new(10, 1.41).with_rollout_cutoff(0));
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+        let children = agent.decision_nodes[root_id].children.clone();
+        for &child_id in &children {
+            agent.chance_nodes[child_id].visits = 2;
+        }
+        agent.chance_nodes[children[0]].visits = 10;
+
+        assert!(agent.best_action_is_decided(root_id, 7));
+        assert!(!agent.best_action_is_decided(root_id, 8));
+        assert_eq!(remaining_iterations(100, Some(Duration::from_millis(10)), Duration::from_millis(5), 10), 10);
+        assert_eq!(remaining_iterations(3, None, Duration::from_secs(1), 10), 3);
+    }
+
+    #[test]
+    fn test_adaptive_budget_skips_forced_moves() {
+        use crate::agents::AdaptiveBudget;
+
+        let state = BattleBuilder::new()
+            .with_deck(vec![])
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        assert_eq!(state.list_available_actions(), vec![crate::battle::battle_action::BattleAction::EndTurn]);
+
+        let mut agent = MCTS::<Battle>::new(100, 1.41).with_budget(AdaptiveBudget::default());
+        let mut rng = rand::rng();
+        let (action, stats) = agent.select_action(&state, &mut rng);
+        assert_eq!(action, crate::battle::battle_action::BattleAction::EndTurn);
+        assert!(stats.is_empty());
+        assert_eq!(agent.node_count(), 1);
+    }
+
+    fn forty_strikes() -> Battle {
+        use crate::cards::ironclad::strike::strike;
+
+        // No reshuffle during the search, so card plays are deterministic
+        BattleBuilder::new()
+            .with_deck(vec![strike(); 40])
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(200, false)))
+            .build()
+    }
+
+    fn root_chance_node(agent: &MCTS<Battle>, state: &Battle, action: &BattleAction) -> usize {
+        let root_id = agent.global_transposition_table[&state_hash(state)];
+        *agent.decision_nodes[root_id].children.iter()
+            .find(|&&id| agent.chance_nodes[id].action == *action)
+            .expect("action was expanded")
+    }
+
+    #[test]
+    fn test_progressive_widening_grows_with_visits() {
+        let widening = ProgressiveWidening::default();
+        assert_eq!(widening.max_outcomes(0), 1);
+        assert_eq!(widening.max_outcomes(4), 2);
+        assert_eq!(widening.max_outcomes(10), 4);
+
+        let agent = MCTS::<Battle>::new(10, 1.41).with_fixed_samples(2);
+        assert_eq!(agent.max_outcomes(100), 2);
+    }
+
+    #[test]
+    fn test_deterministic_actions_stop_sampling() {
+        let state = forty_strikes();
+        let mut agent = MCTS::<Battle>::new(200, 1.41)
+            .with_rollout_cutoff(0)
+            .with_deterministic_after(5);
+        let mut rng = rand::rng();
+        agent.select_action(&state, &mut rng);
+
+        let play = BattleAction::PlayCard(0, crate::battle::target::Entity::Enemy(0));
+        let chance_node = &agent.chance_nodes[root_chance_node(&agent, &state, &play)];
+        assert_eq!(chance_node.children.len(), 1);
+        assert!(chance_node.deterministic);
+        // Once found deterministic, visits reuse the stored outcome instead of sampling
+        assert_eq!(chance_node.samples(), 5);
+        assert!(chance_node.visits > 5);
+    }
+
+    #[test]
+    fn test_exact_outcomes_enumerate_enemy_intents() {
+        let state = forty_strikes();
+        let mut agent = MCTS::<Battle>::new(100, 1.41)
+            .with_rollout_cutoff(0)
+            .with_exact_outcomes(10);
+        let mut rng = rand::rng();
+        agent.select_action(&state, &mut rng);
+
+        let chance_id = root_chance_node(&agent, &state, &BattleAction::EndTurn);
+        let chance_node = &agent.chance_nodes[chance_id];
+        assert!(chance_node.enumerated);
+        assert_eq!(chance_node.children.len(), 2);
+        assert!((chance_node.outcome_weights.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+
+        // The value weighs each outcome by its probability, not by how often it was visited
+        let (first, second) = (chance_node.children[0], chance_node.children[1]);
+        let (first_weight, second_weight) = (chance_node.outcome_weights[0], chance_node.outcome_weights[1]);
+        for (child_id, visits, value) in [(first, 1, 1.0), (second, 9, 0.0)] {
+            agent.decision_nodes[child_id].visits = visits;
+            agent.decision_nodes[child_id].total_reward = value * visits as f32;
+        }
+        let expected = first_weight / (first_weight + second_weight);
+        assert!((agent.chance_value(chance_id) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_evicted_enumerated_outcomes_come_back_with_their_probability() {
+        let state = forty_strikes();
+        let mut agent = MCTS::<Battle>::new(100, 1.41)
+            .with_rollout_cutoff(0)
+            .with_exact_outcomes(10);
+        let mut rng = rand::rng();
+        agent.select_action(&state, &mut rng);
+
+        let chance_id = root_chance_node(&agent, &state, &BattleAction::EndTurn);
+        let mut weights = agent.chance_nodes[chance_id].outcome_weights.clone();
+        let evicted = HashSet::from([agent.chance_nodes[chance_id].children[0]]);
+        let root_id = agent.global_transposition_table[&state_hash(&state)];
+        agent.retain_reachable(root_id, &evicted);
+        let chance_id = root_chance_node(&agent, &state, &BattleAction::EndTurn);
+        assert_eq!(agent.chance_nodes[chance_id].children.len(), 1);
+
+        // The next visit adds the evicted outcome back with the probability it had
+        agent.select_outcome(chance_id, &state, &mut rng);
+        let chance_node = &agent.chance_nodes[chance_id];
+        assert_eq!(chance_node.children.len(), 2);
+        let mut restored = chance_node.outcome_weights.clone();
+        weights.sort_by(f32::total_cmp);
+        restored.sort_by(f32::total_cmp);
+        assert_eq!(restored, weights);
+    }
+
+    #[test]
+    fn test_root_only_revisits_respect_progressive_widening() {
+        let (agent, _) = searched_agent(MCTS::<Battle>::new(300, 1.41)
+            .with_rollout_cutoff(2)
+            .with_progressive_widening(1.0, 0.5)
+            .with_state_storage(StateStorage::RootOnly));
+
+        for chance_node in &agent.chance_nodes {
+            assert!(chance_node.children.len() <= agent.max_outcomes(chance_node.visits));
+        }
+    }
+
+    #[test]
+    fn test_fixed_samples_cap_the_outcomes() {
+        let state = forty_strikes();
+        let mut agent = MCTS::<Battle>::new(100, 1.41)
+            .with_rollout_cutoff(0)
+            .with_fixed_samples(1);
+        let mut rng = rand::rng();
+        agent.select_action(&state, &mut rng);
+
+        assert!(agent.chance_nodes.iter().all(|chance_node| chance_node.children.len() <= 1));
+    }
+
     #[test]
     fn test_node_uct_calculation() {
         let state = BattleBuilder::new()
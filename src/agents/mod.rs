@@ -1,12 +1,12 @@
 /// Agents module for Slay the Spire
 ///
 /// This module provides various agent implementations for playing the game,
-/// including baselines (Random) and search-based methods (MCTS).
+/// including baselines (Random, Heuristic) and search-based methods (MCTS).
 ///
 /// # Examples
 ///
 /// ```rust
-/// use slay_the_spire::agents::{Agent, RandomAgent, MCTS};
+/// use slay_the_spire::agents::{Agent, RandomAgent, MCTS, GreedyRollout, NoPotionRollout, HeuristicAgent};
 /// use slay_the_spire::battle_builder::BattleBuilder;
 /// use slay_the_spire::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
 ///
@@ -23,6 +23,15 @@
 /// // Or use MCTS agent
 /// let mut mcts_agent = MCTS::new(1000, 1.41); // 1000 iterations, exploration constant 1.41
 /// let action = mcts_agent.select_action(&battle, &mut rng);
+///
+/// // Rollouts can follow a smarter policy and stop early with a static evaluation
+/// let mut greedy_mcts = MCTS::new(1000, 1.41)
+///     .with_rollout_policy(NoPotionRollout::new(GreedyRollout::new()))
+///     .with_rollout_cutoff(20);
+/// let action = greedy_mcts.select_action(&battle, &mut rng);
+///
+/// // The heuristic agent plays on its own or drives the rollouts
+/// let heuristic_mcts = MCTS::new(1000, 1.41).with_rollout_policy(HeuristicAgent::new());
 /// ```
 
 mod traits;
@@ -31,10 +40,26 @@ mod forward_simulation;
 mod decision_node;
 mod chance_node;
 mod mcts;
+mod rollout_policy;
+mod evaluator;
+mod parallel;
+mod budget;
+mod determinize;
+mod ismcts;
+mod game_agent;
+mod heuristic;
 
-pub use traits::Agent;
+pub use traits::{Agent, GameAgent};
 pub use random::RandomAgent;
 pub use forward_simulation::ForwardSimulation;
 pub use decision_node::MCTSDecisionNode;
-pub use chance_node::MCTSChanceNode;
-pub use mcts::MCTS;
+pub use chance_node::{MCTSChanceNode, ProgressiveWidening};
+pub use mcts::{MCTS, StateStorage};
+pub use budget::{SearchBudget, UniformBudget, AdaptiveBudget};
+pub use parallel::{ParallelMCTS, Parallelism};
+pub use determinize::Determinize;
+pub use ismcts::ISMCTS;
+pub use heuristic::HeuristicAgent;
+pub use game_agent::{RandomGameAgent, RuleBasedGameAgent, WithBattleAgent};
+pub use evaluator::{Evaluator, DefaultEvaluator, BattleEvaluator, BattleWeights, GameEvaluator, GameWeights};
+pub use rollout_policy::{RolloutPolicy, RolloutAction, RandomRollout, GreedyRollout, EpsilonGreedyRollout, NoPotionRollout};
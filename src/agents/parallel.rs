@@ -0,0 +1,281 @@
+/// Parallel Monte Carlo Tree Search on std threads
+///
+/// - Root parallelism: every thread grows its own tree from the same root, and the visit
+///   counts of the root actions are merged at the end.
+/// - Tree parallelism: all threads grow one shared tree behind a lock. A visit whose rollout
+///   is still running counts as a virtual loss so the other threads explore elsewhere.
+///
+/// Each thread gets its own `MCTS` from the factory (for its rollout policy and evaluator)
+/// and its own `StdRng` seeded from the per-thread seeds. Battles draw all their randomness
+/// from the rng they are passed, so root-parallel results are deterministic for given seeds;
+/// tree-parallel results also depend on thread scheduling.
+use crate::agents::ForwardSimulation;
+use super::mcts::MCTS;
+use super::traits::Agent;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+
+/// The best action with (action, visits, q_value) for every root action, as `MCTS::select_action` returns
+type SearchResult<A> = (A, Vec<(A, usize, f32)>);
+
+/// How the threads share the search
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Parallelism {
+    /// Independent trees with merged root statistics
+    Root,
+    /// One shared tree, a running rollout counts as `virtual_loss` reward taken away
+    Tree { virtual_loss: f32 },
+}
+
+/// MCTS spread over several threads
+pub struct ParallelMCTS<S: ForwardSimulation, F: Fn() -> MCTS<S>> {
+    /// Total MCTS iterations per action selection, split evenly between the threads
+    pub iterations: usize,
+    /// Number of threads
+    pub threads: usize,
+    pub parallelism: Parallelism,
+    /// Builds the per-thread searches, with the rollout policy, evaluator and settings to use
+    make_agent: F,
+}
+
+impl<S, F> ParallelMCTS<S, F>
+where
+    S: ForwardSimulation + Send + Sync,
+    S::Action: Send,
+    F: Fn() -> MCTS<S> + Sync,
+{
+    /// Create a root-parallel search
+    ///
+    /// # Arguments
+    /// * `iterations` - Total MCTS iterations per action selection, over all threads
+    /// * `threads` - Number of threads
+    /// * `make_agent` - Builds the MCTS each thread uses (its own `iterations` is ignored)
+    pub fn new(iterations: usize, threads: usize, make_agent: F) -> Self {
+        ParallelMCTS {
+            iterations,
+            threads: threads.max(1),
+            parallelism: Parallelism::Root,
+            make_agent,
+        }
+    }
+
+    /// Builder pattern method to search one shared tree with the given virtual loss
+    pub fn with_tree_parallelism(mut self, virtual_loss: f32) -> Self {
+        self.parallelism = Parallelism::Tree { virtual_loss };
+        self
+    }
+
+    /// Run the search with per-thread seeds drawn from `rng`
+    /// Returns (best_action, Vec<(action, visits, q_value)>) like `MCTS::select_action`
+    pub fn select_action(&self, root_state: &S, rng: &mut impl rand::Rng) -> SearchResult<S::Action> {
+        let seeds: Vec<u64> = (0..self.threads).map(|_| rng.next_u64()).collect();
+        self.select_action_seeded(root_state, &seeds)
+    }
+
+    /// Run the search with one thread per seed
+    pub fn select_action_seeded(&self, root_state: &S, seeds: &[u64]) -> SearchResult<S::Action> {
+        assert!(!seeds.is_empty(), "Parallel MCTS needs at least one seed");
+
+        match self.parallelism {
+            Parallelism::Root => self.search_root_parallel(root_state, seeds),
+            Parallelism::Tree { virtual_loss } => self.search_tree_parallel(root_state, seeds, virtual_loss),
+        }
+    }
+
+    /// Iterations run by the thread with the given index, the remainder going to the first threads
+    fn iterations_for_thread(&self, thread_index: usize, thread_count: usize) -> usize {
+        self.iterations / thread_count + usize::from(thread_index < self.iterations % thread_count)
+    }
+
+    fn search_root_parallel(&self, root_state: &S, seeds: &[u64]) -> SearchResult<S::Action> {
+        let per_thread_stats: Vec<Vec<(S::Action, usize, f32)>> = thread::scope(|scope| {
+            let handles: Vec<_> = seeds.iter().enumerate()
+                .map(|(thread_index, &seed)| {
+                    let iterations = self.iterations_for_thread(thread_index, seeds.len());
+                    scope.spawn(move || {
+                        let mut agent = (self.make_agent)();
+                        agent.iterations = iterations;
+                        let mut rng = StdRng::seed_from_u64(seed);
+                        agent.select_action(root_state, &mut rng).1
+                    })
+                })
+                .collect();
+
+            // Joining in spawn order keeps the merge deterministic
+            handles.into_iter()
+                .map(|handle| handle.join().expect("MCTS search thread panicked"))
+                .collect()
+        });
+
+        let stats = merge_root_statistics(per_thread_stats);
+        let best_action = most_visited(&stats)
+            .unwrap_or_else(|| root_state.list_available_actions()[0].clone());
+        (best_action, stats)
+    }
+
+    fn search_tree_parallel(&self, root_state: &S, seeds: &[u64], virtual_loss: f32) -> SearchResult<S::Action> {
+        let mut tree = (self.make_agent)();
+        let root_id = tree.root_node(root_state);
+        let tree = Mutex::new(tree);
+
+        thread::scope(|scope| {
+            for (thread_index, &seed) in seeds.iter().enumerate() {
+                let iterations = self.iterations_for_thread(thread_index, seeds.len());
+                let tree = &tree;
+                scope.spawn(move || {
+                    // The worker only plays rollouts, the shared tree does selection and backpropagation
+                    let mut worker = (self.make_agent)();
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    for _ in 0..iterations {
+                        let (path, leaf_state) = {
+                            let mut tree = tree.lock().expect("MCTS tree lock poisoned");
+                            let (path, leaf_state) = tree.select_leaf(root_id, root_state.clone(), &mut rng);
+                            tree.add_virtual_loss(&path, virtual_loss);
+                            (path, leaf_state)
+                        };
+
+                        let reward = worker.rollout(leaf_state, &mut rng);
+                        tree.lock()
+                            .expect("MCTS tree lock poisoned")
+                            .backpropagate_virtual_loss(&path, reward, virtual_loss);
+                    }
+                });
+            }
+        });
+
+        let tree = tree.into_inner().expect("MCTS tree lock poisoned");
+        (tree.select_best_action(root_id, root_state), tree.get_action_statistics(root_id))
+    }
+}
+
+/// Sum the visits of each root action over all trees, with the visit-weighted mean Q value
+/// Actions are listed in the order they first appear
+fn merge_root_statistics<A: Clone + Eq + std::hash::Hash>(per_thread_stats: Vec<Vec<(A, usize, f32)>>) -> Vec<(A, usize, f32)> {
+    let mut merged: Vec<(A, usize, f32)> = Vec::new();
+    let mut index_of: HashMap<A, usize> = HashMap::new();
+
+    for (action, visits, q_value) in per_thread_stats.into_iter().flatten() {
+        let index = *index_of.entry(action.clone()).or_insert_with(|| {
+            merged.push((action, 0, 0.0));
+            merged.len() - 1
+        });
+        // Accumulate the total reward for now, divided by the visits below
+        merged[index].1 += visits;
+        merged[index].2 += q_value * visits as f32;
+    }
+
+    for (_, visits, total_reward) in merged.iter_mut() {
+        if *visits > 0 {
+            *total_reward /= *visits as f32;
+        }
+    }
+    merged
+}
+
+/// The action with the most visits, the first one listed on ties
+fn most_visited<A: Clone>(stats: &[(A, usize, f32)]) -> Option<A> {
+    let mut best: Option<&(A, usize, f32)> = None;
+    for entry in stats {
+        if best.is_none_or(|best| entry.1 > best.1) {
+            best = Some(entry);
+        }
+    }
+    best.map(|(action, _, _)| action.clone())
+}
+
+impl<F> Agent for ParallelMCTS<crate::battle::Battle, F>
+where
+    F: Fn() -> MCTS<crate::battle::Battle> + Sync,
+{
+    fn select_action(&mut self, state: &crate::battle::Battle, rng: &mut impl rand::Rng) -> crate::battle::battle_action::BattleAction {
+        let (action, _stats) = ParallelMCTS::select_action(self, state, rng);
+        action
+    }
+
+    fn name(&self) -> &str {
+        match self.parallelism {
+            Parallelism::Root => "MCTS-RootParallel",
+            Parallelism::Tree { .. } => "MCTS-TreeParallel",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::Battle;
+    use crate::battle_builder::BattleBuilder;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    fn battle() -> Battle {
+        BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build()
+    }
+
+    #[test]
+    fn test_iterations_are_split_between_threads() {
+        let search = ParallelMCTS::new(10, 3, || MCTS::<Battle>::new(0, 1.41));
+        let split: Vec<usize> = (0..3).map(|i| search.iterations_for_thread(i, 3)).collect();
+        assert_eq!(split, vec![4, 3, 3]);
+    }
+
+    #[test]
+    fn test_root_parallel_merges_visits() {
+        let state = battle();
+        let search = ParallelMCTS::new(40, 4, || MCTS::<Battle>::new(0, 1.41).with_rollout_cutoff(3));
+
+        let (action, stats) = search.select_action_seeded(&state, &[1, 2, 3, 4]);
+        assert!(state.list_available_actions().contains(&action));
+        assert_eq!(stats.iter().map(|(_, visits, _)| visits).sum::<usize>(), 40);
+
+        // Every action shows up once in the merged statistics
+        let actions: std::collections::HashSet<_> = stats.iter().map(|(action, _, _)| action.clone()).collect();
+        assert_eq!(actions.len(), stats.len());
+    }
+
+    #[test]
+    fn test_root_parallel_is_deterministic_for_given_seeds() {
+        use crate::cards::ironclad::{strike::strike, defend::defend, bash::bash};
+
+        // A small deck is reshuffled every other turn, so rollouts go through many reshuffles
+        let state = BattleBuilder::new()
+            .with_deck(vec![strike(), strike(), strike(), defend(), defend(), bash()])
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(200, false)))
+            .build();
+        let search = ParallelMCTS::new(40, 4, || MCTS::<Battle>::new(0, 1.41).with_rollout_cutoff(30));
+
+        let first = search.select_action_seeded(&state, &[7, 8, 9, 10]);
+        let second = search.select_action_seeded(&state, &[7, 8, 9, 10]);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_tree_parallel_shares_one_tree() {
+        let state = battle();
+        let search = ParallelMCTS::new(40, 4, || MCTS::<Battle>::new(0, 1.41).with_rollout_cutoff(3))
+            .with_tree_parallelism(1.0);
+        assert_eq!(search.name(), "MCTS-TreeParallel");
+
+        let (action, stats) = search.select_action_seeded(&state, &[1, 2, 3, 4]);
+        assert!(state.list_available_actions().contains(&action));
+        // Every virtual loss was replaced by a real visit
+        assert_eq!(stats.iter().map(|(_, visits, _)| visits).sum::<usize>(), 40);
+        assert!(stats.iter().all(|(_, _, q_value)| *q_value > -1.0));
+    }
+
+    #[test]
+    fn test_merge_root_statistics() {
+        let merged = merge_root_statistics(vec![
+            vec![("a", 3, 1.0), ("b", 1, 0.0)],
+            vec![("b", 3, 1.0), ("a", 1, 0.0)],
+            vec![("c", 2, 0.5)],
+        ]);
+
+        assert_eq!(merged, vec![("a", 4, 0.75), ("b", 4, 0.75), ("c", 2, 0.5)]);
+        // Ties go to the action listed first
+        assert_eq!(most_visited(&merged), Some("a"));
+    }
+}
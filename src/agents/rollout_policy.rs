@@ -0,0 +1,268 @@
+/// Rollout policies for MCTS
+///
+/// A rollout policy picks the actions played out from a newly expanded node until the
+/// rollout ends. Uniformly random rollouts mostly end turns early and waste potions,
+/// so better policies give much less noisy value estimates.
+use crate::agents::ForwardSimulation;
+use crate::agents::evaluator::{DefaultEvaluator, Evaluator};
+use crate::battle::battle_action::BattleAction;
+use crate::game::action::GameAction;
+use rand::{Rng, RngCore};
+
+/// Chooses the next action of a rollout
+///
+/// `actions` is the non-empty list of legal actions for `state`.
+/// Learned policies implement this trait directly.
+pub trait RolloutPolicy<S: ForwardSimulation> {
+    fn select_action(&mut self, state: &S, actions: &[S::Action], rng: &mut dyn RngCore) -> S::Action;
+
+    /// Get the name of this policy (for logging/identification)
+    fn name(&self) -> &str;
+}
+
+/// What a rollout policy can tell about an action without simulating it
+pub trait RolloutAction {
+    fn is_potion_use(&self) -> bool;
+    fn is_end_turn(&self) -> bool;
+}
+
+impl RolloutAction for BattleAction {
+    fn is_potion_use(&self) -> bool {
+        matches!(self, BattleAction::UsePotion(..))
+    }
+
+    fn is_end_turn(&self) -> bool {
+        matches!(self, BattleAction::EndTurn)
+    }
+}
+
+impl RolloutAction for GameAction {
+    fn is_potion_use(&self) -> bool {
+        matches!(self, GameAction::Battle(action) if action.is_potion_use())
+    }
+
+    fn is_end_turn(&self) -> bool {
+        matches!(self, GameAction::Battle(action) if action.is_end_turn())
+    }
+}
+
+/// Pick a legal action uniformly at random
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomRollout;
+
+impl<S: ForwardSimulation> RolloutPolicy<S> for RandomRollout {
+    fn select_action(&mut self, _state: &S, actions: &[S::Action], rng: &mut dyn RngCore) -> S::Action {
+        actions[rng.random_range(0..actions.len())].clone()
+    }
+
+    fn name(&self) -> &str {
+        "Random"
+    }
+}
+
+/// One-step lookahead: simulate every action once and pick the one whose resulting state
+/// the evaluator scores highest (the state's own `ForwardSimulation::evaluate` by default)
+/// Ending the turn is only picked when nothing else scores better
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyRollout<E = DefaultEvaluator> {
+    pub evaluator: E,
+}
+
+impl GreedyRollout {
+    pub fn new() -> Self {
+        GreedyRollout { evaluator: DefaultEvaluator }
+    }
+}
+
+impl<E> GreedyRollout<E> {
+    /// Score the lookahead states with another evaluator, usually the one MCTS scores leaves with
+    pub fn with_evaluator(evaluator: E) -> Self {
+        GreedyRollout { evaluator }
+    }
+}
+
+impl<S, E> RolloutPolicy<S> for GreedyRollout<E>
+where
+    S: ForwardSimulation,
+    S::Action: RolloutAction,
+    E: Evaluator<S>,
+{
+    fn select_action(&mut self, state: &S, actions: &[S::Action], mut rng: &mut dyn RngCore) -> S::Action {
+        let mut best: Option<(f32, &S::Action)> = None;
+        for action in actions {
+            let mut next_state = state.clone();
+            if next_state.eval_action(action.clone(), &mut rng).is_err() {
+                continue;
+            }
+            let value = self.evaluator.evaluate(&next_state);
+            // Ties go to the action that keeps the turn going
+            let better = match best {
+                None => true,
+                Some((best_value, best_action)) => {
+                    value > best_value || (value == best_value && best_action.is_end_turn() && !action.is_end_turn())
+                }
+            };
+            if better {
+                best = Some((value, action));
+            }
+        }
+
+        best.map_or_else(|| actions[0].clone(), |(_, action)| action.clone())
+    }
+
+    fn name(&self) -> &str {
+        "Greedy"
+    }
+}
+
+/// Follow another policy, but take a uniformly random action with probability `epsilon`
+#[derive(Debug, Clone, Copy)]
+pub struct EpsilonGreedyRollout<P> {
+    pub epsilon: f64,
+    pub policy: P,
+}
+
+impl<P> EpsilonGreedyRollout<P> {
+    pub fn new(epsilon: f64, policy: P) -> Self {
+        EpsilonGreedyRollout { epsilon, policy }
+    }
+}
+
+impl<S: ForwardSimulation, P: RolloutPolicy<S>> RolloutPolicy<S> for EpsilonGreedyRollout<P> {
+    fn select_action(&mut self, state: &S, actions: &[S::Action], rng: &mut dyn RngCore) -> S::Action {
+        if rng.random_bool(self.epsilon.clamp(0.0, 1.0)) {
+            RandomRollout.select_action(state, actions, rng)
+        } else {
+            self.policy.select_action(state, actions, rng)
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Epsilon-Greedy"
+    }
+}
+
+/// Follow another policy without ever drinking potions
+/// Potions are a scarce run resource, so rollouts that spend them overvalue the current battle
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoPotionRollout<P> {
+    pub policy: P,
+}
+
+impl<P> NoPotionRollout<P> {
+    pub fn new(policy: P) -> Self {
+        NoPotionRollout { policy }
+    }
+}
+
+impl<S, P> RolloutPolicy<S> for NoPotionRollout<P>
+where
+    S: ForwardSimulation,
+    S::Action: RolloutAction,
+    P: RolloutPolicy<S>,
+{
+    fn select_action(&mut self, state: &S, actions: &[S::Action], rng: &mut dyn RngCore) -> S::Action {
+        let without_potions: Vec<S::Action> = actions.iter()
+            .filter(|action| !action.is_potion_use())
+            .cloned()
+            .collect();
+
+        if without_potions.is_empty() {
+            self.policy.select_action(state, actions, rng)
+        } else {
+            self.policy.select_action(state, &without_potions, rng)
+        }
+    }
+
+    fn name(&self) -> &str {
+        "No-Potion"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::Battle;
+    use crate::battle::target::Entity;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    fn battle_with_hand() -> Battle {
+        let mut battle = BattleBuilder::new()
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build();
+        battle.add_card_to_hand_for_testing(strike());
+        battle
+    }
+
+    #[test]
+    fn test_greedy_prefers_dealing_damage_over_ending_turn() {
+        let battle = battle_with_hand();
+        let actions = vec![BattleAction::EndTurn, BattleAction::PlayCard(battle.get_hand().len() - 1, Entity::Enemy(0))];
+        let mut rng = rand::rng();
+
+        let action = GreedyRollout::new().select_action(&battle, &actions, &mut rng);
+        assert_eq!(action, actions[1]);
+    }
+
+    #[test]
+    fn test_greedy_scores_with_its_evaluator() {
+        use crate::agents::Evaluator;
+
+        /// Prefers states where the enemy has the most HP left, the opposite of the default
+        struct SparingEvaluator;
+        impl Evaluator<Battle> for SparingEvaluator {
+            fn evaluate(&self, state: &Battle) -> f32 {
+                state.get_enemies()[0].battle_info.get_hp() as f32
+            }
+
+            fn name(&self) -> &str {
+                "Sparing"
+            }
+        }
+
+        let battle = battle_with_hand();
+        let actions = vec![BattleAction::PlayCard(battle.get_hand().len() - 1, Entity::Enemy(0)), BattleAction::EndTurn];
+        let mut rng = rand::rng();
+
+        let action = GreedyRollout::with_evaluator(SparingEvaluator).select_action(&battle, &actions, &mut rng);
+        assert_eq!(action, BattleAction::EndTurn);
+    }
+
+    #[test]
+    fn test_no_potion_rollout_skips_potions() {
+        let battle = battle_with_hand();
+        let actions = vec![BattleAction::UsePotion(0, None), BattleAction::EndTurn];
+        let mut rng = rand::rng();
+        let mut policy = NoPotionRollout::new(RandomRollout);
+
+        for _ in 0..20 {
+            assert_eq!(policy.select_action(&battle, &actions, &mut rng), BattleAction::EndTurn);
+        }
+        // With only potions left, they are still played rather than getting stuck
+        let potions_only = vec![BattleAction::UsePotion(0, None)];
+        assert_eq!(policy.select_action(&battle, &potions_only, &mut rng), potions_only[0]);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_extremes() {
+        let battle = battle_with_hand();
+        let actions = vec![BattleAction::EndTurn, BattleAction::PlayCard(battle.get_hand().len() - 1, Entity::Enemy(0))];
+        let mut rng = rand::rng();
+
+        let mut always_greedy = EpsilonGreedyRollout::new(0.0, GreedyRollout::new());
+        for _ in 0..10 {
+            assert_eq!(always_greedy.select_action(&battle, &actions, &mut rng), actions[1]);
+        }
+        let mut always_random = EpsilonGreedyRollout::new(1.0, GreedyRollout::new());
+        assert!(actions.contains(&always_random.select_action(&battle, &actions, &mut rng)));
+    }
+
+    #[test]
+    fn test_game_actions_forward_to_battle_actions() {
+        assert!(GameAction::Battle(BattleAction::UsePotion(1, None)).is_potion_use());
+        assert!(GameAction::Battle(BattleAction::EndTurn).is_end_turn());
+        assert!(!GameAction::Skip.is_end_turn());
+    }
+}
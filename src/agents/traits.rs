@@ -3,6 +3,7 @@
 /// This trait provides a common interface for different agent implementations
 /// (e.g., Random, MCTS, Deep RL, etc.)
 use crate::battle::{Battle, battle_action::BattleAction};
+use crate::game::{action::GameAction, game::Game};
 
 pub trait Agent {
     /// Select an action for the given battle state
@@ -23,3 +24,24 @@ pub trait Agent {
         // Default implementation does nothing
     }
 }
+
+/// Agent making every decision of a run: paths, rewards, events, shops, rest sites and battles
+pub trait GameAgent {
+    /// Select an action for the given game state
+    ///
+    /// # Arguments
+    /// * `game` - The current game state
+    /// * `rng` - Random number generator for stochastic decisions
+    ///
+    /// # Returns
+    /// The selected game action to execute
+    fn select_action(&mut self, game: &Game, rng: &mut impl rand::Rng) -> GameAction;
+
+    /// Get the name of this agent (for logging/identification)
+    fn name(&self) -> &str;
+
+    /// Optional: Reset agent state between runs
+    fn reset(&mut self) {
+        // Default implementation does nothing
+    }
+}
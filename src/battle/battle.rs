@@ -1,6 +1,24 @@
-use crate::{enemies::enemy_enum::EnemyMove, game::{card::Card, deck::Deck, effect::{BaseEffect, BattleEffect}, global_info::GlobalInfo, player_run_state::PlayerRunState}, relics::Relic};
-use super::{battle_events::{EventListener, BattleEvent}, player::Player, deck_hand_pile::DeckHandPile, enemy_in_battle::EnemyInBattle, battle_error::BattleError, target::Entity, battle_state::BattleState, event_listener_enum::EventListenerEnum};
+use crate::{enemies::enemy_enum::EnemyMove, game::{card::Card, deck::Deck, effect::{BaseEffect, BattleEffect}, global_info::GlobalInfo, player_run_state::PlayerRunState}, relics::{Relic, philosophers_stone, snecko_eye}};
+use super::{battle_events::{EventListener, BattleEvent}, player::Player, deck_hand_pile::DeckHandPile, enemy_in_battle::EnemyInBattle, battle_error::BattleError, target::Entity, battle_state::BattleState, event_listener_enum::EventListenerEnum, power::{Power, PowerId}, counters::Counters, battle_rng::BattleRng};
 use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+
+/// A change to a card that outlives the combat, applied to the card's master deck copy after battle
+/// Only the change itself is kept: upgrades (Armaments, Apotheosis, Searing Blow) and cost changes
+/// made during combat stay in combat. Genetic Algorithm and Lesson Learned are not in the card pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(crate) enum PermanentCardChange {
+    /// Ritual Dagger killed an enemy: increase its damage
+    IncreaseDamage(u32),
+}
+
+impl PermanentCardChange {
+    fn apply(self, card: Card) -> Card {
+        match self {
+            PermanentCardChange::IncreaseDamage(amount) => crate::cards::colorless::ritual_dagger::increase_ritual_dagger_damage(card, amount),
+        }
+    }
+}
 
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Battle {
@@ -14,22 +32,37 @@ pub struct Battle {
     pub(super) relics: Vec<Relic>,
     /// Stores all powers played during the current battle
     pub(super) powers: Vec<crate::game::card::Card>,
-    /// Queue of effects to be processed
-    pub(super) effect_queue: Vec<BaseEffect>,
+    /// Queue of effects to be processed, triggered effects may jump to the front
+    pub(super) effect_queue: VecDeque<BaseEffect>,
     pub(crate) battle_state: BattleState,
     /// Gold stolen during battle (e.g., by Looter)
     pub(super) gold_stolen: u32,
+    /// Gold gained during battle (e.g., from Hand of Greed)
+    pub(super) gold_gained: u32,
     /// Events that occurred during the last action (for GUI to read)
     pub battle_events: Vec<BattleEvent>,
     /// Potion inventory for the player
     pub(super) potions: crate::potion::PotionInventory,
     /// Cards that need to be discarded after effects are processed
     pub(super) to_be_discarded: Vec<Card>,
+    /// The deck the battle started from, with every card given its instance id
+    pub(super) master_deck: Deck,
+    /// Changes to cards that outlive the combat (e.g., Ritual Dagger), by card instance id, applied to the master deck after battle
+    pub(super) permanent_card_changes: Vec<(u32, PermanentCardChange)>,
+    /// Cards played, HP lost, ... this turn and this combat
+    pub(super) counters: Counters,
+    /// Set when an effect ends the player's turn (Time Warp), acted on once the current card has resolved
+    pub(super) end_turn_requested: bool,
+    /// Randomness for effects, reseeded from the caller's rng at every action
+    #[serde(skip)]
+    pub(super) rng: BattleRng,
 }
 
 impl Battle {
     /// Create a new battle with PlayerRunState
-    pub fn new(deck: Deck, global_info: GlobalInfo, player_state: PlayerRunState, enemies: Vec<EnemyInBattle>, rng: &mut impl rand::Rng) -> Self {
+    pub fn new(mut deck: Deck, global_info: GlobalInfo, player_state: PlayerRunState, enemies: Vec<EnemyInBattle>, rng: &mut impl rand::Rng) -> Self {
+        deck.assign_card_ids();
+        let master_deck = deck.clone();
         let cards = DeckHandPile::new(deck);
         let enemy_count = enemies.len();
 
@@ -39,7 +72,7 @@ impl Battle {
             .collect();
 
         let mut battle = Battle {
-            player: Player::new(player_state.current_hp, player_state.max_hp, 3),
+            player: Player::new(player_state.current_hp, player_state.max_hp, 0),
             enemies,
             cards,
             event_listeners,
@@ -47,17 +80,38 @@ impl Battle {
             enemy_actions: vec![None; enemy_count],
             relics: player_state.relics,
             powers: Vec::new(),
-            effect_queue: Vec::new(),
+            effect_queue: VecDeque::new(),
             battle_state: BattleState::PlayerTurn,
             gold_stolen: 0,
+            gold_gained: 0,
             battle_events: Vec::new(),
             potions: player_state.potions,
             to_be_discarded: Vec::new(),
+            master_deck,
+            permanent_card_changes: Vec::new(),
+            counters: Counters::new(),
+            end_turn_requested: false,
+            rng: BattleRng::from_rng(rng),
         };
 
+        battle.player.battle_info.energy = battle.energy_per_turn();
+
         // Initialize event listeners for enemies
         battle.initialize_enemy_listeners(&global_info, rng);
 
+        // Philosopher's Stone strengthens every enemy before combat starts
+        let enemy_strength = philosophers_stone::enemy_starting_strength(&battle.relics);
+        if enemy_strength > 0 {
+            for idx in 0..battle.enemies.len() {
+                battle.apply_power(Entity::Enemy(idx), Power::new(PowerId::Strength, enemy_strength as i32));
+            }
+        }
+
+        // Snecko Eye confuses the player before the opening hand is drawn
+        if snecko_eye::starts_confused(&battle.relics) {
+            battle.apply_power(Entity::Player, Power::new(PowerId::Confused, 1));
+        }
+
         // Emit combat start event for relics
         battle.emit_event(BattleEvent::CombatStart { player: Entity::Player });
 
@@ -89,19 +143,35 @@ impl Battle {
     }
 
     /// Extract the final player run state after battle
-    /// This includes updated HP, gold (after stolen gold), and potions
+    /// This includes updated HP, gold (after gained and stolen gold), potions and the master deck
+    /// with permanent card modifications applied
     /// Relics are NOT updated as they remain static during battle
     pub fn get_final_player_run_state(&self, original_gold: u32, original_relics: Vec<Relic>) -> PlayerRunState {
         let final_hp = self.player.battle_info.get_hp();
-        let final_gold = original_gold.saturating_sub(self.gold_stolen);
+        let final_gold = (original_gold + self.gold_gained).saturating_sub(self.gold_stolen);
+
+        let mut final_deck = self.master_deck.clone();
+        for &(id, change) in &self.permanent_card_changes {
+            final_deck.update_card_by_id(id, |card| change.apply(card));
+        }
 
-        PlayerRunState::new_with_relics_and_potions(
+        let mut final_state = PlayerRunState::new_with_relics_and_potions(
             final_hp,
             self.player.battle_info.get_max_hp(),
             final_gold,
             original_relics,
             self.potions.clone(),
-        )
+        );
+        final_state.deck = Some(final_deck);
+        final_state
+    }
+
+    /// Record a permanent change to a card, it is applied to the card's master deck copy after battle
+    /// Cards created during combat are not in the master deck and are ignored
+    pub(crate) fn record_permanent_card_change(&mut self, card: &Card, change: PermanentCardChange) {
+        if let Some(id) = card.get_id() {
+            self.permanent_card_changes.push((id, change));
+        }
     }
 
     /// Get the current battle state
@@ -183,6 +253,11 @@ impl Battle {
         self.gold_stolen
     }
 
+    /// Get total gold gained during battle
+    pub fn get_gold_gained(&self) -> u32 {
+        self.gold_gained
+    }
+
     /// Get global info
     pub fn get_global_info(&self) -> &GlobalInfo {
         &self.global_info
@@ -272,18 +347,15 @@ impl Battle {
         &self.powers
     }
 
-    /// Check if Corruption power is active (makes skills cost 0)
-    pub fn has_corruption_active(&self) -> bool {
-        self.powers.iter().any(|card| card.get_name() == "Corruption" || card.get_name() == "Corruption+")
+    /// Cards played, HP lost, ... this turn and this combat
+    pub fn get_counters(&self) -> &Counters {
+        &self.counters
     }
 
-    /// Get the modified cost of a card considering active powers like Corruption
+    /// Get the energy a card in hand costs to play
+    /// Corruption, Establishment and other cost changes are kept on each card instance
     pub fn get_modified_cost(&self, card: &crate::game::card::Card) -> u32 {
-        if card.get_card_type() == crate::game::card_type::CardType::Skill && self.has_corruption_active() {
-            0  // Skills cost 0 with Corruption
-        } else {
-            card.get_cost()
-        }
+        card.get_cost()
     }
 
     /// Get reference to the potion inventory
@@ -369,33 +441,6 @@ impl Battle {
             Entity::None => modified_damage,
         }
     }
-
-    /// Evaluate if a condition is met in the current battle context
-    pub fn eval_condition(&self, condition: crate::game::effect::Condition) -> bool {
-        use crate::game::effect::Condition;
-        match condition {
-            Condition::True => true,
-            Condition::False => false,
-            Condition::TargetIsVulnerable => {
-                // This would need target context, for now return false
-                false
-            }
-            Condition::HandAllAttacks => {
-                let hand = self.cards.get_hand();
-                hand.iter().all(|c| c.get_card_type() == crate::game::card_type::CardType::Attack)
-            }
-            Condition::HandNoAttacks => {
-                let hand = self.cards.get_hand();
-                hand.iter().all(|c| c.get_card_type() != crate::game::card_type::CardType::Attack)
-            }
-            Condition::EnemyIsAttacking => {
-                // Check if any enemy is currently attacking
-                // For now, assume all moves are attacking (simplified)
-                // In a full implementation, we'd check specific move types
-                !self.enemies.is_empty()
-            }
-        }
-    }
 }
 
 #[cfg(test)]
@@ -532,54 +577,32 @@ impl crate::agents::ForwardSimulation for Battle {
             .map_err(crate::game::game_error::GameError::Battle)
     }
 
+    fn outcome_distribution(&self, action: &Self::Action, max_outcomes: usize, rng: &mut impl rand::Rng) -> Option<Vec<(Self, f64)>> {
+        match action {
+            super::battle_action::BattleAction::EndTurn => self.end_turn_outcomes(max_outcomes, rng),
+            _ => None,
+        }
+    }
+
     fn is_terminal(&self) -> bool {
         self.is_battle_over()
     }
 
     fn evaluate(&self) -> f32 {
-        // Terminal: player dead
-        if !self.player.battle_info.is_alive() {
-            return 0.0;
-        }
-
-        // Terminal: victory (all enemies dead)
-        if self.enemies.iter().all(|e| !e.battle_info.is_alive()) {
-            let player_hp = self.player.battle_info.get_hp() as f32;
-            let player_max_hp = self.player.battle_info.get_max_hp() as f32;
-            let hp_ratio = if player_max_hp > 0.0 {
-                player_hp / player_max_hp
-            } else {
-                0.0
-            };
-            // Victory reward: 1.2 + (hp_ratio * 0.5) → range [1.2, 1.7]
-            return 1.2 + (hp_ratio * 0.5);
-        }
+        // The default weights of the battle heuristic, other objectives plug their own evaluator into MCTS
+        use crate::agents::Evaluator;
+        crate::agents::BattleEvaluator::default().evaluate(self)
+    }
+}
 
-        // Non-terminal: heuristic evaluation
-        // Player HP ratio
-        let player_hp = self.player.battle_info.get_hp() as f32;
-        let player_max_hp = self.player.battle_info.get_max_hp() as f32;
-        let player_ratio = if player_max_hp > 0.0 {
-            player_hp / player_max_hp
-        } else {
-            0.0
-        };
+impl crate::agents::Determinize for Battle {
+    type Observation = super::observation::BattleObservation;
 
-        // Enemy HP ratios
-        let total_enemy_hp: f32 = self.enemies.iter()
-            .map(|e| e.battle_info.get_hp() as f32)
-            .sum();
-        let total_enemy_max_hp: f32 = self.enemies.iter()
-            .map(|e| e.battle_info.get_max_hp() as f32)
-            .sum();
-
-        let enemy_ratio = if total_enemy_max_hp > 0.0 {
-            total_enemy_hp / total_enemy_max_hp
-        } else {
-            0.0
-        };
+    fn observation(&self) -> Self::Observation {
+        self.player_observation()
+    }
 
-        // Combined score: player_ratio - enemy_ratio → range [-1, 1]
-        player_ratio - enemy_ratio
+    fn determinize(&self, rng: &mut impl rand::Rng) -> Self {
+        self.determinize(rng)
     }
 }
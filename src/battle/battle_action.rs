@@ -6,7 +6,9 @@ pub enum BattleAction {
     SelectCardInHand(usize), // Select a card from hand (for upgrade, put on deck, or duplicate effects)
     SelectCardInDiscard(usize), // Select a card from discard pile
     SelectCardInExhaust(usize), // Select a card from exhaust pile
+    SelectCardInDrawPile(usize), // Select a card from draw pile (e.g., from Secret Weapon)
     SelectCardFromChoices(usize), // Select a card from offered choices (e.g., from Attack Potion)
+    ConfirmSelection,         // Finish a multi-card selection early (e.g., Purity exhausting fewer than 3 cards)
     UsePotion(usize, Option<Entity>), // Use a potion from inventory by slot index with optional target
     KillAllEnemies,           // Kill all enemies (for easy debugging)
     EndTurn,                 // End the current turn
@@ -19,10 +19,14 @@ pub enum BattleEvent {
     CardPlayed {
         source: Entity,
         card_type: crate::game::card_type::CardType,
+        x_value: Option<u32>, // X for X-cost cards (energy spent, plus Chemical X), None otherwise
     },
     CardExhausted {
         source: Entity,
     },
+    CardDiscarded {
+        source: Entity, // Discarded from hand by a card or potion, not at the end of the turn
+    },
     BlockGained {
         source: Entity,
         amount: u32,
@@ -43,15 +47,39 @@ pub enum BattleEvent {
     CardDrawn {
         card_type: crate::game::card_type::CardType,
         is_status_or_curse: bool,
+        on_draw: Vec<BattleEffect>, // The drawn card's own on-draw effects (Void)
     },
     EnemyDeath {
         enemy: Entity,
     },
+    DebuffApplied {
+        target: Entity,
+    },
     EnemySpawned {
-        new_enemy_count: usize, // Total enemy count after spawning
+        enemy: Entity, // Slot the new enemy was placed in
+    },
+    CardOverflowedToDiscard {
+        card: crate::game::card_enum::CardEnum, // Card that went to the discard pile because the hand was full
     },
 }
 
+/// Where effects triggered by an event are inserted into the effect queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QueuePosition {
+    Front, // Resolve before anything already queued (on-damage and on-HP-loss triggers)
+    Back,  // Resolve after everything already queued
+}
+
+impl BattleEvent {
+    /// Reactions to damage and HP loss happen immediately, everything else waits its turn
+    pub fn trigger_position(&self) -> QueuePosition {
+        match self {
+            BattleEvent::DamageTaken { .. } | BattleEvent::HpLostFromCard { .. } => QueuePosition::Front,
+            _ => QueuePosition::Back,
+        }
+    }
+}
+
 pub trait EventListener: Any + Send + Sync {
     fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect>;
     fn is_active(&self) -> bool;
@@ -0,0 +1,80 @@
+use rand::{RngCore, SeedableRng, rngs::SmallRng};
+use std::hash::{Hash, Hasher};
+
+/// The randomness effects draw from while an action resolves (random targets, spawned enemies' intents, ...)
+/// It is reseeded from the caller's rng at every action, so a seeded caller replays the same battle.
+/// The rng is not part of the battle state: battles that only differ in it are equal and hash alike.
+/// It counts how often it was drawn from, so callers can tell whether a step of the battle was random.
+#[derive(Debug, Clone)]
+pub struct BattleRng {
+    rng: SmallRng,
+    draws: u64,
+}
+
+impl BattleRng {
+    pub fn from_rng(rng: &mut impl rand::Rng) -> Self {
+        BattleRng { rng: SmallRng::from_rng(rng), draws: 0 }
+    }
+
+    /// Continue from the caller's rng, dropping whatever was left of the previous seed
+    pub fn reseed(&mut self, rng: &mut impl rand::Rng) {
+        self.rng = SmallRng::from_rng(rng);
+    }
+
+    /// Number of draws made so far
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+}
+
+impl Default for BattleRng {
+    fn default() -> Self {
+        BattleRng { rng: SmallRng::seed_from_u64(0), draws: 0 }
+    }
+}
+
+impl RngCore for BattleRng {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 1;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.draws += 1;
+        self.rng.fill_bytes(dst)
+    }
+}
+
+impl PartialEq for BattleRng {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for BattleRng {}
+
+impl Hash for BattleRng {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_same_caller_seed_gives_same_draws() {
+        let mut a = BattleRng::from_rng(&mut SmallRng::seed_from_u64(7));
+        let mut b = BattleRng::from_rng(&mut SmallRng::seed_from_u64(7));
+        let draws_a: Vec<u32> = (0..8).map(|_| a.random_range(0..100)).collect();
+        let draws_b: Vec<u32> = (0..8).map(|_| b.random_range(0..100)).collect();
+        assert_eq!(draws_a, draws_b);
+        assert!(a.draws() >= 8);
+        assert!(a == BattleRng::default());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::game::card_enum::CardEnum;
+use crate::game::{card_enum::CardEnum, card_type::CardType};
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -7,6 +7,11 @@ pub enum BattleState {
     SelectCardInHand (CardInHandTo),
     SelectCardInDiscard,
     SelectCardInExhaust,
+    /// Choose cards of the given type from the draw pile to put into hand
+    SelectCardInDrawPile {
+        card_type: CardType,
+        selection: MultiSelect,
+    },
     SelectCardFromChoices {
         /// The cards to choose from
         choices: Vec<CardEnum>,
@@ -17,9 +22,89 @@ pub enum BattleState {
     },
 }
 
+impl BattleState {
+    /// The selection in progress, for states where several cards are picked one at a time
+    pub fn multi_select(&self) -> Option<&MultiSelect> {
+        match self {
+            BattleState::SelectCardInHand(CardInHandTo::PutOnBottomOfDeck(selection))
+            | BattleState::SelectCardInHand(CardInHandTo::Exhaust(selection))
+            | BattleState::SelectCardInHand(CardInHandTo::DiscardAndDraw { selection, .. })
+            | BattleState::SelectCardInDrawPile { selection, .. } => Some(selection),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CardInHandTo {
     PutOnDeck,
     Upgrade,
     Duplicate { copies: u32 },
-}
\ No newline at end of file
+    /// Put cards on the bottom of the draw pile; they cost 0 until played
+    PutOnBottomOfDeck(MultiSelect),
+    /// Exhaust cards
+    Exhaust(MultiSelect),
+    /// Discard cards, then draw as many as were discarded (Gambler's Brew)
+    DiscardAndDraw { discarded: u32, selection: MultiSelect },
+}
+
+/// Progress of a selection of several cards from one pile
+/// Every pick removes the card from its pile, and picks must be made in pile order:
+/// after picking index i the next pick is at index i or later, so each subset is reachable one way only
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultiSelect {
+    /// Picks still allowed
+    pub remaining: u32,
+    /// Picks still needed before the selection can be confirmed
+    pub required: u32,
+    /// Lowest pile index the next pick can use
+    pub next_index: usize,
+}
+
+impl MultiSelect {
+    /// Choose up to `count` cards, confirming at any time
+    pub fn up_to(count: u32) -> Self {
+        MultiSelect { remaining: count, required: 0, next_index: 0 }
+    }
+
+    /// Choose exactly `count` cards, or as many as there are
+    pub fn exactly(count: u32) -> Self {
+        MultiSelect { remaining: count, required: count, next_index: 0 }
+    }
+
+    /// Whether `ConfirmSelection` may end the selection now
+    pub fn can_confirm(&self) -> bool {
+        self.required == 0
+    }
+
+    /// The selection after the card at `index` was picked and removed from its pile
+    pub fn after_pick(self, index: usize) -> Self {
+        MultiSelect {
+            remaining: self.remaining.saturating_sub(1),
+            required: self.required.saturating_sub(1),
+            next_index: index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_up_to_can_be_confirmed_right_away() {
+        let selection = MultiSelect::up_to(3);
+        assert!(selection.can_confirm());
+
+        let selection = selection.after_pick(2);
+        assert_eq!(selection, MultiSelect { remaining: 2, required: 0, next_index: 2 });
+    }
+
+    #[test]
+    fn test_exactly_needs_every_pick() {
+        let selection = MultiSelect::exactly(2);
+        assert!(!selection.can_confirm());
+        assert!(!selection.after_pick(0).can_confirm());
+        assert!(selection.after_pick(0).after_pick(0).can_confirm());
+    }
+}
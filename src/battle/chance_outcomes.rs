@@ -0,0 +1,213 @@
+use super::Battle;
+use super::battle_state::BattleState;
+use super::deck_hand_pile::MAX_HAND_SIZE;
+use super::power::PowerId;
+use crate::enemies::enemy_enum::EnemyMoveOutcome;
+
+/// Largest discard pile whose reshuffle orders are listed (4! = 24 orders)
+const MAX_LISTED_RESHUFFLE: usize = 4;
+
+impl Battle {
+    /// Every state ending the turn can lead to, with its probability, if there are at most `max_outcomes`
+    ///
+    /// The enemy turn is played once with `rng`, the chance events listed are those of the next turn start:
+    /// the intents the enemies pick and, when the draw runs through the draw pile, the order the
+    /// discard pile is reshuffled into.
+    /// None when an enemy cannot list its moves, the discard pile to reshuffle is too large,
+    /// there are more outcomes than `max_outcomes` or anything else is random (random targets or
+    /// debuffs, spawned enemies, Confused costs, ...), as playing it once would fix its result.
+    pub fn end_turn_outcomes(&self, max_outcomes: usize, rng: &mut impl rand::Rng) -> Option<Vec<(Battle, f64)>> {
+        if self.is_battle_over() || !matches!(self.battle_state, BattleState::PlayerTurn) {
+            return None;
+        }
+
+        let mut turn_start = self.clone();
+        let draws = turn_start.rng.draws();
+        turn_start.at_end_of_player_turn();
+        turn_start.at_start_of_enemy_turn();
+        let global_info = turn_start.global_info;
+        turn_start.process_enemy_effects(rng, &global_info);
+        turn_start.at_end_of_enemy_turn();
+        turn_start.begin_player_turn();
+        if turn_start.rng.draws() != draws {
+            return None;
+        }
+
+        let intents = turn_start.enemies.iter()
+            .map(|enemy| enemy.enemy.move_outcomes(&global_info, rng))
+            .collect::<Option<Vec<_>>>()?;
+        let reshuffles = turn_start.reshuffle_orders()?;
+        let outcome_count = intents.iter()
+            .try_fold(reshuffles.len(), |count, moves| count.checked_mul(moves.len()))?;
+        if outcome_count > max_outcomes {
+            return None;
+        }
+
+        if turn_start.enemy_actions.len() < turn_start.enemies.len() {
+            turn_start.enemy_actions.resize(turn_start.enemies.len(), None);
+        }
+
+        let mut outcomes: Vec<(Battle, f64)> = Vec::new();
+        for (enemy_moves, intent_probability) in intent_combinations(&intents) {
+            for (order, order_probability) in &reshuffles {
+                let mut battle = turn_start.clone();
+                for (index, (enemy, enemy_move, effects, _)) in enemy_moves.iter().enumerate() {
+                    battle.enemies[index].set_intent_ai_state(enemy.clone());
+                    battle.enemy_actions[index] = Some((enemy_move.clone(), effects.clone()));
+                }
+                if let Some(order) = order {
+                    battle.cards.put_discard_under_deck(order);
+                }
+                battle.draw_turn_hand();
+                if battle.rng.draws() != draws {
+                    return None;
+                }
+                // eval_action hands the events out, they are not part of the resulting state
+                battle.take_battle_events();
+
+                // Different choices can end in the same state (e.g. identical cards reshuffled)
+                let probability = intent_probability * order_probability;
+                match outcomes.iter_mut().find(|(existing, _)| *existing == battle) {
+                    Some((_, total)) => *total += probability,
+                    None => outcomes.push((battle, probability)),
+                }
+            }
+        }
+        Some(outcomes)
+    }
+
+    /// The orders the discard pile can be reshuffled into by the turn start draw, each with its probability
+    /// A single `None` when the draw does not reshuffle, None when there are too many orders to list
+    fn reshuffle_orders(&self) -> Option<Vec<(Option<Vec<usize>>, f64)>> {
+        let draw_pile = self.cards.deck_size();
+        let reshuffles = !self.player.battle_info.has_power(PowerId::NoDraw)
+            && self.cards_per_turn() > draw_pile
+            && self.cards.hand_size() + draw_pile < MAX_HAND_SIZE
+            && self.cards.discard_pile_size() > 0;
+        if !reshuffles {
+            return Some(vec![(None, 1.0)]);
+        }
+
+        let discarded = self.cards.discard_pile_size();
+        if discarded > MAX_LISTED_RESHUFFLE {
+            return None;
+        }
+        let orders = permutations(discarded);
+        let probability = 1.0 / orders.len() as f64;
+        Some(orders.into_iter().map(|order| (Some(order), probability)).collect())
+    }
+}
+
+/// Every way to pick one move per enemy, with the product of the probabilities
+fn intent_combinations(intents: &[Vec<EnemyMoveOutcome>]) -> Vec<(Vec<&EnemyMoveOutcome>, f64)> {
+    intents.iter().fold(vec![(Vec::new(), 1.0)], |combinations, moves| {
+        combinations.iter()
+            .flat_map(|(chosen, probability)| moves.iter().map(move |outcome| {
+                let mut chosen = chosen.clone();
+                chosen.push(outcome);
+                (chosen, probability * outcome.3)
+            }))
+            .collect()
+    })
+}
+
+/// Every order of the indices 0..n
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    (0..n).fold(vec![Vec::new()], |orders, item| {
+        orders.into_iter()
+            .flat_map(|order| (0..=order.len()).map(move |position| {
+                let mut order = order.clone();
+                order.insert(position, item);
+                order
+            }))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::battle_action::BattleAction;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::defend::defend;
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+    use crate::game::{card_enum::CardEnum, effect::{BattleEffect, StatusPlacement}};
+
+    fn battle_with_deck(deck_size: usize) -> Battle {
+        BattleBuilder::new()
+            .with_deck(vec![strike(); deck_size])
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(200, false)))
+            .build()
+    }
+
+    fn assert_sampled_outcomes_are_listed(battle: &Battle, outcomes: &[(Battle, f64)]) {
+        let total: f64 = outcomes.iter().map(|(_, probability)| probability).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        let mut rng = rand::rng();
+        for _ in 0..20 {
+            let mut sampled = battle.clone();
+            sampled.eval_action(BattleAction::EndTurn, &mut rng).unwrap();
+            assert!(outcomes.iter().any(|(outcome, _)| *outcome == sampled));
+        }
+    }
+
+    #[test]
+    fn test_end_turn_lists_enemy_intents() {
+        let battle = battle_with_deck(40);
+        let mut rng = rand::rng();
+
+        let outcomes = battle.end_turn_outcomes(10, &mut rng).unwrap();
+        // Jaw Worm can follow its opening Chomp with Bellow or Thrash
+        assert_eq!(outcomes.len(), 2);
+        assert_sampled_outcomes_are_listed(&battle, &outcomes);
+    }
+
+    #[test]
+    fn test_end_turn_lists_small_reshuffles() {
+        let battle = battle_with_deck(3);
+        let mut rng = rand::rng();
+
+        let outcomes = battle.end_turn_outcomes(100, &mut rng).unwrap();
+        // The 3! orders of identical Strikes are one state, only the 2 intents remain
+        assert_eq!(outcomes.len(), 2);
+        assert_sampled_outcomes_are_listed(&battle, &outcomes);
+
+        let battle = BattleBuilder::new()
+            .with_deck(vec![strike(), strike(), defend()])
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(200, false)))
+            .build();
+        let outcomes = battle.end_turn_outcomes(100, &mut rng).unwrap();
+        // 3 places for the Defend times 2 intents
+        assert_eq!(outcomes.len(), 6);
+        assert_sampled_outcomes_are_listed(&battle, &outcomes);
+    }
+
+    #[test]
+    fn test_end_turn_outcomes_respect_the_limit() {
+        let mut rng = rand::rng();
+        assert!(battle_with_deck(3).end_turn_outcomes(11, &mut rng).is_none());
+        // Reshuffling the 5 card hand has too many orders to list
+        assert!(battle_with_deck(5).end_turn_outcomes(usize::MAX, &mut rng).is_none());
+        assert_eq!(permutations(3).len(), 6);
+    }
+
+    #[test]
+    fn test_end_turn_outcomes_refuse_random_enemy_effects() {
+        let mut battle = battle_with_deck(40);
+        let mut rng = rand::rng();
+
+        // Jaw Worm's attack also shuffles a Dazed into a random place of the draw pile
+        let (enemy_move, mut effects) = battle.enemy_actions[0].clone().unwrap();
+        effects.push(BattleEffect::AddStatusCard {
+            status_card: CardEnum::Dazed,
+            count: 1,
+            placement: StatusPlacement::ShuffleIntoDrawPile,
+            upgraded: false,
+        });
+        battle.enemy_actions[0] = Some((enemy_move, effects));
+
+        assert!(battle.end_turn_outcomes(usize::MAX, &mut rng).is_none());
+    }
+}
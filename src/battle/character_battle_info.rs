@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::battle::power::{Power, PowerDuration, PowerId, Powers};
+use crate::battle::damage_type::DamageType;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CharacterBattleInfo {
@@ -6,17 +8,8 @@ pub struct CharacterBattleInfo {
     pub current_hp: u32,
     pub block: u32,
     pub energy: u32,
-    pub vulnerable_turns: u32,
-    pub strength: i32,
-    pub dexterity: i32,
-    pub weak_turns: u32,
-    pub frail_turns: u32,
-    pub entangled_turns: u32,  // Number of turns unable to play Attack cards
-    pub ritual: u32,
-    pub rampage_damage: u32,
-    pub artifact: u32,  // Number of artifact charges (blocks debuffs)
     pub escaped: bool,  // Whether this enemy has escaped from combat
-    // Additional status effects can be added here
+    powers: Powers,  // Buffs and debuffs (Strength, Vulnerable, Artifact, Metallicize...)
 }
 
 impl CharacterBattleInfo {
@@ -26,16 +19,8 @@ impl CharacterBattleInfo {
             current_hp: current_hp.min(max_hp),
             block: 0,
             energy,
-            vulnerable_turns: 0,
-            strength: 0,
-            dexterity: 0,
-            weak_turns: 0,
-            frail_turns: 0,
-            entangled_turns: 0,
-            ritual: 0,
-            rampage_damage: 0,
-            artifact: 0,
             escaped: false,
+            powers: Powers::new(),
         }
     }
 
@@ -45,43 +30,61 @@ impl CharacterBattleInfo {
             current_hp: hp,
             block: 0,
             energy: 0, // Enemies don't use energy
-            vulnerable_turns: 0,
-            strength: 0,
-            dexterity: 0,
-            weak_turns: 0,
-            frail_turns: 0,
-            entangled_turns: 0,
-            ritual: 0,
-            rampage_damage: 0,
-            artifact: 0,
             escaped: false,
+            powers: Powers::new(),
         }
     }
 
-    /// Take damage, accounting for block (vulnerable should be calculated by Battle)
+    /// Take attack damage, accounting for block (vulnerable should be calculated by Battle)
     pub fn take_damage(&mut self, incoming_damage: u32) -> u32 {
+        let unblocked_damage = self.absorb_damage(incoming_damage, DamageType::Normal);
+        self.lose_hp(unblocked_damage)
+    }
+
+    /// Apply Intangible and block to incoming damage, returning what is left to come off HP
+    pub(crate) fn absorb_damage(&mut self, incoming_damage: u32, damage_type: DamageType) -> u32 {
+        // Intangible caps every hit at 1 damage before block is applied
+        let incoming_damage = if self.is_intangible() {
+            incoming_damage.min(1)
+        } else {
+            incoming_damage
+        };
+
+        if !damage_type.is_blockable() {
+            return incoming_damage;
+        }
+
         // Apply block reduction
-        let damage_after_block = if incoming_damage > self.block {
+        if incoming_damage > self.block {
             let remaining_damage = incoming_damage - self.block;
             self.block = 0;
             remaining_damage
         } else {
             self.block -= incoming_damage;
             0
-        };
-        
-        // Apply damage to HP
-        self.current_hp = self.current_hp.saturating_sub(damage_after_block);
-        
-        damage_after_block
+        }
+    }
+
+    /// Lose HP that got past block, unless a stack of Buffer negates it
+    pub(crate) fn lose_hp(&mut self, amount: u32) -> u32 {
+        if amount == 0 {
+            return 0;
+        }
+        if self.has_power(PowerId::Buffer) {
+            self.apply_power(Power::new(PowerId::Buffer, -1));
+            return 0;
+        }
+
+        self.current_hp = self.current_hp.saturating_sub(amount);
+        amount
     }
 
     /// Gain block (defense)
     pub fn gain_block(&mut self, amount: u32) {
         // Add dexterity bonus to block amount
-        let amount_with_dexterity = (amount as i32 + self.dexterity).max(0) as u32;
+        let amount_with_dexterity = (amount as i32 + self.get_dexterity()).max(0) as u32;
 
-        let actual_amount = if self.frail_turns > 0 {
+        let actual_amount = if self.is_frail() {
             // Frail reduces block gain by 25%
             (amount_with_dexterity as f32 * 0.75) as u32
         } else {
@@ -105,29 +108,80 @@ impl CharacterBattleInfo {
         }
     }
 
+    /// Apply a power, stacking it with the same power already present
+    pub fn apply_power(&mut self, power: Power) {
+        self.powers.apply(power);
+    }
+
+    /// Remove a power entirely (e.g., Lagavulin losing Metallicize when it wakes)
+    pub fn remove_power(&mut self, id: PowerId) {
+        self.powers.remove(id);
+    }
+
+    /// Current amount of a power, 0 if absent
+    pub fn get_power_amount(&self, id: PowerId) -> i32 {
+        self.powers.amount(id)
+    }
+
+    /// Check if a power is present
+    pub fn has_power(&self, id: PowerId) -> bool {
+        self.powers.has(id)
+    }
+
+    /// All buffs and debuffs on this character, sorted by id
+    pub fn get_powers(&self) -> &[Power] {
+        self.powers.as_slice()
+    }
+
+    /// Mutable access for running power hooks
+    pub(in crate::battle) fn powers_mut(&mut self) -> &mut Powers {
+        &mut self.powers
+    }
+
+    /// Turns remaining of a turn-based power
+    fn power_turns(&self, id: PowerId) -> u32 {
+        self.powers.amount(id).max(0) as u32
+    }
+
     /// Apply vulnerable status (additive)
     pub fn apply_vulnerable(&mut self, turns: u32) {
-        self.vulnerable_turns += turns;
+        self.apply_power(Power::new(PowerId::Vulnerable, turns as i32));
     }
 
     /// Apply weak status (additive)
     pub fn apply_weak(&mut self, turns: u32) {
-        self.weak_turns += turns;
+        self.apply_power(Power::new(PowerId::Weak, turns as i32));
     }
 
     /// Apply frail status (additive)
     pub fn apply_frail(&mut self, turns: u32) {
-        self.frail_turns += turns;
+        self.apply_power(Power::new(PowerId::Frail, turns as i32));
     }
 
     /// Apply entangled status (additive)
     pub fn apply_entangled(&mut self, turns: u32) {
-        self.entangled_turns += turns;
+        self.apply_power(Power::new(PowerId::Entangled, turns as i32));
+    }
+
+    /// Apply intangible status (additive)
+    pub fn apply_intangible(&mut self, turns: u32) {
+        self.apply_power(Power::new(PowerId::Intangible, turns as i32));
+    }
+
+    /// Check if intangible
+    pub fn is_intangible(&self) -> bool {
+        self.has_power(PowerId::Intangible)
     }
 
     /// Start of turn - reset block and decrement status effects
     pub fn at_start_of_turn(&mut self) {
-        self.block = 0;
+        // Barricade keeps block forever, Blur keeps it for as many turns as it has left
+        if !self.has_power(PowerId::Barricade) && !self.has_power(PowerId::Blur) {
+            self.block = 0;
+        }
+
+        // Intangible lasts through the opposing turn, so it ticks down when the owner's turn starts again
+        self.powers.tick(PowerDuration::TurnsAtTurnStart);
     }
 
     /// End of turn - apply end-of-turn effects
@@ -135,18 +189,7 @@ impl CharacterBattleInfo {
         // Apply ritual effect (gain strength equal to ritual stacks)
         self.apply_ritual_effect();
 
-        if self.vulnerable_turns > 0 {
-            self.vulnerable_turns -= 1;
-        }
-        if self.weak_turns > 0 {
-            self.weak_turns -= 1;
-        }
-        if self.frail_turns > 0 {
-            self.frail_turns -= 1;
-        }
-        if self.entangled_turns > 0 {
-            self.entangled_turns -= 1;
-        }
+        self.powers.tick(PowerDuration::Turns);
     }
 
     /// Check if character is alive (not dead and not escaped)
@@ -192,103 +235,103 @@ impl CharacterBattleInfo {
 
     /// Check if vulnerable
     pub fn is_vulnerable(&self) -> bool {
-        self.vulnerable_turns > 0
+        self.has_power(PowerId::Vulnerable)
     }
 
     /// Get vulnerable turns remaining
     pub fn get_vulnerable_turns(&self) -> u32 {
-        self.vulnerable_turns
+        self.power_turns(PowerId::Vulnerable)
     }
 
     /// Check if weak
     pub fn is_weak(&self) -> bool {
-        self.weak_turns > 0
+        self.has_power(PowerId::Weak)
     }
 
     /// Get weak turns remaining
     pub fn get_weak_turns(&self) -> u32 {
-        self.weak_turns
+        self.power_turns(PowerId::Weak)
     }
 
     /// Check if frail
     pub fn is_frail(&self) -> bool {
-        self.frail_turns > 0
+        self.has_power(PowerId::Frail)
     }
 
     /// Get frail turns remaining
     pub fn get_frail_turns(&self) -> u32 {
-        self.frail_turns
+        self.power_turns(PowerId::Frail)
     }
 
     /// Check if entangled
     pub fn is_entangled(&self) -> bool {
-        self.entangled_turns > 0
+        self.has_power(PowerId::Entangled)
     }
 
     /// Get entangled turns remaining
     pub fn get_entangled_turns(&self) -> u32 {
-        self.entangled_turns
+        self.power_turns(PowerId::Entangled)
     }
 
     /// Gain strength
     pub fn gain_strength(&mut self, amount: u32) {
-        self.strength += amount as i32;
+        self.apply_power(Power::new(PowerId::Strength, amount as i32));
     }
 
     /// Get strength
     pub fn get_strength(&self) -> i32 {
-        self.strength
+        self.get_power_amount(PowerId::Strength)
     }
 
     /// Set strength to a specific value
     pub fn set_strength(&mut self, amount: u32) {
-        self.strength = amount as i32;
+        self.powers.set(PowerId::Strength, amount as i32);
     }
 
     /// Lose strength (now allows negative values)
     pub fn lose_strength(&mut self, amount: u32) {
-        self.strength -= amount as i32;
+        self.apply_power(Power::new(PowerId::Strength, -(amount as i32)));
     }
 
     /// Gain dexterity
     pub fn gain_dexterity(&mut self, amount: u32) {
-        self.dexterity += amount as i32;
+        self.apply_power(Power::new(PowerId::Dexterity, amount as i32));
     }
 
     /// Get dexterity
     pub fn get_dexterity(&self) -> i32 {
-        self.dexterity
+        self.get_power_amount(PowerId::Dexterity)
     }
 
     /// Set dexterity to a specific value
     pub fn set_dexterity(&mut self, amount: u32) {
-        self.dexterity = amount as i32;
+        self.powers.set(PowerId::Dexterity, amount as i32);
     }
 
     /// Lose dexterity (now allows negative values)
     pub fn lose_dexterity(&mut self, amount: u32) {
-        self.dexterity -= amount as i32;
+        self.apply_power(Power::new(PowerId::Dexterity, -(amount as i32)));
     }
 
     /// Gain artifact (prevents debuffs)
     pub fn gain_artifact(&mut self, amount: u32) {
-        self.artifact += amount;
+        self.apply_power(Power::new(PowerId::Artifact, amount as i32));
     }
 
     /// Get artifact charges
     pub fn get_artifact(&self) -> u32 {
-        self.artifact
+        self.power_turns(PowerId::Artifact)
     }
 
     /// Check if has artifact
     pub fn has_artifact(&self) -> bool {
-        self.artifact > 0
+        self.has_power(PowerId::Artifact)
     }
 
     /// Consume one artifact charge (returns true if consumed, false if none available)
     pub fn consume_artifact(&mut self) -> bool {
-        if self.artifact > 0 {
-            self.artifact -= 1;
+        if self.has_artifact() {
+            self.apply_power(Power::new(PowerId::Artifact, -1));
             true
         } else {
             false
@@ -313,11 +356,11 @@ impl CharacterBattleInfo {
 
     /// Calculate damage output with custom strength multiplier and weak penalty
     pub(crate) fn calculate_damage_with_multiplier(&self, base_damage: u32, strength_multiplier: u32) -> u32 {
-        let strength_bonus = self.strength * strength_multiplier as i32;
+        let strength_bonus = self.get_strength() * strength_multiplier as i32;
         let damage_with_strength = (base_damage as i32 + strength_bonus).max(0) as u32;
 
         // Apply weak penalty (25% less damage)
-        if self.weak_turns > 0 {
+        if self.is_weak() {
             (damage_with_strength as f32 * 0.75) as u32
         } else {
             damage_with_strength
@@ -327,7 +370,7 @@ impl CharacterBattleInfo {
     /// Calculate incoming damage with vulnerable multiplier (before block)
     pub fn calculate_incoming_damage(&self, base_damage: u32) -> u32 {
         // Apply vulnerable multiplier (50% more damage)
-        if self.vulnerable_turns > 0 {
+        if self.is_vulnerable() {
             (base_damage as f32 * 1.5) as u32
         } else {
             base_damage
@@ -350,31 +393,22 @@ impl CharacterBattleInfo {
 
     /// Gain ritual stacks
     pub fn gain_ritual(&mut self, amount: u32) {
-        self.ritual += amount;
+        self.apply_power(Power::new(PowerId::Ritual, amount as i32));
     }
 
     /// Get ritual stacks
     pub fn get_ritual(&self) -> u32 {
-        self.ritual
+        self.power_turns(PowerId::Ritual)
     }
 
     /// Apply ritual effect (gain strength equal to ritual stacks)
     pub(in crate::battle) fn apply_ritual_effect(&mut self) {
-        if self.ritual > 0 {
-            self.gain_strength(self.ritual);
+        let ritual = self.get_ritual();
+        if ritual > 0 {
+            self.gain_strength(ritual);
         }
     }
 
-    /// Get rampage damage scaling
-    pub fn get_rampage_damage(&self) -> u32 {
-        self.rampage_damage
-    }
-
-    /// Increase rampage damage scaling
-    pub fn increase_rampage_damage(&mut self, amount: u32) {
-        self.rampage_damage += amount;
-    }
-
     /// Increase max HP and also heal by the same amount
     pub fn increase_max_hp(&mut self, amount: u32) {
         self.max_hp += amount;
@@ -393,9 +427,9 @@ mod tests {
         assert_eq!(character.max_hp, 50);
         assert_eq!(character.energy, 3);
         assert_eq!(character.block, 0);
-        assert_eq!(character.vulnerable_turns, 0);
-        assert_eq!(character.strength, 0);
-        assert_eq!(character.ritual, 0);
+        assert_eq!(character.get_vulnerable_turns(), 0);
+        assert_eq!(character.get_strength(), 0);
+        assert_eq!(character.get_ritual(), 0);
     }
 
     #[test]
@@ -459,14 +493,14 @@ mod tests {
         // At start of turn - block is reset but status effects remain
         character.at_start_of_turn();
         assert_eq!(character.block, 0);
-        assert_eq!(character.vulnerable_turns, 3); // Status effects don't change at start of turn
+        assert_eq!(character.get_vulnerable_turns(), 3); // Status effects don't change at start of turn
         
         // At end of turn - status effects decrement
         character.at_end_of_turn();
-        assert_eq!(character.vulnerable_turns, 2);
+        assert_eq!(character.get_vulnerable_turns(), 2);
         
         character.at_end_of_turn();
-        assert_eq!(character.vulnerable_turns, 1);
+        assert_eq!(character.get_vulnerable_turns(), 1);
     }
 
     #[test]
@@ -490,9 +524,9 @@ mod tests {
         assert_eq!(character.max_hp, 30);
         assert_eq!(character.energy, 0); // Enemies have no energy
         assert_eq!(character.block, 0);
-        assert_eq!(character.vulnerable_turns, 0);
-        assert_eq!(character.strength, 0);
-        assert_eq!(character.ritual, 0);
+        assert_eq!(character.get_vulnerable_turns(), 0);
+        assert_eq!(character.get_strength(), 0);
+        assert_eq!(character.get_ritual(), 0);
     }
 
     #[test]
@@ -577,7 +611,7 @@ mod tests {
         assert_eq!(character.get_block(), 7); // 10 * 0.75 = 7.5, rounded down to 7
         
         // Block gain without frail
-        character.frail_turns = 0;
+        character.remove_power(PowerId::Frail);
         character.block = 0;
         character.gain_block(10);
         assert_eq!(character.get_block(), 10);
@@ -606,4 +640,70 @@ mod tests {
         character.apply_frail(2);
         assert_eq!(character.get_frail_turns(), 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_intangible_caps_damage_at_one() {
+        let mut character = CharacterBattleInfo::new(50, 50, 3);
+        character.apply_intangible(1);
+        assert!(character.is_intangible());
+
+        let damage = character.take_damage(20);
+        assert_eq!(damage, 1);
+        assert_eq!(character.get_hp(), 49);
+    }
+
+    #[test]
+    fn test_intangible_wears_off_at_start_of_turn() {
+        let mut character = CharacterBattleInfo::new(50, 50, 3);
+        character.apply_intangible(1);
+
+        character.at_start_of_turn();
+        assert!(!character.is_intangible());
+
+        character.take_damage(20);
+        assert_eq!(character.get_hp(), 30);
+    }
+
+    #[test]
+    fn test_hp_loss_ignores_block() {
+        let mut character = CharacterBattleInfo::new(50, 50, 3);
+        character.gain_block(10);
+
+        assert_eq!(character.absorb_damage(6, DamageType::HpLoss), 6);
+        assert_eq!(character.get_block(), 10);
+        assert_eq!(character.absorb_damage(6, DamageType::Thorns), 0);
+        assert_eq!(character.get_block(), 4);
+    }
+
+    #[test]
+    fn test_buffer_negates_hp_loss_once_per_stack() {
+        let mut character = CharacterBattleInfo::new(50, 50, 3);
+        character.apply_power(Power::new(PowerId::Buffer, 1));
+        character.gain_block(5);
+
+        // Fully blocked hits do not use up Buffer
+        assert_eq!(character.take_damage(5), 0);
+        assert!(character.has_power(PowerId::Buffer));
+
+        assert_eq!(character.take_damage(20), 0);
+        assert_eq!(character.get_hp(), 50);
+        assert!(!character.has_power(PowerId::Buffer));
+
+        assert_eq!(character.take_damage(20), 20);
+        assert_eq!(character.get_hp(), 30);
+    }
+
+    #[test]
+    fn test_blur_keeps_block_for_one_turn() {
+        let mut character = CharacterBattleInfo::new(50, 50, 3);
+        character.apply_power(Power::new(PowerId::Blur, 1));
+        character.gain_block(8);
+
+        character.at_start_of_turn();
+        assert_eq!(character.get_block(), 8);
+        assert!(!character.has_power(PowerId::Blur));
+
+        character.at_start_of_turn();
+        assert_eq!(character.get_block(), 0);
+    }
+}
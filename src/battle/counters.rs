@@ -0,0 +1,186 @@
+use crate::battle::{battle_events::BattleEvent, target::Entity};
+use crate::game::card_type::CardType;
+use serde::{Serialize, Deserialize};
+
+/// Things the battle counts for cards, relics and UIs to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Counter {
+    CardsPlayed,
+    AttacksPlayed,
+    SkillsPlayed,
+    PowersPlayed,
+    CardsDrawn,
+    CardsExhausted,
+    CardsDiscarded, // Eviscerate
+    TimesPlayerLostHp, // Blood for Blood
+    PlayerHpLost,
+    EnemiesKilled,
+    PlayerTurns, // Player turns started, so the combat count is the current turn number
+}
+
+impl Counter {
+    const COUNT: usize = 11;
+
+    pub const ALL: [Counter; Counter::COUNT] = [
+        Counter::CardsPlayed,
+        Counter::AttacksPlayed,
+        Counter::SkillsPlayed,
+        Counter::PowersPlayed,
+        Counter::CardsDrawn,
+        Counter::CardsExhausted,
+        Counter::CardsDiscarded,
+        Counter::TimesPlayerLostHp,
+        Counter::PlayerHpLost,
+        Counter::EnemiesKilled,
+        Counter::PlayerTurns,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Whether a count covers the current player turn or the whole combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CounterScope {
+    ThisTurn,
+    ThisCombat,
+}
+
+/// Per-turn and per-combat counts, updated from battle events
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Counters {
+    this_turn: [u32; Counter::COUNT],
+    this_combat: [u32; Counter::COUNT],
+}
+
+impl Counters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, counter: Counter, scope: CounterScope) -> u32 {
+        match scope {
+            CounterScope::ThisTurn => self.this_turn[counter.index()],
+            CounterScope::ThisCombat => self.this_combat[counter.index()],
+        }
+    }
+
+    pub fn this_turn(&self, counter: Counter) -> u32 {
+        self.get(counter, CounterScope::ThisTurn)
+    }
+
+    pub fn this_combat(&self, counter: Counter) -> u32 {
+        self.get(counter, CounterScope::ThisCombat)
+    }
+
+    /// Clear the per-turn counts, called when the player's turn starts (before the draw)
+    pub(in crate::battle) fn start_turn(&mut self) {
+        self.this_turn = [0; Counter::COUNT];
+    }
+
+    /// Update the counts an event contributes to
+    pub(in crate::battle) fn record(&mut self, event: &BattleEvent) {
+        match event {
+            BattleEvent::CardPlayed { source: Entity::Player, card_type, .. } => {
+                self.add(Counter::CardsPlayed, 1);
+                match card_type {
+                    CardType::Attack => self.add(Counter::AttacksPlayed, 1),
+                    CardType::Skill => self.add(Counter::SkillsPlayed, 1),
+                    CardType::Power => self.add(Counter::PowersPlayed, 1),
+                    _ => {}
+                }
+            }
+            BattleEvent::CardDrawn { .. } => self.add(Counter::CardsDrawn, 1),
+            BattleEvent::CardExhausted { source: Entity::Player } => self.add(Counter::CardsExhausted, 1),
+            BattleEvent::CardDiscarded { source: Entity::Player } => self.add(Counter::CardsDiscarded, 1),
+            BattleEvent::DamageTaken { target: Entity::Player, amount, .. }
+            | BattleEvent::HpLostFromCard { target: Entity::Player, amount } if *amount > 0 => {
+                self.add(Counter::TimesPlayerLostHp, 1);
+                self.add(Counter::PlayerHpLost, *amount);
+            }
+            BattleEvent::EnemyDeath { .. } => self.add(Counter::EnemiesKilled, 1),
+            BattleEvent::StartOfPlayerTurn => self.add(Counter::PlayerTurns, 1),
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, counter: Counter, amount: u32) {
+        self.this_turn[counter.index()] += amount;
+        self.this_combat[counter.index()] += amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attack_played() -> BattleEvent {
+        BattleEvent::CardPlayed { source: Entity::Player, card_type: CardType::Attack, x_value: None }
+    }
+
+    #[test]
+    fn test_counter_indices_match_all() {
+        for (i, counter) in Counter::ALL.iter().enumerate() {
+            assert_eq!(counter.index(), i);
+        }
+    }
+
+    #[test]
+    fn test_cards_played_by_type() {
+        let mut counters = Counters::new();
+        counters.record(&attack_played());
+        counters.record(&attack_played());
+        counters.record(&BattleEvent::CardPlayed { source: Entity::Player, card_type: CardType::Skill, x_value: None });
+
+        assert_eq!(counters.this_turn(Counter::CardsPlayed), 3);
+        assert_eq!(counters.this_turn(Counter::AttacksPlayed), 2);
+        assert_eq!(counters.this_turn(Counter::SkillsPlayed), 1);
+        assert_eq!(counters.this_turn(Counter::PowersPlayed), 0);
+    }
+
+    #[test]
+    fn test_turn_counts_reset_but_combat_counts_stay() {
+        let mut counters = Counters::new();
+        counters.record(&attack_played());
+        counters.start_turn();
+        counters.record(&attack_played());
+
+        assert_eq!(counters.get(Counter::AttacksPlayed, CounterScope::ThisTurn), 1);
+        assert_eq!(counters.get(Counter::AttacksPlayed, CounterScope::ThisCombat), 2);
+    }
+
+    #[test]
+    fn test_player_hp_loss_counts_damage_and_hp_loss() {
+        let mut counters = Counters::new();
+        counters.record(&BattleEvent::DamageTaken { target: Entity::Player, amount: 6, source: Entity::Enemy(0) });
+        counters.record(&BattleEvent::HpLostFromCard { target: Entity::Player, amount: 3 });
+        // Damage to enemies is not counted
+        counters.record(&BattleEvent::DamageTaken { target: Entity::Enemy(0), amount: 9, source: Entity::Player });
+
+        assert_eq!(counters.this_combat(Counter::TimesPlayerLostHp), 2);
+        assert_eq!(counters.this_combat(Counter::PlayerHpLost), 9);
+    }
+
+    #[test]
+    fn test_cards_discarded_by_the_player() {
+        let mut counters = Counters::new();
+        counters.record(&BattleEvent::CardDiscarded { source: Entity::Player });
+        counters.record(&BattleEvent::CardDiscarded { source: Entity::Player });
+
+        assert_eq!(counters.this_turn(Counter::CardsDiscarded), 2);
+        assert_eq!(counters.this_turn(Counter::CardsExhausted), 0);
+    }
+
+    #[test]
+    fn test_player_turns_count_the_turn_number() {
+        let mut counters = Counters::new();
+        for _ in 0..3 {
+            counters.start_turn();
+            counters.record(&BattleEvent::StartOfPlayerTurn);
+        }
+
+        assert_eq!(counters.this_turn(Counter::PlayerTurns), 1);
+        assert_eq!(counters.this_combat(Counter::PlayerTurns), 3);
+    }
+}
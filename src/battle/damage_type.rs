@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+/// Kind of damage being dealt, deciding which modifiers and reactions apply
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Normal, // Attacks: reduced by block, capped by Torii, and trigger Thorns
+    Thorns, // Non-attack damage from powers and relics: reduced by block, never triggers Thorns
+    HpLoss, // Ignores block entirely (Offering, Combust, Regret)
+}
+
+impl DamageType {
+    /// Whether block absorbs this damage
+    pub fn is_blockable(self) -> bool {
+        !matches!(self, DamageType::HpLoss)
+    }
+}
@@ -1,29 +1,83 @@
-use crate::game::{card::Card, deck::Deck};
-use rand::Rng;
+use crate::game::{card::Card, card_enum::CardEnum, card_keyword::CardKeyword, card_type::CardType, deck::Deck};
 use serde::{Serialize, Deserialize};
 
+/// The most cards the hand can hold
+pub const MAX_HAND_SIZE: usize = 10;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DeckHandPile {
     deck: Deck,
     hand: Vec<Card>,
     discard_pile: Vec<Card>,
     exhausted: Vec<Card>,
+    /// Instance id given to the next card created during combat
+    next_card_id: u32,
+    /// Corruption is active: Skills cost 0 and exhaust when played, including Skills created later
+    corruption: bool,
 }
 
 impl DeckHandPile {
-    pub fn new(deck: Deck) -> Self {
+    pub fn new(mut deck: Deck) -> Self {
+        let next_card_id = deck.assign_card_ids();
         DeckHandPile {
             deck,
             hand: Vec::new(),
             discard_pile: Vec::new(),
             exhausted: Vec::new(),
+            next_card_id,
+            corruption: false,
+        }
+    }
+
+    /// Prepares a card put into a pile: a card created during combat gets its own instance id
+    /// (cards moving between piles keep theirs), and active card modifiers apply to it
+    fn admit_card(&mut self, mut card: Card) -> Card {
+        if card.get_id().is_none() {
+            card.set_id(self.next_card_id);
+            self.next_card_id += 1;
+        }
+        self.apply_modifiers(&mut card);
+        card
+    }
+
+    /// Applies the combat-wide card modifiers (Corruption) to one card
+    fn apply_modifiers(&self, card: &mut Card) {
+        if self.corruption {
+            corrupt(card);
         }
     }
+
+    /// Activates Corruption for the rest of combat: every Skill, in any pile or created later, costs 0 and exhausts
+    pub(in crate::battle) fn activate_corruption(&mut self) {
+        self.corruption = true;
+        self.reapply_modifiers();
+    }
+
+    pub fn is_corruption_active(&self) -> bool {
+        self.corruption
+    }
+
+    /// Applies the card modifiers again to every card, after cards were rebuilt (e.g., upgraded)
+    fn reapply_modifiers(&mut self) {
+        if !self.corruption {
+            return;
+        }
+        let mut deck_cards = self.deck.get_cards().clone();
+        for card in deck_cards.iter_mut().chain(self.hand.iter_mut()).chain(self.discard_pile.iter_mut()) {
+            corrupt(card);
+        }
+        self.deck = Deck::new(deck_cards);
+    }
     
-    pub fn draw_card(&mut self) -> Option<Card> {
+    /// Draw the top card into hand, drawing stops once the hand is full and the card stays in the draw pile
+    pub fn draw_card(&mut self, rng: &mut impl rand::Rng) -> Option<Card> {
+        if self.is_hand_full() {
+            return None;
+        }
+
         // If deck is empty, shuffle discard pile into deck
         if self.is_deck_empty() && !self.discard_pile.is_empty() {
-            self.shuffle_discard_into_deck();
+            self.shuffle_discard_into_deck(rng);
         }
         
         // Draw from deck if available
@@ -36,10 +90,10 @@ impl DeckHandPile {
     }
     
     /// Draw n cards into hand, returns the number of cards actually drawn
-    pub(in crate::battle) fn draw_n(&mut self, n: usize) -> usize {
+    pub(in crate::battle) fn draw_n(&mut self, n: usize, rng: &mut impl rand::Rng) -> usize {
         let mut cards_drawn = 0;
         for _ in 0..n {
-            if self.draw_card().is_some() {
+            if self.draw_card(rng).is_some() {
                 cards_drawn += 1;
             } else {
                 break;
@@ -50,14 +104,14 @@ impl DeckHandPile {
 
     /// Draw initial hand with innate cards (only used at the start of combat)
     /// Innate cards are added to hand from deck or discard, then draw additional cards up to n
-    pub(in crate::battle) fn draw_initial_hand(&mut self, n: usize) -> usize {
+    pub(in crate::battle) fn draw_initial_hand(&mut self, n: usize, rng: &mut impl rand::Rng) -> usize {
         // First, ensure all innate cards are in hand
         self.ensure_innate_cards_in_hand();
 
         // Then draw additional cards up to n
         let current_hand_size = self.hand_size();
         let cards_to_draw = n.saturating_sub(current_hand_size);
-        self.draw_n(cards_to_draw)
+        self.draw_n(cards_to_draw, rng)
     }
 
     /// Ensure all innate cards from deck are in hand
@@ -76,6 +130,9 @@ impl DeckHandPile {
 
         // Move innate cards from deck to hand
         for i in innate_indices.into_iter().rev() {
+            if self.is_hand_full() {
+                return;
+            }
             if let Some(card) = self.deck.remove_card(i) {
                 self.hand.push(card);
             }
@@ -88,6 +145,9 @@ impl DeckHandPile {
 
         // Move innate cards from discard to hand
         for i in innate_discard_indices.into_iter().rev() {
+            if self.is_hand_full() {
+                return;
+            }
             let card = self.discard_pile.remove(i);
             self.hand.push(card);
         }
@@ -103,39 +163,90 @@ impl DeckHandPile {
         }
     }
 
-    pub(in crate::battle) fn replace_card_in_hand(&mut self, hand_index: usize, new_card: Card) {
+    /// Replaces a card in hand with a modified version of it, keeping its instance id
+    pub(in crate::battle) fn replace_card_in_hand(&mut self, hand_index: usize, mut new_card: Card) {
         if hand_index < self.hand.len() {
+            if let (None, Some(id)) = (new_card.get_id(), self.hand[hand_index].get_id()) {
+                new_card.set_id(id);
+            }
+            self.apply_modifiers(&mut new_card);
             self.hand[hand_index] = new_card;
         }
     }
     
-    pub(in crate::battle) fn discard_entire_hand(&mut self) {
-        while !self.hand.is_empty() {
-            let card = self.hand.remove(0);
-            self.discard_pile.push(card);
+    /// Discards the hand at the end of turn, leaving cards with Retain in hand
+    pub(in crate::battle) fn discard_hand_except_retained(&mut self) {
+        let (retained, discarded): (Vec<Card>, Vec<Card>) = self.hand.drain(..).partition(|card| card.is_retained());
+        self.discard_pile.extend(discarded);
+        self.hand = retained;
+    }
+
+    /// Grants a keyword to every card in hand, optionally only to cards of one type
+    pub(in crate::battle) fn add_keyword_to_hand(&mut self, keyword: CardKeyword, card_type: Option<CardType>) {
+        for card in self.hand.iter_mut() {
+            if card_type.is_none_or(|t| card.get_card_type() == t) {
+                card.add_keyword(keyword);
+            }
         }
     }
-    
-    pub(in crate::battle) fn shuffle_discard_into_deck(&mut self) {
+
+    /// Grants a keyword to the card at the given hand index
+    pub(in crate::battle) fn add_keyword_to_hand_card(&mut self, hand_index: usize, keyword: CardKeyword) {
+        if let Some(card) = self.hand.get_mut(hand_index) {
+            card.add_keyword(keyword);
+        }
+    }
+
+    /// Shuffle the draw pile in place
+    pub(in crate::battle) fn shuffle_draw_pile(&mut self, rng: &mut impl rand::Rng) {
+        self.deck.shuffle(rng);
+    }
+
+    /// Put the discard pile under the draw pile, in the given order of its indices
+    /// Drawing through the draw pile then gives the same cards as a reshuffle into that order would
+    pub(in crate::battle) fn put_discard_under_deck(&mut self, order: &[usize]) {
+        let discarded = std::mem::take(&mut self.discard_pile);
+        for &index in order {
+            self.deck.add_card(discarded[index].clone());
+        }
+    }
+
+    pub(in crate::battle) fn shuffle_discard_into_deck(&mut self, rng: &mut impl rand::Rng) {
         // Move all cards from discard pile to deck
         while let Some(card) = self.discard_pile.pop() {
             self.deck.add_card(card);
         }
         
         // Shuffle the deck
-        let mut rng = rand::rng();
-        self.deck.shuffle(&mut rng);
+        self.deck.shuffle(rng);
     }
     
-    pub(in crate) fn add_card_to_hand(&mut self, card: Card) {
+    /// Add a card to hand, a full hand sends it to the discard pile instead
+    /// Returns false if the card overflowed
+    pub(in crate) fn add_card_to_hand(&mut self, card: Card) -> bool {
+        let card = self.admit_card(card);
+        if self.is_hand_full() {
+            self.discard_pile.push(card);
+            false
+        } else {
+            self.hand.push(card);
+            true
+        }
+    }
+
+    /// Put a card in hand only to play it right away (Havoc), ignoring the hand limit
+    pub(in crate::battle) fn put_card_in_hand_to_play(&mut self, card: Card) {
+        let card = self.admit_card(card);
         self.hand.push(card);
     }
     
     pub(in crate::battle) fn add_card_to_deck(&mut self, card: Card) {
+        let card = self.admit_card(card);
         self.deck.add_card(card);
     }
     
     pub(in crate::battle) fn add_card_to_discard(&mut self, card: Card) {
+        let card = self.admit_card(card);
         self.discard_pile.push(card);
     }
     
@@ -150,10 +261,10 @@ impl DeckHandPile {
     }
     
     /// Look at the top card of the draw pile without removing it
-    pub(in crate) fn peek_top_card(&mut self) -> Option<Card> {
+    pub(in crate) fn peek_top_card(&mut self, rng: &mut impl rand::Rng) -> Option<Card> {
         // If deck is empty, shuffle discard pile into deck
         if self.is_deck_empty() && !self.discard_pile.is_empty() {
-            self.shuffle_discard_into_deck();
+            self.shuffle_discard_into_deck(rng);
         }
 
         // Return top card without removing it
@@ -162,26 +273,26 @@ impl DeckHandPile {
 
     /// Put a card on top of the draw pile
     pub(in crate::battle) fn put_card_on_top_of_deck(&mut self, card: Card) {
+        let card = self.admit_card(card);
         self.deck.put_card_on_top(card);
     }
 
     /// Draw top card from deck without adding to hand
-    pub(in crate::battle) fn draw_top_card(&mut self) -> Option<Card> {
+    pub(in crate::battle) fn draw_top_card(&mut self, rng: &mut impl rand::Rng) -> Option<Card> {
         // If deck is empty, shuffle discard pile into deck
         if self.is_deck_empty() && !self.discard_pile.is_empty() {
-            self.shuffle_discard_into_deck();
+            self.shuffle_discard_into_deck(rng);
         }
 
         self.deck.draw_card()
     }
 
     /// Put a random card from discard pile on top of draw pile
-    pub(in crate::battle) fn put_random_discard_on_top(&mut self) -> bool {
+    pub(in crate::battle) fn put_random_discard_on_top(&mut self, rng: &mut impl rand::Rng) -> bool {
         if self.discard_pile.is_empty() {
             return false;
         }
 
-        let mut rng = rand::rng();
         let random_index = rng.random_range(0..self.discard_pile.len());
         let card = self.discard_pile.remove(random_index);
         self.put_card_on_top_of_deck(card);
@@ -253,6 +364,10 @@ impl DeckHandPile {
     pub fn hand_size(&self) -> usize {
         self.hand.len()
     }
+
+    pub fn is_hand_full(&self) -> bool {
+        self.hand.len() >= MAX_HAND_SIZE
+    }
     
     pub fn deck_size(&self) -> usize {
         self.deck.size()
@@ -284,19 +399,137 @@ impl DeckHandPile {
                 *card = upgraded_card;
             }
         }
+        self.reapply_modifiers();
     }
     
+    /// Upgrade every card in hand, draw pile, discard pile and exhaust pile for the rest of combat
+    pub(in crate::battle) fn upgrade_all_cards(&mut self) {
+        self.upgrade_all_cards_in_hand();
+
+        let upgraded_deck: Vec<Card> = self.deck.get_cards().iter()
+            .map(|card| if card.is_upgraded() { card.clone() } else { card.clone().upgrade() })
+            .collect();
+        self.deck = Deck::new(upgraded_deck);
+
+        for card in self.discard_pile.iter_mut().chain(self.exhausted.iter_mut()) {
+            if !card.is_upgraded() {
+                *card = card.clone().upgrade();
+            }
+        }
+        self.reapply_modifiers();
+    }
+
+    /// Upgrade every copy of a card in hand, draw pile and discard pile (e.g., Inferno upgrading Burns)
+    pub(in crate::battle) fn upgrade_cards_of(&mut self, card_enum: CardEnum) {
+        let upgraded_deck: Vec<Card> = self.deck.get_cards().iter()
+            .map(|card| if card.get_card_enum() == card_enum { card.clone().upgrade() } else { card.clone() })
+            .collect();
+        self.deck = Deck::new(upgraded_deck);
+
+        for card in self.hand.iter_mut().chain(self.discard_pile.iter_mut()) {
+            if card.get_card_enum() == card_enum {
+                *card = card.clone().upgrade();
+            }
+        }
+        self.reapply_modifiers();
+    }
+
+    /// Insert a card at a random position in the draw pile
+    pub(in crate::battle) fn shuffle_card_into_deck(&mut self, card: Card, rng: &mut impl rand::Rng) {
+        let position = rng.random_range(0..=self.deck.size());
+        let card = self.admit_card(card);
+        self.deck.insert_card(position, card);
+    }
+
+    /// Remove a card from the draw pile at specific index
+    pub(in crate::battle) fn remove_card_from_deck(&mut self, index: usize) -> Option<Card> {
+        self.deck.remove_card(index)
+    }
+
+    /// Reduce the cost of every card in hand that costs more than `max_cost`
+    /// Either only for this turn or for the rest of combat
+    pub(in crate::battle) fn reduce_hand_costs_to(&mut self, max_cost: u32, this_combat: bool) {
+        for card in &mut self.hand {
+            if card.get_cost() > max_cost {
+                if this_combat {
+                    card.set_combat_cost(max_cost);
+                } else {
+                    card.set_modified_cost(max_cost);
+                }
+            }
+        }
+    }
+
+    /// Reduce the cost of every card in hand for the rest of combat (Establishment on retained cards)
+    pub(in crate::battle) fn reduce_hand_combat_costs(&mut self, amount: u32) {
+        for card in &mut self.hand {
+            let cost = card.get_cost().saturating_sub(amount);
+            card.set_combat_cost(cost);
+            card.clear_modified_cost();
+        }
+    }
+
+    /// Grows the damage of a scaling attack in hand for its later plays (Rampage)
+    pub(in crate::battle) fn increase_hand_card_scaling_damage(&mut self, hand_index: usize) {
+        if let Some(card) = self.hand.get_mut(hand_index) {
+            card.increase_scaling_damage();
+        }
+    }
+
+    /// Set the cost of a card in hand for the rest of combat
+    pub(in crate::battle) fn set_hand_card_combat_cost(&mut self, hand_index: usize, cost: u32) {
+        if let Some(card) = self.hand.get_mut(hand_index) {
+            card.set_combat_cost(cost);
+            card.clear_modified_cost();
+        }
+    }
+
     // Helper methods
     fn is_deck_empty(&self) -> bool {
         self.deck.is_empty()
     }
 }
 
+/// Corruption: a Skill costs 0 and exhausts when played
+fn corrupt(card: &mut Card) {
+    if card.get_card_type() == CardType::Skill {
+        card.set_combat_cost(0);
+        card.clear_modified_cost();
+        card.clear_cost_until_played();
+        card.add_keyword(CardKeyword::Exhaust);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cards::ironclad::{strike::strike, defend::defend};
 
+    #[test]
+    fn test_corruption_applies_to_skills_in_every_pile_and_created_later() {
+        use crate::cards::ironclad::shrug_it_off::shrug_it_off;
+
+        let mut deck_hand_pile = DeckHandPile::new(Deck::new(vec![defend(), strike()]));
+        deck_hand_pile.add_card_to_discard(shrug_it_off());
+        deck_hand_pile.activate_corruption();
+
+        // Skills created after Corruption was played are corrupted too
+        deck_hand_pile.add_card_to_hand(defend());
+        deck_hand_pile.draw_n(2, &mut rand::rng());
+
+        for card in deck_hand_pile.get_hand().iter().chain(deck_hand_pile.get_discard_pile()) {
+            let is_skill = card.get_card_type() == CardType::Skill;
+            assert_eq!(card.get_cost() == 0, is_skill, "{} cost", card.get_name());
+            assert_eq!(card.exhausts(), is_skill, "{} exhausts", card.get_name());
+        }
+
+        // Upgrading rebuilds the card, which stays corrupted
+        deck_hand_pile.upgrade_all_cards_in_hand();
+        assert!(deck_hand_pile.get_hand().iter()
+            .filter(|card| card.get_card_type() == CardType::Skill)
+            .all(|card| card.get_cost() == 0 && card.exhausts()));
+    }
+
     #[test]
     fn test_deck_hand_pile_creation() {
         let cards = vec![strike(), defend(), strike(), defend(), strike()];
@@ -321,7 +554,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
         
         // Draw cards into hand first
-        deck_hand_pile.draw_n(3);
+        deck_hand_pile.draw_n(3, &mut rand::rng());
         
         // Discard a card from hand
         let discarded = deck_hand_pile.discard_card_from_hand(0);
@@ -337,7 +570,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
 
         // Draw some cards into hand first
-        deck_hand_pile.draw_n(2);
+        deck_hand_pile.draw_n(2, &mut rand::rng());
 
         let initial_hand_size = deck_hand_pile.hand_size();
         let initial_discard_size = deck_hand_pile.discard_pile_size();
@@ -357,9 +590,9 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
         
         // Draw cards into hand first
-        deck_hand_pile.draw_n(5);
+        deck_hand_pile.draw_n(5, &mut rand::rng());
         
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 5);
     }
@@ -371,7 +604,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
         
         // Draw cards into hand first
-        deck_hand_pile.draw_n(2);
+        deck_hand_pile.draw_n(2, &mut rand::rng());
         
         // Initial state: deck is empty (all cards drawn), hand has 2 cards, discard empty
         assert_eq!(deck_hand_pile.deck_size(), 0);
@@ -379,27 +612,27 @@ mod tests {
         assert_eq!(deck_hand_pile.discard_pile_size(), 0);
         
         // Discard all cards from hand
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.deck_size(), 0);
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 2);
         
         // Try to draw a card - should reshuffle discard into deck and then draw
-        let drawn_card = deck_hand_pile.draw_card();
+        let drawn_card = deck_hand_pile.draw_card(&mut rand::rng());
         assert!(drawn_card.is_some());
         assert_eq!(deck_hand_pile.hand_size(), 1);
         assert_eq!(deck_hand_pile.discard_pile_size(), 0);
         assert_eq!(deck_hand_pile.deck_size(), 1); // One card left in deck after drawing
         
         // Draw the second card
-        let second_card = deck_hand_pile.draw_card();
+        let second_card = deck_hand_pile.draw_card(&mut rand::rng());
         assert!(second_card.is_some());
         assert_eq!(deck_hand_pile.hand_size(), 2);
         assert_eq!(deck_hand_pile.discard_pile_size(), 0);
         assert_eq!(deck_hand_pile.deck_size(), 0); // Deck is empty again
         
         // Try to draw again - should return None (no cards left anywhere)
-        let third_card = deck_hand_pile.draw_card();
+        let third_card = deck_hand_pile.draw_card(&mut rand::rng());
         assert!(third_card.is_none());
         assert_eq!(deck_hand_pile.hand_size(), 2); // Hand unchanged
     }
@@ -415,7 +648,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
         
         // Draw initial hand of 5 cards
-        deck_hand_pile.draw_n(5);
+        deck_hand_pile.draw_n(5, &mut rand::rng());
         
         // Initial state: 5 cards in hand, 5 in deck, 0 in discard
         assert_eq!(deck_hand_pile.hand_size(), 5);
@@ -423,37 +656,37 @@ mod tests {
         assert_eq!(deck_hand_pile.discard_pile_size(), 0);
         
         // Turn 1: Discard hand, then draw new hand (5 cards)
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.deck_size(), 5);
         assert_eq!(deck_hand_pile.discard_pile_size(), 5);
         
         // Draw 5 cards for new turn
-        let drawn_cards = deck_hand_pile.draw_n(5);
+        let drawn_cards = deck_hand_pile.draw_n(5, &mut rand::rng());
         assert_eq!(drawn_cards, 5, "Should draw 5 cards on turn 1");
         assert_eq!(deck_hand_pile.hand_size(), 5);
         assert_eq!(deck_hand_pile.deck_size(), 0); // Deck empty  
         assert_eq!(deck_hand_pile.discard_pile_size(), 5); // Discard pile has the cards we discarded
         
         // Turn 2: Discard hand again, then draw new hand
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.deck_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 10); // Now have 10 cards in discard (5 from each turn)
         
-        let drawn_cards = deck_hand_pile.draw_n(5);
+        let drawn_cards = deck_hand_pile.draw_n(5, &mut rand::rng());
         assert_eq!(drawn_cards, 5, "Should draw 5 cards on turn 2");
         assert_eq!(deck_hand_pile.hand_size(), 5);
         assert_eq!(deck_hand_pile.deck_size(), 5); // Remaining 5 cards after drawing 5 of the 10 reshuffled
         assert_eq!(deck_hand_pile.discard_pile_size(), 0); // Discard was reshuffled into deck
         
         // Turn 3: This should work fine now
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.deck_size(), 5); // Still 5 cards in deck from turn 2
         assert_eq!(deck_hand_pile.discard_pile_size(), 5); // 5 cards just discarded
         
-        let drawn_cards = deck_hand_pile.draw_n(5);
+        let drawn_cards = deck_hand_pile.draw_n(5, &mut rand::rng());
         
         
         assert_eq!(drawn_cards, 5, "Should draw 5 cards on turn 3");
@@ -464,7 +697,7 @@ mod tests {
     fn test_exhaust_card_from_hand() {
         let deck = Deck::new(vec![strike(), defend(), strike()]);
         let mut deck_hand_pile = DeckHandPile::new(deck);
-        deck_hand_pile.draw_n(3);
+        deck_hand_pile.draw_n(3, &mut rand::rng());
         
         assert_eq!(deck_hand_pile.hand_size(), 3);
         assert_eq!(deck_hand_pile.exhausted_size(), 0);
@@ -492,7 +725,7 @@ mod tests {
         assert_eq!(deck_hand_pile.cards_in_play(), 5);
 
         // Draw 2 cards
-        deck_hand_pile.draw_n(2);
+        deck_hand_pile.draw_n(2, &mut rand::rng());
         assert_eq!(deck_hand_pile.total_cards(), 5); // Still 5 total
         assert_eq!(deck_hand_pile.cards_in_play(), 5); // Still 5 in play
 
@@ -528,7 +761,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
 
         // Draw initial hand - should have innate card + 4 other cards
-        deck_hand_pile.draw_initial_hand(5);
+        deck_hand_pile.draw_initial_hand(5, &mut rand::rng());
         assert_eq!(deck_hand_pile.hand_size(), 5);
 
         // Verify innate card is in hand
@@ -545,12 +778,12 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
 
         // Draw initial hand
-        deck_hand_pile.draw_initial_hand(2);
+        deck_hand_pile.draw_initial_hand(2, &mut rand::rng());
         assert_eq!(deck_hand_pile.hand_size(), 2);
         assert!(deck_hand_pile.get_hand().iter().any(|c| c.is_innate()), "Innate card should be in starting hand");
 
         // Discard entire hand - innate card should go to discard pile
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 2); // Both cards in discard
     }
@@ -564,16 +797,16 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
 
         // Draw initial hand
-        deck_hand_pile.draw_initial_hand(2);
+        deck_hand_pile.draw_initial_hand(2, &mut rand::rng());
         assert_eq!(deck_hand_pile.hand_size(), 2);
 
         // Discard entire hand
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 2);
 
         // Draw normally - innate card should be drawn like any other card
-        let drawn = deck_hand_pile.draw_n(2);
+        let drawn = deck_hand_pile.draw_n(2, &mut rand::rng());
         assert_eq!(drawn, 2);
         assert_eq!(deck_hand_pile.hand_size(), 2);
         assert_eq!(deck_hand_pile.discard_pile_size(), 0);
@@ -592,7 +825,7 @@ mod tests {
         let mut deck_hand_pile = DeckHandPile::new(deck);
 
         // Draw initial hand - Writhe should be in hand
-        deck_hand_pile.draw_initial_hand(3);
+        deck_hand_pile.draw_initial_hand(3, &mut rand::rng());
         assert_eq!(deck_hand_pile.hand_size(), 3);
 
         // Verify Writhe is in hand
@@ -606,8 +839,62 @@ mod tests {
         assert!(writhe_in_hand.is_removable(), "Writhe should be removable");
 
         // Discard entire hand - Writhe should go to discard
-        deck_hand_pile.discard_entire_hand();
+        deck_hand_pile.discard_hand_except_retained();
         assert_eq!(deck_hand_pile.hand_size(), 0);
         assert_eq!(deck_hand_pile.discard_pile_size(), 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_card_instance_ids_are_unique() {
+        let deck = Deck::new(vec![strike(), strike(), defend()]);
+        let mut deck_hand_pile = DeckHandPile::new(deck);
+
+        deck_hand_pile.draw_n(2, &mut rand::rng());
+        deck_hand_pile.add_card_to_discard(strike());
+        deck_hand_pile.shuffle_card_into_deck(defend(), &mut rand::rng());
+
+        let mut ids: Vec<u32> = deck_hand_pile.get_hand().iter()
+            .chain(deck_hand_pile.get_discard_pile().iter())
+            .chain(deck_hand_pile.get_deck().get_cards().iter())
+            .map(|card| card.get_id().unwrap())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_card_keeps_instance_id_between_piles() {
+        let deck = Deck::new(vec![strike()]);
+        let mut deck_hand_pile = DeckHandPile::new(deck);
+
+        deck_hand_pile.draw_n(1, &mut rand::rng());
+        let id = deck_hand_pile.get_hand()[0].get_id();
+        deck_hand_pile.discard_hand_except_retained();
+        deck_hand_pile.draw_n(1, &mut rand::rng());
+        assert_eq!(deck_hand_pile.get_hand()[0].get_id(), id);
+
+        // Upgrading in hand keeps the same instance
+        let upgraded = deck_hand_pile.get_hand()[0].clone().upgrade();
+        assert_eq!(upgraded.get_id(), id);
+        deck_hand_pile.replace_card_in_hand(0, strike());
+        assert_eq!(deck_hand_pile.get_hand()[0].get_id(), id);
+
+        // A copy is a new instance
+        let copy = deck_hand_pile.get_hand()[0].new_copy();
+        deck_hand_pile.add_card_to_discard(copy);
+        assert_ne!(deck_hand_pile.get_discard_pile()[0].get_id(), id);
+    }
+
+    #[test]
+    fn test_add_card_to_full_hand_goes_to_discard() {
+        let mut deck_hand_pile = DeckHandPile::new(Deck::new(vec![]));
+        for _ in 0..MAX_HAND_SIZE {
+            assert!(deck_hand_pile.add_card_to_hand(strike()));
+        }
+        assert!(deck_hand_pile.is_hand_full());
+
+        assert!(!deck_hand_pile.add_card_to_hand(defend()));
+        assert_eq!(deck_hand_pile.hand_size(), MAX_HAND_SIZE);
+        assert_eq!(deck_hand_pile.get_discard_pile()[0].get_name(), "Defend");
+    }
+}
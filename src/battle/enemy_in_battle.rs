@@ -1,10 +1,16 @@
-use crate::{enemies::enemy_enum::EnemyEnum, battle::character_battle_info::CharacterBattleInfo};
+use crate::{enemies::enemy_enum::{EnemyEnum, EnemyMove}, battle::{character_battle_info::CharacterBattleInfo, target::Entity}};
+use crate::game::{effect::BattleEffect, global_info::GlobalInfo};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct EnemyInBattle {
     pub enemy: EnemyEnum,
     pub battle_info: CharacterBattleInfo,
+    leader: Option<usize>,  // Slot of the enemy this one is a minion of (leaves combat when the leader dies)
+    /// Moves the enemy has made, the player sees them even when intents are hidden
+    moves_made: Vec<EnemyMove>,
+    /// The enemy's AI state before it picked its current intent
+    ai_state_before_intent: Option<EnemyEnum>,
 }
 
 impl EnemyInBattle {
@@ -15,9 +21,67 @@ impl EnemyInBattle {
         EnemyInBattle {
             enemy,
             battle_info,
+            leader: None,
+            moves_made: Vec::new(),
+            ai_state_before_intent: None,
         }
     }
 
+    /// Builder pattern method to make this enemy a minion of the enemy in the given slot
+    pub fn with_leader(mut self, leader: usize) -> Self {
+        self.leader = Some(leader);
+        self
+    }
+
+    /// Stop following a leader that has left its slot
+    pub(in crate::battle) fn clear_leader(&mut self) {
+        self.leader = None;
+    }
+
+    /// Check if this enemy is a minion of another enemy
+    pub fn is_minion(&self) -> bool {
+        self.leader.is_some()
+    }
+
+    /// The enemy this one is a minion of
+    pub fn get_leader(&self) -> Option<Entity> {
+        self.leader.map(Entity::Enemy)
+    }
+
+    /// Pick the enemy's next move, keeping the AI state it was picked from
+    pub(in crate::battle) fn sample_intent(&mut self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> (EnemyMove, Vec<BattleEffect>) {
+        self.ai_state_before_intent = Some(self.enemy.clone());
+        self.enemy.sample_move_and_effects(global_info, rng)
+    }
+
+    /// Switch to `enemy`, this enemy after picking its next move, keeping the AI state it was picked from
+    pub(in crate::battle) fn set_intent_ai_state(&mut self, enemy: EnemyEnum) {
+        self.ai_state_before_intent = Some(std::mem::replace(&mut self.enemy, enemy));
+    }
+
+    /// Pick the current intent again from the AI state it was picked from
+    /// None when that pick has a single outcome or the AI state is unknown, the enemy is then unchanged
+    pub(in crate::battle) fn redraw_intent(&mut self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<(EnemyMove, Vec<BattleEffect>)> {
+        let before = self.ai_state_before_intent.as_ref()?;
+        if before.move_outcomes(global_info, rng).is_some_and(|outcomes| outcomes.len() == 1) {
+            return None;
+        }
+        let mut enemy = before.clone();
+        let intent = enemy.sample_move_and_effects(global_info, rng);
+        self.enemy = enemy;
+        Some(intent)
+    }
+
+    /// Record a move the enemy made
+    pub(in crate::battle) fn record_move_made(&mut self, enemy_move: EnemyMove) {
+        self.moves_made.push(enemy_move);
+    }
+
+    /// Moves the enemy has made so far, in order
+    pub fn get_moves_made(&self) -> &[EnemyMove] {
+        &self.moves_made
+    }
+
     /// Delegate damage to character (keeping this for the i32 -> u32 conversion)
     pub(in crate::battle) fn take_damage(&mut self, damage: i32) -> u32 {
         if damage <= 0 {
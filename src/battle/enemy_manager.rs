@@ -1,105 +1,171 @@
 use super::Battle;
 use crate::enemies::enemy_enum::{EnemyEnum, EnemyMove};
 use crate::game::{effect::BattleEffect, global_info::GlobalInfo};
-use crate::battle::target::Entity;
+use crate::battle::{target::Entity, power::{Power, PowerId}, battle_events::{BattleEvent, EventListener}, enemy_in_battle::EnemyInBattle};
 use crate::enemies::red_louse::CurlUpListener;
 use crate::enemies::acid_slime_l::AcidSlimeLSplitListener;
 use crate::enemies::spike_slime_l::SpikeSlimeLSplitListener;
+use crate::relics::philosophers_stone;
 
 impl Battle {
     /// Initialize event listeners for enemies based on their type
     pub(in crate::battle) fn initialize_enemy_listeners(&mut self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) {
-        for (i, enemy) in self.enemies.iter().enumerate() {
-            match &enemy.enemy {
-                EnemyEnum::RedLouse(_) => {
-                    // Red Louse gets a curl up listener with randomly generated block amount
-                    let curl_up = CurlUpListener::new(Entity::Enemy(i), global_info.ascention, rng);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::CurlUp(curl_up));
-                }
-                EnemyEnum::GreenLouse(_) => {
-                    // Green Louse also gets a curl up listener with randomly generated block amount
-                    let curl_up = CurlUpListener::new(Entity::Enemy(i), global_info.ascention, rng);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::CurlUp(curl_up));
-                }
-                EnemyEnum::JawWorm(_) => {
-                    // Jaw Worm has no special listeners
-                }
-                EnemyEnum::Cultist(_) => {
-                    // Cultist has no special listeners
-                }
-                EnemyEnum::SpikeSlimeS(_) => {
-                    // Spike Slime (S) has no special listeners
-                }
-                EnemyEnum::SpikeSlimeM(_) => {
-                    // Spike Slime (M) has no special listeners
-                }
-                EnemyEnum::SpikeSlimeL(_) => {
-                    // Spike Slime (L) gets a half-HP split listener
-                    let split_listener = SpikeSlimeLSplitListener::new(i);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::SpikeSlimeLSplit(split_listener));
-                }
-                EnemyEnum::AcidSlimeS(_) => {
-                    // Acid Slime (S) has no special listeners
-                }
-                EnemyEnum::AcidSlimeM(_) => {
-                    // Acid Slime (M) has no special listeners
-                }
-                EnemyEnum::AcidSlimeL(_) => {
-                    // Acid Slime (L) gets a half-HP split listener
-                    let split_listener = AcidSlimeLSplitListener::new(i);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::AcidSlimeLSplit(split_listener));
-                }
-                EnemyEnum::GremlinNob(_) => {
-                    // Gremlin Nob gets an enrage listener only AFTER it uses its first move (Bellow)
-                    // This will be added dynamically when the first move is executed
-                }
-                EnemyEnum::Lagavulin(_) => {
-                    // Lagavulin gets a listener for wake-from-damage, initial block, and Stunned→Awake transition
-                    let lagavulin_listener = crate::enemies::lagavulin::LagavulinListener::new(i);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Lagavulin(lagavulin_listener));
-
-                    // Lagavulin starts with Metallicize 8 while asleep (removed when awakened)
-                    let metallicize_listener = crate::cards::ironclad::metallicize::MetallicizeListener::new(Entity::Enemy(i), 8);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Metallicize(metallicize_listener));
-                }
-                EnemyEnum::Sentry(_) => {
-                    // Sentry gets 1 Artifact at combat start
-                    let sentry_listener = crate::enemies::sentry::SentryListener::new(i);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Sentry(sentry_listener));
-                }
-                EnemyEnum::FatGremlin(_) => {
-                    // Fat Gremlin has no special listeners
-                }
-                EnemyEnum::SneakyGremlin(_) => {
-                    // Sneaky Gremlin has no special listeners
-                }
-                EnemyEnum::MadGremlin(mad_gremlin) => {
-                    // Mad Gremlin gets an Angry listener at combat start
-                    use crate::enemies::mad_gremlin::AngryListener;
-                    let angry_amount = mad_gremlin.get_angry_stacks();
-                    let listener = AngryListener::new(Entity::Enemy(i), angry_amount);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Angry(listener));
-                }
-                EnemyEnum::ShieldGremlin(_) => {
-                    // Shield Gremlin has no special listeners
-                }
-                EnemyEnum::GremlinWizard(_) => {
-                    // Gremlin Wizard has no special listeners
-                }
-                EnemyEnum::Looter(_) => {
-                    // Looter has no special listeners
-                }
-                EnemyEnum::FungiBeast(_) => {
-                    // Fungi Beast gets a Spore Cloud listener that triggers on death
-                    let spore_cloud_listener = crate::enemies::fungi_beast::SporeCloudListener::new(i);
-                    self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::SporeCloud(spore_cloud_listener));
-                }
-                EnemyEnum::BlueSlaver(_) => {
-                    // Blue Slaver has no special listeners
-                }
-                EnemyEnum::RedSlaver(_) => {
-                    // Red Slaver has no special listeners
-                }
+        for i in 0..self.enemies.len() {
+            self.initialize_listeners_for_enemy(i, global_info, rng);
+        }
+    }
+
+    /// Initialize the event listeners and starting powers of the enemy in one slot
+    fn initialize_listeners_for_enemy(&mut self, i: usize, global_info: &GlobalInfo, rng: &mut impl rand::Rng) {
+        let mut starting_power = None;
+        match &self.enemies[i].enemy {
+            EnemyEnum::RedLouse(_) => {
+                // Red Louse gets a curl up listener with randomly generated block amount
+                let curl_up = CurlUpListener::new(Entity::Enemy(i), global_info.ascention, rng);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::CurlUp(curl_up));
+            }
+            EnemyEnum::GreenLouse(_) => {
+                // Green Louse also gets a curl up listener with randomly generated block amount
+                let curl_up = CurlUpListener::new(Entity::Enemy(i), global_info.ascention, rng);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::CurlUp(curl_up));
+            }
+            EnemyEnum::JawWorm(_) => {
+                // Jaw Worm has no special listeners
+            }
+            EnemyEnum::Cultist(_) => {
+                // Cultist has no special listeners
+            }
+            EnemyEnum::SpikeSlimeS(_) => {
+                // Spike Slime (S) has no special listeners
+            }
+            EnemyEnum::SpikeSlimeM(_) => {
+                // Spike Slime (M) has no special listeners
+            }
+            EnemyEnum::SpikeSlimeL(_) => {
+                // Spike Slime (L) gets a half-HP split listener
+                let split_listener = SpikeSlimeLSplitListener::new(i);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::SpikeSlimeLSplit(split_listener));
+            }
+            EnemyEnum::AcidSlimeS(_) => {
+                // Acid Slime (S) has no special listeners
+            }
+            EnemyEnum::AcidSlimeM(_) => {
+                // Acid Slime (M) has no special listeners
+            }
+            EnemyEnum::AcidSlimeL(_) => {
+                // Acid Slime (L) gets a half-HP split listener
+                let split_listener = AcidSlimeLSplitListener::new(i);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::AcidSlimeLSplit(split_listener));
+            }
+            EnemyEnum::GremlinNob(_) => {
+                // Gremlin Nob gets an enrage listener only AFTER it uses its first move (Bellow)
+                // This will be added dynamically when the first move is executed
+            }
+            EnemyEnum::Lagavulin(_) => {
+                // Lagavulin gets a listener for wake-from-damage, initial block, and Stunned→Awake transition
+                let lagavulin_listener = crate::enemies::lagavulin::LagavulinListener::new(i);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Lagavulin(lagavulin_listener));
+
+                // Lagavulin starts with Metallicize 8 while asleep (removed when awakened)
+                starting_power = Some(Power::new(PowerId::Metallicize, 8));
+            }
+            EnemyEnum::Sentry(_) => {
+                // Sentry gets 1 Artifact at combat start
+                let sentry_listener = crate::enemies::sentry::SentryListener::new(i);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Sentry(sentry_listener));
+            }
+            EnemyEnum::FatGremlin(_) => {
+                // Fat Gremlin has no special listeners
+            }
+            EnemyEnum::SneakyGremlin(_) => {
+                // Sneaky Gremlin has no special listeners
+            }
+            EnemyEnum::MadGremlin(mad_gremlin) => {
+                // Mad Gremlin gets an Angry listener at combat start
+                use crate::enemies::mad_gremlin::AngryListener;
+                let angry_amount = mad_gremlin.get_angry_stacks();
+                let listener = AngryListener::new(Entity::Enemy(i), angry_amount);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::Angry(listener));
+            }
+            EnemyEnum::ShieldGremlin(_) => {
+                // Shield Gremlin has no special listeners
+            }
+            EnemyEnum::GremlinWizard(_) => {
+                // Gremlin Wizard has no special listeners
+            }
+            EnemyEnum::Looter(_) => {
+                // Looter has no special listeners
+            }
+            EnemyEnum::FungiBeast(_) => {
+                // Fungi Beast gets a Spore Cloud listener that triggers on death
+                let spore_cloud_listener = crate::enemies::fungi_beast::SporeCloudListener::new(i);
+                self.event_listeners.push(crate::battle::event_listener_enum::EventListenerEnum::SporeCloud(spore_cloud_listener));
+            }
+            EnemyEnum::BlueSlaver(_) => {
+                // Blue Slaver has no special listeners
+            }
+            EnemyEnum::RedSlaver(_) => {
+                // Red Slaver has no special listeners
+            }
+        }
+
+        if let Some(power) = starting_power {
+            self.enemies[i].battle_info.apply_power(power);
+        }
+    }
+
+    /// Put a new enemy into combat and return its slot
+    /// A requested slot is only reused if the enemy there is dead or fled, so living enemies never change index.
+    /// Otherwise the enemy is added at the end.
+    pub(crate) fn spawn_enemy(&mut self, enemy: EnemyInBattle, slot: Option<usize>) -> usize {
+        let reusable_slot = slot.filter(|&idx| self.enemies.get(idx).is_some_and(|e| !e.battle_info.is_alive()));
+        let idx = match reusable_slot {
+            Some(idx) => {
+                // The previous occupant's listeners and minions must not act for or follow the newcomer
+                self.event_listeners.retain(|listener| listener.get_owner() != Entity::Enemy(idx));
+                self.release_minions_of(idx);
+                self.enemies[idx] = enemy;
+                idx
+            }
+            None => {
+                self.enemies.push(enemy);
+                self.enemies.len() - 1
+            }
+        };
+
+        // Keep enemy_actions aligned with the enemies list and give the newcomer an intent right away
+        if self.enemy_actions.len() < self.enemies.len() {
+            self.enemy_actions.resize(self.enemies.len(), None);
+        }
+        let global_info = self.global_info;
+        let mut rng = std::mem::take(&mut self.rng);
+        self.initialize_listeners_for_enemy(idx, &global_info, &mut rng);
+        let enemy_strength = philosophers_stone::enemy_starting_strength(&self.relics);
+        if enemy_strength > 0 {
+            self.apply_power(Entity::Enemy(idx), Power::new(PowerId::Strength, enemy_strength as i32));
+        }
+        self.enemy_actions[idx] = Some(self.enemies[idx].sample_intent(&global_info, &mut rng));
+        self.rng = rng;
+
+        self.emit_event(BattleEvent::EnemySpawned { enemy: Entity::Enemy(idx) });
+        idx
+    }
+
+    /// Minions leave combat when their leader dies
+    pub(in crate::battle) fn dismiss_minions_of(&mut self, leader: usize) {
+        for enemy in self.enemies.iter_mut() {
+            if enemy.get_leader() == Some(Entity::Enemy(leader)) && enemy.battle_info.is_alive() {
+                enemy.battle_info.mark_escaped();
+            }
+        }
+        self.release_minions_of(leader);
+    }
+
+    /// Forget which enemies followed the one in this slot, so a later occupant of the slot does not lead them
+    fn release_minions_of(&mut self, leader: usize) {
+        for enemy in self.enemies.iter_mut() {
+            if enemy.get_leader() == Some(Entity::Enemy(leader)) {
+                enemy.clear_leader();
             }
         }
     }
@@ -112,7 +178,7 @@ impl Battle {
         }
 
         for (i, enemy) in self.enemies.iter_mut().enumerate() {
-            let (enemy_move, effects) = enemy.enemy.sample_move_and_effects(&self.global_info, rng);
+            let (enemy_move, effects) = enemy.sample_intent(&self.global_info, rng);
             self.enemy_actions[i] = Some((enemy_move, effects));
         }
     }
@@ -136,6 +202,7 @@ mod tests {
     use crate::enemies::{red_louse::RedLouse, enemy_enum::EnemyEnum};
     use crate::game::PlayerRunState;
     use crate::game::{global_info::GlobalInfo, enemy::EnemyTrait};
+    use crate::battle::damage_type::DamageType;
 
     #[test]
     fn test_red_louse_curl_up_event_system() {
@@ -152,7 +219,7 @@ mod tests {
         
         // Deal damage to the enemy to trigger curl up
         let initial_hp = battle.enemies[0].battle_info.get_hp();
-        let damage_dealt = battle.apply_damage(Entity::Enemy(0), 6);
+        let damage_dealt = battle.deal_damage(Entity::Player, Entity::Enemy(0), 6, DamageType::Normal);
 
         // Check that damage was dealt and curl up was triggered (enemy gained block)
         assert_eq!(damage_dealt, 6);
@@ -164,7 +231,7 @@ mod tests {
         
         // Deal damage again - curl up should not trigger a second time
         let hp_before_second_damage = battle.enemies[0].battle_info.get_hp();
-        let second_damage = battle.apply_damage(Entity::Enemy(0), 4);
+        let second_damage = battle.deal_damage(Entity::Player, Entity::Enemy(0), 4, DamageType::Normal);
 
         // Calculate expected outcome based on curl up block amount
         let expected_damage = if curl_up_block >= 4 { 0 } else { 4 - curl_up_block };
@@ -188,7 +255,7 @@ mod tests {
         let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
         let player_state = PlayerRunState::new(80, 80, 0);
         let mut normal_battle = Battle::new(deck.clone(), normal_global_info, player_state, enemies, &mut rng);
-        normal_battle.apply_damage(Entity::Enemy(0), 6);
+        normal_battle.deal_damage(Entity::Player, Entity::Enemy(0), 6, DamageType::Normal);
         let normal_block = normal_battle.enemies[0].battle_info.get_block();
         assert!(normal_block >= 3 && normal_block <= 7);
         
@@ -198,7 +265,7 @@ mod tests {
         let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
         let player_state = PlayerRunState::new(80, 80, 0);
         let mut mid_battle = Battle::new(deck.clone(), mid_global_info, player_state, enemies, &mut rng);
-        mid_battle.apply_damage(Entity::Enemy(0), 6);
+        mid_battle.deal_damage(Entity::Player, Entity::Enemy(0), 6, DamageType::Normal);
         let mid_block = mid_battle.enemies[0].battle_info.get_block();
         assert!(mid_block >= 4 && mid_block <= 8);
         
@@ -208,7 +275,7 @@ mod tests {
         let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
         let player_state = PlayerRunState::new(80, 80, 0);
         let mut high_battle = Battle::new(deck, high_global_info, player_state, enemies, &mut rng);
-        high_battle.apply_damage(Entity::Enemy(0), 6);
+        high_battle.deal_damage(Entity::Player, Entity::Enemy(0), 6, DamageType::Normal);
         let high_block = high_battle.enemies[0].battle_info.get_block();
         assert!(high_block >= 9 && high_block <= 12);
     }
@@ -233,7 +300,7 @@ mod tests {
         assert_eq!(battle.player.battle_info.get_vulnerable_turns(), 0);
 
         // Deal enough damage to kill the Fungi Beast
-        battle.apply_damage(Entity::Enemy(0), 10);
+        battle.deal_damage(Entity::Player, Entity::Enemy(0), 10, DamageType::Normal);
 
         // After killing Fungi Beast, player should have 2 Vulnerable from Spore Cloud
         assert_eq!(battle.player.battle_info.get_vulnerable_turns(), 2);
@@ -268,13 +335,13 @@ mod tests {
         assert_eq!(battle.player.battle_info.get_vulnerable_turns(), 0);
 
         // Kill the first Fungi Beast
-        battle.apply_damage(Entity::Enemy(0), 10);
+        battle.deal_damage(Entity::Player, Entity::Enemy(0), 10, DamageType::Normal);
 
         // After killing first Fungi Beast, player should have 2 Vulnerable
         assert_eq!(battle.player.battle_info.get_vulnerable_turns(), 2);
 
         // Kill the second Fungi Beast
-        battle.apply_damage(Entity::Enemy(1), 10);
+        battle.deal_damage(Entity::Player, Entity::Enemy(1), 10, DamageType::Normal);
 
         // After killing both, Vulnerable should stack to 4 turns (2 + 2)
         assert_eq!(battle.player.battle_info.get_vulnerable_turns(), 4);
@@ -283,4 +350,99 @@ mod tests {
         assert!(!battle.enemies[0].battle_info.is_alive());
         assert!(!battle.enemies[1].battle_info.is_alive());
     }
-}
\ No newline at end of file
+
+    fn jaw_worm_battle() -> Battle {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(40, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(40, false))),
+        ];
+        Battle::new(starter_deck(), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_spawned_enemy_gets_listeners_intent_and_event() {
+        let mut battle = jaw_worm_battle();
+        let mut rng = rand::rng();
+        let red_louse = RedLouse::instantiate(&mut rng, &battle.global_info);
+        battle.take_battle_events();
+
+        let slot = battle.spawn_enemy(EnemyInBattle::new(EnemyEnum::RedLouse(red_louse)), None);
+
+        assert_eq!(slot, 2);
+        assert!(battle.get_enemy_move(2).is_some());
+        assert!(battle.event_listeners.iter().any(|listener| listener.get_owner() == Entity::Enemy(2)));
+        assert!(battle.take_battle_events().contains(&BattleEvent::EnemySpawned { enemy: Entity::Enemy(2) }));
+    }
+
+    #[test]
+    fn test_spawn_only_reuses_slots_of_dead_enemies() {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut battle = jaw_worm_battle();
+        battle.deal_damage(Entity::Player, Entity::Enemy(0), 100, DamageType::Normal);
+
+        // Slot 1 holds a living enemy, so the newcomer goes to the end
+        let slot = battle.spawn_enemy(EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(20, false))), Some(1));
+        assert_eq!(slot, 2);
+        assert_eq!(battle.enemies[1].battle_info.get_hp(), 40);
+
+        // Slot 0 is free again
+        let slot = battle.spawn_enemy(EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(30, false))), Some(0));
+        assert_eq!(slot, 0);
+        assert_eq!(battle.enemies.len(), 3);
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), 30);
+        assert_eq!(battle.enemy_actions.len(), 3);
+    }
+
+    #[test]
+    fn test_minions_leave_when_leader_dies() {
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::game::effect::BaseEffect;
+
+        let mut battle = jaw_worm_battle();
+        battle.eval_base_effect(&BaseEffect::SpawnEnemy {
+            source: Entity::Enemy(0),
+            enemy: Box::new(EnemyEnum::JawWorm(JawWorm::new(20, false))),
+            slot: None,
+            minion: true,
+        });
+        assert!(battle.enemies[2].is_minion());
+        assert_eq!(battle.enemies[2].get_leader(), Some(Entity::Enemy(0)));
+        assert!(!battle.enemies[1].is_minion());
+
+        battle.deal_damage(Entity::Player, Entity::Enemy(0), 100, DamageType::Normal);
+
+        assert!(!battle.enemies[2].battle_info.is_alive());
+        assert!(battle.enemies[1].battle_info.is_alive());
+
+        battle.deal_damage(Entity::Player, Entity::Enemy(1), 100, DamageType::Normal);
+        assert!(battle.is_battle_over());
+    }
+
+    #[test]
+    fn test_new_enemy_in_a_fled_leaders_slot_does_not_lead_its_minions() {
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::game::effect::BaseEffect;
+
+        let mut battle = jaw_worm_battle();
+        battle.eval_base_effect(&BaseEffect::SpawnEnemy {
+            source: Entity::Enemy(0),
+            enemy: Box::new(EnemyEnum::JawWorm(JawWorm::new(20, false))),
+            slot: None,
+            minion: true,
+        });
+
+        // The leader flees, leaving its minion behind, and a newcomer takes its slot
+        battle.enemies[0].battle_info.mark_escaped();
+        let slot = battle.spawn_enemy(EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(30, false))), Some(0));
+        assert_eq!(slot, 0);
+        assert!(!battle.enemies[2].is_minion());
+
+        battle.deal_damage(Entity::Player, Entity::Enemy(0), 100, DamageType::Normal);
+        assert!(battle.enemies[2].battle_info.is_alive());
+    }
+}
@@ -1,6 +1,5 @@
 use super::Battle;
-use crate::battle::{battle_action::BattleAction, battle_state::CardInHandTo, target::Entity, BattleResult, BattleError};
-use crate::game::{effect::BattleEffect, card::Card, card_type::CardType};
+use crate::battle::{battle_action::BattleAction, battle_events::BattleEvent, battle_state::{BattleState, CardInHandTo}, target::Entity, BattleResult, BattleError};
 
 impl Battle {
     /// Evaluate a player action and return the battle result
@@ -8,6 +7,7 @@ impl Battle {
         if self.is_battle_over() {
             return Err(BattleError::GameAlreadyOver);
         }
+        self.rng.reseed(rng);
 
         // Validate action based on current battle state
         match &self.battle_state {
@@ -41,6 +41,17 @@ impl Battle {
                     _ => {}
                 }
             }
+            crate::battle::battle_state::BattleState::SelectCardInDrawPile { .. } => {
+                match action {
+                    BattleAction::PlayCard(_, _) => return Err(BattleError::InvalidAction),
+                    BattleAction::EndTurn => return Err(BattleError::InvalidAction),
+                    BattleAction::SelectCardInHand(_) => return Err(BattleError::InvalidAction),
+                    BattleAction::SelectCardInDiscard(_) => return Err(BattleError::InvalidAction),
+                    BattleAction::SelectCardInExhaust(_) => return Err(BattleError::InvalidAction),
+                    BattleAction::SelectCardFromChoices(_) => return Err(BattleError::InvalidAction),
+                    _ => {}
+                }
+            }
             crate::battle::battle_state::BattleState::SelectCardFromChoices { .. } => {
                 match action {
                     BattleAction::PlayCard(_, _) => return Err(BattleError::InvalidAction),
@@ -99,8 +110,11 @@ impl Battle {
                     return Err(BattleError::CardNotInHand);
                 }
 
+                // Multi-card selections stay in the selection state until they are finished
+                let mut next_state = None;
+
                 // Check which state we're in to determine behavior
-                match &self.battle_state {
+                match &self.battle_state.clone() {
                     crate::battle::battle_state::BattleState::SelectCardInHand(card_in_hand_to) => {
                         match card_in_hand_to {
                             CardInHandTo::Upgrade => {
@@ -128,11 +142,44 @@ impl Battle {
                                 let hand = self.cards.get_hand();
                                 let card_to_duplicate = hand[card_index].clone();
 
-                                // Add the specified number of copies to the discard pile
-                                for _ in 0..*copies {
-                                    self.cards.add_card_to_discard(card_to_duplicate.clone());
+                                // Add the specified number of copies to hand, copies that don't fit go to the discard pile
+                                let copies = *copies;
+                                for _ in 0..copies {
+                                    self.add_card_to_hand(card_to_duplicate.new_copy());
                                 }
                             }
+                            CardInHandTo::PutOnBottomOfDeck(selection) => {
+                                if card_index < selection.next_index {
+                                    return Err(BattleError::InvalidAction);
+                                }
+                                if let Some(mut card_to_move) = self.cards.remove_card_from_hand(card_index) {
+                                    // The card costs 0 until it is played
+                                    card_to_move.set_cost_until_played(0);
+                                    self.cards.add_card_to_deck(card_to_move);
+                                }
+                                next_state = Some(BattleState::SelectCardInHand(
+                                    CardInHandTo::PutOnBottomOfDeck(selection.after_pick(card_index))
+                                ));
+                            }
+                            CardInHandTo::Exhaust(selection) => {
+                                if card_index < selection.next_index {
+                                    return Err(BattleError::InvalidAction);
+                                }
+                                self.eval_base_effect(&crate::game::effect::BaseEffect::Exhaust { hand_index: card_index });
+                                next_state = Some(BattleState::SelectCardInHand(
+                                    CardInHandTo::Exhaust(selection.after_pick(card_index))
+                                ));
+                            }
+                            CardInHandTo::DiscardAndDraw { discarded, selection } => {
+                                if card_index < selection.next_index {
+                                    return Err(BattleError::InvalidAction);
+                                }
+                                self.cards.discard_card_from_hand(card_index);
+                                self.emit_event(BattleEvent::CardDiscarded { source: Entity::Player });
+                                next_state = Some(BattleState::SelectCardInHand(
+                                    CardInHandTo::DiscardAndDraw { discarded: discarded + 1, selection: selection.after_pick(card_index) }
+                                ));
+                            }
                         }
                     }
                     _ => {
@@ -140,8 +187,16 @@ impl Battle {
                     }
                 }
 
-                // Return to player turn state
-                self.battle_state = crate::battle::battle_state::BattleState::PlayerTurn;
+                // Multi-card selections continue while there are cards left to choose
+                match next_state {
+                    Some(state) => {
+                        self.battle_state = state;
+                        if !self.has_cards_left_to_select() {
+                            self.finish_selection();
+                        }
+                    }
+                    None => self.finish_selection(),
+                }
             }
             BattleAction::SelectCardInDiscard(card_index) => {
                 if card_index >= self.cards.discard_pile_size() {
@@ -175,11 +230,41 @@ impl Battle {
                 // Remove the card from exhaust pile and add to hand
                 let card = exhausted[card_index].clone();
                 self.cards.remove_card_from_exhausted(card_index);
-                self.cards.add_card_to_hand(card);
+                self.add_card_to_hand(card);
 
                 // Return to player turn state
                 self.battle_state = crate::battle::battle_state::BattleState::PlayerTurn;
             }
+            BattleAction::SelectCardInDrawPile(card_index) => {
+                // Check if we're in the SelectCardInDrawPile state
+                let BattleState::SelectCardInDrawPile { card_type, selection } = self.battle_state else {
+                    return Err(BattleError::InvalidAction);
+                };
+
+                // Only cards of the requested type can be selected, in draw pile order
+                let is_matching_card = self.cards.get_deck().get_card(card_index)
+                    .is_some_and(|card| card.get_card_type() == card_type);
+                if !is_matching_card || card_index < selection.next_index {
+                    return Err(BattleError::InvalidAction);
+                }
+
+                // Move the card from the draw pile to hand
+                if let Some(card) = self.cards.remove_card_from_deck(card_index) {
+                    self.add_card_to_hand(card);
+                }
+
+                self.battle_state = BattleState::SelectCardInDrawPile { card_type, selection: selection.after_pick(card_index) };
+                if !self.has_cards_left_to_select() {
+                    // Flushes any cards pending discard (e.g., Secret Weapon+ that triggered this state)
+                    self.finish_selection();
+                }
+            }
+            BattleAction::ConfirmSelection => {
+                if !self.battle_state.multi_select().is_some_and(|selection| selection.can_confirm()) {
+                    return Err(BattleError::InvalidAction);
+                }
+                self.finish_selection();
+            }
             BattleAction::SelectCardFromChoices(choice_index) => {
                 // Check if we're in the SelectCardFromChoices state
                 match &self.battle_state {
@@ -206,8 +291,9 @@ impl Battle {
                         }
 
                         // Add the card num_copies times to the player's hand
-                        for _ in 0..*num_copies {
-                            self.cards.add_card_to_hand(card.clone());
+                        let num_copies = *num_copies;
+                        for _ in 0..num_copies {
+                            self.add_card_to_hand(card.clone());
                         }
 
                         // Return to player turn state
@@ -220,6 +306,13 @@ impl Battle {
             }
         }
 
+        // Time Warp ends the turn once the card that triggered it has resolved
+        if self.end_turn_requested && self.battle_state == BattleState::PlayerTurn && !self.is_battle_over() {
+            self.end_turn_requested = false;
+            let global_info = self.global_info;
+            self.end_turn(rng, &global_info);
+        }
+
         // Check if battle is over after the action
         let battle_events = self.take_battle_events();
         let player_alive = self.player.is_alive();
@@ -236,6 +329,35 @@ impl Battle {
             Ok(BattleResult::Continued(battle_events))
         }
     }
+
+    /// Whether the multi-card selection in progress can still pick another card
+    fn has_cards_left_to_select(&self) -> bool {
+        let Some(selection) = self.battle_state.multi_select() else {
+            return false;
+        };
+        if selection.remaining == 0 {
+            return false;
+        }
+        match &self.battle_state {
+            BattleState::SelectCardInDrawPile { card_type, .. } => self.cards.get_deck().get_cards().iter()
+                .skip(selection.next_index)
+                .any(|card| card.get_card_type() == *card_type),
+            _ => selection.next_index < self.cards.hand_size(),
+        }
+    }
+
+    /// End the current selection and return to the player's turn
+    pub(in crate::battle) fn finish_selection(&mut self) {
+        let state = std::mem::replace(&mut self.battle_state, BattleState::PlayerTurn);
+
+        // Flush any cards pending discard (e.g., the card that triggered this state)
+        self.flush_to_be_discarded();
+
+        if let BattleState::SelectCardInHand(CardInHandTo::DiscardAndDraw { discarded, .. }) = state {
+            self.draw_cards(discarded as usize);
+        }
+        self.process_effect_queue();
+    }
 }
 
 #[cfg(test)]
@@ -245,7 +367,7 @@ mod tests {
     use crate::battle::enemy_in_battle::EnemyInBattle;
     use crate::enemies::{red_louse::RedLouse, enemy_enum::EnemyEnum};
     use crate::game::PlayerRunState;
-    use crate::game::{global_info::GlobalInfo, deck::Deck, enemy::EnemyTrait};
+    use crate::game::{global_info::GlobalInfo, deck::Deck, enemy::EnemyTrait, card::Card, card_type::CardType};
 
     #[test]
     fn test_play_card_with_target() {
@@ -617,4 +739,120 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    fn selection_battle(deck: Vec<Card>) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut player_state = PlayerRunState::new(80, 80, 0);
+        player_state.potions.add_potion(crate::potion::Potion::GamblersBrew);
+        Battle::new(Deck::new(deck), global_info, player_state, enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_up_to_selection_can_be_confirmed_early() {
+        use crate::cards::ironclad::{strike::strike, defend::defend};
+        use crate::game::effect::BaseEffect;
+
+        let mut rng = rand::rng();
+        let mut battle = selection_battle(vec![]);
+        battle.add_card_to_hand_for_testing(strike());
+        battle.add_card_to_hand_for_testing(defend());
+        battle.add_card_to_hand_for_testing(strike());
+
+        // ConfirmSelection only makes sense while selecting
+        assert_eq!(battle.eval_action(BattleAction::ConfirmSelection, &mut rng), Err(BattleError::InvalidAction));
+
+        battle.eval_base_effect(&BaseEffect::EnterSelectCardsInHandToExhaust { count: 3 });
+        battle.eval_action(BattleAction::SelectCardInHand(1), &mut rng).unwrap();
+
+        // Picks are made in hand order, the first Strike can no longer be chosen
+        assert_eq!(battle.eval_action(BattleAction::SelectCardInHand(0), &mut rng), Err(BattleError::InvalidAction));
+
+        battle.eval_action(BattleAction::ConfirmSelection, &mut rng).unwrap();
+        assert_eq!(battle.battle_state, BattleState::PlayerTurn);
+        assert_eq!(battle.cards.hand_size(), 2);
+        assert_eq!(battle.cards.exhausted_size(), 1);
+    }
+
+    #[test]
+    fn test_exact_selection_cannot_be_confirmed() {
+        use crate::cards::ironclad::{strike::strike, defend::defend};
+        use crate::game::effect::BaseEffect;
+
+        let mut battle = selection_battle(vec![]);
+        battle.cards.put_card_on_top_of_deck(defend());
+        battle.cards.put_card_on_top_of_deck(strike());
+        battle.eval_base_effect(&BaseEffect::EnterSelectCardInDrawPile { card_type: CardType::Attack });
+
+        // Only the Strike can be picked, and the selection cannot be skipped
+        assert_eq!(battle.list_available_actions(), vec![BattleAction::SelectCardInDrawPile(0)]);
+    }
+
+    #[test]
+    fn test_selection_actions_skip_identical_cards_and_earlier_picks() {
+        use crate::cards::ironclad::{strike::strike, defend::defend};
+        use crate::game::effect::BaseEffect;
+
+        let mut rng = rand::rng();
+        let mut battle = selection_battle(vec![]);
+        battle.add_card_to_hand_for_testing(strike());
+        battle.add_card_to_hand_for_testing(strike());
+        battle.add_card_to_hand_for_testing(defend());
+        battle.eval_base_effect(&BaseEffect::EnterSelectCardsInHandToExhaust { count: 2 });
+
+        assert_eq!(battle.list_available_actions(), vec![
+            BattleAction::SelectCardInHand(0),
+            BattleAction::SelectCardInHand(2),
+            BattleAction::ConfirmSelection,
+        ]);
+
+        // Every sequence of listed actions ends with a different set of exhausted cards:
+        // nothing, Strike, Defend, Strike + Strike and Strike + Defend
+        let mut outcomes = Vec::new();
+        let mut frontier = vec![battle];
+        while let Some(state) = frontier.pop() {
+            if state.battle_state == BattleState::PlayerTurn {
+                let mut exhausted: Vec<String> = state.cards.get_exhausted().iter().map(|card| card.get_name()).collect();
+                exhausted.sort();
+                outcomes.push(exhausted);
+                continue;
+            }
+            for action in state.list_available_actions() {
+                let mut next = state.clone();
+                next.eval_action(action, &mut rng).unwrap();
+                frontier.push(next);
+            }
+        }
+        outcomes.sort();
+        let total = outcomes.len();
+        outcomes.dedup();
+        assert_eq!(total, 5);
+        assert_eq!(outcomes.len(), 5);
+    }
+
+    #[test]
+    fn test_gamblers_brew_discards_then_draws_as_many() {
+        use crate::cards::ironclad::{strike::strike, defend::defend};
+
+        let mut rng = rand::rng();
+        let mut deck = vec![strike(); 5];
+        deck.extend(vec![defend(); 5]);
+        let mut battle = selection_battle(deck);
+        assert_eq!(battle.cards.hand_size(), 5);
+
+        battle.eval_action(BattleAction::UsePotion(0, None), &mut rng).unwrap();
+        assert!(matches!(battle.battle_state, BattleState::SelectCardInHand(CardInHandTo::DiscardAndDraw { .. })));
+
+        battle.eval_action(BattleAction::SelectCardInHand(0), &mut rng).unwrap();
+        battle.eval_action(BattleAction::SelectCardInHand(2), &mut rng).unwrap();
+        battle.eval_action(BattleAction::ConfirmSelection, &mut rng).unwrap();
+
+        assert_eq!(battle.battle_state, BattleState::PlayerTurn);
+        assert_eq!(battle.cards.discard_pile_size(), 2);
+        assert_eq!(battle.get_counters().this_turn(crate::battle::counters::Counter::CardsDiscarded), 2);
+        assert_eq!(battle.cards.hand_size(), 5);
+        assert!(battle.get_hand().iter().filter(|card| card.get_name() == "Defend").count() >= 2);
+    }
+}
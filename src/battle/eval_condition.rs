@@ -0,0 +1,197 @@
+use super::Battle;
+use crate::battle::target::Entity;
+use crate::game::{card_type::CardType, effect::{Condition, Quantity}};
+
+impl Battle {
+    /// Evaluate if a condition is met in the current battle context
+    /// `target` is the entity the card or effect is aimed at, used by the Target* conditions and quantities
+    pub fn eval_condition(&self, condition: &Condition, target: Entity) -> bool {
+        match condition {
+            Condition::True => true,
+            Condition::False => false,
+            Condition::TargetIsVulnerable => match target {
+                Entity::Enemy(idx) => self.enemies.get(idx).is_some_and(|enemy| enemy.battle_info.is_vulnerable()),
+                Entity::Player => self.player.battle_info.is_vulnerable(),
+                Entity::None => false,
+            },
+            Condition::TargetIntendsToAttack => match target {
+                Entity::Enemy(idx) => self.enemy_intends_to_attack(idx),
+                _ => false,
+            },
+            Condition::HandAllAttacks => {
+                self.cards.get_hand().iter().all(|c| c.get_card_type() == CardType::Attack)
+            }
+            Condition::HandNoAttacks => {
+                self.cards.get_hand().iter().all(|c| c.get_card_type() != CardType::Attack)
+            }
+            Condition::CardTypeInHand(card_type) => {
+                self.cards.get_hand().iter().any(|c| c.get_card_type() == *card_type)
+            }
+            Condition::EnemyIsAttacking => (0..self.enemies.len()).any(|idx| self.enemy_intends_to_attack(idx)),
+            Condition::HasRelic(relic) => self.relics.contains(relic),
+            Condition::Compare(quantity, comparison, value) => {
+                comparison.holds(self.eval_quantity(*quantity, target), *value)
+            }
+            Condition::Not(condition) => !self.eval_condition(condition, target),
+            Condition::And(conditions) => conditions.iter().all(|c| self.eval_condition(c, target)),
+            Condition::Or(conditions) => conditions.iter().any(|c| self.eval_condition(c, target)),
+        }
+    }
+
+    /// Read a battle quantity for `Condition::Compare`
+    pub fn eval_quantity(&self, quantity: Quantity, target: Entity) -> u32 {
+        match quantity {
+            Quantity::PlayerHp => self.player.battle_info.get_hp(),
+            Quantity::PlayerHpPercent => {
+                let info = &self.player.battle_info;
+                (info.get_hp() * 100).checked_div(info.get_max_hp()).unwrap_or(0)
+            }
+            Quantity::PlayerBlock => self.player.get_block(),
+            Quantity::PlayerEnergy => self.player.get_energy(),
+            Quantity::PlayerStrength => self.player.get_strength().max(0) as u32,
+            Quantity::HandSize => self.cards.hand_size() as u32,
+            Quantity::DrawPileSize => self.cards.deck_size() as u32,
+            Quantity::DiscardPileSize => self.cards.discard_pile_size() as u32,
+            Quantity::ExhaustPileSize => self.cards.exhausted_size() as u32,
+            Quantity::Counter(counter, scope) => self.counters.get(counter, scope),
+            Quantity::TargetHp => self.target_battle_info(target).map_or(0, |info| info.get_hp()),
+            Quantity::TargetBlock => self.target_battle_info(target).map_or(0, |info| info.get_block()),
+            Quantity::EnemiesAlive => self.enemies.iter().filter(|e| e.battle_info.is_alive()).count() as u32,
+        }
+    }
+
+    /// Whether the enemy at `idx` is alive and its sampled move deals attack damage
+    pub fn enemy_intends_to_attack(&self, idx: usize) -> bool {
+        let alive = self.enemies.get(idx).is_some_and(|enemy| enemy.battle_info.is_alive());
+        alive && self.enemy_actions.get(idx).is_some_and(|action| {
+            action.as_ref().is_some_and(|(_, effects)| effects.iter().any(|effect| effect.is_attack()))
+        })
+    }
+
+    fn target_battle_info(&self, target: Entity) -> Option<&crate::battle::character_battle_info::CharacterBattleInfo> {
+        match target {
+            Entity::Player => Some(&self.player.battle_info),
+            Entity::Enemy(idx) => self.enemies.get(idx).map(|enemy| &enemy.battle_info).filter(|info| info.is_alive()),
+            Entity::None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{counters::{Counter, CounterScope}, enemy_in_battle::EnemyInBattle, BattleError};
+    use crate::enemies::{enemy_enum::{EnemyEnum, EnemyMove}, jaw_worm::{JawWorm, JawWormMove}};
+    use crate::game::{card::{Card, CardClass, Rarity}, card_enum::CardEnum, deck::Deck, effect::BattleEffect, global_info::GlobalInfo, PlayerRunState};
+    use crate::relics::Relic;
+
+    fn test_battle(relics: Vec<Relic>) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(40, false)))];
+        let player_state = PlayerRunState::new_with_relics(60, 80, 0, relics);
+        Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_combinators() {
+        let battle = test_battle(vec![]);
+        let target = Entity::Enemy(0);
+
+        assert!(battle.eval_condition(&Condition::True.and(Condition::False.negate()), target));
+        assert!(!battle.eval_condition(&Condition::True.and(Condition::False), target));
+        assert!(battle.eval_condition(&Condition::False.or(Condition::True), target));
+        assert!(battle.eval_condition(&Condition::And(vec![]), target));
+        assert!(!battle.eval_condition(&Condition::Or(vec![]), target));
+    }
+
+    #[test]
+    fn test_quantities() {
+        let mut battle = test_battle(vec![Relic::Anchor]);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::strike::strike());
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::defend::defend());
+
+        assert_eq!(battle.eval_quantity(Quantity::PlayerHpPercent, Entity::Player), 75);
+        assert_eq!(battle.eval_quantity(Quantity::HandSize, Entity::Player), 2);
+        assert_eq!(battle.eval_quantity(Quantity::TargetHp, Entity::Enemy(0)), 40);
+        assert_eq!(battle.eval_quantity(Quantity::TargetHp, Entity::None), 0);
+        assert_eq!(battle.eval_quantity(Quantity::EnemiesAlive, Entity::None), 1);
+
+        // Low-HP-or-big-hand check, as a card would write it
+        let condition = Quantity::PlayerHpPercent.at_most(50).or(Quantity::HandSize.at_least(2));
+        assert!(battle.eval_condition(&condition, Entity::Player));
+        assert!(battle.eval_condition(&Condition::HasRelic(Relic::Anchor), Entity::Player));
+        assert!(battle.eval_condition(&Condition::CardTypeInHand(CardType::Skill), Entity::Player));
+        assert!(!battle.eval_condition(&Condition::CardTypeInHand(CardType::Power), Entity::Player));
+    }
+
+    #[test]
+    fn test_counter_quantity() {
+        let mut battle = test_battle(vec![]);
+        let played_two = Quantity::Counter(Counter::CardsPlayed, CounterScope::ThisTurn).at_least(2);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert!(!battle.eval_condition(&played_two, Entity::Player));
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert!(battle.eval_condition(&played_two, Entity::Player));
+    }
+
+    #[test]
+    fn test_target_intends_to_attack_reads_the_sampled_move() {
+        let mut battle = test_battle(vec![]);
+        let attack = BattleEffect::AttackToTarget { amount: 11, num_attacks: 1, strength_multiplier: 1 };
+
+        battle.enemy_actions[0] = Some((EnemyMove::JawWorm(JawWormMove::Chomp), vec![attack]));
+        assert!(battle.eval_condition(&Condition::TargetIntendsToAttack, Entity::Enemy(0)));
+        assert!(!battle.eval_condition(&Condition::TargetIntendsToAttack, Entity::Player));
+
+        battle.enemy_actions[0] = Some((EnemyMove::JawWorm(JawWormMove::Bellow), vec![BattleEffect::GainStrength { amount: 3 }]));
+        assert!(!battle.eval_condition(&Condition::TargetIntendsToAttack, Entity::Enemy(0)));
+    }
+
+    #[test]
+    fn test_compound_play_condition_and_conditional_effect() {
+        let mut battle = test_battle(vec![]);
+        // Playable only with at most 1 card in hand, deals 10 more damage to a target below 30 HP
+        let card = Card::new(CardEnum::Strike, 0, CardClass::IronClad(Rarity::Basic, CardType::Attack), vec![
+            BattleEffect::AttackToTarget { amount: 5, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::ConditionalEffect(
+                Quantity::TargetHp.less_than(30),
+                Box::new(BattleEffect::AttackToTarget { amount: 10, num_attacks: 1, strength_multiplier: 1 }),
+            ),
+        ]).set_play_condition(Quantity::HandSize.at_most(1));
+
+        battle.add_card_to_hand_for_testing(card.clone());
+        battle.add_card_to_hand_for_testing(card);
+        assert_eq!(battle.play_card(0, Entity::Enemy(0)), Err(BattleError::CardNotPlayable));
+
+        // 40 HP: only the base hit lands
+        let card = battle.cards.remove_card_from_hand(0).unwrap();
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), 35);
+
+        // 25 HP: the conditional hit lands too
+        battle.enemies[0].battle_info.set_current_hp(25);
+        battle.add_card_to_hand_for_testing(card);
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), 10);
+    }
+
+    #[test]
+    fn test_enemy_is_attacking_checks_every_living_enemy() {
+        let mut battle = test_battle(vec![]);
+        let attack = BattleEffect::AttackToTarget { amount: 11, num_attacks: 1, strength_multiplier: 1 };
+
+        battle.enemy_actions[0] = Some((EnemyMove::JawWorm(JawWormMove::Bellow), vec![BattleEffect::GainStrength { amount: 3 }]));
+        assert!(!battle.eval_condition(&Condition::EnemyIsAttacking, Entity::None));
+
+        battle.enemy_actions[0] = Some((EnemyMove::JawWorm(JawWormMove::Chomp), vec![attack]));
+        assert!(battle.eval_condition(&Condition::EnemyIsAttacking, Entity::None));
+
+        // A dead enemy's last intent does not count
+        battle.enemies[0].battle_info.set_current_hp(0);
+        assert!(!battle.eval_condition(&Condition::EnemyIsAttacking, Entity::None));
+    }
+}
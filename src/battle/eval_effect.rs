@@ -1,9 +1,15 @@
 use super::Battle;
-use crate::game::effect::BaseEffect;
-use crate::battle::{target::Entity, battle_events::BattleEvent, battle_state::CardInHandTo};
+use crate::game::effect::{BaseEffect, StatusPlacement, TargetSelector};
+use crate::battle::{target::Entity, battle_events::BattleEvent, battle_state::{CardInHandTo, MultiSelect}};
 use crate::enemies::gremlin_nob::EnrageListener;
+use crate::game::card_enum::CardEnum;
+use crate::game::card_keyword::CardKeyword;
+use crate::battle::power::{Power, PowerId};
+use crate::battle::damage_type::DamageType;
+use super::battle::PermanentCardChange;
+use crate::relics::{Relic, ectoplasm, torii, tungsten_rod};
 use rand::prelude::IndexedRandom;
-use log::info;
+use log::{info, warn};
 
 impl Battle {
     /// Apply a specific effect with its target
@@ -12,24 +18,20 @@ impl Battle {
             BaseEffect::AttackToTarget { source, target, amount, num_attacks, strength_multiplier } => {
                 for _ in 0..*num_attacks {
                     let incoming_damage = self.calculate_incoming_damage_with_multiplier(*source, *target, *amount, *strength_multiplier);
-                    self.apply_damage(*target, incoming_damage);
+                    self.deal_damage(*source, *target, incoming_damage, DamageType::Normal);
                 }
             },
-            BaseEffect::AttackToTargetWithBlock { source: _, target } => {
+            BaseEffect::AttackToTargetWithBlock { source, target } => {
                 // Deal damage equal to player's current Block
                 let damage_amount = self.player.get_block();
                 if damage_amount > 0 {
-                    self.apply_damage(*target, damage_amount);
+                    self.deal_damage(*source, *target, damage_amount, DamageType::Normal);
                 }
             },
-            BaseEffect::AttackToTargetWithScaling { source, target, base_damage, scaling } => {
-                // Calculate damage with current rampage scaling
-                let total_damage = base_damage + self.player.battle_info.get_rampage_damage();
-                let incoming_damage = self.calculate_incoming_damage_with_multiplier(*source, *target, total_damage, 1);
-                self.apply_damage(*target, incoming_damage);
-
-                // Increase rampage scaling for next use
-                self.player.battle_info.increase_rampage_damage(*scaling);
+            BaseEffect::AttackToTargetWithScaling { source, target, base_damage } => {
+                // The played card instance already carries the damage it gained from earlier uses
+                let incoming_damage = self.calculate_incoming_damage_with_multiplier(*source, *target, *base_damage, 1);
+                self.deal_damage(*source, *target, incoming_damage, DamageType::Normal);
             },
             BaseEffect::PerfectedStrike { source, target, base_damage, damage_per_strike } => {
                 // Count Strike cards in deck (draw pile + hand + discard pile)
@@ -38,18 +40,7 @@ impl Battle {
                 // Calculate total damage: base + (bonus per Strike card × number of Strikes)
                 let total_damage = base_damage + (damage_per_strike * strike_count);
                 let incoming_damage = self.calculate_incoming_damage_with_multiplier(*source, *target, total_damage, 1);
-                self.apply_damage(*target, incoming_damage);
-            },
-            BaseEffect::AttackAllEnemies { source, amount, num_attacks } => {
-                for _ in 0..*num_attacks {
-                    for enemy_idx in 0..self.enemies.len() {
-                        if self.enemies[enemy_idx].battle_info.is_alive() {
-                            let target = Entity::Enemy(enemy_idx);
-                            let incoming_damage = self.calculate_incoming_damage(*source, target, *amount);
-                            self.apply_damage(target, incoming_damage);
-                        }
-                    }
-                }
+                self.deal_damage(*source, *target, incoming_damage, DamageType::Normal);
             },
             BaseEffect::GainDefense { source, amount } => {
                 // Defense effects apply to the source entity
@@ -60,38 +51,20 @@ impl Battle {
                     Entity::Player => {
                         if !self.player.battle_info.consume_artifact() {
                             self.player.battle_info.apply_vulnerable(*duration);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             if !self.enemies[*idx].battle_info.consume_artifact() {
                                 self.enemies[*idx].battle_info.apply_vulnerable(*duration);
+                                self.emit_event(BattleEvent::DebuffApplied { target: *target });
                             }
                         }
                     },
                     Entity::None => {} // No target
                 }
             },
-            BaseEffect::ApplyVulnerableAll { duration } => {
-                // Apply vulnerable to all enemies
-                for enemy_idx in 0..self.enemies.len() {
-                    if self.enemies[enemy_idx].battle_info.is_alive() {
-                        if !self.enemies[enemy_idx].battle_info.consume_artifact() {
-                            self.enemies[enemy_idx].battle_info.apply_vulnerable(*duration);
-                        }
-                    }
-                }
-            },
-            BaseEffect::ApplyVulnerableToAll { duration } => {
-                // Apply vulnerable to all enemies (same as ApplyVulnerableAll)
-                for enemy_idx in 0..self.enemies.len() {
-                    if self.enemies[enemy_idx].battle_info.is_alive() {
-                        if !self.enemies[enemy_idx].battle_info.consume_artifact() {
-                            self.enemies[enemy_idx].battle_info.apply_vulnerable(*duration);
-                        }
-                    }
-                }
-            },
             BaseEffect::HealToFull => {
                 // Heal player to full HP
                 let max_hp = self.player.get_max_hp();
@@ -107,12 +80,14 @@ impl Battle {
                     Entity::Player => {
                         if !self.player.battle_info.consume_artifact() {
                             self.player.battle_info.apply_weak(*duration);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             if !self.enemies[*idx].battle_info.consume_artifact() {
                                 self.enemies[*idx].battle_info.apply_weak(*duration);
+                                self.emit_event(BattleEvent::DebuffApplied { target: *target });
                             }
                         }
                     },
@@ -147,22 +122,7 @@ impl Battle {
                     Entity::None => {} // No source
                 }
             },
-            BaseEffect::LoseStrengthSelf { source, amount } => {
-                match source {
-                    Entity::Player => {
-                        // Reduce player strength, now allows negative values
-                        self.player.battle_info.lose_strength(*amount);
-                    },
-                    Entity::Enemy(idx) => {
-                        if *idx < self.enemies.len() {
-                            // Reduce enemy strength, now allows negative values
-                            self.enemies[*idx].battle_info.lose_strength(*amount);
-                        }
-                    },
-                    Entity::None => {} // No source
-                }
-            },
-            BaseEffect::LoseStrengthTarget { target, amount } => {
+            BaseEffect::LoseStrength { target, amount } => {
                 match target {
                     Entity::Player => {
                         self.player.battle_info.lose_strength(*amount);
@@ -170,6 +130,7 @@ impl Battle {
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             self.enemies[*idx].battle_info.lose_strength(*amount);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::None => {} // No target
@@ -186,20 +147,7 @@ impl Battle {
                     Entity::None => {} // No source
                 }
             },
-            BaseEffect::LoseDexteritySelf { source, amount } => {
-                match source {
-                    Entity::Player => {
-                        self.player.battle_info.lose_dexterity(*amount);
-                    },
-                    Entity::Enemy(idx) => {
-                        if *idx < self.enemies.len() {
-                            self.enemies[*idx].battle_info.lose_dexterity(*amount);
-                        }
-                    },
-                    Entity::None => {} // No source
-                }
-            },
-            BaseEffect::LoseDexterityTarget { target, amount } => {
+            BaseEffect::LoseDexterity { target, amount } => {
                 match target {
                     Entity::Player => {
                         self.player.battle_info.lose_dexterity(*amount);
@@ -207,6 +155,7 @@ impl Battle {
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             self.enemies[*idx].battle_info.lose_dexterity(*amount);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::None => {} // No target
@@ -228,12 +177,14 @@ impl Battle {
                     Entity::Player => {
                         if !self.player.battle_info.consume_artifact() {
                             self.player.battle_info.apply_frail(*duration);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             if !self.enemies[*idx].battle_info.consume_artifact() {
                                 self.enemies[*idx].battle_info.apply_frail(*duration);
+                                self.emit_event(BattleEvent::DebuffApplied { target: *target });
                             }
                         }
                     },
@@ -245,12 +196,14 @@ impl Battle {
                     Entity::Player => {
                         if !self.player.battle_info.consume_artifact() {
                             self.player.battle_info.apply_entangled(*duration);
+                            self.emit_event(BattleEvent::DebuffApplied { target: *target });
                         }
                     },
                     Entity::Enemy(idx) => {
                         if *idx < self.enemies.len() {
                             if !self.enemies[*idx].battle_info.consume_artifact() {
                                 self.enemies[*idx].battle_info.apply_entangled(*duration);
+                                self.emit_event(BattleEvent::DebuffApplied { target: *target });
                             }
                         }
                     },
@@ -289,9 +242,7 @@ impl Battle {
                 }
             },
             BaseEffect::LoseStrengthAtEndOfTurn { source, amount } => {
-                // Create a LoseStrengthListener to handle strength loss at end of turn
-                let lose_listener = crate::cards::ironclad::flex::LoseStrengthListener::new(*source, *amount);
-                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::LoseStrength(lose_listener));
+                self.apply_power(*source, Power::new(PowerId::LoseStrength, *amount as i32));
             },
             BaseEffect::ActivateEnrage { source, amount } => {
                 // Add EnrageListener for the specified enemy
@@ -301,58 +252,32 @@ impl Battle {
                 }
             },
             BaseEffect::ActivateEmbrace { source } => {
-                // Add EmbraceListener for the player
-                if let Entity::Player = source {
-                    let embrace_listener = crate::cards::ironclad::embrace::EmbraceListener::new(*source);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Embrace(embrace_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::Embrace, 1));
             },
             BaseEffect::ActivateFeelNoPain { source, block_per_exhaust } => {
-                // Add FeelNoPainListener for the player
-                if let Entity::Player = source {
-                    let feel_no_pain_listener = crate::cards::ironclad::feel_no_pain::FeelNoPainListener::new(*source, *block_per_exhaust);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::FeelNoPain(feel_no_pain_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::FeelNoPain, *block_per_exhaust as i32));
             },
             BaseEffect::ActivateBrutality { source } => {
-                // Add BrutalityListener for the player
-                if let Entity::Player = source {
-                    let brutality_listener = crate::cards::ironclad::brutality::BrutalityListener::new(*source);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Brutality(brutality_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::Brutality, 1));
+            },
+            BaseEffect::ActivateCorruption { source } => {
+                self.apply_power(*source, Power::new(PowerId::Corruption, 1));
+                self.cards.activate_corruption();
             },
-            BaseEffect::ActivateCorruption { source: _ } => {
-                // Corruption is now handled passively in play_card logic
-                // Skills cost 0 and are exhausted when Corruption power is in the powers collection
-                // No listener needed
+            BaseEffect::ActivateEstablishment { source, amount } => {
+                self.apply_power(*source, Power::new(PowerId::Establishment, *amount as i32));
             },
             BaseEffect::ActivateMetallicize { source, amount } => {
-                // Add MetallicizeListener for the player
-                if let Entity::Player = source {
-                    let metallicize_listener = crate::cards::ironclad::metallicize::MetallicizeListener::new(*source, *amount);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Metallicize(metallicize_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::Metallicize, *amount as i32));
             },
             BaseEffect::ActivateFlameBarrier { source, damage } => {
-                // Add FlameBarrierListener for the player
-                if let Entity::Player = source {
-                    let flame_barrier_listener = crate::cards::ironclad::flame_barrier::FlameBarrierListener::new(*source, *damage);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::FlameBarrier(flame_barrier_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::FlameBarrier, *damage as i32));
             },
-                        BaseEffect::ActivateDemonForm { source, strength_per_turn } => {
-                // Add DemonFormListener for the player
-                if let Entity::Player = source {
-                    let demon_form_listener = crate::cards::ironclad::demon_form::DemonFormListener::new(*source, *strength_per_turn);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::DemonForm(demon_form_listener));
-                }
+            BaseEffect::ActivateDemonForm { source, strength_per_turn } => {
+                self.apply_power(*source, Power::new(PowerId::DemonForm, *strength_per_turn as i32));
             },
             BaseEffect::ActivateRage { source, block_per_attack } => {
-                // Add RageListener for the player
-                if let Entity::Player = source {
-                    let rage_listener = crate::cards::ironclad::rage::RageListener::new(*source, *block_per_attack);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Rage(rage_listener));
-                }
+                self.apply_power(*source, Power::new(PowerId::Rage, *block_per_attack as i32));
             },
             BaseEffect::AddRandomAttackToHand { source } => {
                 // Add a random Ironclad Attack card to hand
@@ -377,8 +302,8 @@ impl Battle {
                         crate::cards::ironclad::sword_boomerang::sword_boomerang(),
                     ];
 
-                    if let Some(random_attack) = ironclad_attacks.choose(&mut rand::rng()) {
-                        self.cards.add_card_to_hand(random_attack.clone());
+                    if let Some(random_attack) = ironclad_attacks.choose(&mut self.rng) {
+                        self.add_card_to_hand(random_attack.clone());
                     }
                 }
             },
@@ -392,9 +317,8 @@ impl Battle {
 
                     // Select N random unique attack cards with uniform probability
                     use rand::seq::SliceRandom;
-                    let mut rng = rand::rng();
                     let choices: Vec<crate::game::card_enum::CardEnum> = ironclad_attacks
-                        .choose_multiple(&mut rng, *num_choices as usize)
+                        .choose_multiple(&mut self.rng, *num_choices as usize)
                         .cloned()
                         .collect();
 
@@ -416,9 +340,8 @@ impl Battle {
 
                     // Select N random unique skill cards with uniform probability
                     use rand::seq::SliceRandom;
-                    let mut rng = rand::rng();
                     let choices: Vec<crate::game::card_enum::CardEnum> = ironclad_skills
-                        .choose_multiple(&mut rng, *num_choices as usize)
+                        .choose_multiple(&mut self.rng, *num_choices as usize)
                         .cloned()
                         .collect();
 
@@ -430,12 +353,8 @@ impl Battle {
                     };
                 }
             },
-            BaseEffect::ActivateEvolve { source: _ } => {
-                // Evolve activation - currently just draws 1 card immediately
-                // In full implementation, would add a listener for Status card draws
-                for _ in 0..1 {
-                    self.cards.draw_card();
-                }
+            BaseEffect::ActivateEvolve { source: _, cards_per_status } => {
+                self.apply_power(Entity::Player, Power::new(PowerId::Evolve, *cards_per_status as i32));
             },
             BaseEffect::AddCardToDrawPile { source: _, card } => {
                 // Add a specific card to the draw pile
@@ -448,7 +367,7 @@ impl Battle {
             },
             BaseEffect::DrawCard { source: _, count } => {
                 // Draw cards for the player
-                self.cards.draw_n(*count as usize);
+                self.draw_cards(*count as usize);
             },
             BaseEffect::Heal { target, amount } => {
                 // Heal the target entity
@@ -510,11 +429,8 @@ impl Battle {
                 }
             },
             BaseEffect::ActivateCombust { source, amount } => {
-                // Add CombustListener for the player
-                if let Entity::Player = source {
-                    let combust_listener = crate::cards::ironclad::combust::CombustListener::new(*source, *amount);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Combust(combust_listener));
-                }
+                // Every copy adds its damage and 1 more HP lost per turn
+                self.apply_power(*source, Power::new(PowerId::Combust, *amount as i32).with_extra_amount(1));
             },
             BaseEffect::ApplyDamageReduction { target, percentage: _ } => {
                 // Apply damage reduction to the target entity
@@ -533,57 +449,16 @@ impl Battle {
                 }
             },
             BaseEffect::LoseHp { target, amount } => {
-                // Direct HP loss (ignores block)
-                match target {
-                    Entity::Player => {
-                        let current_hp = self.player.battle_info.get_current_hp();
-                        let new_hp = current_hp.saturating_sub(*amount);
-                        self.player.battle_info.set_current_hp(new_hp);
-
-                        // Emit HP loss from card event for Rupture and other listeners
-                        if *amount > 0 {
-                            let hp_loss_event = BattleEvent::HpLostFromCard {
-                                target: *target,
-                                amount: *amount,
-                            };
-                            self.emit_event(hp_loss_event);
-                        }
-                    },
-                    Entity::Enemy(idx) => {
-                        if *idx < self.enemies.len() {
-                            let current_hp = self.enemies[*idx].battle_info.get_current_hp();
-                            let new_hp = current_hp.saturating_sub(*amount);
-                            self.enemies[*idx].battle_info.set_current_hp(new_hp);
-
-                            // Emit HP loss from card event for listeners
-                            if *amount > 0 {
-                                let hp_loss_event = BattleEvent::HpLostFromCard {
-                                    target: *target,
-                                    amount: *amount,
-                                };
-                                self.emit_event(hp_loss_event);
-                            }
-                        }
-                    },
-                    Entity::None => {} // No target, no HP loss
-                }
+                // Direct HP loss ignores block, Intangible still reduces it to 1
+                self.deal_damage(*target, *target, *amount, DamageType::HpLoss);
             },
             BaseEffect::GainEnergy { source: _, amount } => {
                 // Gain energy
                 self.player.battle_info.gain_energy(*amount);
             },
-            BaseEffect::ApplyWeakAll { duration } => {
-                // Apply Weak to all enemies
-                for enemy in &mut self.enemies {
-                    if !enemy.battle_info.consume_artifact() {
-                        enemy.battle_info.apply_weak(*duration);
-                    }
-                }
-            },
-            BaseEffect::Ethereal { hand_index: _ } => {
-                // TODO: Implement ethereal effect
-                // Mark card as ethereal (will be exhausted at end of turn)
-                // This requires implementing set_ethereal method on deck
+            BaseEffect::Ethereal { hand_index } => {
+                // Mark the card as ethereal so it is exhausted at end of turn
+                self.cards.add_keyword_to_hand_card(*hand_index, CardKeyword::Ethereal);
             },
             BaseEffect::AddCardToDiscard { card } => {
                 // Add a card to the discard pile
@@ -619,22 +494,92 @@ impl Battle {
                 self.battle_state = crate::battle::battle_state::BattleState::SelectCardInExhaust;
             },
             BaseEffect::PlayTopCard { source: _, target } => {
-                // Take the top card from draw pile and play it
-                if let Some(card) = self.cards.draw_top_card() {
-                    // Add the card to hand temporarily to play it
-                    let hand_index = self.cards.hand_size();
-                    self.cards.add_card_to_hand(card.clone());
-
-                    // Play the card (this will handle cost, effects, etc.)
-                    let _ = self.play_card(hand_index, *target);
+                // Take the top card from draw pile and play it (this will handle cost, effects, etc.)
+                self.play_top_card(*target, true);
+            },
+            BaseEffect::AddStatusCard { status_card, count, placement, upgraded } => {
+                for _ in 0..*count {
+                    let card = if *upgraded { status_card.to_card().upgrade() } else { status_card.to_card() };
+                    match placement {
+                        StatusPlacement::ShuffleIntoDrawPile => self.cards.shuffle_card_into_deck(card, &mut self.rng),
+                        StatusPlacement::Discard => self.cards.add_card_to_discard(card),
+                        StatusPlacement::TopOfDrawPile => self.cards.put_card_on_top_of_deck(card),
+                    }
+                }
+            },
+            BaseEffect::UpgradeStatusCards { status_card } => {
+                self.cards.upgrade_cards_of(*status_card);
+            },
+            BaseEffect::LoseEnergy { amount } => {
+                let energy = self.player.battle_info.get_energy();
+                self.player.battle_info.spend_energy((*amount).min(energy));
+            },
+            BaseEffect::GrantKeywordToHand { keyword, card_type } => {
+                self.cards.add_keyword_to_hand(*keyword, *card_type);
+            },
+            BaseEffect::RepeatEffect { effect, times, source, target } => {
+                for _ in 0..*times {
+                    let base_effect = BaseEffect::from_effect((**effect).clone(), *source, *target);
+                    self.eval_base_effect(&base_effect);
+                }
+            },
+            BaseEffect::Targeted { selector, effect, source, target } => {
+                for selected in self.select_targets(*selector, *source, *target) {
+                    // An earlier hit may have killed an enemy picked up front
+                    if let Entity::Enemy(idx) = selected {
+                        if !self.enemies[idx].battle_info.is_alive() {
+                            continue;
+                        }
+                    }
+                    let base_effect = BaseEffect::from_effect((**effect).clone(), *source, selected);
+                    self.eval_base_effect(&base_effect);
+                }
+            },
+            BaseEffect::GainThorns { source, amount } => {
+                self.apply_power(*source, Power::new(PowerId::Thorns, *amount as i32));
+            },
+            BaseEffect::GainBuffer { source, amount } => {
+                self.apply_power(*source, Power::new(PowerId::Buffer, *amount as i32));
+            },
+            BaseEffect::GainBlur { source, amount } => {
+                self.apply_power(*source, Power::new(PowerId::Blur, *amount as i32));
+            },
+            BaseEffect::GainEnergyNextTurn { source, amount } => {
+                self.apply_power(*source, Power::new(PowerId::Energized, *amount as i32));
+            },
+            BaseEffect::ConserveEnergy { source } => {
+                self.apply_power(*source, Power::new(PowerId::Conserve, 1));
+            },
+            BaseEffect::ActivateBarricade { source } => {
+                self.apply_power(*source, Power::new(PowerId::Barricade, 1));
+            },
+            BaseEffect::ApplyNoDraw { source } => {
+                self.apply_power(*source, Power::new(PowerId::NoDraw, 1));
+            },
+            BaseEffect::SpawnEnemy { source, enemy, slot, minion } => {
+                let mut new_enemy = crate::battle::enemy_in_battle::EnemyInBattle::new((**enemy).clone());
+                if let (true, Entity::Enemy(leader)) = (*minion, source) {
+                    new_enemy = new_enemy.with_leader(*leader);
+                }
+                self.spawn_enemy(new_enemy, *slot);
+            },
+            BaseEffect::AddRandomColorlessCardFreeThisTurn { upgraded } => {
+                let colorless_cards = CardEnum::all_colorless_cards();
+                if let Some(card_enum) = colorless_cards.choose(&mut self.rng) {
+                    let card = if *upgraded { card_enum.to_card().upgrade() } else { card_enum.to_card() };
+                    self.add_card_to_hand(card.set_cost(0));
                 }
             },
+            BaseEffect::PlayTopCardForFree { target } => {
+                // Take the top card from draw pile and play it without spending energy
+                self.play_top_card(*target, false);
+            },
             BaseEffect::PlayTopCardAndExhaust { source: _, target } => {
                 // Take the top card from draw pile and play it, then exhaust it
-                if let Some(card) = self.cards.draw_top_card() {
+                if let Some(card) = self.cards.draw_top_card(&mut self.rng) {
                     // Add the card to hand temporarily to play it
                     let hand_index = self.cards.hand_size();
-                    self.cards.add_card_to_hand(card.clone());
+                    self.cards.put_card_in_hand_to_play(card.clone());
 
                     // Check if the card has Exhaust effect
                     let has_exhaust = card.get_effects().contains(&crate::game::effect::BattleEffect::Exhaust);
@@ -660,7 +605,7 @@ impl Battle {
             },
             BaseEffect::PutRandomDiscardCardOnTop => {
                 // Take a random card from discard pile and put on top of draw pile
-                self.cards.put_random_discard_on_top();
+                self.cards.put_random_discard_on_top(&mut self.rng);
             },
             BaseEffect::EnterSelectCardInDiscard => {
                 // Transition to SelectCardInDiscard state
@@ -668,40 +613,7 @@ impl Battle {
             },
             BaseEffect::ConditionalEffect { condition, effect, source, target } => {
                 // Check if the condition is met before applying the effect
-                let condition_met = match condition {
-                    crate::game::effect::Condition::TargetIsVulnerable => {
-                        match target {
-                            Entity::Enemy(idx) => {
-                                if *idx < self.enemies.len() {
-                                    self.enemies[*idx].battle_info.is_vulnerable()
-                                } else {
-                                    false
-                                }
-                            },
-                            Entity::Player => self.player.battle_info.is_vulnerable(),
-                            Entity::None => false,
-                        }
-                    },
-                    crate::game::effect::Condition::HandAllAttacks => {
-                        // For conditional effects, this would need to know which player's hand
-                        // For now, use current player's hand
-                        let hand = self.cards.get_hand();
-                        hand.iter().all(|c| c.get_card_type() == crate::game::card_type::CardType::Attack)
-                    },
-                    crate::game::effect::Condition::HandNoAttacks => {
-                        // Check if there are no attack cards in hand
-                        let hand = self.cards.get_hand();
-                        hand.iter().all(|c| c.get_card_type() != crate::game::card_type::CardType::Attack)
-                    },
-                    crate::game::effect::Condition::True => true,
-                    crate::game::effect::Condition::False => false,
-                    crate::game::effect::Condition::EnemyIsAttacking => {
-                        // Check if any enemy is currently attacking
-                        // For now, assume all moves are attacking (simplified)
-                        // In a full implementation, we'd check specific move types
-                        !self.enemies.is_empty()
-                    },
-                };
+                let condition_met = self.eval_condition(condition, *target);
 
                 if condition_met {
                     // Convert the inner effect to BaseEffect and evaluate it
@@ -735,16 +647,10 @@ impl Battle {
                 }
             },
             BaseEffect::ActivateRupture => {
-                // Activate Rupture listener for gaining Strength when losing HP
-                let rupture_listener = crate::cards::ironclad::rupture::RuptureListener::new(crate::battle::target::Entity::Player);
-                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Rupture(rupture_listener));
+                self.apply_power(Entity::Player, Power::new(PowerId::Rupture, 1));
             },
             BaseEffect::ActivateDoubleTap { remaining_attacks } => {
-                // Activate DoubleTap listener for playing next Attack(s) twice
-                if let Entity::Player = crate::battle::target::Entity::Player {
-                    let double_tap_listener = crate::cards::ironclad::double_tap::DoubleTapListener::new(crate::battle::target::Entity::Player, *remaining_attacks);
-                    self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::DoubleTap(double_tap_listener));
-                }
+                self.apply_power(Entity::Player, Power::new(PowerId::DoubleTap, *remaining_attacks as i32));
             },
             BaseEffect::HealOnKill { amount: _ } => {
                 // Add HealOnKill listener for healing if target dies
@@ -752,7 +658,7 @@ impl Battle {
                 // For now, we'll store this in a temporary state
                 // TODO: Implement proper HealOnKill listener system
             },
-            BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks } => {
+            BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks, strength_multiplier } => {
                 // Deal damage to all enemies and heal for unblocked damage
                 let mut total_unblocked_damage = 0u32;
 
@@ -761,8 +667,8 @@ impl Battle {
                         if self.enemies[enemy_idx].battle_info.is_alive() {
                             let target = Entity::Enemy(enemy_idx);
                             let source = Entity::Player; // Assume player is the source
-                            let incoming_damage = self.calculate_incoming_damage(source, target, *amount);
-                            let actual_damage = self.apply_damage(target, incoming_damage);
+                            let incoming_damage = self.calculate_incoming_damage_with_multiplier(source, target, *amount, *strength_multiplier);
+                            let actual_damage = self.deal_damage(source, target, incoming_damage, DamageType::Normal);
 
                             // Add to total unblocked damage (actual damage is what went through block)
                             total_unblocked_damage += actual_damage;
@@ -803,47 +709,17 @@ impl Battle {
                             let source = target;
 
                             let incoming_damage = self.calculate_incoming_damage(*source, damage_target, total_damage);
-                            self.apply_damage(damage_target, incoming_damage);
+                            self.deal_damage(*source, damage_target, incoming_damage, DamageType::Normal);
                             break; // Only damage one enemy
                         }
                     }
                 }
             },
-            // TODO: Implement Juggernaut listener
-            // BaseEffect::ActivateJuggernaut { damage_per_block } => {
-            //     // Activate Juggernaut listener for dealing damage when gaining block
-            //     if let Entity::Player = crate::battle::target::Entity::Player {
-            //         let juggernaut_listener = crate::cards::ironclad::juggernaut::JuggernautListener::new(crate::battle::target::Entity::Player, *damage_per_block);
-            //         self.add_listener(Box::new(juggernaut_listener));
-            //     }
-            // },
-            BaseEffect::AttackRandomEnemy { amount, num_attacks, strength_multiplier } => {
-                // Deal damage to first alive enemy (simplified from random selection)
-                for _ in 0..*num_attacks {
-                    for enemy_idx in 0..self.enemies.len() {
-                        if self.enemies[enemy_idx].battle_info.is_alive() {
-                            let target = Entity::Enemy(enemy_idx);
-                            let source = Entity::Player;
-                            let incoming_damage = self.calculate_incoming_damage(source, target, *amount);
-
-                            // Apply strength multiplier if present
-                            let final_amount = if *strength_multiplier > 1 {
-                                let strength = self.player.battle_info.get_strength();
-                                incoming_damage + (((*amount as i32 * strength) / 2).max(0) as u32)
-                            } else {
-                                incoming_damage
-                            };
-
-                            self.apply_damage(target, final_amount);
-                            break; // Only damage one enemy
-                        }
-                    }
-                }
+            BaseEffect::ActivateJuggernaut { source, damage } => {
+                self.apply_power(*source, Power::new(PowerId::Juggernaut, *damage as i32));
             },
             BaseEffect::ActivateFireBreathing { source: _, damage_per_status } => {
-                // Activate Fire Breathing listener for dealing damage when Status/Curse cards are drawn
-                let fire_breathing_listener = crate::cards::ironclad::fire_breathing::FireBreathingListener::new(crate::battle::target::Entity::Player, *damage_per_status);
-                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::FireBreathing(fire_breathing_listener));
+                self.apply_power(Entity::Player, Power::new(PowerId::FireBreathing, *damage_per_status as i32));
             },
             BaseEffect::ActivateSentinel { source: _, energy_on_exhaust: _ } => {
                 // Sentinel now uses on_exhaust card property instead of a listener
@@ -856,7 +732,7 @@ impl Battle {
                         lagavulin.wake_from_damage();
                     }
                     // Also remove Metallicize power when Lagavulin wakes
-                    self.queue_effect(BaseEffect::RemoveMetallicize { enemy_index: *enemy_index });
+                    self.queue_effects_front(vec![BaseEffect::RemoveMetallicize { enemy_index: *enemy_index }]);
                 }
             },
             BaseEffect::TransitionLagavulinStunnedToAwake { enemy_index } => {
@@ -868,17 +744,8 @@ impl Battle {
                 }
             },
             BaseEffect::RemoveMetallicize { enemy_index } => {
-                // Deactivate all Metallicize listeners for this enemy
-                use crate::battle::battle_events::EventListener;
-                use crate::battle::event_listener_enum::EventListenerEnum;
-
-                for listener in &mut self.event_listeners {
-                    if listener.get_owner() == Entity::Enemy(*enemy_index) {
-                        // Check if this is a MetallicizeListener using pattern matching
-                        if let EventListenerEnum::Metallicize(metallicize) = listener {
-                            metallicize.deactivate();
-                        }
-                    }
+                if let Some(enemy) = self.enemies.get_mut(*enemy_index) {
+                    enemy.battle_info.remove_power(PowerId::Metallicize);
                 }
             },
             BaseEffect::GainArtifact { source, amount } => {
@@ -902,7 +769,7 @@ impl Battle {
                         .map(|(idx, _)| idx)
                         .collect();
 
-                    if let Some(&random_ally_idx) = alive_allies.choose(&mut rand::rng()) {
+                    if let Some(&random_ally_idx) = alive_allies.choose(&mut self.rng) {
                         self.apply_block(Entity::Enemy(random_ally_idx), *amount);
                     }
                 }
@@ -960,7 +827,7 @@ impl Battle {
                             },
                             _ => {
                                 // Other enemy types shouldn't use this effect
-                                eprintln!("Warning: SplitIntoMediumSlimes used on non-slime enemy type");
+                                warn!("SplitIntoMediumSlimes used on non-slime enemy type");
                             }
                         }
 
@@ -974,7 +841,7 @@ impl Battle {
                 // Add card to hand
                 let card_reward_pool = crate::game::card_reward::CardRewardPool::new();
                 let created_card = card_reward_pool.create_card_from_enum(*card);
-                self.cards.add_card_to_hand(created_card);
+                self.add_card_to_hand(created_card);
             },
                         BaseEffect::HealAndIncreaseMaxHp { target, amount } => {
                 // Heal the target and increase max HP by the same amount
@@ -994,27 +861,7 @@ impl Battle {
             },
             BaseEffect::ShuffleDiscardIntoDraw { source: _ } => {
                 // Shuffle discard pile into draw pile
-                self.cards.shuffle_discard_into_deck();
-            },
-            BaseEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit } => {
-                // Spend all available energy and attack all enemies X times where X is energy spent
-                let current_energy = self.player.get_energy();
-                if current_energy > 0 {
-                    // Spend all energy
-                    self.player.spend_energy(current_energy);
-
-                    // Deal damage to all enemies current_energy times
-                    for _ in 0..current_energy {
-                        for enemy_idx in 0..self.enemies.len() {
-                            if self.enemies[enemy_idx].battle_info.is_alive() {
-                                let target = Entity::Enemy(enemy_idx);
-                                let source = Entity::Player;
-                                let incoming_damage = self.calculate_incoming_damage(source, target, *amount_per_hit);
-                                self.apply_damage(target, incoming_damage);
-                            }
-                        }
-                    }
-                }
+                self.cards.shuffle_discard_into_deck(&mut self.rng);
             },
             BaseEffect::LoseHpPerCardInHand { source, damage_per_card } => {
                 // Deal damage to the source equal to damage_per_card * number of cards in hand
@@ -1022,7 +869,193 @@ impl Battle {
                 let total_damage = damage_per_card * hand_size;
 
                 if total_damage > 0 {
-                    self.apply_damage(*source, total_damage);
+                    self.deal_damage(*source, *source, total_damage, DamageType::HpLoss);
+                }
+            },
+            BaseEffect::UpgradeAllCardsInCombat => {
+                // Upgrade every card in every pile for the rest of combat
+                self.cards.upgrade_all_cards();
+            },
+            BaseEffect::ShuffleRandomCardsIntoDrawPile { card_type, count } => {
+                // Shuffle random Ironclad cards of the given type into the draw pile, they cost 0 this combat
+                let candidates: Vec<CardEnum> = CardEnum::all_ironclad_cards().into_iter()
+                    .filter(|card_enum| card_enum.is_rewardable() && card_enum.to_card().get_card_type() == *card_type)
+                    .collect();
+
+                for _ in 0..*count {
+                    if let Some(card_enum) = candidates.choose(&mut self.rng) {
+                        let card = card_enum.to_card().set_cost_for_combat(0);
+                        self.cards.shuffle_card_into_deck(card, &mut self.rng);
+                    }
+                }
+            },
+            BaseEffect::GainGoldOnKill { target, amount } => {
+                // The attack was queued before this effect, so a dead target means this card killed it
+                if self.is_killed_enemy(*target) && ectoplasm::can_gain_gold(&self.relics) {
+                    self.gold_gained += amount;
+                    info!("Gained {} gold from killing {:?}", amount, target);
+                }
+            },
+            BaseEffect::IncreaseDamageOnKill { target, hand_index, amount } => {
+                // The played card is still in hand until its Exhaust effect is processed
+                if self.is_killed_enemy(*target) && *hand_index < self.cards.hand_size() {
+                    let card = self.cards.get_hand()[*hand_index].clone();
+                    if card.get_card_enum() == CardEnum::RitualDagger {
+                        // The damage increase carries over to the master deck
+                        self.record_permanent_card_change(&card, PermanentCardChange::IncreaseDamage(*amount));
+                        let improved_card = crate::cards::colorless::ritual_dagger::increase_ritual_dagger_damage(card, *amount);
+                        self.cards.replace_card_in_hand(*hand_index, improved_card);
+                    }
+                }
+            },
+            BaseEffect::ActivateMagnetism { source, cards_per_turn } => {
+                let magnetism_listener = crate::cards::colorless::magnetism::MagnetismListener::new(*source, *cards_per_turn);
+                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Magnetism(magnetism_listener));
+            },
+            BaseEffect::ActivateMayhem { source, cards_per_turn } => {
+                let mayhem_listener = crate::cards::colorless::mayhem::MayhemListener::new(*source, *cards_per_turn);
+                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Mayhem(mayhem_listener));
+            },
+            BaseEffect::ActivatePanache { source, damage } => {
+                let panache_listener = crate::cards::colorless::panache::PanacheListener::new(*source, *damage);
+                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::Panache(panache_listener));
+            },
+            BaseEffect::ActivateSadisticNature { source, damage } => {
+                let sadistic_nature_listener = crate::cards::colorless::sadistic_nature::SadisticNatureListener::new(*source, *damage);
+                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::SadisticNature(sadistic_nature_listener));
+            },
+            BaseEffect::ActivateTheBomb { source, damage, turns } => {
+                let bomb_listener = crate::cards::colorless::the_bomb::TheBombListener::new(*source, *damage, *turns);
+                self.add_listener(crate::battle::event_listener_enum::EventListenerEnum::TheBomb(bomb_listener));
+            },
+            BaseEffect::DealDamage { target, amount } => {
+                // Non-attack damage is not modified by Strength, Weak or Vulnerable
+                let target_alive = match target {
+                    Entity::Player => self.player.is_alive(),
+                    Entity::Enemy(idx) => self.enemies.get(*idx).is_some_and(|e| e.battle_info.is_alive()),
+                    Entity::None => false,
+                };
+                if target_alive {
+                    self.deal_damage(Entity::None, *target, *amount, DamageType::Thorns);
+                }
+            },
+            BaseEffect::DealDamageToAllEnemies { amount } => {
+                // Non-attack damage is not modified by Strength, Weak or Vulnerable
+                for enemy_idx in 0..self.enemies.len() {
+                    if self.enemies[enemy_idx].battle_info.is_alive() {
+                        self.deal_damage(Entity::None, Entity::Enemy(enemy_idx), *amount, DamageType::Thorns);
+                    }
+                }
+            },
+            BaseEffect::DealDamageToRandomEnemy { amount } => {
+                // Non-attack damage is not modified by Strength, Weak or Vulnerable
+                for target in self.select_targets(TargetSelector::RandomEnemy, Entity::Player, Entity::None) {
+                    self.deal_damage(Entity::None, target, *amount, DamageType::Thorns);
+                }
+            },
+            BaseEffect::EndTurn => {
+                // eval_action ends the turn once the card being played has resolved
+                self.end_turn_requested = true;
+            },
+            BaseEffect::EnterSelectCardInDrawPile { card_type } => {
+                // Only enter the selection state if there is something to choose
+                let has_matching_card = self.cards.get_deck().get_cards().iter()
+                    .any(|card| card.get_card_type() == *card_type);
+                if has_matching_card {
+                    self.battle_state = crate::battle::battle_state::BattleState::SelectCardInDrawPile {
+                        card_type: *card_type,
+                        selection: MultiSelect::exactly(1),
+                    };
+                }
+            },
+            BaseEffect::MoveRandomCardsFromDrawPileToHand { card_type, count } => {
+                for _ in 0..*count {
+                    let matching_indices: Vec<usize> = self.cards.get_deck().get_cards().iter()
+                        .enumerate()
+                        .filter(|(_, card)| card.get_card_type() == *card_type)
+                        .map(|(i, _)| i)
+                        .collect();
+
+                    let Some(&index) = matching_indices.choose(&mut self.rng) else {
+                        break;
+                    };
+                    if let Some(card) = self.cards.remove_card_from_deck(index) {
+                        self.add_card_to_hand(card);
+                    }
+                }
+            },
+            BaseEffect::AddRandomCardsToHand { source, num_choices, num_copies, cost } => {
+                // Present player with N random Ironclad cards of any type to choose from
+                if let Entity::Player = source {
+                    let ironclad_cards: Vec<CardEnum> = CardEnum::all_ironclad_cards().into_iter()
+                        .filter(|card_enum| card_enum.is_rewardable())
+                        .collect();
+                    self.enter_select_card_from_choices(&ironclad_cards, *num_choices, *num_copies, *cost);
+                }
+            },
+            BaseEffect::AddRandomColorlessCardsToHand { source, num_choices, num_copies, cost } => {
+                // Present player with N random Colorless cards to choose from
+                if let Entity::Player = source {
+                    let colorless_cards = CardEnum::all_colorless_cards();
+                    self.enter_select_card_from_choices(&colorless_cards, *num_choices, *num_copies, *cost);
+                }
+            },
+            BaseEffect::AddRandomColorlessCardToHand { count } => {
+                let colorless_cards = CardEnum::all_colorless_cards();
+                for _ in 0..*count {
+                    if let Some(card_enum) = colorless_cards.choose(&mut self.rng) {
+                        self.add_card_to_hand(card_enum.to_card());
+                    }
+                }
+            },
+            BaseEffect::ReduceHandCostToOne { this_combat } => {
+                self.cards.reduce_hand_costs_to(1, *this_combat);
+            },
+            BaseEffect::EnterSelectCardsInHandToPutOnBottom { count } => {
+                if *count > 0 && self.cards.hand_size() > 0 {
+                    self.battle_state = crate::battle::battle_state::BattleState::SelectCardInHand(CardInHandTo::PutOnBottomOfDeck(MultiSelect::up_to(*count)));
+                }
+            },
+            BaseEffect::EnterSelectCardsInHandToExhaust { count } => {
+                if *count > 0 && self.cards.hand_size() > 0 {
+                    self.battle_state = crate::battle::battle_state::BattleState::SelectCardInHand(CardInHandTo::Exhaust(MultiSelect::up_to(*count)));
+                }
+            },
+            BaseEffect::EnterSelectCardsInHandToDiscardAndDraw => {
+                let hand_size = self.cards.hand_size() as u32;
+                if hand_size > 0 {
+                    self.battle_state = crate::battle::battle_state::BattleState::SelectCardInHand(CardInHandTo::DiscardAndDraw {
+                        discarded: 0,
+                        selection: MultiSelect::up_to(hand_size),
+                    });
+                }
+            },
+            BaseEffect::MakeRandomCardInHandFree => {
+                // Prefer cards that do not already cost 0
+                let hand = self.cards.get_hand();
+                let costly_indices: Vec<usize> = (0..hand.len()).filter(|&i| hand[i].get_cost() > 0).collect();
+                let all_indices: Vec<usize> = (0..hand.len()).collect();
+                let candidates = if costly_indices.is_empty() { all_indices } else { costly_indices };
+
+                if let Some(&index) = candidates.choose(&mut self.rng) {
+                    self.cards.set_hand_card_combat_cost(index, 0);
+                }
+            },
+            BaseEffect::AttackToTargetWithDrawPileSize { source, target } => {
+                // Deal damage equal to the number of cards in the draw pile
+                let base_damage = self.cards.deck_size() as u32;
+                let incoming_damage = self.calculate_incoming_damage(*source, *target, base_damage);
+                self.deal_damage(*source, *target, incoming_damage, DamageType::Normal);
+            },
+            BaseEffect::GainIntangible { source, amount } => {
+                match source {
+                    Entity::Player => self.player.battle_info.apply_intangible(*amount),
+                    Entity::Enemy(idx) => {
+                        if *idx < self.enemies.len() {
+                            self.enemies[*idx].battle_info.apply_intangible(*amount);
+                        }
+                    },
+                    Entity::None => {} // No source
                 }
             },
 
@@ -1078,26 +1111,122 @@ impl Battle {
         }
     }
 
-    /// Apply damage to an entity (player or enemy)
-    pub(in crate::battle) fn apply_damage(&mut self, target: Entity, incoming_damage: u32) -> u32 {
-        let actual_damage = match target {
-            Entity::Player => self.player.battle_info.take_damage(incoming_damage),
-            Entity::Enemy(idx) => {
-                if idx < self.enemies.len() {
-                    self.enemies[idx].battle_info.take_damage(incoming_damage)
-                } else {
-                    0 // Invalid enemy index, no damage dealt
-                }
-            }
-            Entity::None => 0, // No target, no damage dealt
+    /// The entities a `TargetSelector` picks for an effect owned by `source` and aimed at `target`
+    pub(in crate::battle) fn select_targets(&mut self, selector: TargetSelector, source: Entity, target: Entity) -> Vec<Entity> {
+        let living_enemies = || -> Vec<Entity> {
+            (0..self.enemies.len())
+                .filter(|&idx| self.enemies[idx].battle_info.is_alive())
+                .map(Entity::Enemy)
+                .collect()
+        };
+        // Enemies of an enemy source are the player, and its allies are the enemies
+        let (opponents, allies) = match source {
+            Entity::Enemy(_) => (vec![Entity::Player], living_enemies()),
+            _ => (living_enemies(), vec![Entity::Player]),
+        };
+
+        match selector {
+            TargetSelector::ChosenTarget => vec![target],
+            TargetSelector::Source => vec![source],
+            TargetSelector::AllEnemies => opponents,
+            TargetSelector::RandomEnemy => opponents.choose(&mut self.rng).copied().into_iter().collect(),
+            TargetSelector::LowestHpEnemy => opponents.iter()
+                .copied()
+                .min_by_key(|&entity| match entity {
+                    Entity::Enemy(idx) => self.enemies[idx].battle_info.get_hp(),
+                    _ => self.player.battle_info.get_hp(),
+                })
+                .into_iter()
+                .collect(),
+            TargetSelector::AllAllies => allies,
+        }
+    }
+
+    /// Draw the top card of the draw pile and play it
+    /// If the requested target is not valid for the card, a random valid target is chosen instead
+    fn play_top_card(&mut self, target: Entity, pay_cost: bool) {
+        let Some(card) = self.cards.draw_top_card(&mut self.rng) else {
+            return;
+        };
+
+        let valid_targets = self.get_valid_targets_for_card(&card);
+        let target = if valid_targets.contains(&target) {
+            target
+        } else {
+            valid_targets.choose(&mut self.rng).copied().unwrap_or(Entity::None)
+        };
+
+        // Add the card to hand temporarily to play it
+        let hand_index = self.cards.hand_size();
+        self.cards.put_card_in_hand_to_play(card);
+
+        let result = if pay_cost {
+            self.play_card(hand_index, target)
+        } else {
+            self.play_card_for_free(hand_index, target)
+        };
+
+        // Cards that could not be played are discarded instead of staying in hand
+        if result.is_err() {
+            self.cards.discard_card_from_hand(hand_index);
+        }
+    }
+
+    /// Sample N distinct cards from the pool and transition to SelectCardFromChoices
+    fn enter_select_card_from_choices(&mut self, pool: &[CardEnum], num_choices: u32, num_copies: u32, cost: u32) {
+        let choices: Vec<CardEnum> = pool
+            .choose_multiple(&mut self.rng, num_choices as usize)
+            .cloned()
+            .collect();
+
+        self.battle_state = crate::battle::battle_state::BattleState::SelectCardFromChoices {
+            choices,
+            num_copies,
+            cost_override: Some(cost),
+        };
+    }
+
+    /// Check whether the target is an enemy that has been killed (not escaped)
+    fn is_killed_enemy(&self, target: Entity) -> bool {
+        match target {
+            Entity::Enemy(idx) => self.enemies.get(idx).is_some_and(|e| e.battle_info.get_hp() == 0),
+            _ => false,
+        }
+    }
+
+    /// Deal damage of a given type from `source` to `target`, returning the HP actually lost
+    /// Intangible and block come first, then Torii, Buffer and Tungsten Rod for the player
+    pub(in crate::battle) fn deal_damage(&mut self, source: Entity, target: Entity, incoming_damage: u32, damage_type: DamageType) -> u32 {
+        // Relics only belong to the player
+        let relics: &[Relic] = if target == Entity::Player { &self.relics } else { &[] };
+        let battle_info = match target {
+            Entity::Player => &mut self.player.battle_info,
+            Entity::Enemy(idx) if idx < self.enemies.len() => &mut self.enemies[idx].battle_info,
+            _ => return 0, // No valid target, no damage dealt
         };
 
+        // Thorns and Flame Barrier hit back whenever an attack lands, even if it was fully blocked
+        let retaliation = (battle_info.get_power_amount(PowerId::Thorns) + battle_info.get_power_amount(PowerId::FlameBarrier)).max(0) as u32;
+
+        let unblocked_damage = battle_info.absorb_damage(incoming_damage, damage_type);
+        let unblocked_damage = torii::reduce_unblocked_damage(unblocked_damage, damage_type, relics);
+        // Buffer negates the loss before Tungsten Rod gets to reduce it
+        let hp_loss = if battle_info.has_power(PowerId::Buffer) {
+            unblocked_damage
+        } else {
+            tungsten_rod::reduce_hp_loss(unblocked_damage, relics)
+        };
+        let actual_damage = battle_info.lose_hp(hp_loss);
+
+        if retaliation > 0 && damage_type == DamageType::Normal && source != target && source != Entity::None {
+            self.queue_effects_front(vec![BaseEffect::DealDamage { target: source, amount: retaliation }]);
+        }
+
         // Emit damage taken event if actual damage was dealt
         if actual_damage > 0 {
-            let damage_event = BattleEvent::DamageTaken {
-                target,
-                amount: actual_damage,
-                source: Entity::None, // TODO: Track damage source
+            let damage_event = match damage_type {
+                DamageType::HpLoss => BattleEvent::HpLostFromCard { target, amount: actual_damage },
+                _ => BattleEvent::DamageTaken { target, amount: actual_damage, source },
             };
             self.emit_event(damage_event);
         }
@@ -1128,6 +1257,7 @@ impl Battle {
                         enemy: target,
                     };
                     self.emit_event(death_event);
+                    self.dismiss_minions_of(idx);
                 }
             }
         }
@@ -1137,14 +1267,18 @@ impl Battle {
 
     /// Apply block to an entity (player or enemy) 
     pub(in crate::battle) fn apply_block(&mut self, target: Entity, amount: u32) {
-        match target {
-            Entity::Player => self.player.battle_info.gain_block(amount),
-            Entity::Enemy(idx) => {
-                if idx < self.enemies.len() {
-                    self.enemies[idx].battle_info.gain_block(amount);
-                }
-            }
-            Entity::None => {} // No target, no block gained
+        let battle_info = match target {
+            Entity::Player => &mut self.player.battle_info,
+            Entity::Enemy(idx) if idx < self.enemies.len() => &mut self.enemies[idx].battle_info,
+            _ => return, // No target, no block gained
+        };
+        let block_before = battle_info.get_block();
+        battle_info.gain_block(amount);
+        let gained = battle_info.get_block() - block_before;
+
+        // Block gain triggers (Juggernaut) wait for everything already queued
+        if gained > 0 {
+            self.emit_event(BattleEvent::BlockGained { source: target, amount: gained });
         }
     }
 }
@@ -1207,7 +1341,7 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng);
         
         // Draw hand
-        battle.cards.draw_n(5);
+        battle.cards.draw_n(5, &mut rand::rng());
         
         let initial_enemy1_hp = battle.enemies[0].battle_info.get_hp();
         let initial_enemy2_hp = battle.enemies[1].battle_info.get_hp();
@@ -1245,10 +1379,11 @@ mod tests {
         let initial_enemy1_hp = battle.enemies[0].battle_info.get_hp();
         let initial_enemy2_hp = battle.enemies[1].battle_info.get_hp();
         
-        let attack_all_effect = BaseEffect::AttackAllEnemies {
+        let attack_all_effect = BaseEffect::Targeted {
+            selector: TargetSelector::AllEnemies,
+            effect: Box::new(crate::game::effect::BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }),
             source: Entity::Player,
-            amount: 8,
-            num_attacks: 1,
+            target: Entity::None,
         };
         
         battle.eval_base_effect(&attack_all_effect);
@@ -1408,6 +1543,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_status_card_placements() {
+        let deck = starter_deck();
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        let initial_deck_size = battle.cards.deck_size();
+        let initial_discard_size = battle.cards.discard_pile_size();
+
+        battle.eval_base_effect(&BaseEffect::AddStatusCard {
+            status_card: CardEnum::Dazed,
+            count: 2,
+            placement: StatusPlacement::ShuffleIntoDrawPile,
+            upgraded: false,
+        });
+        assert_eq!(battle.cards.deck_size(), initial_deck_size + 2);
+
+        battle.eval_base_effect(&BaseEffect::AddStatusCard {
+            status_card: CardEnum::Void,
+            count: 1,
+            placement: StatusPlacement::TopOfDrawPile,
+            upgraded: false,
+        });
+        assert_eq!(battle.cards.deck_size(), initial_deck_size + 3);
+        assert_eq!(battle.cards.get_deck().get_cards()[0].get_name(), "Void");
+
+        battle.eval_base_effect(&BaseEffect::AddStatusCard {
+            status_card: CardEnum::Burn,
+            count: 1,
+            placement: StatusPlacement::Discard,
+            upgraded: true,
+        });
+        assert_eq!(battle.cards.discard_pile_size(), initial_discard_size + 1);
+        assert_eq!(battle.cards.get_discard_pile().last().unwrap().get_name(), "Burn+");
+    }
+
+    #[test]
+    fn test_upgrade_status_cards_effect() {
+        let deck = starter_deck();
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        for placement in [StatusPlacement::ShuffleIntoDrawPile, StatusPlacement::Discard] {
+            battle.eval_base_effect(&BaseEffect::AddStatusCard {
+                status_card: CardEnum::Burn,
+                count: 1,
+                placement,
+                upgraded: false,
+            });
+        }
+        battle.eval_base_effect(&BaseEffect::UpgradeStatusCards { status_card: CardEnum::Burn });
+
+        let burns: Vec<&crate::game::card::Card> = battle.cards.get_deck().get_cards().iter()
+            .chain(battle.cards.get_discard_pile().iter())
+            .filter(|c| c.get_card_enum() == CardEnum::Burn)
+            .collect();
+        assert_eq!(burns.len(), 2);
+        assert!(burns.iter().all(|c| c.is_upgraded()));
+
+        // Strikes and Defends are untouched
+        assert!(battle.get_hand().iter().all(|c| !c.is_upgraded()));
+    }
+
     #[test]
     fn test_artifact_blocks_vulnerable() {
         let deck = starter_deck();
@@ -1630,55 +1834,190 @@ mod tests {
         battle.eval_base_effect(&artifact_effect);
         assert_eq!(battle.player.battle_info.get_artifact(), 6);
     }
+
+    #[test]
+    fn test_list_powers_covers_player_and_enemies() {
+        let deck = starter_deck();
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+        let enemy_powers_before = battle.enemies[0].battle_info.get_powers().len();
+
+        battle.eval_base_effect(&BaseEffect::ActivateMetallicize { source: Entity::Player, amount: 3 });
+        battle.eval_base_effect(&BaseEffect::ApplyVulnerable { target: Entity::Enemy(0), duration: 2 });
+
+        let powers = battle.list_powers();
+        assert_eq!(powers[0], (Entity::Player, Power::new(PowerId::Metallicize, 3)));
+        assert!(powers.contains(&(Entity::Enemy(0), Power::new(PowerId::Vulnerable, 2))));
+        assert_eq!(powers.len(), 2 + enemy_powers_before);
+
+        // Metallicize fires from the power hook at end of turn
+        battle.emit_event(BattleEvent::EndOfTurn { entity: Entity::Player });
+        assert_eq!(battle.player.battle_info.get_block(), 3);
+    }
+
+    fn damage_type_battle(relics: Vec<crate::relics::Relic>) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        // Jaw Worm has no on-damage reactions that would absorb Thorns
+        let jaw_worm = crate::enemies::jaw_worm::JawWorm::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(jaw_worm))];
+        let player_state = PlayerRunState::new_with_relics(50, 80, 0, relics);
+        Battle::new(crate::game::deck::Deck::new(vec![]), global_info, player_state, enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_lose_hp_ignores_block() {
+        let mut battle = damage_type_battle(vec![]);
+        battle.player.battle_info.gain_block(10);
+
+        battle.eval_base_effect(&BaseEffect::LoseHp { target: Entity::Player, amount: 3 });
+
+        assert_eq!(battle.player.battle_info.get_hp(), 47);
+        assert_eq!(battle.player.battle_info.get_block(), 10);
+    }
+
+    #[test]
+    fn test_thorns_hits_attacker_but_not_non_attack_damage() {
+        let mut battle = damage_type_battle(vec![]);
+        battle.eval_base_effect(&BaseEffect::GainThorns { source: Entity::Player, amount: 3 });
+        battle.player.battle_info.gain_block(20);
+        let enemy_hp = battle.enemies[0].battle_info.get_hp();
+
+        // Two blocked hits still trigger Thorns twice
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 5, num_attacks: 2, strength_multiplier: 1,
+        });
+        battle.process_effect_queue();
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), enemy_hp - 6);
+
+        // Non-attack damage does not
+        let enemy_hp = battle.enemies[0].battle_info.get_hp();
+        battle.eval_base_effect(&BaseEffect::DealDamage { target: Entity::Player, amount: 2 });
+        battle.process_effect_queue();
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), enemy_hp);
+    }
+
+    #[test]
+    fn test_torii_reduces_small_unblocked_attacks() {
+        let mut battle = damage_type_battle(vec![crate::relics::Relic::Torii]);
+
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 4, num_attacks: 3, strength_multiplier: 0,
+        });
+        assert_eq!(battle.player.battle_info.get_hp(), 47);
+
+        // HP loss is not an attack
+        battle.eval_base_effect(&BaseEffect::LoseHp { target: Entity::Player, amount: 4 });
+        assert_eq!(battle.player.battle_info.get_hp(), 43);
+    }
+
+    #[test]
+    fn test_tungsten_rod_reduces_every_hp_loss() {
+        let mut battle = damage_type_battle(vec![crate::relics::Relic::TungstenRod]);
+
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 10, num_attacks: 1, strength_multiplier: 0,
+        });
+        assert_eq!(battle.player.battle_info.get_hp(), 41);
+
+        battle.eval_base_effect(&BaseEffect::LoseHp { target: Entity::Player, amount: 1 });
+        assert_eq!(battle.player.battle_info.get_hp(), 41);
+    }
+
+    fn two_jaw_worm_battle() -> Battle {
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::game::deck::Deck;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(40, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(30, false))),
+        ];
+        Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_select_targets_by_side() {
+        let mut battle = two_jaw_worm_battle();
+        let player = Entity::Player;
+
+        assert_eq!(battle.select_targets(TargetSelector::AllEnemies, player, Entity::None), vec![Entity::Enemy(0), Entity::Enemy(1)]);
+        assert_eq!(battle.select_targets(TargetSelector::LowestHpEnemy, player, Entity::None), vec![Entity::Enemy(1)]);
+        assert_eq!(battle.select_targets(TargetSelector::Source, player, Entity::Enemy(0)), vec![Entity::Player]);
+        assert_eq!(battle.select_targets(TargetSelector::ChosenTarget, player, Entity::Enemy(0)), vec![Entity::Enemy(0)]);
+        // An enemy's enemy is the player and its allies are the living enemies
+        assert_eq!(battle.select_targets(TargetSelector::AllEnemies, Entity::Enemy(1), Entity::Player), vec![Entity::Player]);
+        assert_eq!(battle.select_targets(TargetSelector::AllAllies, Entity::Enemy(1), Entity::Player), vec![Entity::Enemy(0), Entity::Enemy(1)]);
+
+        battle.enemies[1].battle_info.take_damage(30);
+        assert_eq!(battle.select_targets(TargetSelector::RandomEnemy, player, Entity::None), vec![Entity::Enemy(0)]);
+        assert_eq!(battle.select_targets(TargetSelector::LowestHpEnemy, player, Entity::None), vec![Entity::Enemy(0)]);
+    }
+
+    #[test]
+    fn test_targeted_effect_applies_to_each_selected_entity() {
+        use crate::game::effect::BattleEffect;
+
+        let mut battle = two_jaw_worm_battle();
+        let weaken_all = BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies);
+        battle.eval_base_effect(&BaseEffect::from_effect(weaken_all, Entity::Player, Entity::None));
+        assert!(battle.enemies.iter().all(|enemy| enemy.battle_info.is_weak()));
+
+        let hit_weakest = BattleEffect::AttackToTarget { amount: 5, num_attacks: 1, strength_multiplier: 1 }
+            .targeting(TargetSelector::LowestHpEnemy);
+        battle.eval_base_effect(&BaseEffect::from_effect(hit_weakest, Entity::Player, Entity::Enemy(0)));
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), 40);
+        assert_eq!(battle.enemies[1].battle_info.get_hp(), 25);
+    }
+
+    #[test]
+    fn test_valid_targets_follow_effect_selectors() {
+        use crate::cards::ironclad::{cleave::cleave, defend::defend, iron_wave::iron_wave};
+
+        let battle = two_jaw_worm_battle();
+        // Iron Wave attacks a chosen enemy; its Block goes to the player without a separate target
+        assert_eq!(battle.get_valid_targets_for_card(&iron_wave()), vec![Entity::Enemy(0), Entity::Enemy(1)]);
+        assert_eq!(battle.get_valid_targets_for_card(&cleave()), vec![Entity::None]);
+        assert_eq!(battle.get_valid_targets_for_card(&defend()), vec![Entity::Player]);
+    }
 }
 
 impl Battle {
-    /// Queue an effect to be processed later
+    /// Queue an effect to be processed after everything already queued
     pub(crate) fn queue_effect(&mut self, effect: BaseEffect) {
-        self.effect_queue.push(effect);
+        self.effect_queue.push_back(effect);
+    }
+
+    /// Queue effects to be processed before everything already queued, keeping their order
+    pub(crate) fn queue_effects_front(&mut self, effects: Vec<BaseEffect>) {
+        for effect in effects.into_iter().rev() {
+            self.effect_queue.push_front(effect);
+        }
     }
 
     /// Spawn medium slimes with specified HP
     fn spawn_medium_slimes_with_hp(&mut self, slime_type: &str, count: u32, hp: u32) {
-        // Create new medium slimes with the specified HP
-
         for _ in 0..count {
-            match slime_type {
-                "acid" => {
-                    let acid_slime_m = crate::enemies::acid_slime_m::AcidSlimeM::new(hp);
-                    let enemy_enum = crate::enemies::enemy_enum::EnemyEnum::AcidSlimeM(acid_slime_m);
-                    let enemy_in_battle = crate::battle::enemy_in_battle::EnemyInBattle::new(enemy_enum);
-                    self.enemies.push(enemy_in_battle);
-                },
-                "spike" => {
-                    let spike_slime_m = crate::enemies::spike_slime_m::SpikeSlimeM::new(hp);
-                    let enemy_enum = crate::enemies::enemy_enum::EnemyEnum::SpikeSlimeM(spike_slime_m);
-                    let enemy_in_battle = crate::battle::enemy_in_battle::EnemyInBattle::new(enemy_enum);
-                    self.enemies.push(enemy_in_battle);
-                },
+            let enemy_enum = match slime_type {
+                "acid" => crate::enemies::enemy_enum::EnemyEnum::AcidSlimeM(crate::enemies::acid_slime_m::AcidSlimeM::new(hp)),
+                "spike" => crate::enemies::enemy_enum::EnemyEnum::SpikeSlimeM(crate::enemies::spike_slime_m::SpikeSlimeM::new(hp)),
                 _ => {
                     eprintln!("Warning: Cannot spawn non-medium slime type: {}", slime_type);
+                    return;
                 }
-            }
-
-            // Keep enemy_actions aligned with the enemies list so intent sampling won't panic
-            self.enemy_actions.push(None);
+            };
+            self.spawn_enemy(crate::battle::enemy_in_battle::EnemyInBattle::new(enemy_enum), None);
         }
-
-        // Emit enemy spawn event to notify UI systems
-        let spawn_event = BattleEvent::EnemySpawned {
-            new_enemy_count: self.enemies.len(),
-        };
-        self.emit_event(spawn_event);
     }
 
     /// Process all effects in the effect queue
     pub(crate) fn process_effect_queue(&mut self) {
-        while !self.effect_queue.is_empty() {
-            // Take the first effect from the queue
-            let effect = self.effect_queue.remove(0);
-
-            // Process it (this might add more effects to the queue)
+        // Process each effect in turn (this might add more effects to the queue)
+        while let Some(effect) = self.effect_queue.pop_front() {
             self.eval_base_effect(&effect);
         }
     }
@@ -1,5 +1,6 @@
 use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
 use crate::game::effect::BattleEffect;
+use crate::relics::Relic;
 use std::hash::{Hash, Hasher};
 use serde::{Serialize, Deserialize};
 
@@ -21,31 +22,22 @@ use crate::relics::{
     lantern::LanternRelic,
     letter_opener::LetterOpenerRelic,
     mercury_hourglass::MercuryHourglassRelic,
-    nunchaku::NunchakuRelic,
     oddly_smooth_stone::OddlySmoothStoneRelic,
     orichalcum::OrichalcumRelic,
     ornamental_fan::OrnamentalFanRelic,
-    pen_nib::PenNibRelic,
     red_mask::RedMaskRelic,
     shuriken::ShurikenRelic,
     the_boot::TheBootRelic,
     vajra::VajraRelic,
 };
 
-// Power card imports
-use crate::cards::ironclad::{
-    brutality::BrutalityListener,
-    combust::CombustListener,
-    demon_form::DemonFormListener,
-    double_tap::DoubleTapListener,
-    embrace::EmbraceListener,
-    feel_no_pain::FeelNoPainListener,
-    fire_breathing::FireBreathingListener,
-    flame_barrier::FlameBarrierListener,
-    flex::LoseStrengthListener,
-    metallicize::MetallicizeListener,
-    rage::RageListener,
-    rupture::RuptureListener,
+// Colorless card imports
+use crate::cards::colorless::{
+    magnetism::MagnetismListener,
+    mayhem::MayhemListener,
+    panache::PanacheListener,
+    sadistic_nature::SadisticNatureListener,
+    the_bomb::TheBombListener,
 };
 
 // Enemy imports
@@ -68,7 +60,7 @@ use crate::battle::listeners::regen::RegenListener;
 /// This allows Clone and Hash to be derived automatically
 #[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EventListenerEnum {
-    // Relics (25)
+    // Relics (23)
     Akabeko(AkabekoRelic),
     Anchor(AnchorRelic),
     ArtOfWar(ArtOfWarRelic),
@@ -85,29 +77,20 @@ pub enum EventListenerEnum {
     Lantern(LanternRelic),
     LetterOpener(LetterOpenerRelic),
     MercuryHourglass(MercuryHourglassRelic),
-    Nunchaku(NunchakuRelic),
     OddlySmoothStone(OddlySmoothStoneRelic),
     Orichalcum(OrichalcumRelic),
     OrnamentalFan(OrnamentalFanRelic),
-    PenNib(PenNibRelic),
     RedMask(RedMaskRelic),
     Shuriken(ShurikenRelic),
     TheBoot(TheBootRelic),
     Vajra(VajraRelic),
 
-    // Powers (12)
-    Brutality(BrutalityListener),
-    Combust(CombustListener),
-    DemonForm(DemonFormListener),
-    DoubleTap(DoubleTapListener),
-    Embrace(EmbraceListener),
-    FeelNoPain(FeelNoPainListener),
-    FireBreathing(FireBreathingListener),
-    FlameBarrier(FlameBarrierListener),
-    LoseStrength(LoseStrengthListener),
-    Metallicize(MetallicizeListener),
-    Rage(RageListener),
-    Rupture(RuptureListener),
+    // Colorless cards (5)
+    Magnetism(MagnetismListener),
+    Mayhem(MayhemListener),
+    Panache(PanacheListener),
+    SadisticNature(SadisticNatureListener),
+    TheBomb(TheBombListener),
 
     // Enemy abilities (9)
     AcidSlimeLSplit(AcidSlimeLSplitListener),
@@ -124,6 +107,21 @@ pub enum EventListenerEnum {
     Regen(RegenListener),
 }
 
+impl EventListenerEnum {
+    /// The relic this listener belongs to and the counter shown on it, for relics that show one
+    pub fn relic_counter(&self) -> Option<(Relic, u32)> {
+        match self {
+            EventListenerEnum::HappyFlower(l) => Some((Relic::HappyFlower, l.get_counter())),
+            EventListenerEnum::InkBottle(l) => Some((Relic::InkBottle, l.get_counter())),
+            EventListenerEnum::Kunai(l) => Some((Relic::Kunai, l.get_counter())),
+            EventListenerEnum::LetterOpener(l) => Some((Relic::LetterOpener, l.get_counter())),
+            EventListenerEnum::OrnamentalFan(l) => Some((Relic::OrnamentalFan, l.get_counter())),
+            EventListenerEnum::Shuriken(l) => Some((Relic::Shuriken, l.get_counter())),
+            _ => None,
+        }
+    }
+}
+
 impl EventListener for EventListenerEnum {
     fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
         match self {
@@ -144,29 +142,20 @@ impl EventListener for EventListenerEnum {
             EventListenerEnum::Lantern(l) => l.on_event(event),
             EventListenerEnum::LetterOpener(l) => l.on_event(event),
             EventListenerEnum::MercuryHourglass(l) => l.on_event(event),
-            EventListenerEnum::Nunchaku(l) => l.on_event(event),
             EventListenerEnum::OddlySmoothStone(l) => l.on_event(event),
             EventListenerEnum::Orichalcum(l) => l.on_event(event),
             EventListenerEnum::OrnamentalFan(l) => l.on_event(event),
-            EventListenerEnum::PenNib(l) => l.on_event(event),
             EventListenerEnum::RedMask(l) => l.on_event(event),
             EventListenerEnum::Shuriken(l) => l.on_event(event),
             EventListenerEnum::TheBoot(l) => l.on_event(event),
             EventListenerEnum::Vajra(l) => l.on_event(event),
 
-            // Powers
-            EventListenerEnum::Brutality(l) => l.on_event(event),
-            EventListenerEnum::Combust(l) => l.on_event(event),
-            EventListenerEnum::DemonForm(l) => l.on_event(event),
-            EventListenerEnum::DoubleTap(l) => l.on_event(event),
-            EventListenerEnum::Embrace(l) => l.on_event(event),
-            EventListenerEnum::FeelNoPain(l) => l.on_event(event),
-            EventListenerEnum::FireBreathing(l) => l.on_event(event),
-            EventListenerEnum::FlameBarrier(l) => l.on_event(event),
-            EventListenerEnum::LoseStrength(l) => l.on_event(event),
-            EventListenerEnum::Metallicize(l) => l.on_event(event),
-            EventListenerEnum::Rage(l) => l.on_event(event),
-            EventListenerEnum::Rupture(l) => l.on_event(event),
+            // Colorless cards
+            EventListenerEnum::Magnetism(l) => l.on_event(event),
+            EventListenerEnum::Mayhem(l) => l.on_event(event),
+            EventListenerEnum::Panache(l) => l.on_event(event),
+            EventListenerEnum::SadisticNature(l) => l.on_event(event),
+            EventListenerEnum::TheBomb(l) => l.on_event(event),
 
             // Enemy abilities
             EventListenerEnum::AcidSlimeLSplit(l) => l.on_event(event),
@@ -203,29 +192,20 @@ impl EventListener for EventListenerEnum {
             EventListenerEnum::Lantern(l) => l.is_active(),
             EventListenerEnum::LetterOpener(l) => l.is_active(),
             EventListenerEnum::MercuryHourglass(l) => l.is_active(),
-            EventListenerEnum::Nunchaku(l) => l.is_active(),
             EventListenerEnum::OddlySmoothStone(l) => l.is_active(),
             EventListenerEnum::Orichalcum(l) => l.is_active(),
             EventListenerEnum::OrnamentalFan(l) => l.is_active(),
-            EventListenerEnum::PenNib(l) => l.is_active(),
             EventListenerEnum::RedMask(l) => l.is_active(),
             EventListenerEnum::Shuriken(l) => l.is_active(),
             EventListenerEnum::TheBoot(l) => l.is_active(),
             EventListenerEnum::Vajra(l) => l.is_active(),
 
-            // Powers
-            EventListenerEnum::Brutality(l) => l.is_active(),
-            EventListenerEnum::Combust(l) => l.is_active(),
-            EventListenerEnum::DemonForm(l) => l.is_active(),
-            EventListenerEnum::DoubleTap(l) => l.is_active(),
-            EventListenerEnum::Embrace(l) => l.is_active(),
-            EventListenerEnum::FeelNoPain(l) => l.is_active(),
-            EventListenerEnum::FireBreathing(l) => l.is_active(),
-            EventListenerEnum::FlameBarrier(l) => l.is_active(),
-            EventListenerEnum::LoseStrength(l) => l.is_active(),
-            EventListenerEnum::Metallicize(l) => l.is_active(),
-            EventListenerEnum::Rage(l) => l.is_active(),
-            EventListenerEnum::Rupture(l) => l.is_active(),
+            // Colorless cards
+            EventListenerEnum::Magnetism(l) => l.is_active(),
+            EventListenerEnum::Mayhem(l) => l.is_active(),
+            EventListenerEnum::Panache(l) => l.is_active(),
+            EventListenerEnum::SadisticNature(l) => l.is_active(),
+            EventListenerEnum::TheBomb(l) => l.is_active(),
 
             // Enemy abilities
             EventListenerEnum::AcidSlimeLSplit(l) => l.is_active(),
@@ -262,29 +242,20 @@ impl EventListener for EventListenerEnum {
             EventListenerEnum::Lantern(l) => l.get_owner(),
             EventListenerEnum::LetterOpener(l) => l.get_owner(),
             EventListenerEnum::MercuryHourglass(l) => l.get_owner(),
-            EventListenerEnum::Nunchaku(l) => l.get_owner(),
             EventListenerEnum::OddlySmoothStone(l) => l.get_owner(),
             EventListenerEnum::Orichalcum(l) => l.get_owner(),
             EventListenerEnum::OrnamentalFan(l) => l.get_owner(),
-            EventListenerEnum::PenNib(l) => l.get_owner(),
             EventListenerEnum::RedMask(l) => l.get_owner(),
             EventListenerEnum::Shuriken(l) => l.get_owner(),
             EventListenerEnum::TheBoot(l) => l.get_owner(),
             EventListenerEnum::Vajra(l) => l.get_owner(),
 
-            // Powers
-            EventListenerEnum::Brutality(l) => l.get_owner(),
-            EventListenerEnum::Combust(l) => l.get_owner(),
-            EventListenerEnum::DemonForm(l) => l.get_owner(),
-            EventListenerEnum::DoubleTap(l) => l.get_owner(),
-            EventListenerEnum::Embrace(l) => l.get_owner(),
-            EventListenerEnum::FeelNoPain(l) => l.get_owner(),
-            EventListenerEnum::FireBreathing(l) => l.get_owner(),
-            EventListenerEnum::FlameBarrier(l) => l.get_owner(),
-            EventListenerEnum::LoseStrength(l) => l.get_owner(),
-            EventListenerEnum::Metallicize(l) => l.get_owner(),
-            EventListenerEnum::Rage(l) => l.get_owner(),
-            EventListenerEnum::Rupture(l) => l.get_owner(),
+            // Colorless cards
+            EventListenerEnum::Magnetism(l) => l.get_owner(),
+            EventListenerEnum::Mayhem(l) => l.get_owner(),
+            EventListenerEnum::Panache(l) => l.get_owner(),
+            EventListenerEnum::SadisticNature(l) => l.get_owner(),
+            EventListenerEnum::TheBomb(l) => l.get_owner(),
 
             // Enemy abilities
             EventListenerEnum::AcidSlimeLSplit(l) => l.get_owner(),
@@ -0,0 +1,154 @@
+use super::Battle;
+use super::battle_events::EventListener;
+use super::event_listener_enum::EventListenerEnum;
+use crate::enemies::red_louse::CurlUpListener;
+use crate::relics::runic_dome;
+
+impl Battle {
+    /// A battle the player cannot tell apart from this one, with the hidden information re-sampled:
+    /// the draw pile is shuffled, the Curl Up blocks not revealed yet are rolled again and enemies without
+    /// a shown intent roll one. The enemies keep the move history the player saw, under Runic Dome
+    /// their current intent is picked again from the AI state it was picked from
+    pub fn determinize(&self, rng: &mut impl rand::Rng) -> Battle {
+        let mut battle = self.clone();
+        battle.cards.shuffle_draw_pile(rng);
+
+        if battle.enemy_actions.len() < battle.enemies.len() {
+            battle.enemy_actions.resize(battle.enemies.len(), None);
+        }
+        let global_info = battle.global_info;
+        let intents_hidden = runic_dome::hides_intents(&battle.relics);
+        for (enemy, action) in battle.enemies.iter_mut().zip(battle.enemy_actions.iter_mut()) {
+            if !enemy.battle_info.is_alive() {
+                continue;
+            }
+            if intents_hidden && action.is_some()
+                && let Some(intent) = enemy.redraw_intent(&global_info, rng) {
+                *action = Some(intent);
+            }
+            if action.is_none() {
+                *action = Some(enemy.sample_intent(&global_info, rng));
+            }
+        }
+
+        for listener in &mut battle.event_listeners {
+            if let EventListenerEnum::CurlUp(curl_up) = listener
+                && curl_up.is_active() {
+                *curl_up = CurlUpListener::new(curl_up.get_owner(), global_info.ascention, rng);
+            }
+        }
+        battle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::battle::Battle;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::starter_deck::starter_deck;
+    use crate::enemies::{enemy_enum::{EnemyEnum, EnemyMove}, jaw_worm::{JawWorm, JawWormMove}, red_louse::RedLouse};
+    use crate::relics::Relic;
+    use std::collections::HashSet;
+
+    fn battle() -> Battle {
+        BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build()
+    }
+
+    #[test]
+    fn test_determinize_keeps_what_the_player_sees() {
+        let battle = battle();
+        let mut rng = rand::rng();
+
+        for _ in 0..10 {
+            let determinized = battle.determinize(&mut rng);
+            assert_eq!(determinized.player_observation(), battle.player_observation());
+            assert_eq!(determinized.get_hand(), battle.get_hand());
+            assert_eq!(determinized.get_enemy_move(0), battle.get_enemy_move(0));
+        }
+    }
+
+    #[test]
+    fn test_determinize_shuffles_the_draw_pile() {
+        let battle = battle();
+        let mut rng = rand::rng();
+
+        // The 4 cards left in the draw pile have 24 orders, 20 draws all matching is next to impossible
+        let reshuffled = (0..20).any(|_| battle.determinize(&mut rng) != battle);
+        assert!(reshuffled);
+    }
+
+    #[test]
+    fn test_curl_up_block_is_not_observed() {
+        let louse_battle = || BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::RedLouse(RedLouse::new(6, 12)))
+            .build();
+        let battle = louse_battle();
+        let mut rng = rand::rng();
+
+        // Another Curl Up block behind the same louse
+        let mut other = battle.clone();
+        while other.event_listeners == battle.event_listeners {
+            other.event_listeners = louse_battle().event_listeners;
+        }
+        assert!(other != battle);
+        assert_eq!(other.player_observation(), battle.player_observation());
+
+        // Determinizations draw the Curl Up block again, 20 draws all matching is next to impossible
+        let rerolled = (0..20).any(|_| battle.determinize(&mut rng).event_listeners != battle.event_listeners);
+        assert!(rerolled);
+    }
+
+    /// A battle against a Jaw Worm that has Thrashed twice in a row, so it cannot Thrash next
+    fn battle_after_two_thrashes(relics: Vec<Relic>) -> Battle {
+        let mut jaw_worm = JawWorm::new(40, false);
+        jaw_worm.record_move(JawWormMove::Thrash);
+        jaw_worm.record_move(JawWormMove::Thrash);
+        let mut builder = BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(jaw_worm));
+        for relic in relics {
+            builder = builder.add_relic(relic);
+        }
+        let mut battle = builder.build();
+        battle.enemies[0].record_move_made(EnemyMove::JawWorm(JawWormMove::Thrash));
+        battle.enemies[0].record_move_made(EnemyMove::JawWorm(JawWormMove::Thrash));
+        battle
+    }
+
+    #[test]
+    fn test_determinize_keeps_the_observed_move_history() {
+        let battle = battle_after_two_thrashes(vec![]);
+        let mut rng = rand::rng();
+
+        for _ in 0..10 {
+            let determinized = battle.determinize(&mut rng);
+            assert!(determinized.enemies == battle.enemies);
+            assert_eq!(determinized.get_enemy_move(0), battle.get_enemy_move(0));
+        }
+    }
+
+    #[test]
+    fn test_hidden_intent_is_redrawn_from_the_observed_move_history() {
+        let battle = battle_after_two_thrashes(vec![Relic::RunicDome]);
+        let mut rng = rand::rng();
+
+        let intents: HashSet<EnemyMove> = (0..20)
+            .map(|_| battle.determinize(&mut rng).get_enemy_move(0).cloned().unwrap())
+            .collect();
+        // Chomp or Bellow each time, 20 draws all alike is next to impossible
+        assert!(!intents.contains(&EnemyMove::JawWorm(JawWormMove::Thrash)));
+        assert_eq!(intents.len(), 2);
+    }
+
+    #[test]
+    fn test_move_history_is_observed() {
+        let battle = battle();
+        let mut other = battle.clone();
+        other.enemies[0].record_move_made(EnemyMove::JawWorm(JawWormMove::Thrash));
+        assert_ne!(other.player_observation(), battle.player_observation());
+    }
+}
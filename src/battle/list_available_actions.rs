@@ -1,24 +1,35 @@
-use crate::battle::{battle_action::BattleAction, Battle};
+use crate::battle::{battle_action::BattleAction, battle_state::{BattleState, CardInHandTo}, Battle};
 use crate::battle::target::Entity;
-use crate::game::{card::Card, card_type::CardType, effect::BattleEffect};
+use crate::game::{card::Card, card_type::CardType, effect::TargetSelector};
 
 impl Battle {
     pub fn list_available_actions(&self) -> Vec<BattleAction> {
-        let mut available_actions = Vec::new();
-
         // Battle is over - no actions available
         if self.is_battle_over() {
-            return available_actions;
+            return Vec::new();
+        }
+
+        match &self.battle_state {
+            BattleState::PlayerTurn => self.list_player_turn_actions(),
+            _ => self.list_selection_actions(),
         }
+    }
+
+    /// Cards to play, potions to use and ending the turn
+    fn list_player_turn_actions(&self) -> Vec<BattleAction> {
+        let mut available_actions = Vec::new();
 
-        // Check each card in hand
+        // Check each card in hand (none can be played once the turn's card limit is reached)
+        let limit_reached = self.card_play_limit_reached();
         let hand = self.cards.get_hand();
         for (card_index, card) in hand.iter().enumerate() {
             // Check if card is playable, player has enough energy, and card is not an Attack while Entangled
+            // X-cost cards can always be played, even with 0 energy
+            let can_afford = card.is_x_cost() || self.player.get_energy() >= card.get_cost();
             let is_attack_while_entangled = self.player.battle_info.is_entangled()
                 && card.get_card_type() == CardType::Attack;
 
-            if card.is_playable() && self.player.get_energy() >= card.get_cost() && !is_attack_while_entangled {
+            if !limit_reached && card.is_playable() && can_afford && !is_attack_while_entangled {
                 // Determine valid targets for this card based on its type and effects
                 let valid_targets = self.get_valid_targets_for_card(card);
 
@@ -53,63 +64,66 @@ impl Battle {
         available_actions
     }
 
-    /// Get valid targets for a specific card based on its effects
-    pub(in crate::battle) fn get_valid_targets_for_card(&self, card: &Card) -> Vec<Entity> {
-        let mut valid_targets = Vec::new();
-
-        // Check if any effect attacks all enemies (doesn't need specific targeting)
-        let attacks_all_enemies = card.get_effects().iter().any(|effect| {
-            matches!(effect, BattleEffect::AttackAllEnemies { .. })
-        });
-
-        // Check if any effect targets specific enemies
-        let targets_specific_enemies = card.get_effects().iter().any(|effect| {
-            matches!(effect,
-                BattleEffect::AttackToTarget { .. } |
-                BattleEffect::ApplyVulnerable { .. } |
-                BattleEffect::ApplyWeak { .. }
-            )
-        });
-
-        // Check if any effect targets self/player
-        let targets_self = card.get_effects().iter().any(|effect| {
-            matches!(effect,
-                BattleEffect::GainDefense { amount: _ } |
-                BattleEffect::GainStrength { amount: _ }
-            )
-        });
-
-        // AttackAllEnemies cards use Entity::None as target (no specific targeting needed)
-        if attacks_all_enemies {
-            valid_targets.push(Entity::None);
-        }
-
-        // Add valid enemy targets for specific targeting
-        if targets_specific_enemies {
-            for (enemy_index, enemy) in self.enemies.iter().enumerate() {
-                if enemy.battle_info.is_alive() {
-                    valid_targets.push(Entity::Enemy(enemy_index));
-                }
+    /// Picks for the selection in progress
+    /// Cards that differ only in their instance id are offered once, and multi-card selections only offer
+    /// cards at or after the previous pick, so every distinct set of cards is reached by exactly one sequence
+    fn list_selection_actions(&self) -> Vec<BattleAction> {
+        let mut available_actions: Vec<BattleAction> = match &self.battle_state {
+            BattleState::PlayerTurn => Vec::new(),
+            BattleState::SelectCardInHand(card_in_hand_to) => {
+                let from = match card_in_hand_to {
+                    CardInHandTo::PutOnBottomOfDeck(selection)
+                    | CardInHandTo::Exhaust(selection)
+                    | CardInHandTo::DiscardAndDraw { selection, .. } => selection.next_index,
+                    CardInHandTo::PutOnDeck | CardInHandTo::Upgrade | CardInHandTo::Duplicate { .. } => 0,
+                };
+                distinct_card_indices(self.cards.get_hand(), from, |_| true)
+                    .into_iter().map(BattleAction::SelectCardInHand).collect()
+            }
+            BattleState::SelectCardInDiscard => distinct_card_indices(self.cards.get_discard_pile(), 0, |_| true)
+                .into_iter().map(BattleAction::SelectCardInDiscard).collect(),
+            BattleState::SelectCardInExhaust => distinct_card_indices(self.cards.get_exhausted(), 0, |_| true)
+                .into_iter().map(BattleAction::SelectCardInExhaust).collect(),
+            BattleState::SelectCardInDrawPile { card_type, selection } => {
+                distinct_card_indices(self.cards.get_deck().get_cards(), selection.next_index, |card| card.get_card_type() == *card_type)
+                    .into_iter().map(BattleAction::SelectCardInDrawPile).collect()
             }
+            BattleState::SelectCardFromChoices { choices, .. } => (0..choices.len())
+                .filter(|&i| !choices[..i].contains(&choices[i]))
+                .map(BattleAction::SelectCardFromChoices)
+                .collect(),
+        };
+
+        if self.battle_state.multi_select().is_some_and(|selection| selection.can_confirm()) {
+            available_actions.push(BattleAction::ConfirmSelection);
         }
 
-        // Add player target
-        if targets_self {
-            valid_targets.push(Entity::Player);
-        }
+        available_actions
+    }
 
-        // If no specific targeting logic applies, default to allowing enemy targets only
-        // This handles cards with unknown effect types - default to assuming they target enemies
-        if valid_targets.is_empty() {
-            // Add all alive enemies as potential targets
-            for (enemy_index, enemy) in self.enemies.iter().enumerate() {
-                if enemy.battle_info.is_alive() {
-                    valid_targets.push(Entity::Enemy(enemy_index));
-                }
-            }
+    /// Get valid targets for a specific card, derived from the target selectors of its effects
+    /// A card needs a chosen enemy if any effect does; otherwise cards hitting enemies by group use
+    /// `Entity::None` and cards acting only on the player target `Entity::Player`
+    pub(in crate::battle) fn get_valid_targets_for_card(&self, card: &Card) -> Vec<Entity> {
+        let selectors: Vec<TargetSelector> = card.get_effects().iter()
+            .map(|effect| effect.target_selector())
+            .collect();
+
+        if selectors.iter().any(|selector| selector.needs_chosen_target()) {
+            return self.enemies.iter().enumerate()
+                .filter(|(_, enemy)| enemy.battle_info.is_alive())
+                .map(|(enemy_index, _)| Entity::Enemy(enemy_index))
+                .collect();
         }
 
-        valid_targets
+        let targets_enemy_group = selectors.iter().any(|selector| matches!(selector,
+            TargetSelector::AllEnemies | TargetSelector::RandomEnemy | TargetSelector::LowestHpEnemy
+        ));
+        if targets_enemy_group {
+            vec![Entity::None]
+        } else {
+            vec![Entity::Player]
+        }
     }
 
     /// Check if a target is valid for the current battle state
@@ -117,7 +131,24 @@ impl Battle {
         match target {
             Entity::Enemy(idx) => *idx < self.enemies.len(),
             Entity::Player => true,  // Player is always a valid target
-            Entity::None => true,    // None is valid for AttackAllEnemies cards
+            Entity::None => true,    // None is valid for cards hitting enemies by group
+        }
+    }
+}
+
+/// Indices of the cards from `from` on that pass `filter`, skipping cards identical to an earlier one apart from their instance id
+fn distinct_card_indices(cards: &[Card], from: usize, filter: impl Fn(&Card) -> bool) -> Vec<usize> {
+    let mut seen: Vec<Card> = Vec::new();
+    let mut indices = Vec::new();
+    for (index, card) in cards.iter().enumerate().skip(from) {
+        if !filter(card) {
+            continue;
+        }
+        let copy = card.new_copy();
+        if !seen.contains(&copy) {
+            seen.push(copy);
+            indices.push(index);
         }
     }
+    indices
 }
@@ -1,6 +1,7 @@
 use super::Battle;
-use crate::battle::{target::Entity, battle_events::{BattleEvent, EventListener}, event_listener_enum::EventListenerEnum};
-use crate::game::effect::BaseEffect;
+use crate::battle::{target::Entity, battle_events::{BattleEvent, EventListener, QueuePosition}, event_listener_enum::EventListenerEnum, power::Power, counters::Counter};
+use crate::game::{effect::BaseEffect, card_type::CardType};
+use crate::relics::nunchaku;
 
 impl Battle {
     /// Add an event listener to the battle
@@ -13,9 +14,16 @@ impl Battle {
     pub(crate) fn emit_event(&mut self, event: BattleEvent) {
         // Store the event for GUI to read
         self.battle_events.push(event.clone());
+        self.counters.record(&event);
 
         let mut new_effects = Vec::new();
 
+        // The drawn card's own on-draw effects resolve before the listeners that react to the draw
+        if let BattleEvent::CardDrawn { on_draw, .. } = &event {
+            new_effects.extend(on_draw.iter()
+                .map(|effect| BaseEffect::from_effect(effect.clone(), Entity::Player, Entity::Player)));
+        }
+
         // Process all active listeners
         for listener in &mut self.event_listeners {
             if listener.is_active() {
@@ -31,15 +39,69 @@ impl Battle {
         // Remove inactive listeners
         self.event_listeners.retain(|listener| listener.is_active());
 
+        // Relics that read the battle's counters instead of keeping their own
+        new_effects.extend(self.counter_relic_effects(&event));
+
+        // Run the power hooks of the player and every enemy
+        let player_effects = self.player.battle_info.powers_mut().on_event(&event, Entity::Player);
+        new_effects.extend(player_effects.into_iter()
+            .map(|effect| BaseEffect::from_effect(effect, Entity::Player, Entity::Player)));
+        for (i, enemy) in self.enemies.iter_mut().enumerate() {
+            let enemy_effects = enemy.battle_info.powers_mut().on_event(&event, Entity::Enemy(i));
+            new_effects.extend(enemy_effects.into_iter()
+                .map(|effect| BaseEffect::from_effect(effect, Entity::Enemy(i), Entity::Player)));
+        }
+
         // Queue all new effects instead of processing immediately
-        for effect in new_effects {
-            self.queue_effect(effect);
+        match event.trigger_position() {
+            QueuePosition::Front => self.queue_effects_front(new_effects),
+            QueuePosition::Back => {
+                for effect in new_effects {
+                    self.queue_effect(effect);
+                }
+            }
         }
 
         // Process the effect queue immediately
         self.process_effect_queue();
     }
 
+    /// Effects of relics without a listener, triggered by an event once the counters include it
+    fn counter_relic_effects(&self, event: &BattleEvent) -> Vec<BaseEffect> {
+        match event {
+            BattleEvent::CardPlayed { source: Entity::Player, card_type: CardType::Attack, .. } => {
+                let attacks_played = self.counters.this_combat(Counter::AttacksPlayed);
+                match nunchaku::energy_for_attack(&self.relics, attacks_played) {
+                    0 => vec![],
+                    amount => vec![BaseEffect::GainEnergy { source: Entity::Player, amount }],
+                }
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Apply a power to the player or an enemy
+    pub(crate) fn apply_power(&mut self, target: Entity, power: Power) {
+        match target {
+            Entity::Player => self.player.battle_info.apply_power(power),
+            Entity::Enemy(idx) => {
+                if let Some(enemy) = self.enemies.get_mut(idx) {
+                    enemy.battle_info.apply_power(power);
+                }
+            }
+            Entity::None => {}
+        }
+    }
+
+    /// Every power in combat with its owner, the player's first and then each enemy's (for UIs and feature encoders)
+    pub fn list_powers(&self) -> Vec<(Entity, Power)> {
+        let player_powers = self.player.battle_info.get_powers().iter()
+            .map(|power| (Entity::Player, *power));
+        let enemy_powers = self.enemies.iter().enumerate()
+            .flat_map(|(i, enemy)| enemy.battle_info.get_powers().iter().map(move |power| (Entity::Enemy(i), *power)));
+        player_powers.chain(enemy_powers).collect()
+    }
+
     /// Get and clear all battle events that occurred since last call
     pub fn take_battle_events(&mut self) -> Vec<BattleEvent> {
         std::mem::take(&mut self.battle_events)
@@ -1,16 +1,20 @@
 // Public modules
 pub mod battle_action;
 pub mod character_battle_info;
+pub mod power;
 pub mod target;
+pub mod damage_type;
 pub mod battle_events;
 pub mod battle_result;
 pub mod player;
 pub mod deck_hand_pile;
 pub mod enemy_in_battle;
 pub mod battle_state;
+pub mod counters;
 pub mod listeners;
 pub mod battle_error;
 pub mod event_listener_enum;
+pub mod observation;
 
 // Private modules
 mod battle;
@@ -19,8 +23,12 @@ mod list_available_actions;
 mod eval_action;
 mod play_card;
 mod eval_effect;
+mod eval_condition;
 mod enemy_manager;
 mod listener_manager;
+mod chance_outcomes;
+mod hidden_information;
+mod battle_rng;
 
 // Re-export commonly used types for easier access
 pub use target::Entity;
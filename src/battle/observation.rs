@@ -0,0 +1,229 @@
+use crate::enemies::enemy_enum::EnemyMove;
+use crate::game::{card::Card, effect::BattleEffect};
+use crate::potion::PotionInventory;
+use crate::relics::{Relic, nunchaku, pen_nib, runic_dome};
+use super::{Battle, battle_events::EventListener, battle_state::BattleState, character_battle_info::CharacterBattleInfo, counters::Counter, power::Power};
+use serde::{Serialize, Deserialize};
+
+/// What the player can see of a battle, and nothing more
+///
+/// Includes the moves each enemy has made, which the player saw being made. Leaves out the draw pile
+/// order, the enemies' intents under Runic Dome, hidden rolls such as Curl Up's block and the relics'
+/// internal flags, so agents and external clients given only this cannot use hidden information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BattleObservation {
+    pub player: CharacterObservation,
+    pub enemies: Vec<EnemyObservation>,
+    pub hand: Vec<Card>,
+    /// The draw pile as a multiset: its cards sorted by name and upgrade, whatever their order in the pile
+    pub draw_pile: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+    pub exhaust_pile: Vec<Card>,
+    pub relics: Vec<RelicObservation>,
+    pub potions: PotionInventory,
+    pub battle_state: BattleState,
+}
+
+/// HP, block, energy and powers of the player or an enemy
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CharacterObservation {
+    pub current_hp: u32,
+    pub max_hp: u32,
+    pub block: u32,
+    pub energy: u32,
+    pub powers: Vec<Power>,
+}
+
+impl From<&CharacterBattleInfo> for CharacterObservation {
+    fn from(battle_info: &CharacterBattleInfo) -> Self {
+        CharacterObservation {
+            current_hp: battle_info.get_current_hp(),
+            max_hp: battle_info.get_max_hp(),
+            block: battle_info.get_block(),
+            energy: battle_info.get_energy(),
+            powers: battle_info.get_powers().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EnemyObservation {
+    pub name: String,
+    pub character: CharacterObservation,
+    /// The move the enemy shows it will make and its effects, None when no intent is shown (or Runic Dome hides it)
+    pub intent: Option<(EnemyMove, Vec<BattleEffect>)>,
+    /// The moves the enemy has made so far, in order
+    pub moves_made: Vec<EnemyMove>,
+    pub is_alive: bool,
+    pub escaped: bool,
+    pub is_minion: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RelicObservation {
+    pub relic: Relic,
+    /// The counter shown on the relic (e.g. Nunchaku's attacks played), for relics that show one
+    pub counter: Option<u32>,
+}
+
+impl Battle {
+    /// The battle as the player sees it, see `BattleObservation`
+    pub fn player_observation(&self) -> BattleObservation {
+        let mut draw_pile = self.cards.get_deck().get_cards().clone();
+        draw_pile.sort_by_key(|card| (card.get_name(), card.get_upgrade_level()));
+        let intents_hidden = runic_dome::hides_intents(&self.relics);
+
+        let enemies = self.enemies.iter().enumerate()
+            .map(|(index, enemy)| EnemyObservation {
+                name: enemy.enemy.get_name(),
+                character: CharacterObservation::from(&enemy.battle_info),
+                intent: self.get_enemy_move_and_effects(index)
+                    .filter(|_| !intents_hidden)
+                    .map(|(enemy_move, effects)| (enemy_move.clone(), effects.clone())),
+                moves_made: enemy.get_moves_made().to_vec(),
+                is_alive: enemy.battle_info.is_alive(),
+                escaped: enemy.battle_info.escaped,
+                is_minion: enemy.is_minion(),
+            })
+            .collect();
+
+        let relics = self.relics.iter()
+            .map(|relic| RelicObservation {
+                relic: relic.clone(),
+                counter: self.relic_counter(relic),
+            })
+            .collect();
+
+        BattleObservation {
+            player: CharacterObservation::from(&self.player.battle_info),
+            enemies,
+            hand: self.cards.get_hand().clone(),
+            draw_pile,
+            discard_pile: self.cards.get_discard_pile().clone(),
+            exhaust_pile: self.cards.get_exhausted().clone(),
+            relics,
+            potions: self.potions.clone(),
+            battle_state: self.battle_state.clone(),
+        }
+    }
+
+    /// The counter shown on a relic, read from the battle's counters or from the relic's listener
+    fn relic_counter(&self, relic: &Relic) -> Option<u32> {
+        let attacks_played = self.counters.this_combat(Counter::AttacksPlayed);
+        match relic {
+            Relic::Nunchaku => Some(nunchaku::counter(attacks_played)),
+            Relic::PenNib => Some(pen_nib::counter(attacks_played)),
+            _ => self.event_listeners.iter()
+                .filter(|listener| listener.get_owner() == super::Entity::Player)
+                .filter_map(|listener| listener.relic_counter())
+                .find(|(counter_relic, _)| counter_relic == relic)
+                .map(|(_, counter)| counter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle_builder::BattleBuilder;
+    use crate::cards::ironclad::starter_deck::starter_deck;
+    use crate::enemies::{enemy_enum::EnemyEnum, jaw_worm::JawWorm};
+
+    fn battle() -> Battle {
+        BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .build()
+    }
+
+    #[test]
+    fn test_observation_does_not_show_the_draw_pile_order() {
+        let battle = battle();
+        let mut rng = rand::rng();
+
+        let observation = battle.player_observation();
+        assert_eq!(observation.hand.len(), 5);
+        assert_eq!(observation.draw_pile.len(), battle.get_draw_pile_count());
+        for _ in 0..10 {
+            assert_eq!(battle.determinize(&mut rng).player_observation(), observation);
+        }
+    }
+
+    #[test]
+    fn test_observation_shows_enemy_intents_and_relic_counters() {
+        use crate::battle::{battle_action::BattleAction, target::Entity};
+        use crate::game::card_type::CardType;
+
+        let mut battle = BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .add_relic(Relic::Anchor)
+            .add_relic(Relic::Nunchaku)
+            .build();
+
+        let observation = battle.player_observation();
+        let jaw_worm = &observation.enemies[0];
+        assert_eq!(jaw_worm.name, "Jaw Worm");
+        assert_eq!(jaw_worm.character.current_hp, 40);
+        assert_eq!(jaw_worm.intent.as_ref().map(|(enemy_move, _)| enemy_move), battle.get_enemy_move(0));
+        assert_eq!(observation.relics, vec![
+            RelicObservation { relic: Relic::Anchor, counter: None },
+            RelicObservation { relic: Relic::Nunchaku, counter: Some(0) },
+        ]);
+
+        // 5 of the 9 starter cards are attacks, so the opening hand has one
+        let attack = battle.get_hand().iter()
+            .position(|card| card.get_card_type() == CardType::Attack)
+            .expect("Opening hand should have an attack");
+        battle.eval_action(BattleAction::PlayCard(attack, Entity::Enemy(0)), &mut rand::rng()).unwrap();
+        assert_eq!(battle.player_observation().relics[1].counter, Some(1));
+    }
+
+    #[test]
+    fn test_observation_shows_the_moves_made_even_under_runic_dome() {
+        use crate::battle::battle_action::BattleAction;
+        use crate::enemies::{enemy_enum::EnemyMove, jaw_worm::JawWormMove};
+
+        let mut battle = BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .add_relic(Relic::RunicDome)
+            .build();
+        assert!(battle.player_observation().enemies[0].moves_made.is_empty());
+
+        // Jaw Worm always opens with Chomp
+        battle.eval_action(BattleAction::EndTurn, &mut rand::rng()).unwrap();
+        let observation = battle.player_observation();
+        assert_eq!(observation.enemies[0].moves_made, vec![EnemyMove::JawWorm(JawWormMove::Chomp)]);
+        assert_eq!(observation.enemies[0].intent, None);
+    }
+
+    #[test]
+    fn test_runic_dome_hides_intents() {
+        let battle = BattleBuilder::new()
+            .with_deck(starter_deck().get_cards().clone())
+            .add_enemy(EnemyEnum::JawWorm(JawWorm::new(40, false)))
+            .add_relic(Relic::RunicDome)
+            .build();
+        assert!(battle.get_enemy_move(0).is_some());
+
+        let observation = battle.player_observation();
+        assert_eq!(observation.enemies[0].intent, None);
+        // The intent is hidden information: determinizations roll it again and still look the same
+        let mut rng = rand::rng();
+        for _ in 0..10 {
+            let determinized = battle.determinize(&mut rng);
+            assert!(determinized.get_enemy_move(0).is_some());
+            assert_eq!(determinized.player_observation(), observation);
+        }
+    }
+
+    #[test]
+    fn test_observation_round_trips_through_json() {
+        let observation = battle().player_observation();
+
+        let json = serde_json::to_string(&observation).expect("Should serialize");
+        let deserialized: BattleObservation = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(deserialized, observation);
+    }
+}
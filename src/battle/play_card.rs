@@ -1,25 +1,128 @@
 use super::Battle;
-use crate::battle::{target::Entity, BattleError, battle_events::BattleEvent};
-use crate::game::{effect::BaseEffect, card_type::CardType};
+use crate::battle::{target::Entity, BattleError, battle_events::BattleEvent, counters::Counter};
+use crate::cards::curse::NORMALITY_CARD_LIMIT;
+use crate::relics::{pen_nib, velvet_choker};
+use crate::game::{effect::{BaseEffect, BattleEffect}, card_type::CardType, card_keyword::CardKeyword, card_enum::CardEnum};
 
 impl Battle {
     /// Play a card from hand targeting a specific entity
     pub(crate) fn play_card(&mut self, idx: usize, target: Entity) -> Result<(), BattleError> {
+        self.play_card_with_cost(idx, target, true)
+    }
+
+    /// Play a card from hand without spending energy (e.g., Mayhem playing the top card)
+    pub(crate) fn play_card_for_free(&mut self, idx: usize, target: Entity) -> Result<(), BattleError> {
+        self.play_card_with_cost(idx, target, false)
+    }
+
+    /// Most cards the player may play this turn, from Normality in hand and Velvet Choker
+    pub(crate) fn card_play_limit(&self) -> Option<u32> {
+        let normality = self.cards.get_hand().iter()
+            .any(|card| card.get_card_enum() == CardEnum::Normality)
+            .then_some(NORMALITY_CARD_LIMIT);
+        normality.into_iter().chain(velvet_choker::card_limit(&self.relics)).min()
+    }
+
+    /// Whether Normality or Velvet Choker forbids playing any more cards this turn
+    pub(crate) fn card_play_limit_reached(&self) -> bool {
+        self.card_play_limit().is_some_and(|limit| self.counters.this_turn(Counter::CardsPlayed) >= limit)
+    }
+
+    /// Queue an effect of the card being played
+    /// Card damage is calculated when the card is played, so Strength gained while it resolves
+    /// (e.g., Rupture from Hemokinesis' HP loss) does not add to its attacks.
+    /// `damage_multiplier` doubles the damage of Pen Nib's 10th Attack
+    fn queue_card_effect(&mut self, effect: BaseEffect, damage_multiplier: u32) {
+        let effect = self.lock_attack_damage(effect, damage_multiplier);
+        self.queue_effect(effect);
+    }
+
+    /// Damage of a player attack with the player's current Strength, times `damage_multiplier`
+    fn locked_damage(&self, amount: u32, strength_multiplier: u32, damage_multiplier: u32) -> u32 {
+        let strength = self.player.battle_info.get_strength() * strength_multiplier as i32;
+        (amount as i32 + strength).max(0) as u32 * damage_multiplier
+    }
+
+    /// Turn a player attack into one whose damage no longer depends on Strength,
+    /// including attacks nested in Targeted and RepeatEffect (Cleave, Whirlwind)
+    fn lock_attack_damage(&self, effect: BaseEffect, damage_multiplier: u32) -> BaseEffect {
+        let locked_attack = |target, amount| BaseEffect::AttackToTarget {
+            source: Entity::Player, target, amount, num_attacks: 1, strength_multiplier: 0,
+        };
+        match effect {
+            BaseEffect::AttackToTarget { source: Entity::Player, target, amount, num_attacks, strength_multiplier } => {
+                let amount = self.locked_damage(amount, strength_multiplier, damage_multiplier);
+                BaseEffect::AttackToTarget { source: Entity::Player, target, amount, num_attacks, strength_multiplier: 0 }
+            },
+            BaseEffect::AttackToTargetWithScaling { source: Entity::Player, target, base_damage } => {
+                locked_attack(target, self.locked_damage(base_damage, 1, damage_multiplier))
+            },
+            BaseEffect::PerfectedStrike { source: Entity::Player, target, base_damage, damage_per_strike } => {
+                let amount = base_damage + damage_per_strike * self.count_strike_cards_in_deck();
+                locked_attack(target, self.locked_damage(amount, 1, damage_multiplier))
+            },
+            BaseEffect::AttackToTargetWithBlock { source: Entity::Player, target } => {
+                locked_attack(target, self.locked_damage(self.player.get_block(), 1, damage_multiplier))
+            },
+            BaseEffect::AttackToTargetWithDrawPileSize { source: Entity::Player, target } => {
+                locked_attack(target, self.locked_damage(self.cards.deck_size() as u32, 1, damage_multiplier))
+            },
+            BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks, strength_multiplier } => {
+                let amount = self.locked_damage(amount, strength_multiplier, damage_multiplier);
+                BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks, strength_multiplier: 0 }
+            },
+            BaseEffect::RepeatEffect { effect, times, source: Entity::Player, target } => {
+                let effect = Box::new(self.lock_nested_attack_damage(*effect, damage_multiplier));
+                BaseEffect::RepeatEffect { effect, times, source: Entity::Player, target }
+            },
+            BaseEffect::Targeted { selector, effect, source: Entity::Player, target } => {
+                let effect = Box::new(self.lock_nested_attack_damage(*effect, damage_multiplier));
+                BaseEffect::Targeted { selector, effect, source: Entity::Player, target }
+            },
+            effect => effect,
+        }
+    }
+
+    /// `lock_attack_damage` for the card effects wrapped by Targeted and RepeatEffect
+    fn lock_nested_attack_damage(&self, effect: BattleEffect, damage_multiplier: u32) -> BattleEffect {
+        match effect {
+            BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier } => {
+                let amount = self.locked_damage(amount, strength_multiplier, damage_multiplier);
+                BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier: 0 }
+            },
+            BattleEffect::Targeted { selector, effect } => {
+                BattleEffect::Targeted { selector, effect: Box::new(self.lock_nested_attack_damage(*effect, damage_multiplier)) }
+            },
+            BattleEffect::RepeatX { effect, extra } => {
+                BattleEffect::RepeatX { effect: Box::new(self.lock_nested_attack_damage(*effect, damage_multiplier)), extra }
+            },
+            effect => effect,
+        }
+    }
+
+    fn play_card_with_cost(&mut self, idx: usize, target: Entity, pay_cost: bool) -> Result<(), BattleError> {
         if idx >= self.cards.hand_size() {
             return Err(BattleError::CardNotInHand);
         }
 
+        // Cards played for free by other cards (Havoc, Mayhem) are not limited by Normality or Velvet Choker
+        if pay_cost && self.card_play_limit_reached() {
+            return Err(BattleError::CardNotPlayable);
+        }
+
         let card_effects;
+        let x_value;
         let is_skill_card;
         let is_power_card;
         let has_exhaust;
+        let card_type;
 
         {
             let hand = self.cards.get_hand();
             let card = &hand[idx];
 
-            // Check if card is playable based on its condition
-            if !self.eval_condition(card.get_play_condition()) {
+            // Check if card is playable based on its Unplayable keyword and its condition
+            if card.has_keyword(CardKeyword::Unplayable) || !self.eval_condition(card.get_play_condition(), target) {
                 return Err(BattleError::CardNotPlayable);
             }
 
@@ -29,19 +132,29 @@ impl Battle {
             }
 
             // Calculate modified cost considering active powers like Corruption
-            let modified_cost = self.get_modified_cost(card);
+            let modified_cost = if pay_cost { self.get_modified_cost(card) } else { 0 };
 
             if !self.player.spend_energy(modified_cost) {
                 return Err(BattleError::NotEnoughEnergy);
             }
 
+            // X-cost cards spend all remaining energy (unless played for free) and X is that energy
+            x_value = if card.is_x_cost() {
+                let energy = self.player.get_energy();
+                if pay_cost {
+                    self.player.spend_energy(energy);
+                }
+                Some(crate::relics::chemical_x::x_value(energy, &self.relics))
+            } else {
+                None
+            };
+
             card_effects = card.get_effects().clone();
-            let card_type_clone = card.get_card_type().clone();
+            card_type = card.get_card_type();
             is_skill_card = card.get_card_type() == CardType::Skill;
             is_power_card = card.get_card_type() == CardType::Power;
-            // A card has exhaust if it naturally has the Exhaust effect OR if it's a Skill with Corruption active
-            has_exhaust = card_effects.contains(&crate::game::effect::BattleEffect::Exhaust)
-                || (is_skill_card && self.has_corruption_active());
+            // The Exhaust keyword or effect, Corruption grants the keyword to Skills
+            has_exhaust = card.exhausts();
 
             // Emit SkillCardPlayed event if this is a Skill card
             if is_skill_card {
@@ -54,11 +167,22 @@ impl Battle {
             // Emit CardPlayed event for Corruption and other listeners
             let card_played_event = BattleEvent::CardPlayed {
                 source: Entity::Player,
-                card_type: card_type_clone,
+                card_type,
+                x_value,
             };
             self.emit_event(card_played_event);
         }
 
+        // The counters already include this card, so Pen Nib knows whether it is the 10th Attack
+        let damage_multiplier = if card_type == CardType::Attack {
+            pen_nib::damage_multiplier(&self.relics, self.counters.this_combat(Counter::AttacksPlayed))
+        } else {
+            1
+        };
+
+        // The effects are already queued with the current damage, later plays of this card deal more
+        self.cards.increase_hand_card_scaling_damage(idx);
+
         // Handle different card types
         let result = if is_power_card {
             // Power cards are removed from hand but NOT added to discard pile (they stay in play)
@@ -68,7 +192,7 @@ impl Battle {
 
                 // Queue all effects
                 for effect in card_effects {
-                    self.queue_effect(BaseEffect::from_effect(effect, Entity::Player, target));
+                    self.queue_card_effect(BaseEffect::from_effect_with_x(effect, Entity::Player, target, x_value.unwrap_or(0)), damage_multiplier);
                 }
                 Ok(())
             } else {
@@ -85,13 +209,20 @@ impl Battle {
                         hand_index: idx,
                     });
                     has_exhaust_effect = true;
+                } else if let crate::game::effect::BattleEffect::IncreaseDamageOnKill { amount } = effect {
+                    // The card is still in hand, so it can be modified before it gets exhausted
+                    self.queue_effect(BaseEffect::IncreaseDamageOnKill {
+                        target,
+                        hand_index: idx,
+                        amount,
+                    });
                 } else {
-                    self.queue_effect(BaseEffect::from_effect(effect, Entity::Player, target));
+                    self.queue_card_effect(BaseEffect::from_effect_with_x(effect, Entity::Player, target, x_value.unwrap_or(0)), damage_multiplier);
                 }
             }
 
-            // If the card didn't have its own Exhaust effect but should be exhausted due to Corruption,
-            // queue the Exhaust effect now
+            // If the card didn't have its own Exhaust effect but should be exhausted due to its Exhaust
+            // keyword or Corruption, queue the Exhaust effect now
             if !has_exhaust_effect {
                 self.queue_effect(BaseEffect::Exhaust {
                     hand_index: idx,
@@ -102,13 +233,16 @@ impl Battle {
         } else {
             // Regular cards (Attack, Skill, Status without Exhaust)
             // Remove from hand and add to to_be_discarded (will be moved to discard after effects process)
-            if let Some(played_card) = self.cards.play_card_from_hand(idx) {
+            if let Some(mut played_card) = self.cards.play_card_from_hand(idx) {
+                // Costs that only last until the card is played (e.g., Forethought) are cleared
+                played_card.clear_cost_until_played();
+
                 // Store card for later discard
                 self.to_be_discarded.push(played_card);
 
                 // Queue all effects
                 for effect in card_effects {
-                    self.queue_effect(BaseEffect::from_effect(effect, Entity::Player, target));
+                    self.queue_card_effect(BaseEffect::from_effect_with_x(effect, Entity::Player, target, x_value.unwrap_or(0)), damage_multiplier);
                 }
                 Ok(())
             } else {
@@ -154,7 +288,7 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
 
         // Draw the hand
-        battle.cards.draw_n(5);
+        battle.cards.draw_n(5, &mut rand::rng());
 
         // Find the Slimed card in hand (if any) or add one
         let mut slimed_index = None;
@@ -195,4 +329,293 @@ mod tests {
         let exhausted_cards = battle.cards.get_exhausted();
         assert_eq!(exhausted_cards.last().unwrap().get_name(), "Slimed");
     }
+
+    fn x_cost_battle(relics: Vec<crate::relics::Relic>) -> Battle {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)))];
+        let player_state = PlayerRunState::new_with_relics(50, 80, 0, relics);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::whirlwind::whirlwind());
+        battle
+    }
+
+    #[test]
+    fn test_x_cost_card_playable_with_zero_energy() {
+        use crate::battle::battle_action::BattleAction;
+
+        let mut battle = x_cost_battle(vec![]);
+        battle.player.battle_info.energy = 0;
+
+        let actions = battle.list_available_actions();
+        assert!(actions.contains(&BattleAction::PlayCard(0, Entity::None)));
+
+        battle.play_card(0, Entity::None).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 44);
+        assert!(battle.battle_events.contains(&BattleEvent::CardPlayed {
+            source: Entity::Player,
+            card_type: CardType::Attack,
+            x_value: Some(0),
+        }));
+    }
+
+    #[test]
+    fn test_x_cost_card_spends_all_energy() {
+        let mut battle = x_cost_battle(vec![]);
+        battle.player.battle_info.energy = 2;
+
+        battle.play_card(0, Entity::None).unwrap();
+
+        assert_eq!(battle.player.get_energy(), 0);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 34);
+        assert!(battle.battle_events.contains(&BattleEvent::CardPlayed {
+            source: Entity::Player,
+            card_type: CardType::Attack,
+            x_value: Some(2),
+        }));
+    }
+
+    #[test]
+    fn test_chemical_x_adds_two_to_x() {
+        let mut battle = x_cost_battle(vec![crate::relics::Relic::ChemicalX]);
+        battle.player.battle_info.energy = 1;
+
+        battle.play_card(0, Entity::None).unwrap();
+
+        assert_eq!(battle.player.get_energy(), 0);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 29);
+        assert!(battle.battle_events.contains(&BattleEvent::CardPlayed {
+            source: Entity::Player,
+            card_type: CardType::Attack,
+            x_value: Some(3),
+        }));
+    }
+
+    #[test]
+    fn test_x_amount_scales_effect_magnitudes() {
+        use crate::game::{card::{Card, CardClass, Rarity}, card_enum::CardEnum, effect::BattleEffect};
+
+        // Malaise+: the enemy loses X+1 Strength and gets X+1 Weak
+        let malaise_plus = Card::new(
+            CardEnum::Whirlwind,
+            0,
+            CardClass::IronClad(Rarity::Rare, CardType::Skill),
+            vec![
+                BattleEffect::XAmount { effect: Box::new(BattleEffect::LoseStrength(0)), extra: 1 },
+                BattleEffect::XAmount { effect: Box::new(BattleEffect::ApplyWeak { duration: 0 }), extra: 1 },
+            ],
+        ).set_x_cost(true);
+
+        let mut battle = x_cost_battle(vec![crate::relics::Relic::ChemicalX]);
+        battle.cards.remove_card_from_hand(0);
+        battle.add_card_to_hand_for_testing(malaise_plus);
+        battle.player.battle_info.energy = 2;
+
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        // X = 2 energy + 2 from Chemical X
+        assert_eq!(battle.get_enemies()[0].battle_info.get_strength(), -5);
+        assert_eq!(battle.get_enemies()[0].get_weak(), 5);
+    }
+
+    #[test]
+    fn test_non_x_cost_card_has_no_x_value() {
+        let mut battle = x_cost_battle(vec![]);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::strike::strike());
+
+        battle.play_card(1, Entity::Enemy(0)).unwrap();
+        assert!(battle.battle_events.contains(&BattleEvent::CardPlayed {
+            source: Entity::Player,
+            card_type: CardType::Attack,
+            x_value: None,
+        }));
+    }
+    #[test]
+    fn test_exhaust_keyword_exhausts_played_card() {
+        let mut battle = x_cost_battle(vec![]);
+        battle.cards.remove_card_from_hand(0);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::strike::strike().set_exhaust(true));
+
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.cards.hand_size(), 0);
+        assert_eq!(battle.cards.exhausted_size(), 1);
+        assert_eq!(battle.cards.discard_pile_size(), 0);
+    }
+
+    #[test]
+    fn test_unplayable_keyword_blocks_play() {
+        let mut battle = x_cost_battle(vec![]);
+        battle.cards.remove_card_from_hand(0);
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::strike::strike().set_playable(false));
+
+        assert_eq!(battle.play_card(0, Entity::Enemy(0)), Err(BattleError::CardNotPlayable));
+        assert_eq!(battle.cards.hand_size(), 1);
+    }
+
+    fn normality_battle() -> Battle {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(100, false)))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+        battle.add_card_to_hand_for_testing(crate::cards::curse::normality());
+        for _ in 0..4 {
+            battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+        }
+        battle
+    }
+
+    #[test]
+    fn test_cards_played_are_counted_this_turn_and_this_combat() {
+        let mut battle = normality_battle();
+        battle.play_card(1, Entity::Enemy(0)).unwrap();
+        battle.play_card(1, Entity::Enemy(0)).unwrap();
+
+        let counters = battle.get_counters();
+        assert_eq!(counters.this_turn(Counter::CardsPlayed), 2);
+        assert_eq!(counters.this_turn(Counter::AttacksPlayed), 2);
+        assert_eq!(counters.this_combat(Counter::CardsPlayed), 2);
+
+        battle.counters.start_turn();
+        assert_eq!(battle.get_counters().this_turn(Counter::CardsPlayed), 0);
+        assert_eq!(battle.get_counters().this_combat(Counter::CardsPlayed), 2);
+    }
+
+    #[test]
+    fn test_normality_blocks_the_fourth_card() {
+        use crate::battle::battle_action::BattleAction;
+
+        let mut battle = normality_battle();
+        for _ in 0..3 {
+            battle.play_card(1, Entity::Enemy(0)).unwrap();
+        }
+
+        assert!(battle.card_play_limit_reached());
+        assert_eq!(battle.play_card(1, Entity::Enemy(0)), Err(BattleError::CardNotPlayable));
+        assert!(!battle.list_available_actions().iter().any(|action| matches!(action, BattleAction::PlayCard(..))));
+    }
+
+    #[test]
+    fn test_no_limit_without_normality_in_hand() {
+        let mut battle = normality_battle();
+        battle.cards.remove_card_from_hand(0).unwrap();
+
+        for _ in 0..4 {
+            battle.play_card(0, Entity::Enemy(0)).unwrap();
+        }
+        assert!(!battle.card_play_limit_reached());
+    }
+
+    #[test]
+    fn test_velvet_choker_blocks_the_seventh_card() {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(100, false)))];
+        let player_state = PlayerRunState::new_with_relics(80, 80, 0, vec![crate::relics::Relic::VelvetChoker]);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+        for _ in 0..7 {
+            battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+        }
+
+        for _ in 0..6 {
+            battle.play_card(0, Entity::Enemy(0)).unwrap();
+        }
+        assert_eq!(battle.card_play_limit(), Some(6));
+        assert_eq!(battle.play_card(0, Entity::Enemy(0)), Err(BattleError::CardNotPlayable));
+
+        // Normality's lower limit wins when both apply
+        battle.add_card_to_hand_for_testing(crate::cards::curse::normality());
+        assert_eq!(battle.card_play_limit(), Some(3));
+    }
+
+    #[test]
+    fn test_tenth_attack_triggers_nunchaku_and_pen_nib() {
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::relics::Relic;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(200, false)))];
+        let player_state = PlayerRunState::new_with_relics(80, 80, 0, vec![Relic::Nunchaku, Relic::PenNib]);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+        let energy = battle.player.get_energy();
+        for _ in 0..10 {
+            battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+        }
+
+        for _ in 0..9 {
+            battle.play_card(0, Entity::Enemy(0)).unwrap();
+        }
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 200 - 9 * 6);
+        assert_eq!(battle.player.get_energy(), energy);
+
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 200 - 9 * 6 - 12);
+        assert_eq!(battle.player.get_energy(), energy + 1);
+    }
+
+    #[test]
+    fn test_pen_nib_doubles_an_all_enemies_tenth_attack() {
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::relics::Relic;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(200, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(200, false))),
+        ];
+        let player_state = PlayerRunState::new_with_relics(80, 80, 0, vec![Relic::PenNib]);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+        for _ in 0..9 {
+            battle.add_card_to_hand_for_testing(crate::cards::ironclad::anger::anger());
+        }
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::cleave::cleave());
+
+        for _ in 0..9 {
+            battle.play_card(0, Entity::Enemy(0)).unwrap();
+        }
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 200 - 9 * 6);
+
+        // Cleave is the 10th Attack and deals double damage to every enemy
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 200 - 9 * 6 - 16);
+        assert_eq!(battle.get_enemies()[1].get_current_hp(), 200 - 16);
+    }
+
+    #[test]
+    fn test_cleave_damage_is_locked_when_played() {
+        use crate::enemies::jaw_worm::JawWorm;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(100, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(100, false))),
+        ];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        // A Strength gain triggered while Cleave resolves jumps ahead of it (as Rupture does),
+        // but Cleave's damage was calculated when it was played
+        let cleave_effect = crate::cards::ironclad::cleave::cleave().get_effects()[0].clone();
+        battle.queue_card_effect(BaseEffect::from_effect(cleave_effect, Entity::Player, Entity::Enemy(0)), 1);
+        battle.queue_effects_front(vec![BaseEffect::GainStrength { source: Entity::Player, amount: 3 }]);
+        battle.process_effect_queue();
+
+        assert_eq!(battle.get_player().battle_info.get_strength(), 3);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 92);
+        assert_eq!(battle.get_enemies()[1].get_current_hp(), 92);
+
+        // The next Cleave uses it
+        battle.add_card_to_hand_for_testing(crate::cards::ironclad::cleave::cleave());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 81);
+        assert_eq!(battle.get_enemies()[1].get_current_hp(), 81);
+    }
 }
@@ -37,9 +37,9 @@ impl Player {
         self.battle_info.is_vulnerable()
     }
     
+    /// Reset block for the new turn, energy is refilled by the battle which knows the relics and powers
     pub fn at_start_of_turn(&mut self) {
         self.battle_info.at_start_of_turn();
-        self.battle_info.energy = 3;
     }
 
     pub fn is_alive(&self) -> bool {
@@ -0,0 +1,492 @@
+use crate::battle::{battle_events::BattleEvent, target::Entity};
+use crate::game::{card_type::CardType, effect::{BattleEffect, TargetSelector}};
+use serde::{Serialize, Deserialize};
+
+/// Every buff and debuff an entity can have during combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PowerId {
+    // Stats
+    Strength,
+    Dexterity,
+    Artifact,
+    Ritual,
+    Intangible,
+    Thorns,
+    Buffer,
+    Blur,
+    Energized, // Energy gained at the start of next turn
+    Conserve,  // Unspent energy carries over into next turn
+
+    // Debuffs
+    Vulnerable,
+    Weak,
+    Frail,
+    Entangled,
+    NoDraw,
+    Confused,
+    EnergyDown, // Energy lost at the start of every turn
+
+    // Power cards
+    Barricade,
+    Brutality,
+    Combust,
+    Corruption,
+    DemonForm,
+    DevaForm,
+    DoubleTap,
+    Embrace,
+    Establishment, // Retained cards cost less
+    Evolve,
+    FeelNoPain,
+    FireBreathing,
+    FlameBarrier,
+    Juggernaut,
+    LoseStrength, // Flex
+    Metallicize,
+    Rage,
+    Rupture,
+
+    // Enemy powers
+    TimeWarp, // Time Eater, amount is the cards the player can still play before their turn ends
+}
+
+/// Cards the player may play each turn before Time Warp ends it
+pub const TIME_WARP_CARDS: i32 = 12;
+
+/// Whether a power helps or hurts its owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerType {
+    Buff,
+    Debuff,
+}
+
+/// How long a power lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerDuration {
+    Permanent,        // Lasts for the rest of combat (Strength, Demon Form)
+    Turns,            // Amount is turns remaining, counted down at end of the owner's turn (Vulnerable, Weak)
+    TurnsAtTurnStart, // Amount is turns remaining, counted down at start of the owner's turn (Intangible, Blur)
+    ThisTurn,         // Removed at end of the owner's turn, after its end-of-turn hooks ran (Rage, Flex)
+    UntilNextTurn,    // Removed at start of the owner's next turn (Flame Barrier)
+}
+
+/// What happens when the same power is applied again
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerStacking {
+    Additive,    // Amounts are added together
+    NonStacking, // The power is either present or not, applying it again does nothing
+}
+
+/// Points in combat where a power can react
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerHook {
+    AtTurnStart,
+    AtTurnEnd,
+    OnCardPlayed,
+    OnCardDrawn,
+    OnCardExhausted,
+    OnAttack,
+    OnDamageReceived,
+    OnHpLostFromCard,
+    OnBlockGained,
+    OnPlayerCardPlayed, // An enemy's power seeing the player play a card
+}
+
+impl PowerHook {
+    /// The hook an event triggers for powers owned by `owner`, if any
+    pub fn from_event(event: &BattleEvent, owner: Entity) -> Option<PowerHook> {
+        match event {
+            BattleEvent::StartOfPlayerTurn if owner == Entity::Player => Some(PowerHook::AtTurnStart),
+            BattleEvent::StartOfEnemyTurn { enemy_index } if owner == Entity::Enemy(*enemy_index) => Some(PowerHook::AtTurnStart),
+            BattleEvent::EndOfTurn { entity } if *entity == owner => Some(PowerHook::AtTurnEnd),
+            BattleEvent::CardPlayed { source, .. } if *source == owner => Some(PowerHook::OnCardPlayed),
+            // Only the player draws cards
+            BattleEvent::CardDrawn { .. } if owner == Entity::Player => Some(PowerHook::OnCardDrawn),
+            BattleEvent::CardExhausted { source } if *source == owner => Some(PowerHook::OnCardExhausted),
+            BattleEvent::DamageTaken { target, .. } if *target == owner => Some(PowerHook::OnDamageReceived),
+            BattleEvent::DamageTaken { source, .. } if *source == owner => Some(PowerHook::OnAttack),
+            BattleEvent::HpLostFromCard { target, .. } if *target == owner => Some(PowerHook::OnHpLostFromCard),
+            BattleEvent::BlockGained { source, .. } if *source == owner => Some(PowerHook::OnBlockGained),
+            BattleEvent::CardPlayed { source: Entity::Player, .. } if owner != Entity::Player => Some(PowerHook::OnPlayerCardPlayed),
+            _ => None,
+        }
+    }
+}
+
+impl PowerId {
+    pub fn name(self) -> &'static str {
+        match self {
+            PowerId::Strength => "Strength",
+            PowerId::Dexterity => "Dexterity",
+            PowerId::Artifact => "Artifact",
+            PowerId::Ritual => "Ritual",
+            PowerId::Intangible => "Intangible",
+            PowerId::Thorns => "Thorns",
+            PowerId::Buffer => "Buffer",
+            PowerId::Blur => "Blur",
+            PowerId::Energized => "Energized",
+            PowerId::Conserve => "Conserve",
+            PowerId::Vulnerable => "Vulnerable",
+            PowerId::Weak => "Weak",
+            PowerId::Frail => "Frail",
+            PowerId::Entangled => "Entangled",
+            PowerId::NoDraw => "No Draw",
+            PowerId::Confused => "Confused",
+            PowerId::EnergyDown => "Energy Down",
+            PowerId::Barricade => "Barricade",
+            PowerId::Brutality => "Brutality",
+            PowerId::Combust => "Combust",
+            PowerId::Corruption => "Corruption",
+            PowerId::DemonForm => "Demon Form",
+            PowerId::DevaForm => "Deva Form",
+            PowerId::DoubleTap => "Double Tap",
+            PowerId::Embrace => "Embrace",
+            PowerId::Establishment => "Establishment",
+            PowerId::Evolve => "Evolve",
+            PowerId::FeelNoPain => "Feel No Pain",
+            PowerId::FireBreathing => "Fire Breathing",
+            PowerId::FlameBarrier => "Flame Barrier",
+            PowerId::Juggernaut => "Juggernaut",
+            PowerId::LoseStrength => "Lose Strength",
+            PowerId::Metallicize => "Metallicize",
+            PowerId::Rage => "Rage",
+            PowerId::Rupture => "Rupture",
+            PowerId::TimeWarp => "Time Warp",
+        }
+    }
+
+    /// Buff or debuff, for powers whose amount is positive
+    pub fn power_type(self) -> PowerType {
+        match self {
+            PowerId::Vulnerable | PowerId::Weak | PowerId::Frail | PowerId::Entangled | PowerId::NoDraw | PowerId::Confused | PowerId::EnergyDown | PowerId::LoseStrength => PowerType::Debuff,
+            _ => PowerType::Buff,
+        }
+    }
+
+    pub fn duration(self) -> PowerDuration {
+        match self {
+            PowerId::Vulnerable | PowerId::Weak | PowerId::Frail | PowerId::Entangled => PowerDuration::Turns,
+            PowerId::Intangible | PowerId::Blur => PowerDuration::TurnsAtTurnStart,
+            PowerId::Rage | PowerId::LoseStrength | PowerId::NoDraw => PowerDuration::ThisTurn,
+            PowerId::FlameBarrier | PowerId::Energized | PowerId::Conserve => PowerDuration::UntilNextTurn,
+            _ => PowerDuration::Permanent,
+        }
+    }
+
+    pub fn stacking(self) -> PowerStacking {
+        match self {
+            PowerId::Barricade | PowerId::Corruption | PowerId::NoDraw | PowerId::Confused | PowerId::Conserve | PowerId::TimeWarp => PowerStacking::NonStacking,
+            _ => PowerStacking::Additive,
+        }
+    }
+
+    /// Whether the amount may drop below zero (Strength and Dexterity can be negative)
+    pub fn can_be_negative(self) -> bool {
+        matches!(self, PowerId::Strength | PowerId::Dexterity)
+    }
+
+    /// The hooks this power reacts to
+    pub fn hooks(self) -> &'static [PowerHook] {
+        match self {
+            PowerId::Brutality | PowerId::DemonForm | PowerId::DevaForm | PowerId::Energized | PowerId::EnergyDown => &[PowerHook::AtTurnStart],
+            PowerId::Combust | PowerId::LoseStrength | PowerId::Metallicize => &[PowerHook::AtTurnEnd],
+            PowerId::DoubleTap | PowerId::Rage => &[PowerHook::OnCardPlayed],
+            PowerId::Embrace | PowerId::FeelNoPain => &[PowerHook::OnCardExhausted],
+            PowerId::Evolve | PowerId::FireBreathing => &[PowerHook::OnCardDrawn],
+            PowerId::Rupture => &[PowerHook::OnHpLostFromCard],
+            PowerId::Juggernaut => &[PowerHook::OnBlockGained],
+            PowerId::TimeWarp => &[PowerHook::OnPlayerCardPlayed],
+            // Stats, debuffs and defensive powers (Thorns, Flame Barrier...) are read directly by damage and block calculations,
+            // Corruption and Establishment by the card piles
+            _ => &[],
+        }
+    }
+}
+
+/// A buff or debuff with its current amount
+/// Some powers track a second number, e.g. Combust's HP loss grows by 1 per copy played while its damage grows by 5
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Power {
+    id: PowerId,
+    amount: i32,
+    extra_amount: u32,
+}
+
+impl Power {
+    pub fn new(id: PowerId, amount: i32) -> Self {
+        Power { id, amount, extra_amount: 0 }
+    }
+
+    /// Builder pattern method to set the secondary amount
+    pub fn with_extra_amount(mut self, extra_amount: u32) -> Self {
+        self.extra_amount = extra_amount;
+        self
+    }
+
+    pub fn get_id(&self) -> PowerId {
+        self.id
+    }
+
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+
+    pub fn get_extra_amount(&self) -> u32 {
+        self.extra_amount
+    }
+
+    /// Buff or debuff, negative Strength and Dexterity count as debuffs
+    pub fn power_type(&self) -> PowerType {
+        if self.amount < 0 {
+            PowerType::Debuff
+        } else {
+            self.id.power_type()
+        }
+    }
+
+    fn amount_u32(&self) -> u32 {
+        self.amount.max(0) as u32
+    }
+
+    /// Effects this power produces for a hook, and updates its own state
+    pub fn on_hook(&mut self, hook: PowerHook, event: &BattleEvent) -> Vec<BattleEffect> {
+        if !self.id.hooks().contains(&hook) {
+            return vec![];
+        }
+
+        let amount = self.amount_u32();
+        match (self.id, event) {
+            (PowerId::Brutality, _) => vec![
+                BattleEffect::LoseHp(amount),
+                BattleEffect::DrawCard { count: amount },
+            ],
+            (PowerId::DemonForm, _) => vec![BattleEffect::GainStrength { amount }],
+            (PowerId::DevaForm, _) => {
+                // The energy gained grows by the extra amount every turn
+                self.amount += self.extra_amount as i32;
+                vec![BattleEffect::GainEnergy { amount }]
+            }
+            (PowerId::Energized, _) => vec![BattleEffect::GainEnergy { amount }],
+            (PowerId::EnergyDown, _) => vec![BattleEffect::LoseEnergy { amount }],
+            // Combust, Fire Breathing and Juggernaut deal non-attack damage, unaffected by Strength, Weak and Vulnerable
+            (PowerId::Combust, _) => vec![
+                BattleEffect::DealDamageToAllEnemies { amount },
+                BattleEffect::LoseHp(self.extra_amount),
+            ],
+            (PowerId::LoseStrength, _) => vec![BattleEffect::LoseStrength(amount).targeting(TargetSelector::Source)],
+            (PowerId::Metallicize, _) => vec![BattleEffect::GainDefense { amount }],
+            (PowerId::DoubleTap, BattleEvent::CardPlayed { card_type: CardType::Attack, .. }) => {
+                // Counts down the Attacks left to play twice
+                self.amount -= 1;
+                vec![]
+            }
+            (PowerId::Rage, BattleEvent::CardPlayed { card_type: CardType::Attack, .. }) => {
+                vec![BattleEffect::GainDefense { amount }]
+            }
+            (PowerId::Embrace, _) => vec![BattleEffect::DrawCard { count: amount }],
+            (PowerId::FeelNoPain, _) => vec![BattleEffect::GainDefense { amount }],
+            (PowerId::Evolve, BattleEvent::CardDrawn { card_type: CardType::Status, .. }) => {
+                vec![BattleEffect::DrawCard { count: amount }]
+            }
+            (PowerId::FireBreathing, BattleEvent::CardDrawn { is_status_or_curse: true, .. }) => {
+                vec![BattleEffect::DealDamageToAllEnemies { amount }]
+            }
+            (PowerId::Rupture, BattleEvent::HpLostFromCard { amount: hp_lost, .. }) if *hp_lost > 0 => {
+                vec![BattleEffect::GainStrength { amount: hp_lost * amount }]
+            }
+            (PowerId::Juggernaut, _) => vec![BattleEffect::DealDamageToRandomEnemy { amount }],
+            (PowerId::TimeWarp, _) => {
+                // Counts down the cards left this turn, the last one ends the turn and strengthens Time Eater
+                self.amount -= 1;
+                if self.amount > 0 {
+                    return vec![];
+                }
+                self.amount = TIME_WARP_CARDS;
+                vec![BattleEffect::GainStrength { amount: 2 }, BattleEffect::EndTurn]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// The powers on one entity, kept sorted by id so equal states hash equally
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Powers {
+    powers: Vec<Power>,
+}
+
+impl Powers {
+    pub fn new() -> Self {
+        Powers { powers: Vec::new() }
+    }
+
+    /// All powers currently on the entity
+    pub fn as_slice(&self) -> &[Power] {
+        &self.powers
+    }
+
+    pub fn get(&self, id: PowerId) -> Option<&Power> {
+        self.powers.iter().find(|power| power.id == id)
+    }
+
+    /// Current amount of a power, 0 if absent
+    pub fn amount(&self, id: PowerId) -> i32 {
+        self.get(id).map_or(0, |power| power.amount)
+    }
+
+    pub fn has(&self, id: PowerId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Apply a power, stacking it with an existing one according to its stacking rule
+    pub fn apply(&mut self, power: Power) {
+        match self.powers.binary_search_by_key(&power.id, |p| p.id) {
+            Ok(index) => {
+                if power.id.stacking() == PowerStacking::Additive {
+                    let existing = &mut self.powers[index];
+                    existing.amount += power.amount;
+                    existing.extra_amount += power.extra_amount;
+                }
+            }
+            Err(index) => self.powers.insert(index, power),
+        }
+        self.remove_depleted();
+    }
+
+    /// Set a power to an exact amount, removing it at 0
+    pub fn set(&mut self, id: PowerId, amount: i32) {
+        match self.powers.binary_search_by_key(&id, |p| p.id) {
+            Ok(index) => self.powers[index].amount = amount,
+            Err(index) => self.powers.insert(index, Power::new(id, amount)),
+        }
+        self.remove_depleted();
+    }
+
+    pub fn remove(&mut self, id: PowerId) {
+        self.powers.retain(|power| power.id != id);
+    }
+
+    /// Count down every power with the given duration by one
+    pub fn tick(&mut self, duration: PowerDuration) {
+        for power in self.powers.iter_mut().filter(|power| power.id.duration() == duration) {
+            power.amount -= 1;
+        }
+        self.remove_depleted();
+    }
+
+    /// Run the hooks an event triggers for this entity's powers and expire turn-limited powers
+    pub fn on_event(&mut self, event: &BattleEvent, owner: Entity) -> Vec<BattleEffect> {
+        let Some(hook) = PowerHook::from_event(event, owner) else {
+            return vec![];
+        };
+
+        let mut effects = Vec::new();
+        for power in self.powers.iter_mut() {
+            effects.extend(power.on_hook(hook, event));
+        }
+
+        match hook {
+            PowerHook::AtTurnEnd => self.powers.retain(|power| power.id.duration() != PowerDuration::ThisTurn),
+            PowerHook::AtTurnStart => self.powers.retain(|power| power.id.duration() != PowerDuration::UntilNextTurn),
+            _ => {}
+        }
+        self.remove_depleted();
+
+        effects
+    }
+
+    /// Powers at 0 are gone, and only Strength and Dexterity may go negative
+    fn remove_depleted(&mut self) {
+        self.powers.retain(|power| {
+            power.amount > 0 || (power.amount < 0 && power.id.can_be_negative())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powers_stack_and_stay_sorted() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Weak, 1));
+        powers.apply(Power::new(PowerId::Strength, 2));
+        powers.apply(Power::new(PowerId::Weak, 2));
+
+        assert_eq!(powers.amount(PowerId::Weak), 3);
+        assert_eq!(powers.amount(PowerId::Strength), 2);
+        assert_eq!(powers.as_slice()[0].get_id(), PowerId::Strength);
+
+        // Applying in a different order gives the same state
+        let mut other = Powers::new();
+        other.apply(Power::new(PowerId::Strength, 2));
+        other.apply(Power::new(PowerId::Weak, 3));
+        assert_eq!(powers, other);
+    }
+
+    #[test]
+    fn test_negative_strength_is_a_debuff() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Strength, -2));
+        assert_eq!(powers.get(PowerId::Strength).unwrap().power_type(), PowerType::Debuff);
+
+        powers.apply(Power::new(PowerId::Strength, 2));
+        assert!(!powers.has(PowerId::Strength));
+
+        // Debuffs cannot go negative
+        powers.apply(Power::new(PowerId::Weak, -1));
+        assert!(!powers.has(PowerId::Weak));
+    }
+
+    #[test]
+    fn test_turn_based_powers_count_down() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Vulnerable, 2));
+        powers.apply(Power::new(PowerId::Intangible, 1));
+
+        powers.tick(PowerDuration::Turns);
+        assert_eq!(powers.amount(PowerId::Vulnerable), 1);
+        assert_eq!(powers.amount(PowerId::Intangible), 1);
+
+        powers.tick(PowerDuration::Turns);
+        powers.tick(PowerDuration::TurnsAtTurnStart);
+        assert!(powers.as_slice().is_empty());
+    }
+
+    #[test]
+    fn test_this_turn_power_fires_then_expires() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::LoseStrength, 2));
+
+        let effects = powers.on_event(&BattleEvent::EndOfTurn { entity: Entity::Player }, Entity::Player);
+        assert_eq!(effects, vec![BattleEffect::LoseStrength(2).targeting(TargetSelector::Source)]);
+        assert!(!powers.has(PowerId::LoseStrength));
+    }
+
+    #[test]
+    fn test_hooks_only_fire_for_owner() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Metallicize, 3));
+
+        let enemy_end = powers.on_event(&BattleEvent::EndOfTurn { entity: Entity::Enemy(0) }, Entity::Player);
+        assert!(enemy_end.is_empty());
+
+        let enemy_owned = powers.on_event(&BattleEvent::EndOfTurn { entity: Entity::Enemy(0) }, Entity::Enemy(0));
+        assert_eq!(enemy_owned, vec![BattleEffect::GainDefense { amount: 3 }]);
+    }
+
+    #[test]
+    fn test_flame_barrier_lasts_until_next_turn() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FlameBarrier, 4));
+
+        // Retaliation is dealt by the damage path, not by a hook
+        let hit = BattleEvent::DamageTaken { target: Entity::Player, amount: 5, source: Entity::Enemy(0) };
+        assert!(powers.on_event(&hit, Entity::Player).is_empty());
+        assert!(powers.has(PowerId::FlameBarrier));
+
+        powers.on_event(&BattleEvent::StartOfPlayerTurn, Entity::Player);
+        assert!(!powers.has(PowerId::FlameBarrier));
+    }
+}
@@ -1,18 +1,63 @@
 use super::Battle;
-use crate::game::{effect::BaseEffect, global_info::GlobalInfo};
-use crate::battle::target::Entity;
+use super::battle_events::BattleEvent;
+use crate::game::{card::Card, card_type::CardType, effect::BaseEffect, global_info::GlobalInfo};
+use crate::battle::{power::PowerId, target::Entity};
+use crate::relics::{ice_cream, runic_pyramid, snecko_eye};
+use rand::Rng;
+
+/// Cards drawn at the start of every turn before relics
+const BASE_CARDS_PER_TURN: usize = 5;
+
+/// Energy gained at the start of every turn before relics
+pub const BASE_ENERGY_PER_TURN: u32 = 3;
 
 impl Battle {
+    /// Energy the player gets at the start of every turn: the base plus boss energy relics
+    /// Next-turn gains and losses (Energized, Deva Form, Energy Down) come from powers on top of this
+    pub fn energy_per_turn(&self) -> u32 {
+        BASE_ENERGY_PER_TURN + self.relics.iter().map(|relic| relic.energy_per_turn_bonus()).sum::<u32>()
+    }
+
+    /// Refill energy for the new turn, keeping what was left over when Ice Cream or Conserve applies
+    fn refill_energy(&mut self) {
+        let conserved = if ice_cream::conserves_energy(&self.relics) || self.player.battle_info.has_power(PowerId::Conserve) {
+            self.player.get_energy()
+        } else {
+            0
+        };
+        self.player.battle_info.energy = conserved + self.energy_per_turn();
+    }
+
     /// Full turn start including card draw with deck reshuffling
     pub(crate) fn at_start_of_player_turn(&mut self, rng: &mut impl rand::Rng) {
-        self.player.at_start_of_turn();
+        self.begin_player_turn();
 
         // Sample enemy actions for this turn
         self.sample_enemy_actions(rng);
 
+        self.draw_turn_hand();
+    }
+
+    /// Turn start before the enemies pick their intents: block, energy and turn counters
+    pub(in crate::battle) fn begin_player_turn(&mut self) {
+        self.player.at_start_of_turn();
+        self.refill_energy();
+        self.counters.start_turn();
+    }
+
+    /// Turn start after the enemies picked their intents: the draw, then start-of-turn powers
+    pub(in crate::battle) fn draw_turn_hand(&mut self) {
         // Draw new hand (typically 5 cards)
         // Note: Innate cards go to discard at end of turn and are drawn normally like other cards
-        self.cards.draw_n(5);
+        self.draw_cards(self.cards_per_turn());
+
+        // Start-of-turn powers (e.g. Magnetism, Mayhem) trigger after the draw
+        self.emit_event(super::battle_events::BattleEvent::StartOfPlayerTurn);
+    }
+
+    /// Cards drawn at the start of every turn
+    pub(in crate::battle) fn cards_per_turn(&self) -> usize {
+        snecko_eye::cards_per_turn(BASE_CARDS_PER_TURN, &self.relics)
     }
 
     /// Initialize the first turn of battle (draw cards with innate, sample enemy actions, but don't reset block)
@@ -24,11 +69,79 @@ impl Battle {
 
         // Draw initial hand with innate cards
         // Innate cards are always in the starting hand at the beginning of combat
-        self.cards.draw_initial_hand(5);
+        self.cards.draw_initial_hand(self.cards_per_turn(), &mut self.rng);
+
+        // No powers are active yet, so the opening hand's draw hooks can run after the whole hand is drawn
+        for hand_index in 0..self.cards.hand_size() {
+            self.confuse_card_in_hand(hand_index);
+        }
+        let opening_hand = self.cards.get_hand().clone();
+        for card in &opening_hand {
+            self.on_card_drawn(card);
+        }
+
+        self.emit_event(super::battle_events::BattleEvent::StartOfPlayerTurn);
+    }
+
+    /// Draw cards one at a time, so that on-draw effects (Void) and CardDrawn listeners
+    /// (Evolve, Fire Breathing) resolve before the next card is drawn.
+    /// Drawing stops early when the hand is full or the player has No Draw.
+    /// Returns the number of cards actually drawn
+    pub(in crate::battle) fn draw_cards(&mut self, n: usize) -> usize {
+        if self.player.battle_info.has_power(PowerId::NoDraw) {
+            return 0;
+        }
+
+        let mut cards_drawn = 0;
+        for _ in 0..n {
+            let Some(card) = self.cards.draw_card(&mut self.rng) else {
+                break;
+            };
+            self.confuse_card_in_hand(self.cards.hand_size() - 1);
+            self.on_card_drawn(&card);
+            cards_drawn += 1;
+        }
+        cards_drawn
+    }
+
+    /// Confused (Snecko Eye) gives a freshly drawn card a random cost from 0 to 3 for the rest of combat
+    fn confuse_card_in_hand(&mut self, hand_index: usize) {
+        if !self.player.battle_info.has_power(PowerId::Confused) {
+            return;
+        }
+        let Some(card) = self.cards.get_hand().get(hand_index) else {
+            return;
+        };
+        // Corrupted Skills keep costing 0
+        let corrupted = self.cards.is_corruption_active() && card.get_card_type() == CardType::Skill;
+        if card.is_playable() && !card.is_x_cost() && !corrupted {
+            let cost = self.rng.random_range(0..=3);
+            self.cards.set_hand_card_combat_cost(hand_index, cost);
+        }
+    }
+
+    /// Add a card created or moved during combat to the hand
+    /// If the hand is full the card goes to the discard pile instead
+    pub(in crate::battle) fn add_card_to_hand(&mut self, card: Card) {
+        let card_enum = card.get_card_enum();
+        if !self.cards.add_card_to_hand(card) {
+            self.emit_event(BattleEvent::CardOverflowedToDiscard { card: card_enum });
+        }
     }
 
-    /// End the current player turn and start the enemy turn (for testing)
+    /// Emit CardDrawn, which carries the card's own on-draw effects
+    fn on_card_drawn(&mut self, card: &Card) {
+        let card_type = card.get_card_type();
+        self.emit_event(BattleEvent::CardDrawn {
+            card_type,
+            is_status_or_curse: matches!(card_type, CardType::Status | CardType::Curse),
+            on_draw: card.get_on_draw().cloned().unwrap_or_default(),
+        });
+    }
+
+    /// End the current player turn, play the enemy turn and start the next player turn
     pub fn end_turn(&mut self, rng: &mut impl rand::Rng, global_info: &GlobalInfo) {
+        self.rng.reseed(rng);
         self.at_end_of_player_turn();
         self.at_start_of_enemy_turn();
         self.process_enemy_effects(rng, global_info);
@@ -69,8 +182,17 @@ impl Battle {
             }
         }
 
-        // Discard all remaining cards in hand
-        self.cards.discard_entire_hand();
+        // Discard all remaining cards in hand, keeping the ones with Retain
+        // Runic Pyramid keeps the whole hand
+        if !runic_pyramid::keeps_hand(&self.relics) {
+            self.cards.discard_hand_except_retained();
+        }
+
+        // Establishment makes every card still in hand cheaper
+        let establishment = self.player.battle_info.get_power_amount(PowerId::Establishment);
+        if establishment > 0 {
+            self.cards.reduce_hand_combat_costs(establishment as u32);
+        }
     }
     
     /// Starts enemy turns - emits events and resets enemy block
@@ -144,10 +266,11 @@ impl Battle {
             }
             
             // Use stored effects - panic if none were stored (this should never happen)
-            let Some((_, stored_effects)) = self.enemy_actions.get_mut(i).and_then(|slot| slot.take()) else {
+            let Some((enemy_move, stored_effects)) = self.enemy_actions.get_mut(i).and_then(|slot| slot.take()) else {
                 // Newly spawned enemies may not have sampled actions yet; skip them this turn
                 continue;
             };
+            self.enemies[i].record_move_made(enemy_move);
             
             for effect in stored_effects {
                 let base_effect = BaseEffect::from_effect(effect, source, Entity::Player);
@@ -155,10 +278,11 @@ impl Battle {
             }
         }
         
-        // Apply all collected effects
+        // Queue all collected effects so retaliation (Thorns, Flame Barrier) queued in front resolves before the next one
         for effect in all_effects {
-            self.eval_base_effect(&effect);
+            self.queue_effect(effect);
         }
+        self.process_effect_queue();
     }
 }
 
@@ -337,7 +461,7 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
 
         // Draw the hand
-        battle.cards.draw_n(5);
+        battle.cards.draw_n(5, &mut rand::rng());
 
         // Count ethereal and non-ethereal cards in hand
         let hand = battle.cards.get_hand();
@@ -377,7 +501,7 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
 
         // Draw the hand
-        battle.cards.draw_n(5);
+        battle.cards.draw_n(5, &mut rand::rng());
 
         // Find and play Carnage if it's in hand
         let carnage_idx = battle.cards.get_hand().iter().position(|card| card.get_name() == "Carnage");
@@ -403,4 +527,371 @@ mod tests {
             assert_eq!(battle.cards.exhausted_size(), initial_exhausted + ethereal_in_hand);
         }
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_retained_cards_stay_in_hand_at_end_of_turn() {
+        use crate::cards::ironclad::{strike::strike, defend::defend, carnage::carnage};
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(strike().set_retain(true));
+        battle.add_card_to_hand_for_testing(defend());
+        // Ethereal still exhausts a retained card
+        battle.add_card_to_hand_for_testing(carnage().set_retain(true));
+
+        battle.at_end_of_player_turn();
+
+        assert_eq!(battle.cards.hand_size(), 1);
+        assert_eq!(battle.cards.get_hand()[0].get_name(), "Strike");
+        assert_eq!(battle.cards.discard_pile_size(), 1);
+        assert_eq!(battle.cards.exhausted_size(), 1);
+    }
+
+    #[test]
+    fn test_establishment_lowers_cost_of_retained_cards() {
+        use crate::cards::ironclad::{bludgeon::bludgeon, defend::defend};
+
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.eval_base_effect(&BaseEffect::ActivateEstablishment { source: Entity::Player, amount: 1 });
+        battle.add_card_to_hand_for_testing(bludgeon().set_retain(true));
+        battle.add_card_to_hand_for_testing(defend());
+
+        battle.at_end_of_player_turn();
+        assert_eq!(battle.cards.get_hand()[0].get_cost(), 2);
+        battle.at_end_of_player_turn();
+        assert_eq!(battle.cards.get_hand()[0].get_cost(), 1);
+        assert_eq!(battle.cards.get_discard_pile()[0].get_cost(), 1);
+    }
+
+    #[test]
+    fn test_retain_granted_to_hand_during_combat() {
+        use crate::cards::ironclad::{strike::strike, defend::defend};
+        use crate::game::{card_keyword::CardKeyword, card_type::CardType, effect::BaseEffect};
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(strike());
+        battle.add_card_to_hand_for_testing(defend());
+
+        battle.eval_base_effect(&BaseEffect::GrantKeywordToHand {
+            keyword: CardKeyword::Retain,
+            card_type: Some(CardType::Skill),
+        });
+        battle.at_end_of_player_turn();
+
+        assert_eq!(battle.cards.hand_size(), 1);
+        assert_eq!(battle.cards.get_hand()[0].get_name(), "Defend");
+        assert_eq!(battle.cards.discard_pile_size(), 1);
+    }
+
+    fn battle_with_relics(deck: Deck, relics: Vec<crate::relics::Relic>) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+        Battle::new(deck, global_info, PlayerRunState::new_with_relics(80, 80, 0, relics), enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_drawing_stops_when_hand_is_full() {
+        let mut battle = battle_with_relics(Deck::new(vec![strike(); 20]), vec![]);
+        for _ in 0..4 {
+            battle.add_card_to_hand_for_testing(strike());
+        }
+
+        assert_eq!(battle.draw_cards(3), 1);
+        assert_eq!(battle.cards.hand_size(), 10);
+        // The cards that could not be drawn stay in the draw pile
+        assert_eq!(battle.cards.deck_size(), 14);
+        assert_eq!(battle.cards.discard_pile_size(), 0);
+    }
+
+    #[test]
+    fn test_card_created_into_full_hand_goes_to_discard() {
+        use crate::game::{card_enum::CardEnum, effect::BaseEffect};
+
+        let mut battle = battle_with_relics(Deck::new(vec![strike(); 20]), vec![]);
+        for _ in 0..5 {
+            battle.add_card_to_hand_for_testing(strike());
+        }
+        battle.take_battle_events();
+
+        battle.eval_base_effect(&BaseEffect::AddCardToHand { source: Entity::Player, card: CardEnum::Wound });
+
+        assert_eq!(battle.cards.hand_size(), 10);
+        assert_eq!(battle.cards.get_discard_pile()[0].get_name(), "Wound");
+        assert_eq!(battle.take_battle_events(), vec![BattleEvent::CardOverflowedToDiscard { card: CardEnum::Wound }]);
+    }
+
+    #[test]
+    fn test_runic_pyramid_keeps_hand_at_end_of_turn() {
+        use crate::cards::ironclad::{defend::defend, carnage::carnage};
+
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![crate::relics::Relic::RunicPyramid]);
+        battle.add_card_to_hand_for_testing(strike());
+        battle.add_card_to_hand_for_testing(defend());
+        // Ethereal cards are still exhausted
+        battle.add_card_to_hand_for_testing(carnage());
+
+        battle.at_end_of_player_turn();
+
+        assert_eq!(battle.cards.hand_size(), 2);
+        assert_eq!(battle.cards.discard_pile_size(), 0);
+        assert_eq!(battle.cards.exhausted_size(), 1);
+    }
+
+    #[test]
+    fn test_snecko_eye_draws_two_more_and_confuses_costs() {
+        use crate::cards::ironclad::bash::bash;
+
+        let mut battle = battle_with_relics(Deck::new(vec![bash(); 20]), vec![crate::relics::Relic::SneckoEye]);
+
+        assert!(battle.get_player().battle_info.has_power(PowerId::Confused));
+        assert_eq!(battle.cards.hand_size(), 7);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.cards.hand_size(), 7);
+
+        // Every drawn card got a cost from 0 to 3, it is very unlikely all 14 kept Bash's cost of 2
+        let hand_and_discard: Vec<u32> = battle.cards.get_hand().iter()
+            .chain(battle.cards.get_discard_pile())
+            .map(|card| card.get_cost())
+            .collect();
+        assert!(hand_and_discard.iter().all(|&cost| cost <= 3));
+        assert!(hand_and_discard.iter().any(|&cost| cost != 2));
+    }
+
+    #[test]
+    fn test_confused_costs_follow_the_callers_rng() {
+        use crate::cards::ironclad::bash::bash;
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let confused_costs = |seed: u64| -> Vec<u32> {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+            let enemies = vec![EnemyInBattle::new(EnemyEnum::Cultist(crate::enemies::cultist::Cultist::new(50, 3)))];
+            let relics = vec![crate::relics::Relic::SneckoEye];
+            let mut battle = Battle::new(Deck::new(vec![bash(); 20]), global_info, PlayerRunState::new_with_relics(80, 80, 0, relics), enemies, &mut rng);
+            battle.end_turn(&mut rng, &global_info);
+            battle.cards.get_hand().iter()
+                .chain(battle.cards.get_discard_pile())
+                .map(|card| card.get_cost())
+                .collect()
+        };
+
+        assert_eq!(confused_costs(3), confused_costs(3));
+    }
+
+    #[test]
+    fn test_boss_energy_relics_raise_energy_per_turn() {
+        use crate::relics::Relic;
+
+        let battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        assert_eq!(battle.energy_per_turn(), BASE_ENERGY_PER_TURN);
+        assert_eq!(battle.get_player().get_energy(), 3);
+
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![Relic::Ectoplasm, Relic::VelvetChoker]);
+        assert_eq!(battle.energy_per_turn(), 5);
+        assert_eq!(battle.get_player().get_energy(), 5);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 5);
+    }
+
+    #[test]
+    fn test_unspent_energy_is_lost_without_conservation() {
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.player.battle_info.spend_energy(1);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 3);
+    }
+
+    #[test]
+    fn test_ice_cream_conserves_energy_every_turn() {
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![crate::relics::Relic::IceCream]);
+        battle.player.battle_info.spend_energy(1);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 5);
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 8);
+    }
+
+    #[test]
+    fn test_conserve_keeps_energy_for_one_turn() {
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.eval_base_effect(&BaseEffect::ConserveEnergy { source: Entity::Player });
+        battle.player.battle_info.spend_energy(1);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 5);
+        assert!(!battle.get_player().battle_info.has_power(PowerId::Conserve));
+
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 3);
+    }
+
+    #[test]
+    fn test_energized_adds_energy_next_turn_only() {
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.eval_base_effect(&BaseEffect::GainEnergyNextTurn { source: Entity::Player, amount: 2 });
+        assert_eq!(battle.get_player().get_energy(), 3);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 5);
+        assert!(!battle.get_player().battle_info.has_power(PowerId::Energized));
+
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 3);
+    }
+
+    #[test]
+    fn test_energy_down_and_deva_form_change_turn_energy() {
+        use crate::battle::power::Power;
+
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.apply_power(Entity::Player, Power::new(PowerId::EnergyDown, 1));
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 2);
+
+        let mut battle = battle_with_relics(Deck::new(vec![]), vec![]);
+        battle.apply_power(Entity::Player, Power::new(PowerId::DevaForm, 1).with_extra_amount(1));
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 4);
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 5);
+    }
+
+    /// A Cultist opens with Incantation, so the first enemy turn does not touch the player
+    fn battle_against_cultist(deck: Deck, relics: Vec<crate::relics::Relic>) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let cultist = crate::enemies::cultist::Cultist::new(50, 3);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::Cultist(cultist))];
+        Battle::new(deck, global_info, PlayerRunState::new_with_relics(80, 80, 0, relics), enemies, &mut rng)
+    }
+
+    #[test]
+    fn test_demon_form_gains_strength_at_turn_start() {
+        use crate::battle::power::Power;
+
+        let mut battle = battle_against_cultist(Deck::new(vec![]), vec![]);
+        battle.apply_power(Entity::Player, Power::new(PowerId::DemonForm, 2));
+        assert_eq!(battle.get_player().get_strength(), 0);
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_strength(), 2);
+    }
+
+    #[test]
+    fn test_brutality_loses_hp_and_draws_at_turn_start() {
+        use crate::battle::power::Power;
+
+        let mut battle = battle_against_cultist(Deck::new(vec![strike(); 10]), vec![]);
+        battle.apply_power(Entity::Player, Power::new(PowerId::Brutality, 1));
+
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_current_hp(), 79);
+        assert_eq!(battle.get_hand().len(), 6);
+    }
+
+    #[test]
+    fn test_happy_flower_gives_energy_every_third_turn() {
+        let mut battle = battle_against_cultist(Deck::new(vec![]), vec![crate::relics::Relic::HappyFlower]);
+        let mut rng = rand::rng();
+        let global_info = battle.global_info;
+
+        // The opening turn is the first one
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 3);
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 4);
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_player().get_energy(), 3);
+    }
+
+    #[test]
+    fn test_philosophers_stone_gives_enemies_strength() {
+        let battle = battle_with_relics(Deck::new(vec![]), vec![crate::relics::Relic::PhilosophersStone]);
+        assert_eq!(battle.get_player().get_energy(), 4);
+        assert_eq!(battle.enemies[0].battle_info.get_strength(), 1);
+    }
+
+    #[test]
+    fn test_time_warp_ends_the_turn_after_the_last_card_resolves() {
+        use crate::battle::{battle_action::BattleAction, power::{Power, TIME_WARP_CARDS}};
+
+        let mut battle = battle_against_cultist(Deck::new(vec![strike(), strike()]), vec![]);
+        let mut rng = rand::rng();
+        battle.apply_power(Entity::Enemy(0), Power::new(PowerId::TimeWarp, 2));
+
+        battle.eval_action(BattleAction::PlayCard(0, Entity::Enemy(0)), &mut rng).unwrap();
+        assert_eq!(battle.get_player().get_energy(), 2);
+        assert_eq!(battle.enemies[0].battle_info.get_power_amount(PowerId::TimeWarp), 1);
+
+        let mut without_time_warp = battle.clone();
+        let global_info = battle.global_info;
+        without_time_warp.end_turn(&mut rng, &global_info);
+
+        // The last Strike still hits before the turn ends, then Time Eater gains 2 Strength
+        battle.eval_action(BattleAction::PlayCard(0, Entity::Enemy(0)), &mut rng).unwrap();
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), 38);
+        assert_eq!(battle.enemies[0].battle_info.get_strength(), without_time_warp.enemies[0].battle_info.get_strength() + 2);
+        assert_eq!(battle.enemies[0].battle_info.get_power_amount(PowerId::TimeWarp), TIME_WARP_CARDS);
+
+        // The next turn has started
+        assert_eq!(battle.get_player().get_energy(), 3);
+        assert_eq!(battle.get_hand().len(), 2);
+    }
+
+    #[test]
+    fn test_flame_barrier_hits_back_before_the_rest_of_the_move() {
+        use crate::battle::power::Power;
+        use crate::enemies::{enemy_enum::EnemyMove, jaw_worm::{JawWorm, JawWormMove}};
+        use crate::game::effect::BattleEffect;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(40, false)))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+        battle.apply_power(Entity::Player, Power::new(PowerId::FlameBarrier, 4));
+
+        // Thrash attacks and then gains 5 Block
+        let thrash = vec![
+            BattleEffect::AttackToTarget { amount: 7, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::GainDefense { amount: 5 },
+        ];
+        battle.enemy_actions[0] = Some((EnemyMove::JawWorm(JawWormMove::Thrash), thrash));
+        battle.end_turn(&mut rng, &global_info);
+
+        // The retaliation is resolved before Thrash's Block, so all of it hits HP
+        assert_eq!(battle.get_player().get_current_hp(), 73);
+        assert_eq!(battle.enemies[0].battle_info.get_hp(), 36);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Apotheosis - Colorless Skill Card (Rare)
+/// Cost: 2 (1 when upgraded)
+/// Effect: Upgrade ALL of your cards for the rest of combat. Exhaust
+pub fn apotheosis() -> Card {
+    Card::new(
+        CardEnum::Apotheosis,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::UpgradeAllCardsInCombat,
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn apotheosis_upgraded() -> Card {
+    Card::new(
+        CardEnum::Apotheosis,
+        1,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::UpgradeAllCardsInCombat,
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_apotheosis_creation() {
+        let card = apotheosis();
+
+        assert_eq!(card.get_name(), "Apotheosis");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects().len(), 2);
+        assert!(!card.is_upgraded());
+        assert!(card.is_playable());
+    }
+
+    #[test]
+    fn test_apotheosis_upgraded_creation() {
+        let card = apotheosis_upgraded();
+
+        assert_eq!(card.get_name(), "Apotheosis+");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_apotheosis_upgrades_every_pile() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let deck = Deck::new(vec![strike(), strike(), strike(), strike(), strike(), strike(), strike(), strike()]);
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(apotheosis());
+        let apotheosis_idx = battle.cards.hand_size() - 1;
+        battle.play_card(apotheosis_idx, Entity::Enemy(0)).unwrap();
+
+        assert!(battle.cards.get_hand().iter().all(|card| card.is_upgraded()));
+        assert!(battle.cards.get_deck().get_cards().iter().all(|card| card.is_upgraded()));
+
+        // Apotheosis itself is exhausted (and upgraded along with the rest of the exhaust pile)
+        assert_eq!(battle.cards.exhausted_size(), 1);
+        assert_eq!(battle.cards.get_exhausted()[0].get_card_enum(), CardEnum::Apotheosis);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Apparition - Colorless Skill Card (Special)
+/// Cost: 1
+/// Effect: Ethereal (not when upgraded). Gain 1 Intangible. Exhaust
+pub fn apparition() -> Card {
+    Card::new(
+        CardEnum::Apparition,
+        1,
+        CardClass::Colorless(Rarity::Special, CardType::Skill),
+        vec![
+            BattleEffect::GainIntangible { amount: 1 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_ethereal(true)
+        .set_playable(true)
+}
+
+pub fn apparition_upgraded() -> Card {
+    Card::new(
+        CardEnum::Apparition,
+        1,
+        CardClass::Colorless(Rarity::Special, CardType::Skill),
+        vec![
+            BattleEffect::GainIntangible { amount: 1 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_apparition_creation() {
+        let card = apparition();
+
+        assert_eq!(card.get_name(), "Apparition");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(card.is_ethereal());
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_apparition_upgraded_is_not_ethereal() {
+        let card = apparition_upgraded();
+
+        assert_eq!(card.get_name(), "Apparition+");
+        assert!(!card.is_ethereal());
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_apparition_grants_intangible() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(apparition());
+        battle.play_card(0, Entity::Player).unwrap();
+
+        assert!(battle.get_player().battle_info.is_intangible());
+    }
+}
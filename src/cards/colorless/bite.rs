@@ -0,0 +1,72 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Bite - Colorless Attack Card (Special)
+/// Cost: 1
+/// Effect: Deal 7 (8) damage. Heal 2 (3) HP
+pub fn bite() -> Card {
+    Card::new(
+        CardEnum::Bite,
+        1,
+        CardClass::Colorless(Rarity::Special, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 7, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::Heal(2),
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn bite_upgraded() -> Card {
+    Card::new(
+        CardEnum::Bite,
+        1,
+        CardClass::Colorless(Rarity::Special, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::Heal(3),
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_bite_creation() {
+        let card = bite();
+
+        assert_eq!(card.get_name(), "Bite");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Attack);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_bite_upgraded_creation() {
+        let card = bite_upgraded();
+
+        assert_eq!(card.get_name(), "Bite+");
+        assert_eq!(card.get_effects()[1], BattleEffect::Heal(3));
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_bite_heals_player() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(bite());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 37);
+        assert_eq!(battle.get_player().battle_info.get_hp(), 52);
+    }
+}
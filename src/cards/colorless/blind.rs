@@ -1,4 +1,4 @@
-use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::{BattleEffect, TargetSelector}, card::{Rarity, CardClass}};
 
 /// Blind - Colorless Skill Card
 /// Cost: 0 (0 when upgraded)
@@ -9,7 +9,7 @@ pub fn blind() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
         vec![
-            BattleEffect::ApplyWeakAll { duration: 2 },
+            BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies),
             BattleEffect::Exhaust,
         ]
     )
@@ -22,7 +22,7 @@ pub fn blind_upgraded() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
         vec![
-            BattleEffect::ApplyWeakAll { duration: 2 },
+            BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies),
             BattleEffect::Exhaust,
         ]
     )
@@ -65,13 +65,8 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be ApplyWeakAll
-        match &effects[0] {
-            BattleEffect::ApplyWeakAll { duration } => {
-                assert_eq!(*duration, 2);
-            }
-            _ => panic!("Expected ApplyWeakAll effect as first effect"),
-        }
+        // First effect should apply Weak to all enemies
+        assert_eq!(effects[0], BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies));
 
         // Second effect should be Exhaust
         assert_eq!(effects[1], BattleEffect::Exhaust);
@@ -84,13 +79,8 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be ApplyWeakAll (same as base)
-        match &effects[0] {
-            BattleEffect::ApplyWeakAll { duration } => {
-                assert_eq!(*duration, 2);
-            }
-            _ => panic!("Expected ApplyWeakAll effect as first effect"),
-        }
+        // First effect should apply Weak to all enemies (same as base)
+        assert_eq!(effects[0], BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies));
 
         // Second effect should be Exhaust (same as base)
         assert_eq!(effects[1], BattleEffect::Exhaust);
@@ -9,7 +9,7 @@ pub fn dark_shackles() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
         vec![
-            BattleEffect::LoseStrengthTarget(9),
+            BattleEffect::LoseStrength(9),
             BattleEffect::Exhaust,
         ]
     )
@@ -23,7 +23,7 @@ pub fn dark_shackles_upgraded() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
         vec![
-            BattleEffect::LoseStrengthTarget(15),
+            BattleEffect::LoseStrength(15),
             BattleEffect::Exhaust,
         ]
     )
@@ -43,7 +43,7 @@ mod tests {
         assert_eq!(card.get_name(), "Dark Shackles");
         assert_eq!(card.get_cost(), 0);
         assert_eq!(card.get_card_type(), CardType::Skill);
-        assert_eq!(card.get_effects().len(), 2); // LoseStrengthTarget and Exhaust
+        assert_eq!(card.get_effects().len(), 2); // LoseStrength and Exhaust
         assert!(!card.is_upgraded());
         assert!(card.is_playable());
     }
@@ -55,7 +55,7 @@ mod tests {
         assert_eq!(card.get_name(), "Dark Shackles+");
         assert_eq!(card.get_cost(), 0);
         assert_eq!(card.get_card_type(), CardType::Skill);
-        assert_eq!(card.get_effects().len(), 2); // LoseStrengthTarget and Exhaust
+        assert_eq!(card.get_effects().len(), 2); // LoseStrength and Exhaust
         assert!(card.is_upgraded());
         assert!(card.is_playable());
     }
@@ -67,12 +67,12 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be LoseStrengthTarget(9)
+        // First effect should be LoseStrength(9)
         match &effects[0] {
-            BattleEffect::LoseStrengthTarget(amount) => {
+            BattleEffect::LoseStrength(amount) => {
                 assert_eq!(*amount, 9);
             }
-            _ => panic!("Expected LoseStrengthTarget effect as first effect"),
+            _ => panic!("Expected LoseStrength effect as first effect"),
         }
 
         // Second effect should be Exhaust
@@ -100,12 +100,12 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be LoseStrengthTarget(15) - upgraded
+        // First effect should be LoseStrength(15) - upgraded
         match &effects[0] {
-            BattleEffect::LoseStrengthTarget(amount) => {
+            BattleEffect::LoseStrength(amount) => {
                 assert_eq!(*amount, 15);
             }
-            _ => panic!("Expected LoseStrengthTarget effect as first effect"),
+            _ => panic!("Expected LoseStrength effect as first effect"),
         }
 
         // Second effect should be Exhaust (same as base)
@@ -0,0 +1,69 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Discovery - Colorless Skill Card (Uncommon)
+/// Cost: 1
+/// Effect: Choose 1 of 3 random cards to add to your hand. It costs 0 this turn. Exhaust (no Exhaust when upgraded)
+pub fn discovery() -> Card {
+    Card::new(
+        CardEnum::Discovery,
+        1,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![
+            BattleEffect::AddRandomCardsToHand { num_choices: 3, num_copies: 1, cost: 0 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn discovery_upgraded() -> Card {
+    Card::new(
+        CardEnum::Discovery,
+        1,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![BattleEffect::AddRandomCardsToHand { num_choices: 3, num_copies: 1, cost: 0 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle, battle_state::BattleState};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_discovery_creation() {
+        let card = discovery();
+
+        assert_eq!(card.get_name(), "Discovery");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_discovery_upgraded_creation() {
+        let card = discovery_upgraded();
+
+        assert_eq!(card.get_name(), "Discovery+");
+        assert!(!card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_discovery_offers_a_choice() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(discovery());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert!(matches!(battle.get_battle_state(), BattleState::SelectCardFromChoices { .. }));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::{BattleEffect, TargetSelector}, card::{Rarity, CardClass}};
 
 /// Dramatic Entrance - Colorless Attack Card (Uncommon)
 /// Cost: 0 (0 when upgraded)
@@ -9,7 +9,7 @@ pub fn dramatic_entrance() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Attack),
         vec![
-            BattleEffect::AttackAllEnemies { amount: 8, num_attacks: 1 },
+            BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies),
             BattleEffect::Exhaust,
         ]
     )
@@ -23,7 +23,7 @@ pub fn dramatic_entrance_upgraded() -> Card {
         0,
         CardClass::Colorless(Rarity::Uncommon, CardType::Attack),
         vec![
-            BattleEffect::AttackAllEnemies { amount: 12, num_attacks: 1 }, // Increased to 12
+            BattleEffect::AttackToTarget { amount: 12, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies), // Increased to 12
             BattleEffect::Exhaust,
         ]
     )
@@ -69,14 +69,8 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be AttackAllEnemies(8, 1)
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 8);
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect as first effect"),
-        }
+        // First effect should hit all enemies for 8
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
 
         // Second effect should be Exhaust
         assert_eq!(effects[1], BattleEffect::Exhaust);
@@ -89,14 +83,8 @@ mod tests {
 
         assert_eq!(effects.len(), 2);
 
-        // First effect should be AttackAllEnemies(12, 1) - upgraded
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 12);
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect as first effect"),
-        }
+        // First effect should hit all enemies for 12 - upgraded
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 12, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
 
         // Second effect should be Exhaust (same as base)
         assert_eq!(effects[1], BattleEffect::Exhaust);
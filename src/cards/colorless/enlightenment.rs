@@ -0,0 +1,67 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Enlightenment - Colorless Skill Card (Uncommon)
+/// Cost: 0
+/// Effect: Reduce the cost of all cards in your hand to 1 this turn (this combat when upgraded)
+pub fn enlightenment() -> Card {
+    Card::new(
+        CardEnum::Enlightenment,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![BattleEffect::ReduceHandCostToOne { this_combat: false }]
+    )
+        .set_playable(true)
+}
+
+pub fn enlightenment_upgraded() -> Card {
+    Card::new(
+        CardEnum::Enlightenment,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![BattleEffect::ReduceHandCostToOne { this_combat: true }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::bludgeon::bludgeon;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_enlightenment_creation() {
+        let card = enlightenment();
+
+        assert_eq!(card.get_name(), "Enlightenment");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_enlightenment_upgraded_creation() {
+        let card = enlightenment_upgraded();
+
+        assert_eq!(card.get_name(), "Enlightenment+");
+        assert_eq!(card.get_effects()[0], BattleEffect::ReduceHandCostToOne { this_combat: true });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_enlightenment_reduces_costs() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(enlightenment());
+        battle.add_card_to_hand_for_testing(bludgeon());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_hand()[0].get_cost(), 1);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Forethought - Colorless Skill Card (Uncommon)
+/// Cost: 0
+/// Effect: Put a card (any number of cards when upgraded) from your hand to the bottom of your draw pile. It costs 0 until played
+pub fn forethought() -> Card {
+    Card::new(
+        CardEnum::Forethought,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![BattleEffect::EnterSelectCardsInHandToPutOnBottom { count: 1 }]
+    )
+        .set_playable(true)
+}
+
+pub fn forethought_upgraded() -> Card {
+    Card::new(
+        CardEnum::Forethought,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        // The hand never holds more than 10 cards, so this covers "any number"
+        vec![BattleEffect::EnterSelectCardsInHandToPutOnBottom { count: 10 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle, battle_action::BattleAction, battle_state::BattleState};
+    use crate::cards::ironclad::bludgeon::bludgeon;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_forethought_creation() {
+        let card = forethought();
+
+        assert_eq!(card.get_name(), "Forethought");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_forethought_upgraded_creation() {
+        let card = forethought_upgraded();
+
+        assert_eq!(card.get_name(), "Forethought+");
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_forethought_card_costs_zero_until_played() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(forethought());
+        battle.add_card_to_hand_for_testing(bludgeon());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert!(matches!(battle.get_battle_state(), BattleState::SelectCardInHand(_)));
+        battle.eval_action(BattleAction::SelectCardInHand(0), &mut rng).unwrap();
+
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+        assert!(battle.get_hand().is_empty());
+        let draw_pile = battle.cards.get_deck().get_cards();
+        assert_eq!(draw_pile.len(), 1);
+        assert_eq!(draw_pile[0].get_card_enum(), CardEnum::Bludgeon);
+        assert_eq!(draw_pile[0].get_cost(), 0);
+    }
+
+    #[test]
+    fn test_forethought_selection_can_be_confirmed_without_picking() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(forethought());
+        battle.add_card_to_hand_for_testing(bludgeon());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert!(battle.list_available_actions().contains(&BattleAction::ConfirmSelection));
+        battle.eval_action(BattleAction::ConfirmSelection, &mut rng).unwrap();
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+        assert_eq!(battle.get_hand().len(), 1);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Hand of Greed - Colorless Attack Card (Rare)
+/// Cost: 2 (2 when upgraded)
+/// Effect: Deal 20 (25) damage. If this kills a non-minion enemy, gain 20 (25) Gold
+pub fn hand_of_greed() -> Card {
+    Card::new(
+        CardEnum::HandOfGreed,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 20, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::GainGoldOnKill { amount: 20 },
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn hand_of_greed_upgraded() -> Card {
+    Card::new(
+        CardEnum::HandOfGreed,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 25, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::GainGoldOnKill { amount: 25 },
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_hand_of_greed_creation() {
+        let card = hand_of_greed();
+
+        assert_eq!(card.get_name(), "Hand of Greed");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Attack);
+        assert_eq!(card.get_effects().len(), 2);
+        assert_eq!(card.get_effects()[1], BattleEffect::GainGoldOnKill { amount: 20 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_hand_of_greed_upgraded_creation() {
+        let card = hand_of_greed_upgraded();
+
+        assert_eq!(card.get_name(), "Hand of Greed+");
+        assert_eq!(card.get_effects()[1], BattleEffect::GainGoldOnKill { amount: 25 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_hand_of_greed_gains_gold_on_kill() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(15, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(hand_of_greed());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_gold_gained(), 20);
+        let final_state = battle.get_final_player_run_state(100, vec![]);
+        assert_eq!(final_state.gold, 120);
+    }
+
+    #[test]
+    fn test_hand_of_greed_no_gold_without_kill() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(hand_of_greed());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_gold_gained(), 0);
+    }
+}
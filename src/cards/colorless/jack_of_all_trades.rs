@@ -0,0 +1,64 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Jack of All Trades - Colorless Skill Card (Uncommon)
+/// Cost: 0
+/// Effect: Add 1 (2) random Colorless card(s) to your hand. Exhaust
+pub fn jack_of_all_trades() -> Card {
+    Card::new(
+        CardEnum::JackOfAllTrades,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![
+            BattleEffect::AddRandomColorlessCardToHand { count: 1 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn jack_of_all_trades_upgraded() -> Card {
+    Card::new(
+        CardEnum::JackOfAllTrades,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![
+            BattleEffect::AddRandomColorlessCardToHand { count: 2 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_jack_of_all_trades_creation() {
+        let card = jack_of_all_trades();
+
+        assert_eq!(card.get_name(), "Jack of All Trades");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_jack_of_all_trades_upgraded_adds_two_cards() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(jack_of_all_trades_upgraded());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let hand = battle.get_hand();
+        assert_eq!(hand.len(), 2);
+        assert!(hand.iter().all(|c| matches!(c.get_card_class(), CardClass::Colorless(_, _))));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// J.A.X. - Colorless Skill Card (Special)
+/// Cost: 0
+/// Effect: Lose 3 HP. Gain 2 (3) Strength
+pub fn jax() -> Card {
+    Card::new(
+        CardEnum::Jax,
+        0,
+        CardClass::Colorless(Rarity::Special, CardType::Skill),
+        vec![
+            BattleEffect::LoseHp(3),
+            BattleEffect::GainStrength { amount: 2 },
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn jax_upgraded() -> Card {
+    Card::new(
+        CardEnum::Jax,
+        0,
+        CardClass::Colorless(Rarity::Special, CardType::Skill),
+        vec![
+            BattleEffect::LoseHp(3),
+            BattleEffect::GainStrength { amount: 3 },
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jax_creation() {
+        let card = jax();
+
+        assert_eq!(card.get_name(), "J.A.X.");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects()[0], BattleEffect::LoseHp(3));
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_jax_upgraded_creation() {
+        let card = jax_upgraded();
+
+        assert_eq!(card.get_name(), "J.A.X.+");
+        assert_eq!(card.get_effects()[1], BattleEffect::GainStrength { amount: 3 });
+        assert!(card.is_upgraded());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Madness - Colorless Skill Card (Uncommon)
+/// Cost: 1 (0 when upgraded)
+/// Effect: A random card in your hand costs 0 for the rest of combat. Exhaust
+pub fn madness() -> Card {
+    Card::new(
+        CardEnum::Madness,
+        1,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        // Exhaust first so Madness never picks itself
+        vec![
+            BattleEffect::Exhaust,
+            BattleEffect::MakeRandomCardInHandFree,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn madness_upgraded() -> Card {
+    Card::new(
+        CardEnum::Madness,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![
+            BattleEffect::Exhaust,
+            BattleEffect::MakeRandomCardInHandFree,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::bludgeon::bludgeon;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_madness_creation() {
+        let card = madness();
+
+        assert_eq!(card.get_name(), "Madness");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_madness_upgraded_creation() {
+        let card = madness_upgraded();
+
+        assert_eq!(card.get_name(), "Madness+");
+        assert_eq!(card.get_cost(), 0);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_madness_makes_card_free_for_combat() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(madness());
+        battle.add_card_to_hand_for_testing(bludgeon());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let hand = battle.get_hand();
+        assert_eq!(hand.len(), 1);
+        assert_eq!(hand[0].get_card_enum(), CardEnum::Bludgeon);
+        assert_eq!(hand[0].get_cost(), 0);
+    }
+}
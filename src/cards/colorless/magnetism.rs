@@ -0,0 +1,129 @@
+use serde::{Serialize, Deserialize};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
+
+/// Magnetism Power Listener
+/// At the start of each turn, add a random Colorless card to your hand.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagnetismListener {
+    owner: Entity,
+    cards_per_turn: u32,
+}
+
+impl MagnetismListener {
+    pub fn new(owner: Entity, cards_per_turn: u32) -> Self {
+        MagnetismListener {
+            owner,
+            cards_per_turn,
+        }
+    }
+}
+
+impl EventListener for MagnetismListener {
+    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
+        match event {
+            BattleEvent::StartOfPlayerTurn if self.owner == Entity::Player => {
+                vec![BattleEffect::AddRandomColorlessCardToHand { count: self.cards_per_turn }]
+            }
+            _ => vec![]
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        true // Magnetism is always active once played
+    }
+
+    fn get_owner(&self) -> Entity {
+        self.owner
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
+        use std::hash::Hash;
+        self.hash(state);
+    }
+}
+
+/// Magnetism - Colorless Power Card (Rare)
+/// Cost: 2 (1 when upgraded)
+/// Effect: At the start of each turn, add a random Colorless card to your hand
+pub fn magnetism() -> Card {
+    Card::new(
+        CardEnum::Magnetism,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateMagnetism { cards_per_turn: 1 }]
+    )
+        .set_playable(true)
+}
+
+pub fn magnetism_upgraded() -> Card {
+    Card::new(
+        CardEnum::Magnetism,
+        1,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateMagnetism { cards_per_turn: 1 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_magnetism_creation() {
+        let card = magnetism();
+
+        assert_eq!(card.get_name(), "Magnetism");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Power);
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateMagnetism { cards_per_turn: 1 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_magnetism_upgraded_creation() {
+        let card = magnetism_upgraded();
+
+        assert_eq!(card.get_name(), "Magnetism+");
+        assert_eq!(card.get_cost(), 1);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_magnetism_listener_triggers_on_turn_start() {
+        let mut listener = MagnetismListener::new(Entity::Player, 1);
+
+        let effects = listener.on_event(&BattleEvent::StartOfPlayerTurn);
+        assert_eq!(effects, vec![BattleEffect::AddRandomColorlessCardToHand { count: 1 }]);
+
+        let effects = listener.on_event(&BattleEvent::EndOfTurn { entity: Entity::Player });
+        assert!(effects.is_empty());
+        assert!(listener.is_active());
+    }
+
+    #[test]
+    fn test_magnetism_adds_colorless_card_next_turn() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(magnetism());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        battle.end_turn(&mut rng, &global_info);
+
+        let hand = battle.get_hand();
+        assert_eq!(hand.len(), 1);
+        assert!(matches!(hand[0].get_card_class(), CardClass::Colorless(_, _)));
+    }
+}
@@ -0,0 +1,130 @@
+use serde::{Serialize, Deserialize};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
+
+/// Mayhem Power Listener
+/// At the start of your turn, play the top card of your draw pile.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MayhemListener {
+    owner: Entity,
+    cards_per_turn: u32,
+}
+
+impl MayhemListener {
+    pub fn new(owner: Entity, cards_per_turn: u32) -> Self {
+        MayhemListener {
+            owner,
+            cards_per_turn,
+        }
+    }
+}
+
+impl EventListener for MayhemListener {
+    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
+        match event {
+            BattleEvent::StartOfPlayerTurn if self.owner == Entity::Player => {
+                (0..self.cards_per_turn).map(|_| BattleEffect::PlayTopCardForFree).collect()
+            }
+            _ => vec![]
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        true // Mayhem is always active once played
+    }
+
+    fn get_owner(&self) -> Entity {
+        self.owner
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
+        use std::hash::Hash;
+        self.hash(state);
+    }
+}
+
+/// Mayhem - Colorless Power Card (Rare)
+/// Cost: 2 (1 when upgraded)
+/// Effect: At the start of your turn, play the top card of your draw pile
+pub fn mayhem() -> Card {
+    Card::new(
+        CardEnum::Mayhem,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateMayhem { cards_per_turn: 1 }]
+    )
+        .set_playable(true)
+}
+
+pub fn mayhem_upgraded() -> Card {
+    Card::new(
+        CardEnum::Mayhem,
+        1,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateMayhem { cards_per_turn: 1 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_mayhem_creation() {
+        let card = mayhem();
+
+        assert_eq!(card.get_name(), "Mayhem");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Power);
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateMayhem { cards_per_turn: 1 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_mayhem_upgraded_creation() {
+        let card = mayhem_upgraded();
+
+        assert_eq!(card.get_name(), "Mayhem+");
+        assert_eq!(card.get_cost(), 1);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_mayhem_listener_triggers_on_turn_start() {
+        let mut listener = MayhemListener::new(Entity::Player, 1);
+
+        let effects = listener.on_event(&BattleEvent::StartOfPlayerTurn);
+        assert_eq!(effects, vec![BattleEffect::PlayTopCardForFree]);
+    }
+
+    #[test]
+    fn test_mayhem_plays_top_card_for_free() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        // Six Strikes: five are drawn into the opening hand, one stays on the draw pile
+        let deck = Deck::new(vec![strike(), strike(), strike(), strike(), strike(), strike()]);
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(mayhem());
+        let mayhem_idx = battle.cards.hand_size() - 1;
+        battle.play_card(mayhem_idx, Entity::Enemy(0)).unwrap();
+        let hp_before_turn = battle.get_enemies()[0].get_current_hp();
+
+        battle.end_turn(&mut rng, &global_info);
+
+        // The top Strike was played for free at the start of the turn
+        assert_eq!(battle.get_player().get_energy(), 3);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), hp_before_turn - 6);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Metamorphosis - Colorless Skill Card (Rare)
+/// Cost: 2 (2 when upgraded)
+/// Effect: Shuffle 3 (5 when upgraded) random Attacks into your draw pile. They cost 0 this combat. Exhaust
+pub fn metamorphosis() -> Card {
+    Card::new(
+        CardEnum::Metamorphosis,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::ShuffleRandomCardsIntoDrawPile { card_type: CardType::Attack, count: 3 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn metamorphosis_upgraded() -> Card {
+    Card::new(
+        CardEnum::Metamorphosis,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::ShuffleRandomCardsIntoDrawPile { card_type: CardType::Attack, count: 5 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_metamorphosis_creation() {
+        let card = metamorphosis();
+
+        assert_eq!(card.get_name(), "Metamorphosis");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects()[0], BattleEffect::ShuffleRandomCardsIntoDrawPile { card_type: CardType::Attack, count: 3 });
+        assert!(!card.is_upgraded());
+        assert!(card.is_playable());
+    }
+
+    #[test]
+    fn test_metamorphosis_upgraded_creation() {
+        let card = metamorphosis_upgraded();
+
+        assert_eq!(card.get_name(), "Metamorphosis+");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_effects()[0], BattleEffect::ShuffleRandomCardsIntoDrawPile { card_type: CardType::Attack, count: 5 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_metamorphosis_shuffles_free_attacks_into_draw_pile() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(metamorphosis());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let draw_pile = battle.cards.get_deck().get_cards();
+        assert_eq!(draw_pile.len(), 3);
+        for card in draw_pile {
+            assert_eq!(card.get_card_type(), CardType::Attack);
+            assert_eq!(card.get_cost(), 0);
+        }
+        assert_eq!(battle.cards.exhausted_size(), 1);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Mind Blast - Colorless Attack Card (Uncommon)
+/// Cost: 2 (1 when upgraded)
+/// Effect: Innate. Deal damage equal to the number of cards in your draw pile
+pub fn mind_blast() -> Card {
+    Card::new(
+        CardEnum::MindBlast,
+        2,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Attack),
+        vec![BattleEffect::AttackToTargetWithDrawPileSize]
+    )
+        .set_innate(true)
+        .set_playable(true)
+}
+
+pub fn mind_blast_upgraded() -> Card {
+    Card::new(
+        CardEnum::MindBlast,
+        1,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Attack),
+        vec![BattleEffect::AttackToTargetWithDrawPileSize]
+    )
+        .set_innate(true)
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_mind_blast_creation() {
+        let card = mind_blast();
+
+        assert_eq!(card.get_name(), "Mind Blast");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Attack);
+        assert!(card.is_innate());
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_mind_blast_upgraded_creation() {
+        let card = mind_blast_upgraded();
+
+        assert_eq!(card.get_name(), "Mind Blast+");
+        assert_eq!(card.get_cost(), 1);
+        assert!(card.is_innate());
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_mind_blast_damage_scales_with_draw_pile() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let deck = Deck::new(vec![strike(); 12]);
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        // 12 cards minus the opening hand of 5
+        battle.add_card_to_hand_for_testing(mind_blast());
+        battle.play_card(5, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 44 - 7);
+    }
+}
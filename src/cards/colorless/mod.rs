@@ -13,6 +13,32 @@ pub mod impatience;
 pub mod panic_button;
 pub mod panacea;
 pub mod dramatic_entrance;
+pub mod discovery;
+pub mod enlightenment;
+pub mod forethought;
+pub mod jack_of_all_trades;
+pub mod madness;
+pub mod mind_blast;
+pub mod purity;
+pub mod apotheosis;
+pub mod chrysalis;
+pub mod hand_of_greed;
+pub mod magnetism;
+pub mod mayhem;
+pub mod metamorphosis;
+pub mod panache;
+pub mod sadistic_nature;
+pub mod secret_technique;
+pub mod secret_weapon;
+pub mod the_bomb;
+pub mod thinking_ahead;
+pub mod transmutation;
+pub mod violence;
+pub mod apparition;
+pub mod bite;
+pub mod jax;
+pub mod ritual_dagger;
+pub mod shiv;
 
 // Re-export all colorless cards
 pub use good_instincts::good_instincts;
@@ -43,3 +69,55 @@ pub use panacea::panacea;
 pub use panacea::panacea_upgraded;
 pub use dramatic_entrance::dramatic_entrance;
 pub use dramatic_entrance::dramatic_entrance_upgraded;
+pub use discovery::discovery;
+pub use discovery::discovery_upgraded;
+pub use enlightenment::enlightenment;
+pub use enlightenment::enlightenment_upgraded;
+pub use forethought::forethought;
+pub use forethought::forethought_upgraded;
+pub use jack_of_all_trades::jack_of_all_trades;
+pub use jack_of_all_trades::jack_of_all_trades_upgraded;
+pub use madness::madness;
+pub use madness::madness_upgraded;
+pub use mind_blast::mind_blast;
+pub use mind_blast::mind_blast_upgraded;
+pub use purity::purity;
+pub use purity::purity_upgraded;
+pub use apotheosis::apotheosis;
+pub use apotheosis::apotheosis_upgraded;
+pub use chrysalis::chrysalis;
+pub use chrysalis::chrysalis_upgraded;
+pub use hand_of_greed::hand_of_greed;
+pub use hand_of_greed::hand_of_greed_upgraded;
+pub use magnetism::magnetism;
+pub use magnetism::magnetism_upgraded;
+pub use mayhem::mayhem;
+pub use mayhem::mayhem_upgraded;
+pub use metamorphosis::metamorphosis;
+pub use metamorphosis::metamorphosis_upgraded;
+pub use panache::panache;
+pub use panache::panache_upgraded;
+pub use sadistic_nature::sadistic_nature;
+pub use sadistic_nature::sadistic_nature_upgraded;
+pub use secret_technique::secret_technique;
+pub use secret_technique::secret_technique_upgraded;
+pub use secret_weapon::secret_weapon;
+pub use secret_weapon::secret_weapon_upgraded;
+pub use the_bomb::the_bomb;
+pub use the_bomb::the_bomb_upgraded;
+pub use thinking_ahead::thinking_ahead;
+pub use thinking_ahead::thinking_ahead_upgraded;
+pub use transmutation::transmutation;
+pub use transmutation::transmutation_upgraded;
+pub use violence::violence;
+pub use violence::violence_upgraded;
+pub use apparition::apparition;
+pub use apparition::apparition_upgraded;
+pub use bite::bite;
+pub use bite::bite_upgraded;
+pub use jax::jax;
+pub use jax::jax_upgraded;
+pub use ritual_dagger::ritual_dagger;
+pub use ritual_dagger::ritual_dagger_upgraded;
+pub use shiv::shiv;
+pub use shiv::shiv_upgraded;
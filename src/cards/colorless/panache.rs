@@ -0,0 +1,139 @@
+use serde::{Serialize, Deserialize};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
+
+/// Panache Power Listener
+/// Every time you play 5 cards in a single turn, deal damage to ALL enemies.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PanacheListener {
+    owner: Entity,
+    damage: u32,
+    cards_played_this_turn: u32,
+}
+
+impl PanacheListener {
+    pub fn new(owner: Entity, damage: u32) -> Self {
+        PanacheListener {
+            owner,
+            damage,
+            cards_played_this_turn: 0,
+        }
+    }
+}
+
+impl EventListener for PanacheListener {
+    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
+        match event {
+            BattleEvent::StartOfPlayerTurn => {
+                self.cards_played_this_turn = 0;
+                vec![]
+            }
+            BattleEvent::CardPlayed { source, .. } if *source == self.owner => {
+                self.cards_played_this_turn += 1;
+                if self.cards_played_this_turn >= 5 {
+                    self.cards_played_this_turn = 0;
+                    vec![BattleEffect::DealDamageToAllEnemies { amount: self.damage }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![]
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        true // Panache is always active once played
+    }
+
+    fn get_owner(&self) -> Entity {
+        self.owner
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
+        use std::hash::Hash;
+        self.hash(state);
+    }
+}
+
+/// Panache - Colorless Power Card (Rare)
+/// Cost: 0
+/// Effect: Every time you play 5 cards in a single turn, deal 10 (14) damage to ALL enemies
+pub fn panache() -> Card {
+    Card::new(
+        CardEnum::Panache,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivatePanache { damage: 10 }]
+    )
+        .set_playable(true)
+}
+
+pub fn panache_upgraded() -> Card {
+    Card::new(
+        CardEnum::Panache,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivatePanache { damage: 14 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panache_creation() {
+        let card = panache();
+
+        assert_eq!(card.get_name(), "Panache");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Power);
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivatePanache { damage: 10 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_panache_upgraded_creation() {
+        let card = panache_upgraded();
+
+        assert_eq!(card.get_name(), "Panache+");
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivatePanache { damage: 14 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_panache_triggers_every_fifth_card() {
+        let mut listener = PanacheListener::new(Entity::Player, 10);
+        let played = BattleEvent::CardPlayed { source: Entity::Player, card_type: CardType::Attack, x_value: None };
+
+        for _ in 0..4 {
+            assert!(listener.on_event(&played).is_empty());
+        }
+        assert_eq!(listener.on_event(&played), vec![BattleEffect::DealDamageToAllEnemies { amount: 10 }]);
+
+        // Counter restarts after triggering
+        for _ in 0..4 {
+            assert!(listener.on_event(&played).is_empty());
+        }
+        assert_eq!(listener.on_event(&played).len(), 1);
+    }
+
+    #[test]
+    fn test_panache_counter_resets_each_turn() {
+        let mut listener = PanacheListener::new(Entity::Player, 10);
+        let played = BattleEvent::CardPlayed { source: Entity::Player, card_type: CardType::Skill, x_value: None };
+
+        for _ in 0..4 {
+            listener.on_event(&played);
+        }
+        listener.on_event(&BattleEvent::StartOfPlayerTurn);
+
+        assert!(listener.on_event(&played).is_empty());
+    }
+}
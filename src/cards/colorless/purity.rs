@@ -0,0 +1,98 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Purity - Colorless Skill Card (Uncommon)
+/// Cost: 0
+/// Effect: Exhaust up to 3 (5) cards in your hand. Exhaust
+pub fn purity() -> Card {
+    Card::new(
+        CardEnum::Purity,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        // Exhaust first so Purity is not offered as one of its own choices
+        vec![
+            BattleEffect::Exhaust,
+            BattleEffect::EnterSelectCardsInHandToExhaust { count: 3 },
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn purity_upgraded() -> Card {
+    Card::new(
+        CardEnum::Purity,
+        0,
+        CardClass::Colorless(Rarity::Uncommon, CardType::Skill),
+        vec![
+            BattleEffect::Exhaust,
+            BattleEffect::EnterSelectCardsInHandToExhaust { count: 5 },
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle, battle_action::BattleAction, battle_state::BattleState};
+    use crate::cards::status::wound::wound;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_purity_creation() {
+        let card = purity();
+
+        assert_eq!(card.get_name(), "Purity");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_purity_upgraded_creation() {
+        let card = purity_upgraded();
+
+        assert_eq!(card.get_name(), "Purity+");
+        assert_eq!(card.get_effects()[1], BattleEffect::EnterSelectCardsInHandToExhaust { count: 5 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_purity_exhausts_multiple_cards() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(purity());
+        battle.add_card_to_hand_for_testing(wound());
+        battle.add_card_to_hand_for_testing(wound());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert!(matches!(battle.get_battle_state(), BattleState::SelectCardInHand(_)));
+        battle.eval_action(BattleAction::SelectCardInHand(0), &mut rng).unwrap();
+        battle.eval_action(BattleAction::SelectCardInHand(0), &mut rng).unwrap();
+
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+        assert!(battle.get_hand().is_empty());
+        assert_eq!(battle.cards.get_exhausted().len(), 3);
+    }
+
+    #[test]
+    fn test_purity_selection_can_be_confirmed_without_picking() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(purity());
+        battle.add_card_to_hand_for_testing(wound());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert!(battle.list_available_actions().contains(&BattleAction::ConfirmSelection));
+        battle.eval_action(BattleAction::ConfirmSelection, &mut rng).unwrap();
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+        assert_eq!(battle.get_hand().len(), 1);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Ritual Dagger - Colorless Attack Card (Special)
+/// Cost: 1
+/// Effect: Deal 15 damage. If this kills an enemy, permanently increase this card's damage by 3 (5). Exhaust
+pub fn ritual_dagger() -> Card {
+    ritual_dagger_with_damage(15, false)
+}
+
+pub fn ritual_dagger_upgraded() -> Card {
+    ritual_dagger_with_damage(15, true)
+}
+
+/// Build a Ritual Dagger that deals the given base damage
+pub fn ritual_dagger_with_damage(damage: u32, upgraded: bool) -> Card {
+    let increase = if upgraded { 5 } else { 3 };
+    Card::new(
+        CardEnum::RitualDagger,
+        1,
+        CardClass::Colorless(Rarity::Special, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: damage, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::IncreaseDamageOnKill { amount: increase },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(upgraded)
+        .set_playable(true)
+}
+
+/// Current base damage of a Ritual Dagger card
+fn ritual_dagger_damage(card: &Card) -> u32 {
+    card.get_effects().iter()
+        .find_map(|effect| match effect {
+            BattleEffect::AttackToTarget { amount, .. } => Some(*amount),
+            _ => None,
+        })
+        .unwrap_or(15)
+}
+
+/// Return a copy of the Ritual Dagger with its damage increased by `amount`
+pub fn increase_ritual_dagger_damage(card: Card, amount: u32) -> Card {
+    let damage = ritual_dagger_damage(&card) + amount;
+    ritual_dagger_with_damage(damage, card.is_upgraded())
+}
+
+/// Upgrade a Ritual Dagger while keeping the damage it has already gained
+pub fn upgrade_ritual_dagger(card: Card) -> Card {
+    ritual_dagger_with_damage(ritual_dagger_damage(&card), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_ritual_dagger_creation() {
+        let card = ritual_dagger();
+
+        assert_eq!(card.get_name(), "Ritual Dagger");
+        assert_eq!(card.get_cost(), 1);
+        assert_eq!(card.get_card_type(), CardType::Attack);
+        assert_eq!(card.get_effects()[1], BattleEffect::IncreaseDamageOnKill { amount: 3 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_ritual_dagger_upgrade_keeps_damage() {
+        let card = increase_ritual_dagger_damage(ritual_dagger(), 6);
+        let upgraded = card.upgrade();
+
+        assert_eq!(upgraded.get_name(), "Ritual Dagger+");
+        assert_eq!(ritual_dagger_damage(&upgraded), 21);
+        assert_eq!(upgraded.get_effects()[1], BattleEffect::IncreaseDamageOnKill { amount: 5 });
+    }
+
+    #[test]
+    fn test_ritual_dagger_grows_on_kill() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(10, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false))),
+        ];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(ritual_dagger());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let exhausted = battle.cards.get_exhausted();
+        assert_eq!(exhausted.len(), 1);
+        assert_eq!(ritual_dagger_damage(&exhausted[0]), 18);
+    }
+    #[test]
+    fn test_ritual_dagger_growth_reaches_master_deck() {
+        use crate::cards::ironclad::strike::strike;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(10, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false))),
+        ];
+        let master_deck = Deck::new(vec![strike(), ritual_dagger()]);
+        let mut battle = Battle::new(master_deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        let dagger_idx = battle.cards.get_hand().iter()
+            .position(|card| card.get_card_enum() == CardEnum::RitualDagger)
+            .unwrap();
+        battle.play_card(dagger_idx, Entity::Enemy(0)).unwrap();
+
+        let final_deck = battle.get_final_player_run_state(0, vec![]).deck.unwrap();
+        assert_eq!(final_deck.size(), 2);
+        let dagger = final_deck.get_cards().iter()
+            .find(|card| card.get_card_enum() == CardEnum::RitualDagger)
+            .unwrap();
+        assert_eq!(ritual_dagger_damage(dagger), 18);
+        // The Strike is untouched
+        assert_eq!(final_deck.get_cards().iter().filter(|card| card.get_card_enum() == CardEnum::Strike).count(), 1);
+    }
+
+    #[test]
+    fn test_ritual_dagger_without_kill_leaves_master_deck_unchanged() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)))];
+        let mut battle = Battle::new(Deck::new(vec![ritual_dagger()]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let final_deck = battle.get_final_player_run_state(0, vec![]).deck.unwrap();
+        assert_eq!(ritual_dagger_damage(&final_deck.get_cards()[0]), 15);
+    }
+
+    #[test]
+    fn test_ritual_dagger_master_deck_gets_only_the_damage_increase() {
+        use crate::cards::colorless::apotheosis::apotheosis;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(10, false))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false))),
+        ];
+        let mut battle = Battle::new(Deck::new(vec![ritual_dagger()]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        // Apotheosis upgrades the dagger for this combat only
+        battle.add_card_to_hand_for_testing(apotheosis());
+        battle.play_card(1, Entity::Player).unwrap();
+        assert!(battle.cards.get_hand()[0].is_upgraded());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        let final_deck = battle.get_final_player_run_state(0, vec![]).deck.unwrap();
+        let dagger = &final_deck.get_cards()[0];
+        assert!(!dagger.is_upgraded());
+        assert_eq!(ritual_dagger_damage(dagger), 20);
+    }
+}
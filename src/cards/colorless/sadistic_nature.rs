@@ -0,0 +1,128 @@
+use serde::{Serialize, Deserialize};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
+
+/// Sadistic Nature Power Listener
+/// Whenever you apply a Debuff to an enemy, they take damage.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SadisticNatureListener {
+    owner: Entity,
+    damage: u32,
+}
+
+impl SadisticNatureListener {
+    pub fn new(owner: Entity, damage: u32) -> Self {
+        SadisticNatureListener {
+            owner,
+            damage,
+        }
+    }
+}
+
+impl EventListener for SadisticNatureListener {
+    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
+        match event {
+            BattleEvent::DebuffApplied { target: target @ Entity::Enemy(_) } => {
+                vec![BattleEffect::DealDamage { target: *target, amount: self.damage }]
+            }
+            _ => vec![]
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        true // Sadistic Nature is always active once played
+    }
+
+    fn get_owner(&self) -> Entity {
+        self.owner
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
+        use std::hash::Hash;
+        self.hash(state);
+    }
+}
+
+/// Sadistic Nature - Colorless Power Card (Rare)
+/// Cost: 0
+/// Effect: Whenever you apply a Debuff to an enemy, they take 5 (7) damage
+pub fn sadistic_nature() -> Card {
+    Card::new(
+        CardEnum::SadisticNature,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateSadisticNature { damage: 5 }]
+    )
+        .set_playable(true)
+}
+
+pub fn sadistic_nature_upgraded() -> Card {
+    Card::new(
+        CardEnum::SadisticNature,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Power),
+        vec![BattleEffect::ActivateSadisticNature { damage: 7 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, enemy_in_battle::EnemyInBattle};
+    use crate::cards::colorless::trip::trip;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_sadistic_nature_creation() {
+        let card = sadistic_nature();
+
+        assert_eq!(card.get_name(), "Sadistic Nature");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Power);
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateSadisticNature { damage: 5 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_sadistic_nature_upgraded_creation() {
+        let card = sadistic_nature_upgraded();
+
+        assert_eq!(card.get_name(), "Sadistic Nature+");
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateSadisticNature { damage: 7 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_sadistic_nature_ignores_player_debuffs() {
+        let mut listener = SadisticNatureListener::new(Entity::Player, 5);
+
+        assert!(listener.on_event(&BattleEvent::DebuffApplied { target: Entity::Player }).is_empty());
+        assert_eq!(
+            listener.on_event(&BattleEvent::DebuffApplied { target: Entity::Enemy(0) }),
+            vec![BattleEffect::DealDamage { target: Entity::Enemy(0), amount: 5 }]
+        );
+    }
+
+    #[test]
+    fn test_sadistic_nature_damages_on_debuff() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(sadistic_nature());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        battle.add_card_to_hand_for_testing(trip());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 39);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Secret Technique - Colorless Skill Card (Rare)
+/// Cost: 0
+/// Effect: Choose a Skill from your draw pile and place it into your hand. Exhaust (no Exhaust when upgraded)
+pub fn secret_technique() -> Card {
+    Card::new(
+        CardEnum::SecretTechnique,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::EnterSelectCardInDrawPile { card_type: CardType::Skill },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn secret_technique_upgraded() -> Card {
+    Card::new(
+        CardEnum::SecretTechnique,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![BattleEffect::EnterSelectCardInDrawPile { card_type: CardType::Skill }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle, battle_action::BattleAction, battle_state::{BattleState, MultiSelect}};
+    use crate::cards::ironclad::{strike::strike, defend::defend};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_secret_technique_creation() {
+        let card = secret_technique();
+
+        assert_eq!(card.get_name(), "Secret Technique");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects().len(), 2);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_secret_technique_upgraded_creation() {
+        let card = secret_technique_upgraded();
+
+        assert_eq!(card.get_name(), "Secret Technique+");
+        assert_eq!(card.get_effects().len(), 1);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_secret_technique_fetches_skill_from_draw_pile() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        // Five Strikes fill the opening hand, leaving a Defend in the draw pile
+        let mut cards = vec![strike(), strike(), strike(), strike(), strike()];
+        cards.push(defend());
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(cards), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        // The opening hand is random, so find where the Defend ended up
+        let draw_pile = battle.cards.get_deck().get_cards().clone();
+        let Some(defend_index) = draw_pile.iter().position(|c| c.get_card_enum() == CardEnum::Defend) else {
+            return; // Defend was drawn into the opening hand; nothing to select
+        };
+
+        battle.add_card_to_hand_for_testing(secret_technique());
+        let idx = battle.get_hand().len() - 1;
+        battle.play_card(idx, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_battle_state(), BattleState::SelectCardInDrawPile { card_type: CardType::Skill, selection: MultiSelect::exactly(1) });
+
+        battle.eval_action(BattleAction::SelectCardInDrawPile(defend_index), &mut rng).unwrap();
+
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+        assert!(battle.get_hand().iter().any(|c| c.get_card_enum() == CardEnum::Defend));
+    }
+}
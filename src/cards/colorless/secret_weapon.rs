@@ -0,0 +1,69 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Secret Weapon - Colorless Skill Card (Rare)
+/// Cost: 0
+/// Effect: Choose an Attack from your draw pile and place it into your hand. Exhaust (no Exhaust when upgraded)
+pub fn secret_weapon() -> Card {
+    Card::new(
+        CardEnum::SecretWeapon,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::EnterSelectCardInDrawPile { card_type: CardType::Attack },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn secret_weapon_upgraded() -> Card {
+    Card::new(
+        CardEnum::SecretWeapon,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![BattleEffect::EnterSelectCardInDrawPile { card_type: CardType::Attack }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle, battle_state::BattleState};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_secret_weapon_creation() {
+        let card = secret_weapon();
+
+        assert_eq!(card.get_name(), "Secret Weapon");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects()[0], BattleEffect::EnterSelectCardInDrawPile { card_type: CardType::Attack });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_secret_weapon_upgraded_creation() {
+        let card = secret_weapon_upgraded();
+
+        assert_eq!(card.get_name(), "Secret Weapon+");
+        assert_eq!(card.get_effects().len(), 1);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_secret_weapon_without_attacks_does_nothing() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(secret_weapon());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_battle_state(), BattleState::PlayerTurn);
+    }
+}
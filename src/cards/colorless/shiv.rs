@@ -0,0 +1,56 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Shiv - Colorless Attack Card (Special)
+/// Cost: 0
+/// Effect: Deal 4 (6) damage. Exhaust
+pub fn shiv() -> Card {
+    Card::new(
+        CardEnum::Shiv,
+        0,
+        CardClass::Colorless(Rarity::Special, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 4, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn shiv_upgraded() -> Card {
+    Card::new(
+        CardEnum::Shiv,
+        0,
+        CardClass::Colorless(Rarity::Special, CardType::Attack),
+        vec![
+            BattleEffect::AttackToTarget { amount: 6, num_attacks: 1, strength_multiplier: 1 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shiv_creation() {
+        let card = shiv();
+
+        assert_eq!(card.get_name(), "Shiv");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Attack);
+        assert!(card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_shiv_upgraded_creation() {
+        let card = shiv_upgraded();
+
+        assert_eq!(card.get_name(), "Shiv+");
+        assert_eq!(card.get_effects()[0], BattleEffect::AttackToTarget { amount: 6, num_attacks: 1, strength_multiplier: 1 });
+        assert!(card.is_upgraded());
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Serialize, Deserialize};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
+
+/// The Bomb Listener
+/// At the end of 3 turns, deal damage to ALL enemies.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TheBombListener {
+    owner: Entity,
+    damage: u32,
+    turns_remaining: u32,
+}
+
+impl TheBombListener {
+    pub fn new(owner: Entity, damage: u32, turns: u32) -> Self {
+        TheBombListener {
+            owner,
+            damage,
+            turns_remaining: turns,
+        }
+    }
+}
+
+impl EventListener for TheBombListener {
+    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
+        match event {
+            BattleEvent::EndOfTurn { entity } if *entity == self.owner && self.turns_remaining > 0 => {
+                self.turns_remaining -= 1;
+                if self.turns_remaining == 0 {
+                    vec![BattleEffect::DealDamageToAllEnemies { amount: self.damage }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![]
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.turns_remaining > 0
+    }
+
+    fn get_owner(&self) -> Entity {
+        self.owner
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
+        use std::hash::Hash;
+        self.hash(state);
+    }
+}
+
+/// The Bomb - Colorless Skill Card (Rare)
+/// Cost: 2
+/// Effect: At the end of 3 turns, deal 40 (50) damage to ALL enemies
+pub fn the_bomb() -> Card {
+    Card::new(
+        CardEnum::TheBomb,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![BattleEffect::ActivateTheBomb { damage: 40, turns: 3 }]
+    )
+        .set_playable(true)
+}
+
+pub fn the_bomb_upgraded() -> Card {
+    Card::new(
+        CardEnum::TheBomb,
+        2,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![BattleEffect::ActivateTheBomb { damage: 50, turns: 3 }]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_the_bomb_creation() {
+        let card = the_bomb();
+
+        assert_eq!(card.get_name(), "The Bomb");
+        assert_eq!(card.get_cost(), 2);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateTheBomb { damage: 40, turns: 3 });
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_the_bomb_upgraded_creation() {
+        let card = the_bomb_upgraded();
+
+        assert_eq!(card.get_name(), "The Bomb+");
+        assert_eq!(card.get_effects()[0], BattleEffect::ActivateTheBomb { damage: 50, turns: 3 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_the_bomb_listener_counts_down() {
+        let mut listener = TheBombListener::new(Entity::Player, 40, 3);
+        let end_of_turn = BattleEvent::EndOfTurn { entity: Entity::Player };
+
+        assert!(listener.on_event(&end_of_turn).is_empty());
+        assert!(listener.on_event(&end_of_turn).is_empty());
+        assert_eq!(listener.on_event(&end_of_turn), vec![BattleEffect::DealDamageToAllEnemies { amount: 40 }]);
+        assert!(!listener.is_active());
+
+        // A spent bomb does nothing
+        assert!(listener.on_event(&end_of_turn).is_empty());
+    }
+
+    #[test]
+    fn test_the_bomb_explodes_after_three_turns() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(the_bomb());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        battle.end_turn(&mut rng, &global_info);
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 44);
+
+        battle.end_turn(&mut rng, &global_info);
+        // Jaw Worm may have some Block up when the bomb goes off
+        assert!(battle.get_enemies()[0].get_current_hp() <= 10);
+    }
+}
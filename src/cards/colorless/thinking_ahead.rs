@@ -0,0 +1,58 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Thinking Ahead - Colorless Skill Card (Rare)
+/// Cost: 0
+/// Effect: Draw 2 cards. Place a card from your hand on top of your draw pile. Exhaust (no Exhaust when upgraded)
+pub fn thinking_ahead() -> Card {
+    Card::new(
+        CardEnum::ThinkingAhead,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::DrawCard { count: 2 },
+            BattleEffect::EnterSelectCardInHandToPutOnDeck,
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn thinking_ahead_upgraded() -> Card {
+    Card::new(
+        CardEnum::ThinkingAhead,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::DrawCard { count: 2 },
+            BattleEffect::EnterSelectCardInHandToPutOnDeck,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thinking_ahead_creation() {
+        let card = thinking_ahead();
+
+        assert_eq!(card.get_name(), "Thinking Ahead");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects().len(), 3);
+        assert!(card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_thinking_ahead_upgraded_creation() {
+        let card = thinking_ahead_upgraded();
+
+        assert_eq!(card.get_name(), "Thinking Ahead+");
+        assert!(!card.get_effects().contains(&BattleEffect::Exhaust));
+        assert!(card.is_upgraded());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Transmutation - Colorless Skill Card (Rare)
+/// Cost: X
+/// Effect: Add X random (Upgraded) Colorless cards into your hand. They cost 0 this turn. Exhaust
+pub fn transmutation() -> Card {
+    Card::new(
+        CardEnum::Transmutation,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::RepeatX {
+                effect: Box::new(BattleEffect::AddRandomColorlessCardFreeThisTurn { upgraded: false }),
+                extra: 0,
+            },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_x_cost(true)
+        .set_playable(true)
+}
+
+pub fn transmutation_upgraded() -> Card {
+    Card::new(
+        CardEnum::Transmutation,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::RepeatX {
+                effect: Box::new(BattleEffect::AddRandomColorlessCardFreeThisTurn { upgraded: true }),
+                extra: 0,
+            },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_x_cost(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_transmutation_creation() {
+        let card = transmutation();
+
+        assert_eq!(card.get_name(), "Transmutation");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_transmutation_upgraded_creation() {
+        let card = transmutation_upgraded();
+
+        assert_eq!(card.get_name(), "Transmutation+");
+        assert_eq!(card.get_effects()[0], BattleEffect::RepeatX {
+            effect: Box::new(BattleEffect::AddRandomColorlessCardFreeThisTurn { upgraded: true }),
+            extra: 0,
+        });
+        assert!(card.is_x_cost());
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_transmutation_spends_all_energy() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        battle.add_card_to_hand_for_testing(transmutation_upgraded());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_player().get_energy(), 0);
+        let hand = battle.get_hand();
+        assert_eq!(hand.len(), 3);
+        for card in hand {
+            assert!(card.is_upgraded());
+            assert_eq!(card.get_cost(), 0);
+            assert!(matches!(card.get_card_class(), CardClass::Colorless(_, _)));
+        }
+    }
+}
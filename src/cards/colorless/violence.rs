@@ -0,0 +1,83 @@
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+
+/// Violence - Colorless Skill Card (Rare)
+/// Cost: 0
+/// Effect: Put 3 (4) random Attacks from your draw pile into your hand. Exhaust
+pub fn violence() -> Card {
+    Card::new(
+        CardEnum::Violence,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::MoveRandomCardsFromDrawPileToHand { card_type: CardType::Attack, count: 3 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_playable(true)
+}
+
+pub fn violence_upgraded() -> Card {
+    Card::new(
+        CardEnum::Violence,
+        0,
+        CardClass::Colorless(Rarity::Rare, CardType::Skill),
+        vec![
+            BattleEffect::MoveRandomCardsFromDrawPileToHand { card_type: CardType::Attack, count: 4 },
+            BattleEffect::Exhaust,
+        ]
+    )
+        .set_upgraded(true)
+        .set_playable(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::cards::ironclad::{strike::strike, defend::defend};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, global_info::GlobalInfo, deck::Deck};
+
+    #[test]
+    fn test_violence_creation() {
+        let card = violence();
+
+        assert_eq!(card.get_name(), "Violence");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert!(!card.is_upgraded());
+    }
+
+    #[test]
+    fn test_violence_upgraded_creation() {
+        let card = violence_upgraded();
+
+        assert_eq!(card.get_name(), "Violence+");
+        assert_eq!(card.get_effects()[0], BattleEffect::MoveRandomCardsFromDrawPileToHand { card_type: CardType::Attack, count: 4 });
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_violence_only_moves_attacks() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let cards = vec![
+            defend(), defend(), defend(), defend(), defend(),
+            defend(), defend(), strike(), strike(),
+        ];
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let mut battle = Battle::new(Deck::new(cards), global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        let strikes_in_draw_pile = battle.cards.get_deck().get_cards().iter()
+            .filter(|c| c.get_card_enum() == CardEnum::Strike)
+            .count();
+        let hand_before = battle.get_hand().len();
+
+        battle.add_card_to_hand_for_testing(violence());
+        battle.play_card(hand_before, Entity::Enemy(0)).unwrap();
+
+        // Violence exhausts itself and moves every Strike left in the draw pile (at most 2)
+        assert_eq!(battle.get_hand().len(), hand_before + strikes_in_draw_pile);
+        assert!(battle.cards.get_deck().get_cards().iter().all(|c| c.get_card_enum() != CardEnum::Strike));
+    }
+}
@@ -46,6 +46,16 @@ pub fn writhe() -> Card {
         .set_innate(true)
 }
 
+/// Normality - A curse card that limits how many cards can be played each turn
+/// Cost: 0, Effect: Unplayable. While in hand, you cannot play more than 3 cards this turn.
+pub fn normality() -> Card {
+    Card::new(CardEnum::Normality, 0, CardClass::Curse, vec![])
+        .set_playable(false)
+}
+
+/// Cards that can still be played this turn while Normality is in hand
+pub const NORMALITY_CARD_LIMIT: u32 = 3;
+
 /// Note: Ascender's Curse doesn't have an upgraded version in the original game
 /// The curse effects are typically handled by the game system rather than card effects
 #[cfg(test)]
@@ -0,0 +1,71 @@
+use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
+
+/// Barricade - Rare Power Card
+/// Cost: 3 (2 when upgraded)
+/// Effect: Block is not removed at the start of your turn.
+pub fn barricade() -> Card {
+    Card::new(CardEnum::Barricade, 3, CardClass::IronClad(Rarity::Rare, CardType::Power), vec![
+        BattleEffect::ActivateBarricade,
+    ])
+        .set_play_condition(Condition::True)
+}
+
+pub fn barricade_upgraded() -> Card {
+    Card::new(CardEnum::Barricade, 2, CardClass::IronClad(Rarity::Rare, CardType::Power), vec![
+        BattleEffect::ActivateBarricade,
+    ])
+        .set_upgraded(true)
+        .set_play_condition(Condition::True)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::battle::power::PowerId;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, deck::Deck, enemy::EnemyTrait, global_info::GlobalInfo};
+
+    #[test]
+    fn test_barricade_creation() {
+        let card = barricade();
+
+        assert_eq!(card.get_name(), "Barricade");
+        assert_eq!(card.get_cost(), 3);
+        assert_eq!(card.get_card_type(), CardType::Power);
+        assert_eq!(card.get_effects(), &vec![BattleEffect::ActivateBarricade]);
+        assert!(card.is_playable());
+    }
+
+    #[test]
+    fn test_barricade_upgraded_costs_less() {
+        let card = barricade().upgrade();
+
+        assert_eq!(card.get_name(), "Barricade+");
+        assert_eq!(card.get_cost(), 2);
+        assert!(card.is_upgraded());
+    }
+
+    #[test]
+    fn test_barricade_keeps_block_between_turns() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info)))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(barricade());
+        battle.play_card(0, Entity::Player).unwrap();
+        assert!(battle.get_player().battle_info.has_power(PowerId::Barricade));
+
+        // Playing a second copy does nothing more
+        battle.add_card_to_hand_for_testing(barricade_upgraded());
+        battle.get_player_mut().battle_info.gain_energy(2);
+        battle.play_card(0, Entity::Player).unwrap();
+        assert_eq!(battle.get_player().battle_info.get_power_amount(PowerId::Barricade), 1);
+
+        battle.get_player_mut().battle_info.gain_block(15);
+        battle.get_player_mut().battle_info.at_start_of_turn();
+        battle.get_player_mut().battle_info.at_start_of_turn();
+        assert_eq!(battle.get_player().battle_info.get_block(), 15);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
+
+/// Battle Trance - Uncommon Skill Card
+/// Cost: 0
+/// Effect: Draw 3 cards. You cannot draw additional cards this turn.
+pub fn battle_trance() -> Card {
+    Card::new(CardEnum::BattleTrance, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![
+        BattleEffect::DrawCard { count: 3 },
+        BattleEffect::ApplyNoDraw,
+    ])
+        .set_play_condition(Condition::True)
+}
+
+/// Battle Trance+ (Upgraded)
+/// Cost: 0
+/// Effect: Draw 4 cards. You cannot draw additional cards this turn.
+pub fn battle_trance_upgraded() -> Card {
+    Card::new(CardEnum::BattleTrance, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![
+        BattleEffect::DrawCard { count: 4 },
+        BattleEffect::ApplyNoDraw,
+    ])
+        .set_upgraded(true)
+        .set_play_condition(Condition::True)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::battle::power::PowerId;
+    use crate::cards::ironclad::strike::strike;
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, deck::Deck, enemy::EnemyTrait, global_info::GlobalInfo};
+
+    #[test]
+    fn test_battle_trance_creation() {
+        let card = battle_trance();
+
+        assert_eq!(card.get_name(), "Battle Trance");
+        assert_eq!(card.get_cost(), 0);
+        assert_eq!(card.get_card_type(), CardType::Skill);
+        assert_eq!(card.get_effects(), &vec![BattleEffect::DrawCard { count: 3 }, BattleEffect::ApplyNoDraw]);
+    }
+
+    #[test]
+    fn test_battle_trance_upgraded_draws_four() {
+        let card = battle_trance().upgrade();
+
+        assert_eq!(card.get_name(), "Battle Trance+");
+        assert_eq!(card.get_effects(), &vec![BattleEffect::DrawCard { count: 4 }, BattleEffect::ApplyNoDraw]);
+    }
+
+    #[test]
+    fn test_battle_trance_stops_further_draws_this_turn() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info)))];
+        let deck = Deck::new(vec![strike(); 20]);
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+        assert_eq!(battle.get_hand().len(), 5);
+
+        battle.add_card_to_hand_for_testing(battle_trance());
+        battle.play_card(5, Entity::Player).unwrap();
+        assert_eq!(battle.get_hand().len(), 8);
+        assert!(battle.get_player().battle_info.has_power(PowerId::NoDraw));
+
+        // A second Battle Trance draws nothing
+        battle.add_card_to_hand_for_testing(battle_trance());
+        battle.play_card(8, Entity::Player).unwrap();
+        assert_eq!(battle.get_hand().len(), 8);
+
+        // No Draw wears off at the end of the turn
+        battle.end_turn(&mut rng, &global_info);
+        assert!(!battle.get_player().battle_info.has_power(PowerId::NoDraw));
+        assert_eq!(battle.get_hand().len(), 5);
+    }
+}
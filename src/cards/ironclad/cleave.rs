@@ -1,14 +1,14 @@
-use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::{BattleEffect, TargetSelector}, card::{Rarity, CardClass}};
 
 pub fn cleave() -> Card {
     Card::new(CardEnum::Cleave, 1, CardClass::IronClad(Rarity::Common, CardType::Attack), vec![
-        BattleEffect::AttackAllEnemies { amount: 8, num_attacks: 1 }
+        BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)
     ])
 }
 
 pub fn cleave_upgraded() -> Card {
     Card::new(CardEnum::Cleave, 1, CardClass::IronClad(Rarity::Common, CardType::Attack), vec![
-        BattleEffect::AttackAllEnemies { amount: 11, num_attacks: 1 }
+        BattleEffect::AttackToTarget { amount: 11, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)
     ])
         .set_upgraded(true)
 }
@@ -28,13 +28,7 @@ mod tests {
         
         let effects = card.get_effects();
         assert_eq!(effects.len(), 1);
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 8);
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
     }
 
     #[test]
@@ -46,13 +40,7 @@ mod tests {
         
         let effects = card.get_effects();
         assert_eq!(effects.len(), 1);
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 11); // +3 damage over regular Cleave
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 11, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
     }
 
     #[test]
@@ -1,58 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
-
-/// Combust Power Listener
-/// At the end of your turn, deal 6 damage to ALL enemies.
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct CombustListener {
-    owner: Entity,
-    damage: u32,
-}
-
-impl CombustListener {
-    pub fn new(owner: Entity, damage: u32) -> Self {
-        CombustListener {
-            owner,
-            damage,
-        }
-    }
-}
-
-impl EventListener for CombustListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::EndOfTurn { entity } if *entity == self.owner => {
-                // Lose 1 HP and deal damage to all enemies at end of player's turn
-                vec![
-                    BattleEffect::AttackAllEnemies {
-                        amount: self.damage,
-                        num_attacks: 1,
-                    },
-                    BattleEffect::LoseHp(1),
-                ]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true // Combust is always active once played
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Combust - Power Card
 /// Cost: 1
@@ -78,6 +24,8 @@ mod tests {
     use crate::game::PlayerRunState;
 
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_combust_creation() {
@@ -106,33 +54,32 @@ mod tests {
     }
 
     #[test]
-    fn test_combust_listener_creation() {
-        let listener = CombustListener::new(Entity::Player, 5);
-        assert!(listener.is_active());
-        assert_eq!(listener.get_owner(), Entity::Player);
+    fn test_combust_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
+        assert!(powers.has(PowerId::Combust));
     }
 
     #[test]
     fn test_combust_triggers_on_end_of_turn() {
-        let mut listener = CombustListener::new(Entity::Player, 5);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
 
         let end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Player,
         };
 
-        let effects = listener.on_event(&end_turn_event);
+        let effects = powers.on_event(&end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 2);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies {
-            amount: 5,
-            num_attacks: 1,
-        });
+        assert_eq!(effects[0], BattleEffect::DealDamageToAllEnemies { amount: 5 });
         assert_eq!(effects[1], BattleEffect::LoseHp(1));
-        assert!(listener.is_active()); // Still active after triggering
+        assert!(powers.has(PowerId::Combust)); // Still active after triggering
     }
 
     #[test]
     fn test_combust_does_not_trigger_on_other_events() {
-        let mut listener = CombustListener::new(Entity::Player, 5);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
 
         let damage_event = BattleEvent::DamageTaken {
             target: Entity::Player,
@@ -140,50 +87,46 @@ mod tests {
             source: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&damage_event);
+        let effects = powers.on_event(&damage_event, Entity::Player);
         assert_eq!(effects.len(), 0);
-        assert!(listener.is_active());
+        assert!(powers.has(PowerId::Combust));
     }
 
     #[test]
     fn test_combust_triggers_multiple_times() {
-        let mut listener = CombustListener::new(Entity::Player, 5);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
 
         let end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Player,
         };
 
         // First end of turn
-        let effects1 = listener.on_event(&end_turn_event);
+        let effects1 = powers.on_event(&end_turn_event, Entity::Player);
         assert_eq!(effects1.len(), 2);
-        assert_eq!(effects1[0], BattleEffect::AttackAllEnemies {
-            amount: 5,
-            num_attacks: 1,
-        });
+        assert_eq!(effects1[0], BattleEffect::DealDamageToAllEnemies { amount: 5 });
         assert_eq!(effects1[1], BattleEffect::LoseHp(1));
 
         // Second end of turn should also trigger
-        let effects2 = listener.on_event(&end_turn_event);
+        let effects2 = powers.on_event(&end_turn_event, Entity::Player);
         assert_eq!(effects2.len(), 2);
-        assert_eq!(effects2[0], BattleEffect::AttackAllEnemies {
-            amount: 5,
-            num_attacks: 1,
-        });
+        assert_eq!(effects2[0], BattleEffect::DealDamageToAllEnemies { amount: 5 });
         assert_eq!(effects2[1], BattleEffect::LoseHp(1));
 
-        assert!(listener.is_active()); // Always active
+        assert!(powers.has(PowerId::Combust)); // Always active
     }
 
     #[test]
     fn test_combust_only_triggers_for_owner() {
-        let mut listener = CombustListener::new(Entity::Player, 5);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
 
         // Enemy end of turn should not trigger
         let enemy_end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&enemy_end_turn_event);
+        let effects = powers.on_event(&enemy_end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 0);
 
         // Player end of turn should trigger
@@ -191,39 +134,32 @@ mod tests {
             entity: Entity::Player,
         };
 
-        let effects = listener.on_event(&player_end_turn_event);
+        let effects = powers.on_event(&player_end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 2);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies {
-            amount: 5,
-            num_attacks: 1,
-        });
+        assert_eq!(effects[0], BattleEffect::DealDamageToAllEnemies { amount: 5 });
         assert_eq!(effects[1], BattleEffect::LoseHp(1));
     }
 
     #[test]
     fn test_combust_different_damage_values() {
-        let mut normal_listener = CombustListener::new(Entity::Player, 5);
-        let mut upgraded_listener = CombustListener::new(Entity::Player, 7);
+        let mut normal_powers = Powers::new();
+        normal_powers.apply(Power::new(PowerId::Combust, 5).with_extra_amount(1));
+        let mut upgraded_powers = Powers::new();
+        upgraded_powers.apply(Power::new(PowerId::Combust, 7).with_extra_amount(1));
 
         let end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Player,
         };
 
-        let normal_effects = normal_listener.on_event(&end_turn_event);
-        let upgraded_effects = upgraded_listener.on_event(&end_turn_event);
+        let normal_effects = normal_powers.on_event(&end_turn_event, Entity::Player);
+        let upgraded_effects = upgraded_powers.on_event(&end_turn_event, Entity::Player);
 
         assert_eq!(normal_effects.len(), 2);
-        assert_eq!(normal_effects[0], BattleEffect::AttackAllEnemies {
-            amount: 5,
-            num_attacks: 1,
-        });
+        assert_eq!(normal_effects[0], BattleEffect::DealDamageToAllEnemies { amount: 5 });
         assert_eq!(normal_effects[1], BattleEffect::LoseHp(1));
 
         assert_eq!(upgraded_effects.len(), 2);
-        assert_eq!(upgraded_effects[0], BattleEffect::AttackAllEnemies {
-            amount: 7,
-            num_attacks: 1,
-        });
+        assert_eq!(upgraded_effects[0], BattleEffect::DealDamageToAllEnemies { amount: 7 });
         assert_eq!(upgraded_effects[1], BattleEffect::LoseHp(1));
     }
 
@@ -282,4 +218,27 @@ mod tests {
         // 3. Area damage affects all enemies (5 damage)
         // 4. Enemy interaction works correctly
     }
+
+    #[test]
+    fn test_combust_damage_ignores_strength() {
+        use crate::battle::Battle;
+        use crate::battle::enemy_in_battle::EnemyInBattle;
+        use crate::game::deck::Deck;
+        use crate::game::global_info::GlobalInfo;
+        use crate::battle::battle_action::BattleAction;
+        use crate::enemies::cultist::Cultist;
+        use crate::enemies::enemy_enum::EnemyEnum;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::Cultist(Cultist::new(50, 3)))];
+        let mut battle = Battle::new(Deck::new(vec![combust()]), global_info, PlayerRunState::new(80, 80, 0), enemies, &mut rng);
+
+        battle.apply_power(Entity::Player, Power::new(PowerId::Strength, 4));
+        battle.play_card(0, Entity::Player).unwrap();
+        battle.eval_action(BattleAction::EndTurn, &mut rng).unwrap();
+
+        // Combust is not an attack, so the 4 Strength adds nothing to its 5 damage
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), 45);
+    }
 }
\ No newline at end of file
@@ -133,7 +133,7 @@ mod tests {
 
         // Verify Corruption was activated and energy was spent
         assert_eq!(battle.get_player().get_energy(), initial_energy - 3);
-        assert!(battle.has_corruption_active());
+        assert!(battle.get_player().battle_info.has_power(crate::battle::power::PowerId::Corruption));
 
         // Check what's in hand after playing Corruption
         let hand = battle.get_hand();
@@ -144,8 +144,9 @@ mod tests {
         let card_cost = hand[0].get_cost();
         let card_modified_cost = battle.get_modified_cost(&hand[0]);
         assert_eq!(card_name, "Defend", "Card should be Defend");
-        assert_eq!(card_cost, 1); // Base cost is still 1
-        assert_eq!(card_modified_cost, 0); // Modified cost is 0 with Corruption
+        assert_eq!(card_cost, 0); // Corruption lowers the cost of the card instance
+        assert_eq!(card_modified_cost, 0);
+        assert!(hand[0].exhausts());
 
         // Store player's current block and energy before playing Defend
         let initial_block = battle.get_player().battle_info.get_block();
@@ -204,7 +205,7 @@ mod tests {
         assert!(result.is_ok(), "Should be able to play Corruption");
 
         // Verify Corruption is active
-        assert!(battle.has_corruption_active());
+        assert!(battle.get_player().battle_info.has_power(crate::battle::power::PowerId::Corruption));
 
         // Now play first Defend - should be exhausted
         let initial_exhausted = battle.cards.exhausted_size();
@@ -1,6 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
-use crate::battle::{target::Entity, battle_events::BattleEvent, battle_events::EventListener};
 
 /// Demon Form - Rare Power Card
 /// Cost: 3 (2 when upgraded)
@@ -349,43 +347,3 @@ mod tests {
     }
 }
 
-#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct DemonFormListener {
-    source: Entity,
-    strength_per_turn: u32,
-}
-
-impl DemonFormListener {
-    pub fn new(source: Entity, strength_per_turn: u32) -> Self {
-        Self { source, strength_per_turn }
-    }
-}
-
-impl EventListener for DemonFormListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::StartOfPlayerTurn if self.source == Entity::Player => {
-                // Return a GainStrength effect to be processed
-                vec![BattleEffect::GainStrength { amount: self.strength_per_turn }]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true // Demon Form is always active once played
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.source
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
\ No newline at end of file
@@ -1,6 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
 
 pub fn double_tap() -> Card {
     Card::new(CardEnum::DoubleTap, 1, CardClass::IronClad(Rarity::Rare, CardType::Skill), vec![
@@ -19,59 +17,11 @@ pub fn double_tap_upgraded() -> Card {
         .set_playable(true)
 }
 
-#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct DoubleTapListener {
-    source: Entity,
-    remaining_attacks: u32,
-}
-
-impl DoubleTapListener {
-    pub fn new(source: Entity, remaining_attacks: u32) -> Self {
-        Self {
-            source,
-            remaining_attacks,
-        }
-    }
-}
-
-impl EventListener for DoubleTapListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CardPlayed { source, card_type }
-                if *source == self.source
-                && *card_type == crate::game::card_type::CardType::Attack
-                && self.remaining_attacks > 0 => {
-                // When an Attack card is played, play it again
-                self.remaining_attacks -= 1;
-                // Note: In a full implementation, this would need to trigger card duplication
-                // For now, we'll use a placeholder effect to indicate the card should be played again
-                vec![]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        self.remaining_attacks > 0
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.source
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_double_tap_creation() {
@@ -98,38 +48,41 @@ mod tests {
     }
 
     #[test]
-    fn test_double_tap_listener_creation() {
-        let listener = DoubleTapListener::new(Entity::Player, 1);
-        assert_eq!(listener.source, Entity::Player);
-        assert_eq!(listener.remaining_attacks, 1);
-        assert!(listener.is_active());
+    fn test_double_tap_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::DoubleTap, 1));
+        assert_eq!(powers.amount(PowerId::DoubleTap), 1);
+        assert!(powers.has(PowerId::DoubleTap));
     }
 
     #[test]
-    fn test_double_tap_listener_exhaustion() {
-        let listener = DoubleTapListener::new(Entity::Player, 0);
-        assert_eq!(listener.remaining_attacks, 0);
-        assert!(!listener.is_active());
+    fn test_double_tap_power_exhaustion() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::DoubleTap, 0));
+        assert_eq!(powers.amount(PowerId::DoubleTap), 0);
+        assert!(!powers.has(PowerId::DoubleTap));
     }
 
     #[test]
-    fn test_double_tap_listener_on_attack() {
-        let mut listener = DoubleTapListener::new(Entity::Player, 2);
+    fn test_double_tap_power_on_attack() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::DoubleTap, 2));
 
         // Simulate playing an Attack card
         let event = BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: crate::game::card_type::CardType::Attack,
+            x_value: None,
         };
 
-        listener.on_event(&event);
-        assert_eq!(listener.remaining_attacks, 1);
-        assert!(listener.is_active());
+        powers.on_event(&event, Entity::Player);
+        assert_eq!(powers.amount(PowerId::DoubleTap), 1);
+        assert!(powers.has(PowerId::DoubleTap));
 
         // Play another Attack
-        listener.on_event(&event);
-        assert_eq!(listener.remaining_attacks, 0);
-        assert!(!listener.is_active());
+        powers.on_event(&event, Entity::Player);
+        assert_eq!(powers.amount(PowerId::DoubleTap), 0);
+        assert!(!powers.has(PowerId::DoubleTap));
     }
 }
 
@@ -148,7 +101,7 @@ mod integration_tests {
     use crate::cards::ironclad::strike::strike;
 
     #[test]
-    fn test_double_tap_activates_listener() {
+    fn test_double_tap_activates_power() {
         let mut rng = rand::rng();
         let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
         let jaw_worm = JawWorm::instantiate(&mut rng, &global_info);
@@ -165,7 +118,7 @@ mod integration_tests {
         // Verify Double Tap exhausted
         assert_eq!(battle.cards.exhausted_size(), 1);
 
-        // Verify listener was added (this is tested indirectly via the double attack behavior)
+        // Verify powers was added (this is tested indirectly via the double attack behavior)
         // In the full implementation, when Strike is played next, it should trigger twice
     }
 
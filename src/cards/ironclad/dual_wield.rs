@@ -1,12 +1,12 @@
 use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum};
 
-/// Dual Wield - Duplicate a card in your hand into the discard pile
+/// Dual Wield - Create a copy of a card in your hand (copies that don't fit go to the discard pile)
 pub fn dual_wield() -> Card {
     Card::new(CardEnum::DualWield, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::EnterSelectCardToDuplicate { copies: 1 }])
         .set_play_condition(Condition::True)
 }
 
-/// Dual Wield+ (Upgraded version) - Duplicate a card in your hand twice into the discard pile
+/// Dual Wield+ (Upgraded version) - Create 2 copies of a card in your hand
 pub fn dual_wield_upgraded() -> Card {
     Card::new(CardEnum::DualWield, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::EnterSelectCardToDuplicate { copies: 2 }])
         .set_upgraded(true)
@@ -109,15 +109,14 @@ mod tests {
         // Verify battle returned to PlayerTurn state
         assert_eq!(battle.get_battle_state(), crate::battle::battle_state::BattleState::PlayerTurn);
 
-        // Verify Strike is still in hand
+        // Verify Strike and its copy are in hand
         let hand = battle.get_hand();
-        assert_eq!(hand.len(), 1);
-        assert_eq!(hand[0].get_name(), "Strike");
+        assert_eq!(hand.len(), 2);
+        assert!(hand.iter().all(|card| card.get_name() == "Strike"));
 
-        // Verify one copy of Strike was added to discard pile
-        // (plus Dual Wield itself which goes to discard as a Skill card)
+        // Only Dual Wield itself goes to discard as a Skill card
         let discard_size = battle.cards.discard_pile_size();
-        assert_eq!(discard_size, initial_discard_size + 2);
+        assert_eq!(discard_size, initial_discard_size + 1);
     }
 
     #[test]
@@ -158,15 +157,14 @@ mod tests {
         // Verify battle returned to PlayerTurn state
         assert_eq!(battle.get_battle_state(), crate::battle::battle_state::BattleState::PlayerTurn);
 
-        // Verify Strike is still in hand
+        // Verify Strike and its two copies are in hand
         let hand = battle.get_hand();
-        assert_eq!(hand.len(), 1);
-        assert_eq!(hand[0].get_name(), "Strike");
+        assert_eq!(hand.len(), 3);
+        assert!(hand.iter().all(|card| card.get_name() == "Strike"));
 
-        // Verify two copies of Strike were added to discard pile
-        // (plus Dual Wield+ itself which goes to discard as a Skill card)
+        // Only Dual Wield+ itself goes to discard as a Skill card
         let discard_size = battle.cards.discard_pile_size();
-        assert_eq!(discard_size, initial_discard_size + 3);
+        assert_eq!(discard_size, initial_discard_size + 1);
     }
 
     #[test]
@@ -201,4 +199,33 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), crate::battle::BattleError::CardNotInHand);
     }
+
+    #[test]
+    fn test_dual_wield_copies_overflow_a_full_hand() {
+        use crate::battle::{Battle, BattleResult, battle_events::BattleEvent, target::Entity};
+        use crate::battle::enemy_in_battle::EnemyInBattle;
+        use crate::game::{deck::Deck, global_info::GlobalInfo, enemy::EnemyTrait};
+        use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+        use crate::cards::ironclad::strike::strike;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info)))];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(dual_wield_upgraded());
+        for _ in 0..9 {
+            battle.add_card_to_hand_for_testing(strike());
+        }
+        battle.play_card(0, Entity::Player).unwrap();
+        let result = battle.eval_action(crate::battle::battle_action::BattleAction::SelectCardInHand(0), &mut rng).unwrap();
+
+        // One copy fills the hand, the other goes to the discard pile next to Dual Wield+
+        assert_eq!(battle.get_hand().len(), 10);
+        assert_eq!(battle.get_discard_pile().len(), 2);
+        let BattleResult::Continued(events) = result else {
+            panic!("battle should continue");
+        };
+        assert!(events.contains(&BattleEvent::CardOverflowedToDiscard { card: CardEnum::Strike }));
+    }
 }
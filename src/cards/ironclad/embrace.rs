@@ -1,50 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
-
-/// Embrace Power Listener
-/// Whenever a card is exhausted, draw 1 card
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct EmbraceListener {
-    owner: Entity,
-}
-
-impl EmbraceListener {
-    pub fn new(owner: Entity) -> Self {
-        EmbraceListener {
-            owner,
-        }
-    }
-}
-
-impl EventListener for EmbraceListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CardExhausted { source } if *source == self.owner => {
-                // When the owner exhausts a card, draw 1 card
-                vec![BattleEffect::DrawCard { count: 1 }]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true // Embrace is always active once played
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Embrace - Power Card
 /// Cost: 2 (1 when upgraded)
@@ -67,6 +21,8 @@ pub fn embrace_upgraded() -> Card {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_embrace_creation() {
@@ -132,29 +88,31 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
     }
 
     #[test]
-    fn test_embrace_listener_creation() {
-        let listener = EmbraceListener::new(Entity::Player);
-        assert!(listener.is_active());
-        assert_eq!(listener.get_owner(), Entity::Player);
+    fn test_embrace_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Embrace, 1));
+        assert!(powers.has(PowerId::Embrace));
     }
 
     #[test]
     fn test_embrace_triggers_on_card_exhausted() {
-        let mut listener = EmbraceListener::new(Entity::Player);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Embrace, 1));
 
         let exhaust_event = BattleEvent::CardExhausted {
             source: Entity::Player,
         };
 
-        let effects = listener.on_event(&exhaust_event);
+        let effects = powers.on_event(&exhaust_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert_eq!(effects[0], BattleEffect::DrawCard { count: 1 });
-        assert!(listener.is_active()); // Still active after triggering
+        assert!(powers.has(PowerId::Embrace)); // Still active after triggering
     }
 
     #[test]
     fn test_embrace_does_not_trigger_on_other_events() {
-        let mut listener = EmbraceListener::new(Entity::Player);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Embrace, 1));
 
         let damage_event = BattleEvent::DamageTaken {
             target: Entity::Player,
@@ -162,42 +120,44 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
             source: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&damage_event);
+        let effects = powers.on_event(&damage_event, Entity::Player);
         assert_eq!(effects.len(), 0);
-        assert!(listener.is_active());
+        assert!(powers.has(PowerId::Embrace));
     }
 
     #[test]
     fn test_embrace_triggers_multiple_times() {
-        let mut listener = EmbraceListener::new(Entity::Player);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Embrace, 1));
 
         let exhaust_event = BattleEvent::CardExhausted {
             source: Entity::Player,
         };
 
         // First exhaust
-        let effects1 = listener.on_event(&exhaust_event);
+        let effects1 = powers.on_event(&exhaust_event, Entity::Player);
         assert_eq!(effects1.len(), 1);
         assert_eq!(effects1[0], BattleEffect::DrawCard { count: 1 });
 
         // Second exhaust should also trigger
-        let effects2 = listener.on_event(&exhaust_event);
+        let effects2 = powers.on_event(&exhaust_event, Entity::Player);
         assert_eq!(effects2.len(), 1);
         assert_eq!(effects2[0], BattleEffect::DrawCard { count: 1 });
 
-        assert!(listener.is_active()); // Always active
+        assert!(powers.has(PowerId::Embrace)); // Always active
     }
 
     #[test]
     fn test_embrace_only_triggers_for_owner() {
-        let mut listener = EmbraceListener::new(Entity::Player);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Embrace, 1));
 
         // Enemy exhausting a card should not trigger
         let enemy_exhaust_event = BattleEvent::CardExhausted {
             source: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&enemy_exhaust_event);
+        let effects = powers.on_event(&enemy_exhaust_event, Entity::Player);
         assert_eq!(effects.len(), 0);
 
         // Player exhausting should trigger
@@ -205,7 +165,7 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
             source: Entity::Player,
         };
 
-        let effects = listener.on_event(&player_exhaust_event);
+        let effects = powers.on_event(&player_exhaust_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert_eq!(effects[0], BattleEffect::DrawCard { count: 1 });
     }
@@ -2,24 +2,24 @@ use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::Card
 
 /// Evolve - Uncommon Power Card
 /// Cost: 1 (0 when upgraded)
-/// Effect: Draw 1 card. (In the full game, would draw cards when Status cards are drawn)
+/// Effect: Whenever you draw a Status card, draw 1 card
 pub fn evolve() -> Card {
     Card::new(CardEnum::Evolve, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Power), vec![
-            BattleEffect::DrawCard { count: 1 },
+            BattleEffect::ActivateEvolve { cards_per_status: 1 },
         ])
         .set_play_condition(Condition::True)
 }
 
 /// Evolve+ (Upgraded version)
 /// Cost: 0
-/// Effect: Draw 1 card. (In the full game, would draw cards when Status cards are drawn)
+/// Effect: Whenever you draw a Status card, draw 1 card
 pub fn evolve_upgraded() -> Card {
     Card::new(
         CardEnum::Evolve,
         0, // Costs 0 when upgraded
         CardClass::IronClad(Rarity::Uncommon, CardType::Power),
         vec![
-            BattleEffect::DrawCard { count: 1 },
+            BattleEffect::ActivateEvolve { cards_per_status: 1 },
         ]
     )
         .set_upgraded(true)
@@ -31,6 +31,8 @@ mod tests {
     use crate::game::PlayerRunState;
 
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_evolve_creation() {
@@ -63,10 +65,10 @@ mod tests {
 
         assert_eq!(effects.len(), 1);
         match &effects[0] {
-            BattleEffect::DrawCard { count } => {
-                assert_eq!(*count, 1);
+            BattleEffect::ActivateEvolve { cards_per_status } => {
+                assert_eq!(*cards_per_status, 1);
             }
-            _ => panic!("Expected DrawCard effect"),
+            _ => panic!("Expected ActivateEvolve effect"),
         }
     }
 
@@ -77,10 +79,10 @@ mod tests {
 
         assert_eq!(effects.len(), 1);
         match &effects[0] {
-            BattleEffect::DrawCard { count } => {
-                assert_eq!(*count, 1);
+            BattleEffect::ActivateEvolve { cards_per_status } => {
+                assert_eq!(*cards_per_status, 1);
             }
-            _ => panic!("Expected DrawCard effect"),
+            _ => panic!("Expected ActivateEvolve effect"),
         }
     }
 
@@ -128,11 +130,8 @@ mod tests {
         let result = battle.play_card(evolve_idx, Entity::Player);
         assert!(result.is_ok());
 
-        // Verify hand size (Evolve goes to discard, DrawCard effect draws 1 card)
+        // Evolve is a power, so it leaves the hand and draws nothing by itself
         let final_hand_size = battle.cards.hand_size();
-
-        // Note: The current implementation doesn't handle DrawCard effects in card play
-        // So hand size decreases by 1 (card goes to discard but draw doesn't work)
         assert_eq!(final_hand_size, initial_hand_size - 1, "Hand size decreases by 1 when Evolve is played");
     }
 
@@ -173,10 +172,8 @@ mod tests {
         let final_energy = battle.get_player().get_energy();
         assert_eq!(final_energy, initial_energy);
 
-        // Verify hand size increased by 1 (lost Evolve+, gained 1 card from draw)
+        // Evolve+ is a power, so it leaves the hand and draws nothing by itself
         let final_hand_size = battle.cards.hand_size();
-        // Note: The current implementation doesn't handle DrawCard effects in card play
-        // So hand size decreases by 1 (card goes to discard but draw doesn't work)
         assert_eq!(final_hand_size, initial_hand_size - 1, "Hand size decreases by 1 when Evolve is played");
     }
 
@@ -276,4 +273,56 @@ mod tests {
         let final_hand_size = battle.cards.hand_size();
         assert_eq!(final_hand_size, initial_hand_size - 1, "Hand size should decrease by 1 when no cards to draw");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_evolve_power_only_triggers_on_status() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Evolve, 1));
+
+        let status_drawn = BattleEvent::CardDrawn { card_type: CardType::Status, is_status_or_curse: true, on_draw: vec![] };
+        assert_eq!(powers.on_event(&status_drawn, Entity::Player), vec![BattleEffect::DrawCard { count: 1 }]);
+
+        let curse_drawn = BattleEvent::CardDrawn { card_type: CardType::Curse, is_status_or_curse: true, on_draw: vec![] };
+        assert!(powers.on_event(&curse_drawn, Entity::Player).is_empty());
+
+        let attack_drawn = BattleEvent::CardDrawn { card_type: CardType::Attack, is_status_or_curse: false, on_draw: vec![] };
+        assert!(powers.on_event(&attack_drawn, Entity::Player).is_empty());
+    }
+
+    #[test]
+    fn test_evolve_draws_when_status_drawn() {
+        use crate::battle::Battle;
+        use crate::battle::target::Entity;
+        use crate::battle::enemy_in_battle::EnemyInBattle;
+        use crate::game::deck::Deck;
+        use crate::game::effect::{BaseEffect, StatusPlacement};
+        use crate::game::global_info::GlobalInfo;
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::enemies::enemy_enum::EnemyEnum;
+        use crate::cards::ironclad::strike::strike;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let deck = Deck::new(vec![strike(); 6]);
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+        assert_eq!(battle.cards.deck_size(), 1);
+
+        battle.add_card_to_hand_for_testing(evolve());
+        battle.play_card(battle.cards.hand_size() - 1, Entity::Player).unwrap();
+        assert_eq!(battle.cards.hand_size(), 5);
+
+        battle.eval_base_effect(&BaseEffect::AddStatusCard {
+            status_card: CardEnum::Wound,
+            count: 1,
+            placement: StatusPlacement::TopOfDrawPile,
+            upgraded: false,
+        });
+        battle.eval_base_effect(&BaseEffect::DrawCard { source: Entity::Player, count: 1 });
+
+        // Drew the Wound, which triggered Evolve to draw the last Strike
+        assert_eq!(battle.cards.hand_size(), 7);
+        assert_eq!(battle.cards.deck_size(), 0);
+        assert_eq!(battle.get_hand()[5].get_name(), "Wound");
+    }
+}
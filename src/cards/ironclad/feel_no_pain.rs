@@ -1,55 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::{Card, Rarity, CardClass}, card_type::CardType, card_enum::CardEnum, effect::BattleEffect};
-use crate::battle::battle_events::{BattleEvent, EventListener};
-use crate::battle::target::Entity;
-
-/// Event listener for Feel No Pain power
-/// Whenever a card is exhausted, gain block
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct FeelNoPainListener {
-    owner: Entity,
-    block_per_exhaust: u32,
-}
-
-impl FeelNoPainListener {
-    pub fn new(owner: Entity, block_per_exhaust: u32) -> Self {
-        Self {
-            owner,
-            block_per_exhaust,
-        }
-    }
-}
-
-impl EventListener for FeelNoPainListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CardExhausted { source } if *source == self.owner => {
-                // When the owner exhausts a card, gain block
-                vec![BattleEffect::GainDefense {
-                    amount: self.block_per_exhaust,
-                }]
-            }
-            _ => vec![],
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true // Always active once played
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Feel No Pain - Power Card
 /// Cost: 1
@@ -1,6 +1,4 @@
 use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
-use crate::battle::{target::Entity, battle_events::BattleEvent, battle_events::EventListener};
-use serde::{Serialize, Deserialize};
 
 pub fn fire_breathing() -> Card {
     Card::new(CardEnum::FireBreathing, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Power), vec![
@@ -72,55 +70,11 @@ mod tests {
     }
 }
 
-#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct FireBreathingListener {
-    source: Entity,
-    damage_per_status: u32,
-}
-
-impl FireBreathingListener {
-    pub fn new(source: Entity, damage_per_status: u32) -> Self {
-        Self { source, damage_per_status }
-    }
-}
-
-impl EventListener for FireBreathingListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CardDrawn { card_type: _, is_status_or_curse }
-                if *is_status_or_curse => {
-                vec![
-                    BattleEffect::AttackAllEnemies {
-                        amount: self.damage_per_status,
-                        num_attacks: 1,
-                    }
-                ]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.source
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
-
 #[cfg(test)]
 mod integration_tests {
     use super::*;
+    use crate::battle::battle_events::BattleEvent;
+    use crate::battle::power::{Power, PowerId, Powers};
     use crate::battle::Battle;
     use crate::battle::target::Entity;
     use crate::battle::enemy_in_battle::EnemyInBattle;
@@ -148,7 +102,7 @@ mod integration_tests {
     }
 
     #[test]
-    fn test_fire_breathing_activates_listener() {
+    fn test_fire_breathing_activates_power() {
         let mut rng = rand::rng();
         let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
         let jaw_worm = JawWorm::instantiate(&mut rng, &global_info);
@@ -186,66 +140,87 @@ mod integration_tests {
     }
 
     #[test]
-    fn test_fire_breathing_listener_triggers_on_status_draw() {
-        let mut listener = FireBreathingListener::new(Entity::Player, 6);
+    fn test_fire_breathing_power_triggers_on_status_draw() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FireBreathing, 6));
 
         // Simulate drawing a status card
         let event = BattleEvent::CardDrawn {
             card_type: CardType::Status,
             is_status_or_curse: true,
+            on_draw: vec![],
         };
 
-        let effects = listener.on_event(&event);
+        let effects = powers.on_event(&event, Entity::Player);
         assert_eq!(effects.len(), 1);
 
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 6);
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::DealDamageToAllEnemies { amount: 6 });
     }
 
     #[test]
-    fn test_fire_breathing_upgraded_listener_higher_damage() {
-        let mut listener = FireBreathingListener::new(Entity::Player, 10);
+    fn test_fire_breathing_upgraded_power_higher_damage() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FireBreathing, 10));
 
         // Simulate drawing a status card
         let event = BattleEvent::CardDrawn {
             card_type: CardType::Status,
             is_status_or_curse: true,
+            on_draw: vec![],
         };
 
-        let effects = listener.on_event(&event);
+        let effects = powers.on_event(&event, Entity::Player);
         assert_eq!(effects.len(), 1);
 
-        match &effects[0] {
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                assert_eq!(*amount, 10);
-                assert_eq!(*num_attacks, 1);
-            }
-            _ => panic!("Expected AttackAllEnemies effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::DealDamageToAllEnemies { amount: 10 });
     }
 
     #[test]
-    fn test_fire_breathing_listener_ignores_normal_cards() {
-        let mut listener = FireBreathingListener::new(Entity::Player, 6);
+    fn test_fire_breathing_power_ignores_normal_cards() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FireBreathing, 6));
 
         // Simulate drawing a normal Attack card
         let event = BattleEvent::CardDrawn {
             card_type: CardType::Attack,
             is_status_or_curse: false,
+            on_draw: vec![],
         };
 
-        let effects = listener.on_event(&event);
+        let effects = powers.on_event(&event, Entity::Player);
         assert_eq!(effects.len(), 0);
     }
 
     #[test]
-    fn test_fire_breathing_listener_is_always_active() {
-        let listener = FireBreathingListener::new(Entity::Player, 6);
-        assert!(listener.is_active());
+    fn test_fire_breathing_power_is_always_active() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FireBreathing, 6));
+        assert!(powers.has(PowerId::FireBreathing));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fire_breathing_triggers_when_void_drawn() {
+        use crate::game::effect::{BaseEffect, StatusPlacement};
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)))];
+        let mut battle = Battle::new(Deck::new(vec![fire_breathing()]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+
+        battle.play_card(0, Entity::Player).unwrap();
+        assert_eq!(battle.get_player().get_energy(), 2);
+
+        battle.eval_base_effect(&BaseEffect::AddStatusCard {
+            status_card: CardEnum::Void,
+            count: 1,
+            placement: StatusPlacement::TopOfDrawPile,
+            upgraded: false,
+        });
+        battle.eval_base_effect(&BaseEffect::DrawCard { source: Entity::Player, count: 1 });
+
+        // Void's own on-draw effect and Fire Breathing both resolve
+        assert_eq!(battle.get_hand()[0].get_name(), "Void");
+        assert_eq!(battle.get_player().get_energy(), 1);
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), 38);
+    }
+}
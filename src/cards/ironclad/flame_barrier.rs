@@ -1,58 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
-
-/// Flame Barrier Listener
-/// When player takes damage from enemy attacks this turn, deal damage back
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct FlameBarrierListener {
-    owner: Entity,
-    damage_to_deal: u32,
-    is_active: bool,
-}
-
-impl FlameBarrierListener {
-    pub fn new(owner: Entity, damage_to_deal: u32) -> Self {
-        FlameBarrierListener {
-            owner,
-            damage_to_deal,
-            is_active: true,
-        }
-    }
-}
-
-impl EventListener for FlameBarrierListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::DamageTaken { target, source, amount: _ }
-                if *target == self.owner && self.is_active && matches!(source, Entity::Enemy(_)) => {
-                // When player takes damage from enemy, retaliate with damage
-                vec![BattleEffect::AttackAllEnemies {
-                    amount: self.damage_to_deal,
-                    num_attacks: 1,
-                }]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        self.is_active
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Flame Barrier - Skill Card
 /// Cost: 2 (2 when upgraded)
@@ -77,6 +23,10 @@ pub fn flame_barrier_upgraded() -> Card {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::battle::{Battle, target::Entity, enemy_in_battle::EnemyInBattle};
+    use crate::battle::power::{Power, PowerId, Powers};
+    use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+    use crate::game::{PlayerRunState, deck::Deck, effect::BaseEffect, enemy::EnemyTrait, global_info::GlobalInfo};
 
     #[test]
     fn test_flame_barrier_creation() {
@@ -103,103 +53,95 @@ mod tests {
     }
 
     #[test]
-    fn test_flame_barrier_listener_creation() {
-        let listener = FlameBarrierListener::new(Entity::Player, 4);
-        assert!(listener.is_active());
-        assert_eq!(listener.get_owner(), Entity::Player);
+    fn test_flame_barrier_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::FlameBarrier, 4));
+        assert!(powers.has(PowerId::FlameBarrier));
     }
 
-    #[test]
-    fn test_flame_barrier_triggers_on_enemy_attack() {
-        let mut listener = FlameBarrierListener::new(Entity::Player, 4);
-
-        let damage_event = BattleEvent::DamageTaken {
-            target: Entity::Player,
-            source: Entity::Enemy(0),
-            amount: 10,
-        };
-
-        let effects = listener.on_event(&damage_event);
-        assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies { amount: 4, num_attacks: 1 });
-        assert!(listener.is_active()); // Still active after triggering
+    fn battle_with_flame_barrier(damage: i32) -> Battle {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info))),
+            EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info))),
+        ];
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
+        battle.apply_power(Entity::Player, Power::new(PowerId::FlameBarrier, damage));
+        battle
     }
 
     #[test]
-    fn test_flame_barrier_does_not_trigger_on_self_damage() {
-        let mut listener = FlameBarrierListener::new(Entity::Player, 4);
+    fn test_flame_barrier_triggers_on_enemy_attack() {
+        let mut battle = battle_with_flame_barrier(4);
+        let enemy_hp = battle.get_enemies()[0].battle_info.get_hp();
+        let other_enemy_hp = battle.get_enemies()[1].battle_info.get_hp();
 
-        let self_damage_event = BattleEvent::DamageTaken {
-            target: Entity::Player,
-            source: Entity::Player,
-            amount: 5,
-        };
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 10, num_attacks: 1, strength_multiplier: 1,
+        });
+        battle.process_effect_queue();
 
-        let effects = listener.on_event(&self_damage_event);
-        assert_eq!(effects.len(), 0);
-        assert!(listener.is_active());
+        // Only the attacker is hit back
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), enemy_hp - 4);
+        assert_eq!(battle.get_enemies()[1].battle_info.get_hp(), other_enemy_hp);
+        assert!(battle.get_player().battle_info.has_power(PowerId::FlameBarrier)); // Still active after triggering
     }
 
     #[test]
-    fn test_flame_barrier_does_not_trigger_on_enemy_damage() {
-        let mut listener = FlameBarrierListener::new(Entity::Player, 4);
+    fn test_flame_barrier_triggers_when_attack_is_blocked() {
+        let mut battle = battle_with_flame_barrier(4);
+        let enemy_hp = battle.get_enemies()[0].battle_info.get_hp();
+        battle.eval_base_effect(&BaseEffect::GainDefense { source: Entity::Player, amount: 20 });
 
-        let enemy_damage_event = BattleEvent::DamageTaken {
-            target: Entity::Enemy(0),
-            source: Entity::Player,
-            amount: 8,
-        };
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 10, num_attacks: 2, strength_multiplier: 1,
+        });
+        battle.process_effect_queue();
 
-        let effects = listener.on_event(&enemy_damage_event);
-        assert_eq!(effects.len(), 0);
-        assert!(listener.is_active());
+        assert_eq!(battle.get_player().battle_info.get_hp(), 50);
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), enemy_hp - 8);
     }
 
     #[test]
-    fn test_flame_barrier_damage_amounts() {
-        let mut base_listener = FlameBarrierListener::new(Entity::Player, 4);
-        let mut upgraded_listener = FlameBarrierListener::new(Entity::Player, 6);
+    fn test_flame_barrier_does_not_trigger_on_self_damage() {
+        let mut battle = battle_with_flame_barrier(4);
+        let enemy_hp = battle.get_enemies()[0].battle_info.get_hp();
 
-        let damage_event = BattleEvent::DamageTaken {
-            target: Entity::Player,
-            source: Entity::Enemy(0),
-            amount: 10,
-        };
+        battle.eval_base_effect(&BaseEffect::LoseHp { target: Entity::Player, amount: 5 });
+        battle.process_effect_queue();
 
-        let base_effects = base_listener.on_event(&damage_event);
-        let upgraded_effects = upgraded_listener.on_event(&damage_event);
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), enemy_hp);
+        assert!(battle.get_player().battle_info.has_power(PowerId::FlameBarrier));
+    }
+
+    #[test]
+    fn test_flame_barrier_does_not_trigger_on_enemy_damage() {
+        let mut battle = battle_with_flame_barrier(4);
+        let player_hp = battle.get_player().battle_info.get_hp();
 
-        assert_eq!(base_effects.len(), 1);
-        assert_eq!(base_effects[0], BattleEffect::AttackAllEnemies { amount: 4, num_attacks: 1 });
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Player, target: Entity::Enemy(0), amount: 8, num_attacks: 1, strength_multiplier: 1,
+        });
+        battle.process_effect_queue();
 
-        assert_eq!(upgraded_effects.len(), 1);
-        assert_eq!(upgraded_effects[0], BattleEffect::AttackAllEnemies { amount: 6, num_attacks: 1 });
+        assert_eq!(battle.get_player().battle_info.get_hp(), player_hp);
+        assert!(battle.get_player().battle_info.has_power(PowerId::FlameBarrier));
     }
 
     #[test]
-    fn test_flame_barrier_only_triggers_for_owner() {
-        let mut listener = FlameBarrierListener::new(Entity::Player, 4);
-
-        // Enemy taking damage should not trigger
-        let enemy_damage_event = BattleEvent::DamageTaken {
-            target: Entity::Enemy(0),
-            source: Entity::Enemy(1),
-            amount: 5,
-        };
-
-        let effects = listener.on_event(&enemy_damage_event);
-        assert_eq!(effects.len(), 0);
-
-        // Player taking damage from enemy should trigger
-        let player_damage_event = BattleEvent::DamageTaken {
-            target: Entity::Player,
-            source: Entity::Enemy(0),
-            amount: 8,
-        };
-
-        let effects = listener.on_event(&player_damage_event);
-        assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies { amount: 4, num_attacks: 1 });
+    fn test_flame_barrier_damage_amounts() {
+        for damage in [4, 6] {
+            let mut battle = battle_with_flame_barrier(damage);
+            let enemy_hp = battle.get_enemies()[1].battle_info.get_hp();
+
+            battle.eval_base_effect(&BaseEffect::AttackToTarget {
+                source: Entity::Enemy(1), target: Entity::Player, amount: 10, num_attacks: 1, strength_multiplier: 1,
+            });
+            battle.process_effect_queue();
+
+            assert_eq!(battle.get_enemies()[1].battle_info.get_hp(), enemy_hp - damage as u32);
+        }
     }
 
     #[test]
@@ -1,11 +1,11 @@
-use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum};
+use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition, TargetSelector}, card_type::CardType, card_enum::CardEnum};
 
 /// Immolate - Rare Attack Card
 /// Cost: 2 (2 when upgraded)
 /// Effect: Deal 21 damage to ALL enemies. Add a Burn to your discard pile.
 pub fn immolate() -> Card {
     Card::new(CardEnum::Immolate, 2, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
-            BattleEffect::AttackAllEnemies { amount: 21, num_attacks: 1 },
+            BattleEffect::AttackToTarget { amount: 21, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies),
             BattleEffect::AddCardToDiscard(CardEnum::Burn),
         ])
         .set_play_condition(Condition::True)
@@ -16,7 +16,7 @@ pub fn immolate() -> Card {
 /// Effect: Deal 28 damage to ALL enemies. Add a Burn to your discard pile.
 pub fn immolate_upgraded() -> Card {
     Card::new(CardEnum::Immolate, 2, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
-            BattleEffect::AttackAllEnemies { amount: 28, num_attacks: 1 },
+            BattleEffect::AttackToTarget { amount: 28, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies),
             BattleEffect::AddCardToDiscard(CardEnum::Burn),
         ])
         .set_play_condition(Condition::True)
@@ -59,7 +59,7 @@ mod tests {
         let effects = card.get_effects();
 
         assert_eq!(effects.len(), 2);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies { amount: 21, num_attacks: 1 });
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 21, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
         assert_eq!(effects[1], BattleEffect::AddCardToDiscard(CardEnum::Burn));
     }
 
@@ -69,7 +69,7 @@ mod tests {
         let effects = card.get_effects();
 
         assert_eq!(effects.len(), 2);
-        assert_eq!(effects[0], BattleEffect::AttackAllEnemies { amount: 28, num_attacks: 1 });
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 28, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
         assert_eq!(effects[1], BattleEffect::AddCardToDiscard(CardEnum::Burn));
     }
 
@@ -91,8 +91,8 @@ mod tests {
         let upgraded_effects = upgraded_card.get_effects();
 
         // Check damage amounts
-        assert_eq!(base_effects[0], BattleEffect::AttackAllEnemies { amount: 21, num_attacks: 1 });
-        assert_eq!(upgraded_effects[0], BattleEffect::AttackAllEnemies { amount: 28, num_attacks: 1 });
+        assert_eq!(base_effects[0], BattleEffect::AttackToTarget { amount: 21, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
+        assert_eq!(upgraded_effects[0], BattleEffect::AttackToTarget { amount: 28, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
 
         // Both should add Burn to discard
         assert_eq!(base_effects[1], BattleEffect::AddCardToDiscard(CardEnum::Burn));
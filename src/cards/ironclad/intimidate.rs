@@ -1,14 +1,14 @@
-use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum};
+use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition, TargetSelector}, card_type::CardType, card_enum::CardEnum};
 
 /// Intimidate - Apply 1 Weak to all enemies. Exhaust.
 pub fn intimidate() -> Card {
-    Card::new(CardEnum::Intimidate, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::ApplyWeakAll { duration: 1 }, BattleEffect::Exhaust])
+    Card::new(CardEnum::Intimidate, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::ApplyWeak { duration: 1 }.targeting(TargetSelector::AllEnemies), BattleEffect::Exhaust])
         .set_play_condition(Condition::True)
 }
 
 /// Intimidate+ (Upgraded version) - Apply 2 Weak to all enemies
 pub fn intimidate_upgraded() -> Card {
-    Card::new(CardEnum::Intimidate, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::ApplyWeakAll { duration: 2 }, BattleEffect::Exhaust])
+    Card::new(CardEnum::Intimidate, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Skill), vec![BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies), BattleEffect::Exhaust])
         .set_upgraded(true)
         .set_play_condition(Condition::True)
 }
@@ -29,7 +29,7 @@ mod tests {
         assert!(!intimidate_card.is_upgraded());
 
         let effects = intimidate_card.get_effects();
-        assert!(effects.iter().any(|e| matches!(e, BattleEffect::ApplyWeakAll { duration: 1 })));
+        assert!(effects.iter().any(|e| *e == BattleEffect::ApplyWeak { duration: 1 }.targeting(TargetSelector::AllEnemies)));
         assert!(effects.iter().any(|e| matches!(e, BattleEffect::Exhaust)));
     }
 
@@ -42,7 +42,7 @@ mod tests {
         assert!(intimidate_plus.is_upgraded());
 
         let effects = intimidate_plus.get_effects();
-        assert!(effects.iter().any(|e| matches!(e, BattleEffect::ApplyWeakAll { duration: 2 })));
+        assert!(effects.iter().any(|e| *e == BattleEffect::ApplyWeak { duration: 2 }.targeting(TargetSelector::AllEnemies)));
         assert!(effects.iter().any(|e| matches!(e, BattleEffect::Exhaust)));
     }
 
@@ -1,59 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
-
-/// Metallicize Listener
-/// At the end of each turn, gain Block
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct MetallicizeListener {
-    owner: Entity,
-    block_amount: u32,
-    is_active: bool,
-}
-
-impl MetallicizeListener {
-    pub fn new(owner: Entity, block_amount: u32) -> Self {
-        MetallicizeListener {
-            owner,
-            block_amount,
-            is_active: true,
-        }
-    }
-
-    /// Deactivate this listener (used when Lagavulin wakes up)
-    pub fn deactivate(&mut self) {
-        self.is_active = false;
-    }
-}
-
-impl EventListener for MetallicizeListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::EndOfTurn { entity } if *entity == self.owner && self.is_active => {
-                // At end of turn, gain Block
-                vec![BattleEffect::GainDefense { amount: self.block_amount }]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        self.is_active
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Metallicize - Power Card
 /// Cost: 1 (1 when upgraded)
@@ -78,6 +23,8 @@ mod tests {
     use crate::game::PlayerRunState;
 
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_metallicize_creation() {
@@ -104,50 +51,54 @@ mod tests {
     }
 
     #[test]
-    fn test_metallicize_listener_creation() {
-        let listener = MetallicizeListener::new(Entity::Player, 3);
-        assert!(listener.is_active());
-        assert_eq!(listener.get_owner(), Entity::Player);
+    fn test_metallicize_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Metallicize, 3));
+        assert!(powers.has(PowerId::Metallicize));
     }
 
     #[test]
     fn test_metallicize_triggers_on_end_of_turn() {
-        let mut listener = MetallicizeListener::new(Entity::Player, 3);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Metallicize, 3));
 
         let end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Player,
         };
 
-        let effects = listener.on_event(&end_turn_event);
+        let effects = powers.on_event(&end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert_eq!(effects[0], BattleEffect::GainDefense { amount: 3 });
-        assert!(listener.is_active()); // Still active after triggering
+        assert!(powers.has(PowerId::Metallicize)); // Still active after triggering
     }
 
     #[test]
     fn test_metallicize_does_not_trigger_on_enemy_turn() {
-        let mut listener = MetallicizeListener::new(Entity::Player, 3);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Metallicize, 3));
 
         let enemy_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&enemy_turn_event);
+        let effects = powers.on_event(&enemy_turn_event, Entity::Player);
         assert_eq!(effects.len(), 0);
-        assert!(listener.is_active());
+        assert!(powers.has(PowerId::Metallicize));
     }
 
     #[test]
     fn test_metallicize_block_amounts() {
-        let mut base_listener = MetallicizeListener::new(Entity::Player, 3);
-        let mut upgraded_listener = MetallicizeListener::new(Entity::Player, 4);
+        let mut base_powers = Powers::new();
+        base_powers.apply(Power::new(PowerId::Metallicize, 3));
+        let mut upgraded_powers = Powers::new();
+        upgraded_powers.apply(Power::new(PowerId::Metallicize, 4));
 
         let end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Player,
         };
 
-        let base_effects = base_listener.on_event(&end_turn_event);
-        let upgraded_effects = upgraded_listener.on_event(&end_turn_event);
+        let base_effects = base_powers.on_event(&end_turn_event, Entity::Player);
+        let upgraded_effects = upgraded_powers.on_event(&end_turn_event, Entity::Player);
 
         assert_eq!(base_effects.len(), 1);
         assert_eq!(base_effects[0], BattleEffect::GainDefense { amount: 3 });
@@ -158,14 +109,15 @@ mod tests {
 
     #[test]
     fn test_metallicize_only_triggers_for_owner() {
-        let mut listener = MetallicizeListener::new(Entity::Player, 3);
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Metallicize, 3));
 
         // Enemy end of turn should not trigger
         let enemy_end_turn_event = BattleEvent::EndOfTurn {
             entity: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&enemy_end_turn_event);
+        let effects = powers.on_event(&enemy_end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 0);
 
         // Player end of turn should trigger
@@ -173,7 +125,7 @@ mod tests {
             entity: Entity::Player,
         };
 
-        let effects = listener.on_event(&player_end_turn_event);
+        let effects = powers.on_event(&player_end_turn_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert_eq!(effects[0], BattleEffect::GainDefense { amount: 3 });
     }
@@ -60,6 +60,7 @@ pub mod evolve;
 pub mod demon_form;
 pub mod second_wind;
 pub mod rupture;
+pub mod juggernaut;
 pub mod dual_wield;
 pub mod double_tap;
 pub mod exhume;
@@ -67,6 +68,8 @@ pub mod feed;
 pub mod reaper;
 pub mod fiend_fire;
 pub mod fire_breathing;
+pub mod barricade;
+pub mod battle_trance;
 
 pub use strike::strike;
 pub use strike::strike_upgraded;
@@ -190,6 +193,8 @@ pub use second_wind::second_wind;
 pub use second_wind::second_wind_upgraded;
 pub use rupture::rupture;
 pub use rupture::rupture_upgraded;
+pub use juggernaut::juggernaut;
+pub use juggernaut::juggernaut_upgraded;
 pub use dual_wield::dual_wield;
 pub use dual_wield::dual_wield_upgraded;
 pub use double_tap::double_tap;
@@ -203,4 +208,8 @@ pub use reaper::reaper_upgraded;
 pub use fiend_fire::fiend_fire;
 pub use fiend_fire::fiend_fire_upgraded;
 pub use fire_breathing::fire_breathing;
-pub use fire_breathing::fire_breathing_upgraded;
\ No newline at end of file
+pub use fire_breathing::fire_breathing_upgraded;
+pub use barricade::barricade;
+pub use barricade::barricade_upgraded;
+pub use battle_trance::battle_trance;
+pub use battle_trance::battle_trance_upgraded;
\ No newline at end of file
@@ -187,8 +187,8 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
 
         // Draw both cards
-        battle.cards.draw_card();
-        battle.cards.draw_card();
+        battle.cards.draw_card(&mut rand::rng());
+        battle.cards.draw_card(&mut rand::rng());
 
         // Play Inflame to gain 2 Strength
         let inflame_idx = battle.cards.get_hand().iter()
@@ -1,6 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::{Card, Rarity, CardClass}, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
 
 /// Rage - Uncommon Skill Card
 /// Cost: 0 (0 when upgraded)
@@ -28,6 +26,7 @@ mod tests {
     use crate::game::PlayerRunState;
 
     use super::*;
+    use crate::battle::target::Entity;
 
     #[test]
     fn test_rage_creation() {
@@ -259,56 +258,3 @@ mod tests {
     }
 }
 
-#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct RageListener {
-    source: Entity,
-    block_per_attack: u32,
-    is_active: bool,
-}
-
-impl RageListener {
-    pub fn new(source: Entity, block_per_attack: u32) -> Self {
-        Self {
-            source,
-            block_per_attack,
-            is_active: true,
-        }
-    }
-}
-
-impl EventListener for RageListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CardPlayed { source, card_type }
-                if *source == self.source
-                && *card_type == crate::game::card_type::CardType::Attack
-                && self.is_active => {
-                // When an Attack card is played, gain Block
-                vec![BattleEffect::GainDefense { amount: self.block_per_attack }]
-            }
-            BattleEvent::EndOfTurn { entity } if *entity == self.source => {
-                // Rage effect expires at end of turn
-                self.is_active = false;
-                vec![]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        self.is_active
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.source
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
\ No newline at end of file
@@ -2,31 +2,42 @@ use crate::game::{card::{Card, Rarity, CardClass}, effect::BattleEffect, card_ty
 
 /// Rampage - Uncommon Attack Card
 /// Cost: 1
-/// Effect: Deal 8 damage. Increases by 5 each use
+/// Effect: Deal 8 damage. Increase this card's damage by 5 this combat
 pub fn rampage() -> Card {
-    Card::new(CardEnum::Rampage, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
-            BattleEffect::AttackToTargetWithScaling {
-                base_damage: 8,
-                scaling: 5,
-            },
-        ])
-        .set_playable(true)
+    rampage_with_damage(8, false)
 }
 
 /// Rampage+ (Upgraded version)
 /// Cost: 1
-/// Effect: Deal 8 damage. Increases by 8 each use
+/// Effect: Deal 8 damage. Increase this card's damage by 8 this combat
 pub fn rampage_upgraded() -> Card {
+    rampage_with_damage(8, true)
+}
+
+/// Build a Rampage that deals the given base damage
+fn rampage_with_damage(damage: u32, upgraded: bool) -> Card {
+    let scaling = if upgraded { 8 } else { 5 };
     Card::new(CardEnum::Rampage, 1, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
             BattleEffect::AttackToTargetWithScaling {
-                base_damage: 8,
-                scaling: 8, // upgraded version scales by 8 instead of 5
+                base_damage: damage,
+                scaling,
             },
         ])
-        .set_upgraded(true)
+        .set_upgraded(upgraded)
         .set_playable(true)
 }
 
+/// Upgrade a Rampage while keeping the damage it has already gained this combat
+pub fn upgrade_rampage(card: Card) -> Card {
+    let damage = card.get_effects().iter()
+        .find_map(|effect| match effect {
+            BattleEffect::AttackToTargetWithScaling { base_damage, .. } => Some(*base_damage),
+            _ => None,
+        })
+        .unwrap_or(8);
+    rampage_with_damage(damage, true)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::game::PlayerRunState;
@@ -103,10 +114,6 @@ mod tests {
         let deck = Deck::new(vec![rampage()]);
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
 
-        // Check initial rampage damage scaling (should be 0)
-        let initial_rampage_damage = battle.get_player().battle_info.get_rampage_damage();
-        assert_eq!(initial_rampage_damage, 0);
-
         // Check initial enemy HP
         let initial_hp = battle.get_enemies()[0].get_current_hp();
 
@@ -119,14 +126,15 @@ mod tests {
         let final_hp = battle.get_enemies()[0].get_current_hp();
         assert_eq!(final_hp, initial_hp - 8);
 
-        // Rampage damage scaling should increase by 5
-        let new_rampage_damage = battle.get_player().battle_info.get_rampage_damage();
-        assert_eq!(new_rampage_damage, 5);
+        // The played Rampage now deals 5 more damage
+        let played = &battle.cards.get_discard_pile()[0];
+        assert_eq!(played.get_effects()[0], BattleEffect::AttackToTargetWithScaling { base_damage: 13, scaling: 5 });
     }
 
     #[test]
     fn test_rampage_scaling() {
         use crate::battle::Battle;
+        use crate::battle::battle_action::BattleAction;
         use crate::battle::target::Entity;
         use crate::battle::enemy_in_battle::EnemyInBattle;
         use crate::game::deck::Deck;
@@ -145,40 +153,33 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
 
         // Draw both cards
-        battle.cards.draw_card();
-        battle.cards.draw_card();
+        battle.cards.draw_card(&mut rand::rng());
+        battle.cards.draw_card(&mut rand::rng());
 
         // Check initial enemy HP
         let initial_hp = battle.get_enemies()[0].get_current_hp();
 
-        // Play first Rampage
-        let first_rampage_idx = battle.cards.get_hand().iter()
-            .position(|c| c.get_name() == "Rampage").unwrap();
-        let result = battle.play_card(first_rampage_idx, Entity::Enemy(0));
-        assert!(result.is_ok());
-
-        // First Rampage should deal 8 damage (8 base + 0 scaling)
+        // Each copy scales on its own, so both first plays deal 8 damage
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
         let hp_after_first = battle.get_enemies()[0].get_current_hp();
         assert_eq!(hp_after_first, initial_hp - 8);
 
-        // Play second Rampage
-        let second_rampage_idx = battle.cards.get_hand().iter()
-            .position(|c| c.get_name() == "Rampage").unwrap();
-        let result = battle.play_card(second_rampage_idx, Entity::Enemy(0));
-        assert!(result.is_ok());
-
-        // Second Rampage should deal 13 damage (8 base + 5 scaling from first use)
-        let final_hp = battle.get_enemies()[0].get_current_hp();
-        assert_eq!(final_hp, hp_after_first - 13);
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        let hp_after_second = battle.get_enemies()[0].get_current_hp();
+        assert_eq!(hp_after_second, hp_after_first - 8);
 
-        // Total scaling should be 10 (5 from each use)
-        let final_rampage_damage = battle.get_player().battle_info.get_rampage_damage();
-        assert_eq!(final_rampage_damage, 10);
+        // Both are reshuffled and drawn next turn, each having gained 5 damage
+        battle.eval_action(BattleAction::EndTurn, &mut rng).unwrap();
+        assert_eq!(battle.cards.hand_size(), 2);
+        let hp_before_replay = battle.get_enemies()[0].get_current_hp();
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), hp_before_replay - 13);
     }
 
     #[test]
     fn test_rampage_upgraded_scaling() {
         use crate::battle::Battle;
+        use crate::battle::battle_action::BattleAction;
         use crate::battle::target::Entity;
         use crate::battle::enemy_in_battle::EnemyInBattle;
         use crate::game::deck::Deck;
@@ -192,40 +193,33 @@ mod tests {
         let gremlin_nob = GremlinNob::instantiate(&mut rng, &global_info);
         let enemies = vec![EnemyInBattle::new(EnemyEnum::GremlinNob(gremlin_nob))];
 
-        // Create battle with two Rampage+ cards
-        let deck = Deck::new(vec![rampage_upgraded(), rampage_upgraded()]);
+        // Create battle with a single Rampage+ card
+        let deck = Deck::new(vec![rampage_upgraded()]);
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
-
-        // Draw both cards
-        battle.cards.draw_card();
-        battle.cards.draw_card();
+        battle.cards.draw_card(&mut rand::rng());
 
         // Check initial enemy HP
         let initial_hp = battle.get_enemies()[0].get_current_hp();
 
-        // Play first Rampage+
-        let first_rampage_idx = battle.cards.get_hand().iter()
-            .position(|c| c.get_name() == "Rampage+").unwrap();
-        let result = battle.play_card(first_rampage_idx, Entity::Enemy(0));
-        assert!(result.is_ok());
-
-        // First Rampage+ should deal 8 damage (8 base + 0 scaling)
+        // First Rampage+ should deal 8 damage
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
         let hp_after_first = battle.get_enemies()[0].get_current_hp();
         assert_eq!(hp_after_first, initial_hp - 8);
 
-        // Play second Rampage+
-        let second_rampage_idx = battle.cards.get_hand().iter()
-            .position(|c| c.get_name() == "Rampage+").unwrap();
-        let result = battle.play_card(second_rampage_idx, Entity::Enemy(0));
-        assert!(result.is_ok());
-
-        // Second Rampage+ should deal 16 damage (8 base + 8 scaling from first use)
-        let final_hp = battle.get_enemies()[0].get_current_hp();
-        assert_eq!(final_hp, hp_after_first - 16);
+        // Played again next turn it deals 16 damage (8 base + 8 from the first use)
+        battle.eval_action(BattleAction::EndTurn, &mut rng).unwrap();
+        let hp_before_second = battle.get_enemies()[0].get_current_hp();
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_enemies()[0].get_current_hp(), hp_before_second - 16);
+    }
 
-        // Total scaling should be 16 (8 from each use)
-        let final_rampage_damage = battle.get_player().battle_info.get_rampage_damage();
-        assert_eq!(final_rampage_damage, 16);
+    #[test]
+    fn test_upgrading_rampage_keeps_gained_damage() {
+        let mut card = rampage();
+        card.increase_scaling_damage();
+        let upgraded = card.upgrade();
+        assert_eq!(upgraded.get_name(), "Rampage+");
+        assert_eq!(upgraded.get_effects()[0], BattleEffect::AttackToTargetWithScaling { base_damage: 13, scaling: 8 });
     }
 
     #[test]
@@ -250,8 +244,8 @@ mod tests {
         let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), enemies, &mut rng);
 
         // Draw both cards
-        battle.cards.draw_card();
-        battle.cards.draw_card();
+        battle.cards.draw_card(&mut rand::rng());
+        battle.cards.draw_card(&mut rand::rng());
 
         // Play Inflame to gain 2 Strength
         let inflame_idx = battle.cards.get_hand().iter()
@@ -1,23 +1,23 @@
-use crate::game::{card::{Card, Rarity, CardClass}, card_type::CardType, card_enum::CardEnum, effect::BattleEffect};
+use crate::game::{card::{Card, Rarity, CardClass}, card_type::CardType, card_enum::CardEnum, effect::{BattleEffect, StatusPlacement}};
 
 /// Reckless Charge - Common Attack Card
 /// Cost: 0
-/// Effect: Deal 7 damage. Add Dazed to discard pile
+/// Effect: Deal 7 damage. Shuffle a Dazed into your draw pile
 pub fn reckless_charge() -> Card {
     Card::new(CardEnum::RecklessCharge, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
             BattleEffect::AttackToTarget { amount: 7, num_attacks: 1, strength_multiplier: 1 },
-            BattleEffect::AddStatusToDiscard { status_card: crate::game::card_enum::CardEnum::Dazed },
+            BattleEffect::AddStatusCard { status_card: CardEnum::Dazed, count: 1, placement: StatusPlacement::ShuffleIntoDrawPile, upgraded: false },
         ])
         .set_playable(true)
 }
 
 /// Reckless Charge+ (Upgraded version)
 /// Cost: 0
-/// Effect: Deal 10 damage. Add Dazed to discard pile
+/// Effect: Deal 10 damage. Shuffle a Dazed into your draw pile
 pub fn reckless_charge_upgraded() -> Card {
     Card::new(CardEnum::RecklessCharge, 0, CardClass::IronClad(Rarity::Uncommon, CardType::Attack), vec![
             BattleEffect::AttackToTarget { amount: 10, num_attacks: 1, strength_multiplier: 1 },
-            BattleEffect::AddStatusToDiscard { status_card: crate::game::card_enum::CardEnum::Dazed },
+            BattleEffect::AddStatusCard { status_card: CardEnum::Dazed, count: 1, placement: StatusPlacement::ShuffleIntoDrawPile, upgraded: false },
         ])
         .set_upgraded(true)
         .set_playable(true)
@@ -70,10 +70,13 @@ mod tests {
         }
 
         match &effects[1] {
-            BattleEffect::AddStatusToDiscard { status_card } => {
+            BattleEffect::AddStatusCard { status_card, count, placement, upgraded } => {
                 assert_eq!(status_card.name(), "Dazed");
+                assert_eq!(*count, 1);
+                assert_eq!(*placement, StatusPlacement::ShuffleIntoDrawPile);
+                assert!(!*upgraded);
             }
-            _ => panic!("Expected AddStatusToDiscard effect"),
+            _ => panic!("Expected AddStatusCard effect"),
         }
     }
 
@@ -107,6 +110,7 @@ let mut battle = Battle::new(deck, global_info, player_state, vec![enemy], &mut
 
         let initial_enemy_hp = battle.get_enemies()[0].get_current_hp();
         let initial_discard_size = battle.cards.discard_pile_size();
+        let initial_deck_size = battle.cards.deck_size();
 
         // Play Reckless Charge
         let result = battle.play_card(0, Entity::Enemy(0));
@@ -116,10 +120,10 @@ let mut battle = Battle::new(deck, global_info, player_state, vec![enemy], &mut
         let final_enemy_hp = battle.get_enemies()[0].get_current_hp();
         assert_eq!(final_enemy_hp, initial_enemy_hp.saturating_sub(7));
 
-        // Verify Dazed added to discard
-        assert_eq!(battle.cards.discard_pile_size(), initial_discard_size + 2);
-        let discard_cards = battle.cards.get_discard_pile();
-        assert_eq!(discard_cards.last().unwrap().get_name(), "Dazed");
+        // Verify Dazed shuffled into the draw pile, only Reckless Charge goes to discard
+        assert_eq!(battle.cards.discard_pile_size(), initial_discard_size + 1);
+        assert_eq!(battle.cards.deck_size(), initial_deck_size + 1);
+        assert!(battle.cards.get_deck().get_cards().iter().any(|c| c.get_name() == "Dazed"));
     }
 
     #[test]
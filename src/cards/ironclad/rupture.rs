@@ -1,52 +1,4 @@
-use serde::{Serialize, Deserialize};
 use crate::game::{card::Card, effect::BattleEffect, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
-use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
-
-/// Rupture Listener
-/// Gains 1 Strength whenever player loses HP
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct RuptureListener {
-    owner: Entity,
-    is_active: bool,
-}
-
-impl RuptureListener {
-    pub fn new(owner: Entity) -> Self {
-        RuptureListener {
-            owner,
-            is_active: true,
-        }
-    }
-}
-
-impl EventListener for RuptureListener {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::HpLostFromCard { target, amount } if *target == self.owner && *amount > 0 && self.is_active => {
-                // When player loses HP from cards, gain 1 Strength per HP lost
-                vec![BattleEffect::GainStrength { amount: *amount }]
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        self.is_active
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
-}
 
 /// Rupture - Uncommon Power Card
 /// Cost: 1
@@ -68,6 +20,8 @@ pub fn rupture_upgraded() -> Card {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::battle::{battle_events::BattleEvent, target::Entity};
+    use crate::battle::power::{Power, PowerId, Powers};
 
     #[test]
     fn test_rupture_creation() {
@@ -108,15 +62,16 @@ mod tests {
     }
 
     #[test]
-    fn test_rupture_listener_creation() {
-        let listener = RuptureListener::new(Entity::Player);
-        assert_eq!(listener.get_owner(), Entity::Player);
-        assert!(listener.is_active());
+    fn test_rupture_power_creation() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Rupture, 1));
+        assert!(powers.has(PowerId::Rupture));
     }
 
     #[test]
-    fn test_rupture_listener_hp_lost() {
-        let mut listener = RuptureListener::new(Entity::Player);
+    fn test_rupture_power_hp_lost() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Rupture, 1));
 
         // When player loses 5 HP from cards, should gain 5 Strength
         let hp_loss_event = BattleEvent::HpLostFromCard {
@@ -124,14 +79,15 @@ mod tests {
             amount: 5,
         };
 
-        let effects = listener.on_event(&hp_loss_event);
+        let effects = powers.on_event(&hp_loss_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert!(matches!(effects[0], BattleEffect::GainStrength { amount: 5 }));
     }
 
     #[test]
-    fn test_rupture_listener_hp_lost_small_amount() {
-        let mut listener = RuptureListener::new(Entity::Player);
+    fn test_rupture_power_hp_lost_small_amount() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Rupture, 1));
 
         // When player loses 1 HP from cards, should gain 1 Strength
         let hp_loss_event = BattleEvent::HpLostFromCard {
@@ -139,15 +95,16 @@ mod tests {
             amount: 1,
         };
 
-        let effects = listener.on_event(&hp_loss_event);
+        let effects = powers.on_event(&hp_loss_event, Entity::Player);
         assert_eq!(effects.len(), 1);
         assert!(matches!(effects[0], BattleEffect::GainStrength { amount: 1 }));
     }
 
     
     #[test]
-    fn test_rupture_listener_enemy_damage() {
-        let mut listener = RuptureListener::new(Entity::Player);
+    fn test_rupture_power_enemy_damage() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Rupture, 1));
 
         // When enemy takes damage, should not trigger
         let enemy_damage_event = BattleEvent::DamageTaken {
@@ -156,13 +113,14 @@ mod tests {
             source: Entity::Player,
         };
 
-        let effects = listener.on_event(&enemy_damage_event);
+        let effects = powers.on_event(&enemy_damage_event, Entity::Player);
         assert_eq!(effects.len(), 0);
     }
 
     #[test]
-    fn test_rupture_listener_zero_damage() {
-        let mut listener = RuptureListener::new(Entity::Player);
+    fn test_rupture_power_zero_damage() {
+        let mut powers = Powers::new();
+        powers.apply(Power::new(PowerId::Rupture, 1));
 
         // When player takes zero damage, should not trigger
         let zero_damage_event = BattleEvent::DamageTaken {
@@ -171,7 +129,7 @@ mod tests {
             source: Entity::Enemy(0),
         };
 
-        let effects = listener.on_event(&zero_damage_event);
+        let effects = powers.on_event(&zero_damage_event, Entity::Player);
         assert_eq!(effects.len(), 0);
     }
 
@@ -209,6 +167,43 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
         assert_eq!(battle.get_powers().len(), 1);
     }
 
+    #[test]
+    fn test_rupture_strength_does_not_apply_to_same_card_attack() {
+        use crate::battle::Battle;
+        use crate::battle::enemy_in_battle::EnemyInBattle;
+        use crate::game::deck::Deck;
+        use crate::game::global_info::GlobalInfo;
+        use crate::game::enemy::EnemyTrait;
+        use crate::enemies::jaw_worm::JawWorm;
+        use crate::enemies::enemy_enum::EnemyEnum;
+        use crate::cards::ironclad::hemokinesis::hemokinesis;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let jaw_worm = JawWorm::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(jaw_worm))];
+        let player_state = crate::game::player_run_state::PlayerRunState::new(50, 80, 0);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+
+        battle.add_card_to_hand_for_testing(rupture());
+        battle.play_card(0, Entity::Player).unwrap();
+
+        // Rupture's Strength resolves before Hemokinesis' attack, but the attack damage was calculated when it was played
+        let initial_enemy_hp = battle.get_enemies()[0].battle_info.get_hp();
+        battle.add_card_to_hand_for_testing(hemokinesis());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+
+        assert_eq!(battle.get_player().battle_info.get_strength(), 2);
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), initial_enemy_hp - 15);
+
+        // The next attack uses it
+        let hp_before_second = battle.get_enemies()[0].battle_info.get_hp();
+        battle.add_card_to_hand_for_testing(hemokinesis());
+        battle.play_card(0, Entity::Enemy(0)).unwrap();
+        assert_eq!(battle.get_player().battle_info.get_strength(), 4);
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), hp_before_second - 17);
+    }
+
     #[test]
     fn test_rupture_upgraded_name() {
         let card = rupture();
@@ -89,7 +89,7 @@ mod tests {
 
         // Draw all cards to hand
         for _ in 0..4 {
-            battle.cards.draw_card();
+            battle.cards.draw_card(&mut rand::rng());
         }
 
         let initial_block = battle.get_player().battle_info.get_block();
@@ -146,7 +146,7 @@ mod tests {
 
         // Draw all cards to hand
         for _ in 0..4 {
-            battle.cards.draw_card();
+            battle.cards.draw_card(&mut rand::rng());
         }
 
         let initial_block = battle.get_player().battle_info.get_block();
@@ -200,7 +200,7 @@ mod tests {
 
         // Draw all cards to hand
         for _ in 0..3 {
-            battle.cards.draw_card();
+            battle.cards.draw_card(&mut rand::rng());
         }
 
         let initial_block = battle.get_player().battle_info.get_block();
@@ -1,16 +1,16 @@
-use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::BattleEffect, card::{Rarity, CardClass}};
+use crate::game::{card::Card, card_type::CardType, card_enum::CardEnum, effect::{BattleEffect, TargetSelector}, card::{Rarity, CardClass}};
 
 pub fn thunderclap() -> Card {
     Card::new(CardEnum::Thunderclap, 1, CardClass::IronClad(Rarity::Common, CardType::Attack), vec![
-        BattleEffect::AttackAllEnemies { amount: 4, num_attacks: 1 },
-        BattleEffect::ApplyVulnerableAll { duration: 1 }
+        BattleEffect::AttackToTarget { amount: 4, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies),
+        BattleEffect::ApplyVulnerable { duration: 1 }.targeting(TargetSelector::AllEnemies)
     ])
 }
 
 pub fn thunderclap_upgraded() -> Card {
     Card::new(CardEnum::Thunderclap, 1, CardClass::IronClad(Rarity::Common, CardType::Attack), vec![
-        BattleEffect::AttackAllEnemies { amount: 7, num_attacks: 1 }, // +2 damage
-        BattleEffect::ApplyVulnerableAll { duration: 1 } //
+        BattleEffect::AttackToTarget { amount: 7, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies), // +2 damage
+        BattleEffect::ApplyVulnerable { duration: 1 }.targeting(TargetSelector::AllEnemies) //
     ])
         .set_upgraded(true)
 }
@@ -30,7 +30,7 @@ mod tests {
         let mut rng = rand::rng();
         let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
 
-        // Create multiple enemies to test ApplyVulnerableAll
+        // Create multiple enemies to test Vulnerable on all enemies
         let red_louse1 = RedLouse::instantiate(&mut rng, &global_info);
         let red_louse2 = RedLouse::instantiate(&mut rng, &global_info);
         let red_louse3 = RedLouse::instantiate(&mut rng, &global_info);
@@ -57,7 +57,7 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
         assert!(initial_vulnerable.iter().all(|&v| v == 0),
                 "All enemies should start with 0 vulnerable, got: {:?}", initial_vulnerable);
 
-        // Play thunderclap (target doesn't matter for ApplyVulnerableAll)
+        // Play thunderclap (target doesn't matter for all-enemies effects)
         let action = BattleAction::PlayCard(0, Entity::Enemy(0));
         let result = battle.eval_action(action, &mut rng);
         assert!(result.is_ok(), "Thunderclap should play successfully");
@@ -108,7 +108,7 @@ let mut battle2 = Battle::new(deck2, global_info, player_state, enemies2, &mut r
         let mut rng = rand::rng();
         let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
 
-        // Create multiple enemies to test ApplyVulnerableAll
+        // Create multiple enemies to test Vulnerable on all enemies
         let red_louse1 = RedLouse::instantiate(&mut rng, &global_info);
         let red_louse2 = RedLouse::instantiate(&mut rng, &global_info);
         let enemies = vec![
@@ -133,7 +133,7 @@ let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng)
         assert!(initial_vulnerable.iter().all(|&v| v == 0),
                 "All enemies should start with 0 vulnerable, got: {:?}", initial_vulnerable);
 
-        // Play thunderclap+ (target doesn't matter for ApplyVulnerableAll)
+        // Play thunderclap+ (target doesn't matter for all-enemies effects)
         let action = BattleAction::PlayCard(0, Entity::Enemy(0));
         let result = battle.eval_action(action, &mut rng);
         assert!(result.is_ok(), "Thunderclap+ should play successfully");
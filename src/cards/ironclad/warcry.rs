@@ -139,7 +139,7 @@ let mut battle = Battle::new_with_shuffle(deck, global_info, player_state, vec![
         assert!(select_result.is_ok(), "Should be able to select a card");
 
         // Check that the selected card is now on top of draw pile
-        let new_top_card = battle.cards.peek_top_card();
+        let new_top_card = battle.cards.peek_top_card(&mut rand::rng());
         assert!(new_top_card.is_some(), "Should have a card on top of draw pile");
 
         // The new top card should be the selected card
@@ -1,4 +1,4 @@
-use crate::game::{card::Card, effect::{BattleEffect, Condition}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
+use crate::game::{card::Card, effect::{BattleEffect, Condition, TargetSelector}, card_type::CardType, card_enum::CardEnum, card::{Rarity, CardClass}};
 
 /// Whirlwind - Uncommon Attack Card
 /// Cost: 0 (X-cost - spends all available energy)
@@ -9,9 +9,13 @@ pub fn whirlwind() -> Card {
         0, // X-cost - actual cost determined by available energy
         CardClass::IronClad(Rarity::Uncommon, CardType::Attack),
         vec![
-            BattleEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit: 5 },
+            BattleEffect::RepeatX {
+                effect: Box::new(BattleEffect::AttackToTarget { amount: 5, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)),
+                extra: 0,
+            },
         ]
     )
+        .set_x_cost(true)
         .set_play_condition(Condition::True)
 }
 
@@ -24,9 +28,13 @@ pub fn whirlwind_upgraded() -> Card {
         0, // X-cost - actual cost determined by available energy
         CardClass::IronClad(Rarity::Uncommon, CardType::Attack),
         vec![
-            BattleEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit: 8 },
+            BattleEffect::RepeatX {
+                effect: Box::new(BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)),
+                extra: 0,
+            },
         ]
     )
+        .set_x_cost(true)
         .set_upgraded(true)
         .set_play_condition(Condition::True)
 }
@@ -67,12 +75,10 @@ mod tests {
         let effects = card.get_effects();
 
         assert_eq!(effects.len(), 1);
-        match &effects[0] {
-            BattleEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit } => {
-                assert_eq!(*amount_per_hit, 5);
-            }
-            _ => panic!("Expected AttackAllEnemiesForCurrentEnergy effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::RepeatX {
+            effect: Box::new(BattleEffect::AttackToTarget { amount: 5, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)),
+            extra: 0,
+        });
     }
 
     #[test]
@@ -81,12 +87,10 @@ mod tests {
         let effects = card.get_effects();
 
         assert_eq!(effects.len(), 1);
-        match &effects[0] {
-            BattleEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit } => {
-                assert_eq!(*amount_per_hit, 8);
-            }
-            _ => panic!("Expected AttackAllEnemiesForCurrentEnergy effect"),
-        }
+        assert_eq!(effects[0], BattleEffect::RepeatX {
+            effect: Box::new(BattleEffect::AttackToTarget { amount: 8, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)),
+            extra: 0,
+        });
     }
 
     #[test]
@@ -96,6 +100,8 @@ mod tests {
 
         assert_eq!(base_card.get_cost(), 0, "Whirlwind should cost 0 energy (X-cost)");
         assert_eq!(upgraded_card.get_cost(), 0, "Whirlwind+ should also cost 0 energy (X-cost)");
+        assert!(base_card.is_x_cost());
+        assert!(upgraded_card.is_x_cost());
     }
 
     #[test]
@@ -8,6 +8,14 @@ pub fn burn() -> Card {
         .set_end_of_turn(vec![BattleEffect::LoseHp(2)]) // Deal 2 damage at end of turn
 }
 
+/// Burn+ - created by Hexaghost's Inferno
+pub fn burn_upgraded() -> Card {
+    Card::new(CardEnum::Burn, 0, CardClass::Status, vec![])
+        .set_upgraded(true)
+        .set_playable(false)
+        .set_end_of_turn(vec![BattleEffect::LoseHp(4)]) // Deal 4 damage at end of turn
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +51,18 @@ mod tests {
         let card = burn();
         assert_eq!(card.get_card_enum().upgraded_name(), "Burn+");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_burn_upgraded() {
+        let card = burn().upgrade();
+
+        assert_eq!(card.get_name(), "Burn+");
+        assert!(card.is_upgraded());
+        assert!(!card.is_playable());
+        assert_eq!(card, burn_upgraded());
+
+        let end_of_turn_effects = card.get_end_of_turn().unwrap();
+        assert_eq!(end_of_turn_effects.len(), 1);
+        assert!(matches!(end_of_turn_effects[0], BattleEffect::LoseHp(4)));
+    }
+}
@@ -13,6 +13,7 @@ pub fn dazed() -> Card {
         vec![]
     )
         .set_play_condition(Condition::False)
+        .set_ethereal(true)
 }
 
 #[cfg(test)]
@@ -27,6 +28,7 @@ mod tests {
         assert_eq!(card.get_card_type(), CardType::Status);
         assert!(!card.is_upgraded());
         assert!(!card.is_playable()); // Should not be playable
+        assert!(card.is_ethereal());
     }
 
     #[test]
@@ -2,8 +2,10 @@ pub mod slimed;
 pub mod wound;
 pub mod burn;
 pub mod dazed;
+pub mod void;
 
 pub use slimed::slimed;
 pub use wound::wound;
-pub use burn::burn;
-pub use dazed::dazed;
\ No newline at end of file
+pub use burn::{burn, burn_upgraded};
+pub use dazed::dazed;
+pub use void::void;
\ No newline at end of file
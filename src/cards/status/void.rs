@@ -0,0 +1,63 @@
+use crate::game::{card::{Card, CardClass}, effect::BattleEffect, card_enum::CardEnum};
+#[cfg(test)]
+use crate::game::card_type::CardType;
+
+/// Void - Status Card
+/// Cost: -
+/// Effect: Unplayable. When this card is drawn, lose 1 Energy. Ethereal
+pub fn void() -> Card {
+    Card::new(CardEnum::Void, 0, CardClass::Status, vec![])
+        .set_playable(false)
+        .set_ethereal(true)
+        .set_on_draw(vec![BattleEffect::LoseEnergy { amount: 1 }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::Battle;
+    use crate::battle::battle_events::BattleEvent;
+    use crate::battle::enemy_in_battle::EnemyInBattle;
+    use crate::enemies::enemy_enum::EnemyEnum;
+    use crate::enemies::jaw_worm::JawWorm;
+    use crate::game::PlayerRunState;
+    use crate::game::deck::Deck;
+    use crate::game::global_info::GlobalInfo;
+
+    #[test]
+    fn test_void_creation() {
+        let card = void();
+
+        assert_eq!(card.get_name(), "Void");
+        assert_eq!(card.get_card_type(), CardType::Status);
+        assert_eq!(card.get_effects().len(), 0);
+        assert!(!card.is_playable());
+        assert!(card.is_ethereal());
+
+        let on_draw = card.get_on_draw().unwrap();
+        assert_eq!(on_draw, &vec![BattleEffect::LoseEnergy { amount: 1 }]);
+    }
+
+    #[test]
+    fn test_void_loses_energy_when_drawn() {
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemy = EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::new(44, false)));
+        let deck = Deck::new(vec![void()]);
+        let mut battle = Battle::new(deck, global_info, PlayerRunState::new(50, 80, 0), vec![enemy], &mut rng);
+
+        // Void was drawn in the opening hand
+        assert_eq!(battle.get_hand().len(), 1);
+        assert_eq!(battle.get_player().get_energy(), 2);
+        assert!(battle.take_battle_events().contains(&BattleEvent::CardDrawn {
+            card_type: CardType::Status,
+            is_status_or_curse: true,
+            on_draw: vec![BattleEffect::LoseEnergy { amount: 1 }],
+        }));
+
+        // Ethereal: exhausted at end of turn, so it is not drawn again
+        battle.end_turn(&mut rng, &global_info);
+        assert_eq!(battle.cards.exhausted_size(), 1);
+        assert_eq!(battle.get_player().get_energy(), 3);
+    }
+}
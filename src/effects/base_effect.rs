@@ -1,7 +1,11 @@
 use crate::battle::target::Entity;
 use crate::game::card_enum::CardEnum;
+use crate::game::card_type::CardType;
+use crate::game::card_keyword::CardKeyword;
+use crate::enemies::enemy_enum::EnemyEnum;
 use super::condition::Condition;
-use super::effect::BattleEffect;
+use super::effect::{BattleEffect, StatusPlacement};
+use super::target_selector::TargetSelector;
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -13,11 +17,6 @@ pub enum BaseEffect {
         num_attacks: u32,
         strength_multiplier: u32,
     },
-    AttackAllEnemies {
-        source: Entity,
-        amount: u32,
-        num_attacks: u32,
-    },
     AttackToTargetWithBlock {
         source: Entity,
         target: Entity,
@@ -26,7 +25,6 @@ pub enum BaseEffect {
         source: Entity,
         target: Entity,
         base_damage: u32,
-        scaling: u32,
     },
     PerfectedStrike {
         source: Entity,
@@ -42,12 +40,6 @@ pub enum BaseEffect {
         target: Entity,
         duration: u32,
     },
-    ApplyVulnerableAll {
-        duration: u32,
-    },
-    ApplyVulnerableToAll {
-        duration: u32,
-    },
     HealToFull,
     ApplyWeak {
         target: Entity,
@@ -68,11 +60,7 @@ pub enum BaseEffect {
     DoubleStrength {
         source: Entity,
     },
-    LoseStrengthSelf {
-        source: Entity,
-        amount: u32,
-    },
-    LoseStrengthTarget {
+    LoseStrength {
         target: Entity,
         amount: u32,
     },
@@ -84,11 +72,7 @@ pub enum BaseEffect {
         source: Entity,
         amount: u32,
     },
-    LoseDexteritySelf {
-        source: Entity,
-        amount: u32,
-    },
-    LoseDexterityTarget {
+    LoseDexterity {
         target: Entity,
         amount: u32,
     },
@@ -128,6 +112,10 @@ pub enum BaseEffect {
     ActivateCorruption {
         source: Entity,
     },
+    ActivateEstablishment {
+        source: Entity,
+        amount: u32,
+    },
     ActivateMetallicize {
         source: Entity,
         amount: u32,
@@ -149,6 +137,7 @@ pub enum BaseEffect {
     },
     ActivateEvolve {
         source: Entity,
+        cards_per_status: u32,
     },
     Heal {
         target: Entity,
@@ -185,9 +174,6 @@ pub enum BaseEffect {
         source: Entity,
         amount: u32,
     },
-    ApplyWeakAll {
-        duration: u32,
-    },
     Ethereal {
         hand_index: usize, // hand_index should be set manually when queuing
     },
@@ -242,19 +228,15 @@ pub enum BaseEffect {
     AttackAllEnemiesAndHeal {
         amount: u32,
         num_attacks: u32,
+        strength_multiplier: u32,
     },
     ExhaustHandForDamage {
         damage_per_card: u32,
         target: Entity,
     },
-    // TODO: Uncomment when Juggernaut is implemented
-    // ActivateJuggernaut {
-    //     damage_per_block: u32,
-    // },
-    AttackRandomEnemy {
-        amount: u32,
-        num_attacks: u32,
-        strength_multiplier: u32,
+    ActivateJuggernaut {
+        source: Entity,
+        damage: u32,
     },
     ActivateFireBreathing {
         source: Entity,
@@ -267,9 +249,6 @@ pub enum BaseEffect {
     ShuffleDiscardIntoDraw {
         source: Entity,
     },
-    AttackAllEnemiesForCurrentEnergy {
-        amount_per_hit: u32,
-    },
     WakeLagavulin {
         enemy_index: usize,
     },
@@ -321,6 +300,158 @@ pub enum BaseEffect {
         source: Entity,
         amount: u32,
     },
+    UpgradeAllCardsInCombat,
+    ShuffleRandomCardsIntoDrawPile {
+        card_type: CardType,
+        count: u32,
+    },
+    GainGoldOnKill {
+        target: Entity,
+        amount: u32,
+    },
+    IncreaseDamageOnKill {
+        target: Entity,
+        hand_index: usize, // hand_index should be set manually when queuing
+        amount: u32,
+    },
+    ActivateMagnetism {
+        source: Entity,
+        cards_per_turn: u32,
+    },
+    ActivateMayhem {
+        source: Entity,
+        cards_per_turn: u32,
+    },
+    ActivatePanache {
+        source: Entity,
+        damage: u32,
+    },
+    ActivateSadisticNature {
+        source: Entity,
+        damage: u32,
+    },
+    ActivateTheBomb {
+        source: Entity,
+        damage: u32,
+        turns: u32,
+    },
+    DealDamage {
+        target: Entity,
+        amount: u32,
+    },
+    DealDamageToAllEnemies {
+        amount: u32,
+    },
+    DealDamageToRandomEnemy {
+        amount: u32,
+    },
+    EndTurn,
+    EnterSelectCardInDrawPile {
+        card_type: CardType,
+    },
+    MoveRandomCardsFromDrawPileToHand {
+        card_type: CardType,
+        count: u32,
+    },
+    AddRandomCardsToHand {
+        source: Entity,
+        num_choices: u32,
+        num_copies: u32,
+        cost: u32,
+    },
+    AddRandomColorlessCardsToHand {
+        source: Entity,
+        num_choices: u32,
+        num_copies: u32,
+        cost: u32,
+    },
+    AddRandomColorlessCardToHand {
+        count: u32,
+    },
+    ReduceHandCostToOne {
+        this_combat: bool,
+    },
+    EnterSelectCardsInHandToPutOnBottom {
+        count: u32,
+    },
+    EnterSelectCardsInHandToExhaust {
+        count: u32,
+    },
+    EnterSelectCardsInHandToDiscardAndDraw,
+    MakeRandomCardInHandFree,
+    AttackToTargetWithDrawPileSize {
+        source: Entity,
+        target: Entity,
+    },
+    GainIntangible {
+        source: Entity,
+        amount: u32,
+    },
+    PlayTopCardForFree {
+        target: Entity,
+    },
+    AddStatusCard {
+        status_card: CardEnum,
+        count: u32,
+        placement: StatusPlacement,
+        upgraded: bool,
+    },
+    UpgradeStatusCards {
+        status_card: CardEnum,
+    },
+    LoseEnergy {
+        amount: u32,
+    },
+    GrantKeywordToHand {
+        keyword: CardKeyword,
+        card_type: Option<CardType>,
+    },
+    RepeatEffect {
+        effect: Box<BattleEffect>,
+        times: u32,
+        source: Entity,
+        target: Entity,
+    },
+    Targeted {
+        selector: TargetSelector,
+        effect: Box<BattleEffect>,
+        source: Entity,
+        target: Entity,
+    },
+    AddRandomColorlessCardFreeThisTurn {
+        upgraded: bool,
+    },
+    GainThorns {
+        source: Entity,
+        amount: u32,
+    },
+    GainBuffer {
+        source: Entity,
+        amount: u32,
+    },
+    GainBlur {
+        source: Entity,
+        amount: u32,
+    },
+    GainEnergyNextTurn {
+        source: Entity,
+        amount: u32,
+    },
+    ConserveEnergy {
+        source: Entity,
+    },
+    ActivateBarricade {
+        source: Entity,
+    },
+    ApplyNoDraw {
+        source: Entity,
+    },
+    SpawnEnemy {
+        source: Entity,
+        enemy: Box<EnemyEnum>,
+        slot: Option<usize>,
+        minion: bool,
+    },
 
     // Event-specific base effects
     GainGold {
@@ -346,33 +477,35 @@ pub enum BaseEffect {
 }
 
 impl BaseEffect {
+    /// Convert a card effect played with a known X value (X-cost cards)
+    pub fn from_effect_with_x(effect: BattleEffect, source: Entity, target: Entity, x: u32) -> Self {
+        match effect {
+            BattleEffect::RepeatX { effect, extra } => BaseEffect::RepeatEffect { effect, times: x + extra, source, target },
+            BattleEffect::XAmount { effect, extra } => Self::from_effect_with_x(effect.with_amount(x + extra), source, target, x),
+            other => Self::from_effect(other, source, target),
+        }
+    }
+
     pub fn from_effect(effect: BattleEffect, source: Entity, target: Entity) -> Self {
         match effect {
             BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier } => {
                 BaseEffect::AttackToTarget { source, target, amount, num_attacks, strength_multiplier }
             }
-            BattleEffect::AttackAllEnemies { amount, num_attacks } => {
-                BaseEffect::AttackAllEnemies { source, amount, num_attacks }
-            }
             BattleEffect::AttackToTargetWithBlock => BaseEffect::AttackToTargetWithBlock { source, target },
-            BattleEffect::AttackToTargetWithScaling { base_damage, scaling } => BaseEffect::AttackToTargetWithScaling { source, target, base_damage, scaling },
+            BattleEffect::AttackToTargetWithScaling { base_damage, .. } => BaseEffect::AttackToTargetWithScaling { source, target, base_damage },
             BattleEffect::PerfectedStrike { base_damage, damage_per_strike } => BaseEffect::PerfectedStrike { source, target, base_damage, damage_per_strike },
             BattleEffect::GainDefense { amount } => BaseEffect::GainDefense { source, amount },
             BattleEffect::ApplyVulnerable { duration } => BaseEffect::ApplyVulnerable { target, duration },
-            BattleEffect::ApplyVulnerableAll { duration } => BaseEffect::ApplyVulnerableAll { duration },
-            BattleEffect::ApplyVulnerableToAll { duration } => BaseEffect::ApplyVulnerableToAll { duration },
             BattleEffect::HealToFull => BaseEffect::HealToFull,
             BattleEffect::ApplyWeak { duration } => BaseEffect::ApplyWeak { target, duration },
             BattleEffect::ApplyFrail { duration } => BaseEffect::ApplyFrail { target, duration },
             BattleEffect::ApplyEntangled { duration } => BaseEffect::ApplyEntangled { target, duration },
             BattleEffect::GainStrength { amount } => BaseEffect::GainStrength { source, amount },
             BattleEffect::DoubleStrength => BaseEffect::DoubleStrength { source },
-            BattleEffect::LoseStrengthSelf(amount) => BaseEffect::LoseStrengthSelf { source, amount },
-            BattleEffect::LoseStrengthTarget(amount) => BaseEffect::LoseStrengthTarget { target, amount },
+            BattleEffect::LoseStrength(amount) => BaseEffect::LoseStrength { target, amount },
             BattleEffect::LoseStrengthAtEndOfTurn(amount) => BaseEffect::LoseStrengthAtEndOfTurn { source, amount },
             BattleEffect::GainDexterity { amount } => BaseEffect::GainDexterity { source, amount },
-            BattleEffect::LoseDexteritySelf(amount) => BaseEffect::LoseDexteritySelf { source, amount },
-            BattleEffect::LoseDexterityTarget(amount) => BaseEffect::LoseDexterityTarget { target, amount },
+            BattleEffect::LoseDexterity(amount) => BaseEffect::LoseDexterity { target, amount },
             BattleEffect::GainRitual(amount) => BaseEffect::GainRitual { source, amount },
             BattleEffect::AddSlimed(count) => BaseEffect::AddSlimed { target, count },
             BattleEffect::AddCardToDrawPile(card) => BaseEffect::AddCardToDrawPile { source, card },
@@ -383,13 +516,14 @@ impl BaseEffect {
             BattleEffect::ActivateFeelNoPain { block_per_exhaust } => BaseEffect::ActivateFeelNoPain { source, block_per_exhaust },
             BattleEffect::ActivateBrutality => BaseEffect::ActivateBrutality { source },
             BattleEffect::ActivateCorruption => BaseEffect::ActivateCorruption { source },
+            BattleEffect::ActivateEstablishment { amount } => BaseEffect::ActivateEstablishment { source, amount },
             BattleEffect::ActivateMetallicize { amount } => BaseEffect::ActivateMetallicize { source, amount },
             BattleEffect::ActivateFlameBarrier { damage } => BaseEffect::ActivateFlameBarrier { source, damage },
             BattleEffect::ActivateDemonForm { strength_per_turn } => BaseEffect::ActivateDemonForm { source, strength_per_turn },
             BattleEffect::ActivateRage { block_per_attack } => BaseEffect::ActivateRage { source, block_per_attack },
             BattleEffect::AddRandomAttackToHand => BaseEffect::AddRandomAttackToHand { source },
-            BattleEffect::ActivateEvolve => BaseEffect::ActivateEvolve { source },
-            BattleEffect::Heal(amount) => BaseEffect::Heal { target, amount },
+            BattleEffect::ActivateEvolve { cards_per_status } => BaseEffect::ActivateEvolve { source, cards_per_status },
+            BattleEffect::Heal(amount) => BaseEffect::Heal { target: source, amount },
             BattleEffect::HealAndIncreaseMaxHp(amount) => BaseEffect::HealAndIncreaseMaxHp { target, amount },
             BattleEffect::LoseHp(amount) => BaseEffect::LoseHp { target: source, amount },
             BattleEffect::GainPlatedArmor(amount) => BaseEffect::GainPlatedArmor { source, amount },
@@ -398,7 +532,6 @@ impl BaseEffect {
             BattleEffect::ActivateCombust(amount) => BaseEffect::ActivateCombust { source, amount },
             BattleEffect::ApplyDamageReduction(percentage) => BaseEffect::ApplyDamageReduction { target, percentage },
             BattleEffect::GainEnergy { amount } => BaseEffect::GainEnergy { source, amount },
-            BattleEffect::ApplyWeakAll { duration } => BaseEffect::ApplyWeakAll { duration },
             BattleEffect::Ethereal => BaseEffect::Ethereal { hand_index: 0 }, // hand_index should be set manually when queuing
             BattleEffect::AddCardToDiscard(card) => BaseEffect::AddCardToDiscard { card },
             BattleEffect::AddUpgradedCardToDiscard(card) => BaseEffect::AddUpgradedCardToDiscard { card },
@@ -423,16 +556,14 @@ impl BaseEffect {
             BattleEffect::EnterSelectCardToDuplicate { copies } => BaseEffect::EnterSelectCardToDuplicate { copies },
             BattleEffect::EnterSelectCardInExhaust => BaseEffect::EnterSelectCardInExhaust,
             BattleEffect::HealOnKill { amount } => BaseEffect::HealOnKill { amount },
-            BattleEffect::AttackAllEnemiesAndHeal { amount, num_attacks } => BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks },
+            BattleEffect::AttackAllEnemiesAndHeal { amount, num_attacks } => BaseEffect::AttackAllEnemiesAndHeal { amount, num_attacks, strength_multiplier: 1 },
             BattleEffect::ExhaustHandForDamage { damage_per_card, target } => BaseEffect::ExhaustHandForDamage { damage_per_card, target },
-            BattleEffect::ActivateJuggernaut { .. } => todo!("Implement Juggernaut when ready"),
-            BattleEffect::AttackRandomEnemy { amount, num_attacks, strength_multiplier } => BaseEffect::AttackRandomEnemy { amount, num_attacks, strength_multiplier },
+            BattleEffect::ActivateJuggernaut { damage } => BaseEffect::ActivateJuggernaut { source, damage },
             BattleEffect::AddFireBreathing { damage_per_status } => BaseEffect::ActivateFireBreathing { source, damage_per_status },
             BattleEffect::ActivateSentinel { energy_on_exhaust } => BaseEffect::ActivateSentinel { source, energy_on_exhaust },
             BattleEffect::ShuffleDiscardIntoDraw => BaseEffect::ShuffleDiscardIntoDraw { source },
-            BattleEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit } => BaseEffect::AttackAllEnemiesForCurrentEnergy { amount_per_hit },
             // New effects for the implemented cards
-            BattleEffect::AddStatusToDiscard { status_card } => BaseEffect::AddCardToDiscard { card: status_card },
+            BattleEffect::AddStatusToDiscard { status_card } => BaseEffect::AddStatusCard { status_card, count: 1, placement: StatusPlacement::Discard, upgraded: false },
             BattleEffect::GainEnergyIfNoBlock { amount } => BaseEffect::GainEnergy { source, amount },
             BattleEffect::ExhaustNonAttacksInHand => BaseEffect::ExhaustNonAttackCardsFromHand { block_per_card: 0 },
             BattleEffect::GainStrengthIfEnemyAttacking { amount } => BaseEffect::GainStrength { source, amount },
@@ -449,6 +580,49 @@ impl BaseEffect {
             BattleEffect::AddRandomAttackCardsToHand { num_choices, num_copies, cost } => BaseEffect::AddRandomAttackCardsToHand { source, num_choices, num_copies, cost },
             BattleEffect::AddRandomSkillCardsToHand { num_choices, num_copies, cost } => BaseEffect::AddRandomSkillCardsToHand { source, num_choices, num_copies, cost },
             BattleEffect::ActivateGrantRitualNextTurn { amount } => BaseEffect::ActivateGrantRitualNextTurn { source, amount },
+            BattleEffect::UpgradeAllCardsInCombat => BaseEffect::UpgradeAllCardsInCombat,
+            BattleEffect::ShuffleRandomCardsIntoDrawPile { card_type, count } => BaseEffect::ShuffleRandomCardsIntoDrawPile { card_type, count },
+            BattleEffect::GainGoldOnKill { amount } => BaseEffect::GainGoldOnKill { target, amount },
+            BattleEffect::IncreaseDamageOnKill { amount } => BaseEffect::IncreaseDamageOnKill { target, hand_index: 0, amount }, // hand_index should be set manually when queuing
+            BattleEffect::ActivateMagnetism { cards_per_turn } => BaseEffect::ActivateMagnetism { source, cards_per_turn },
+            BattleEffect::ActivateMayhem { cards_per_turn } => BaseEffect::ActivateMayhem { source, cards_per_turn },
+            BattleEffect::ActivatePanache { damage } => BaseEffect::ActivatePanache { source, damage },
+            BattleEffect::ActivateSadisticNature { damage } => BaseEffect::ActivateSadisticNature { source, damage },
+            BattleEffect::ActivateTheBomb { damage, turns } => BaseEffect::ActivateTheBomb { source, damage, turns },
+            BattleEffect::DealDamage { target, amount } => BaseEffect::DealDamage { target, amount },
+            BattleEffect::DealDamageToAllEnemies { amount } => BaseEffect::DealDamageToAllEnemies { amount },
+            BattleEffect::DealDamageToRandomEnemy { amount } => BaseEffect::DealDamageToRandomEnemy { amount },
+            BattleEffect::EndTurn => BaseEffect::EndTurn,
+            BattleEffect::EnterSelectCardInDrawPile { card_type } => BaseEffect::EnterSelectCardInDrawPile { card_type },
+            BattleEffect::MoveRandomCardsFromDrawPileToHand { card_type, count } => BaseEffect::MoveRandomCardsFromDrawPileToHand { card_type, count },
+            BattleEffect::AddRandomCardsToHand { num_choices, num_copies, cost } => BaseEffect::AddRandomCardsToHand { source, num_choices, num_copies, cost },
+            BattleEffect::AddRandomColorlessCardsToHand { num_choices, num_copies, cost } => BaseEffect::AddRandomColorlessCardsToHand { source, num_choices, num_copies, cost },
+            BattleEffect::AddRandomColorlessCardToHand { count } => BaseEffect::AddRandomColorlessCardToHand { count },
+            BattleEffect::ReduceHandCostToOne { this_combat } => BaseEffect::ReduceHandCostToOne { this_combat },
+            BattleEffect::EnterSelectCardsInHandToPutOnBottom { count } => BaseEffect::EnterSelectCardsInHandToPutOnBottom { count },
+            BattleEffect::EnterSelectCardsInHandToExhaust { count } => BaseEffect::EnterSelectCardsInHandToExhaust { count },
+            BattleEffect::EnterSelectCardsInHandToDiscardAndDraw => BaseEffect::EnterSelectCardsInHandToDiscardAndDraw,
+            BattleEffect::MakeRandomCardInHandFree => BaseEffect::MakeRandomCardInHandFree,
+            BattleEffect::AttackToTargetWithDrawPileSize => BaseEffect::AttackToTargetWithDrawPileSize { source, target },
+            BattleEffect::GainIntangible { amount } => BaseEffect::GainIntangible { source, amount },
+            BattleEffect::PlayTopCardForFree => BaseEffect::PlayTopCardForFree { target },
+            BattleEffect::AddStatusCard { status_card, count, placement, upgraded } => BaseEffect::AddStatusCard { status_card, count, placement, upgraded },
+            BattleEffect::UpgradeStatusCards { status_card } => BaseEffect::UpgradeStatusCards { status_card },
+            BattleEffect::LoseEnergy { amount } => BaseEffect::LoseEnergy { amount },
+            BattleEffect::GrantKeywordToHand { keyword, card_type } => BaseEffect::GrantKeywordToHand { keyword, card_type },
+            // Outside of an X-cost card play there is no energy spent, so X is 0
+            BattleEffect::RepeatX { effect, extra } => BaseEffect::RepeatEffect { effect, times: extra, source, target },
+            BattleEffect::XAmount { effect, extra } => Self::from_effect(effect.with_amount(extra), source, target),
+            BattleEffect::Targeted { selector, effect } => BaseEffect::Targeted { selector, effect, source, target },
+            BattleEffect::AddRandomColorlessCardFreeThisTurn { upgraded } => BaseEffect::AddRandomColorlessCardFreeThisTurn { upgraded },
+            BattleEffect::GainThorns { amount } => BaseEffect::GainThorns { source, amount },
+            BattleEffect::GainBuffer { amount } => BaseEffect::GainBuffer { source, amount },
+            BattleEffect::GainBlur { amount } => BaseEffect::GainBlur { source, amount },
+            BattleEffect::GainEnergyNextTurn { amount } => BaseEffect::GainEnergyNextTurn { source, amount },
+            BattleEffect::ConserveEnergy => BaseEffect::ConserveEnergy { source },
+            BattleEffect::ActivateBarricade => BaseEffect::ActivateBarricade { source },
+            BattleEffect::ApplyNoDraw => BaseEffect::ApplyNoDraw { source },
+            BattleEffect::SpawnEnemy { enemy, slot, minion } => BaseEffect::SpawnEnemy { source, enemy, slot, minion },
         }
     }
 }
@@ -1,18 +1,148 @@
+use crate::battle::counters::{Counter, CounterScope};
+use crate::game::card_type::CardType;
+use crate::relics::Relic;
 use serde::{Serialize, Deserialize};
 
-#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// A test over the battle state, checked by `BattleEffect::ConditionalEffect` and card play conditions
+/// Conditions compose with `Not`, `And` and `Or`, and `Compare` checks a battle quantity against a number
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Condition {
     // Target conditions
     TargetIsVulnerable,
+    TargetIntendsToAttack, // The targeted enemy's next move is an attack
 
     // Hand conditions
     HandAllAttacks,
     HandNoAttacks,
+    CardTypeInHand(CardType),
 
     // Enemy state conditions
     EnemyIsAttacking,
 
+    // Run conditions
+    HasRelic(Relic),
+
+    // Numeric conditions
+    Compare(Quantity, Comparison, u32),
+
+    // Combinators
+    Not(Box<Condition>),
+    And(Vec<Condition>), // True when every condition holds (and when empty)
+    Or(Vec<Condition>),  // True when any condition holds (false when empty)
+
     // Universal conditions
     True,
     False,
 }
+
+impl Condition {
+    pub fn negate(self) -> Condition {
+        Condition::Not(Box::new(self))
+    }
+
+    pub fn and(self, other: Condition) -> Condition {
+        match self {
+            Condition::And(mut conditions) => {
+                conditions.push(other);
+                Condition::And(conditions)
+            }
+            condition => Condition::And(vec![condition, other]),
+        }
+    }
+
+    pub fn or(self, other: Condition) -> Condition {
+        match self {
+            Condition::Or(mut conditions) => {
+                conditions.push(other);
+                Condition::Or(conditions)
+            }
+            condition => Condition::Or(vec![condition, other]),
+        }
+    }
+}
+
+/// A number read from the battle state for `Condition::Compare`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Quantity {
+    PlayerHp,
+    PlayerHpPercent, // Current HP as a percentage of max HP, rounded down
+    PlayerBlock,
+    PlayerEnergy,
+    PlayerStrength,
+    HandSize,
+    DrawPileSize,
+    DiscardPileSize,
+    ExhaustPileSize,
+    Counter(Counter, CounterScope),
+    TargetHp,    // 0 when the target is not a living character
+    TargetBlock, // 0 when the target is not a living character
+    EnemiesAlive,
+}
+
+impl Quantity {
+    pub fn less_than(self, value: u32) -> Condition {
+        Condition::Compare(self, Comparison::LessThan, value)
+    }
+
+    pub fn at_most(self, value: u32) -> Condition {
+        Condition::Compare(self, Comparison::AtMost, value)
+    }
+
+    pub fn equals(self, value: u32) -> Condition {
+        Condition::Compare(self, Comparison::Equal, value)
+    }
+
+    pub fn at_least(self, value: u32) -> Condition {
+        Condition::Compare(self, Comparison::AtLeast, value)
+    }
+
+    pub fn greater_than(self, value: u32) -> Condition {
+        Condition::Compare(self, Comparison::GreaterThan, value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Comparison {
+    LessThan,
+    AtMost,
+    Equal,
+    NotEqual,
+    AtLeast,
+    GreaterThan,
+}
+
+impl Comparison {
+    /// Whether `lhs <op> rhs` holds
+    pub fn holds(self, lhs: u32, rhs: u32) -> bool {
+        match self {
+            Comparison::LessThan => lhs < rhs,
+            Comparison::AtMost => lhs <= rhs,
+            Comparison::Equal => lhs == rhs,
+            Comparison::NotEqual => lhs != rhs,
+            Comparison::AtLeast => lhs >= rhs,
+            Comparison::GreaterThan => lhs > rhs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_or_flatten_chains() {
+        let condition = Condition::True.and(Condition::HandNoAttacks).and(Condition::False);
+        assert_eq!(condition, Condition::And(vec![Condition::True, Condition::HandNoAttacks, Condition::False]));
+
+        let condition = Condition::False.or(Condition::True).or(Condition::HandAllAttacks);
+        assert_eq!(condition, Condition::Or(vec![Condition::False, Condition::True, Condition::HandAllAttacks]));
+    }
+
+    #[test]
+    fn test_quantity_helpers_build_comparisons() {
+        assert_eq!(Quantity::HandSize.at_most(3), Condition::Compare(Quantity::HandSize, Comparison::AtMost, 3));
+        assert!(Comparison::AtMost.holds(3, 3));
+        assert!(!Comparison::LessThan.holds(3, 3));
+        assert!(Comparison::NotEqual.holds(2, 3));
+    }
+}
@@ -1,7 +1,11 @@
 use crate::battle::target::Entity;
 use crate::game::card_enum::CardEnum;
+use crate::game::card_type::CardType;
+use crate::game::card_keyword::CardKeyword;
+use crate::enemies::enemy_enum::EnemyEnum;
 use super::condition::Condition;
 use super::game_effect::GameEffect;
+use super::target_selector::TargetSelector;
 use serde::{Serialize, Deserialize};
 
 /// Unified effect type that can be either a battle effect or a game effect
@@ -22,28 +26,20 @@ pub enum BattleEffect {
         strength_multiplier: u32,
     },
     AttackToTargetWithBlock, // Deal damage equal to player's Block
-    AttackToTargetWithScaling { base_damage: u32, scaling: u32 }, // Scaling damage attack (Rampage)
+    AttackToTargetWithScaling { base_damage: u32, scaling: u32 }, // Attack whose card gains `scaling` damage each time it is played (Rampage)
     PerfectedStrike { base_damage: u32, damage_per_strike: u32 }, // Deal damage + bonus per Strike card in deck
-    AttackAllEnemies {
-        amount: u32,
-        num_attacks: u32,
-    },
     GainDefense { amount: u32 },
     ApplyVulnerable { duration: u32 },
-    ApplyVulnerableAll { duration: u32 },
-    ApplyVulnerableToAll { duration: u32 }, // Apply Vulnerable to all enemies
     HealToFull, // Heal to full HP
     ApplyWeak { duration: u32 },
     ApplyFrail { duration: u32 },
     ApplyEntangled { duration: u32 }, // Prevents Attack card plays
     GainStrength { amount: u32 },
     DoubleStrength, // Double current Strength
-    LoseStrengthSelf (u32), // Self strength loss (targets source)
-    LoseStrengthTarget (u32), // Target strength loss (targets target)
+    LoseStrength (u32), // Target strength loss, use `TargetSelector::Source` for self
     LoseStrengthAtEndOfTurn (u32),
     GainDexterity { amount: u32 },
-    LoseDexteritySelf (u32), // Self dexterity loss (targets source)
-    LoseDexterityTarget (u32), // Target dexterity loss (targets target)
+    LoseDexterity (u32), // Target dexterity loss, use `TargetSelector::Source` for self
     GainRitual (u32),
     AddSlimed (u32),
     AddCardToDrawPile (CardEnum),
@@ -60,7 +56,6 @@ pub enum BattleEffect {
     ActivateCombust (u32), // Activates Combust listener for dealing damage at end of turn
     ApplyDamageReduction (u32), // Target takes X% less damage (like Disarm)
     GainEnergy { amount: u32 }, // Gain energy
-    ApplyWeakAll { duration: u32 }, // Apply Weak to all enemies
     Ethereal, // Card will be exhausted at end of turn
     AddCardToDiscard (CardEnum), // Add a card to discard pile
     AddUpgradedCardToDiscard (CardEnum), // Add an upgraded card to discard pile
@@ -76,12 +71,13 @@ pub enum BattleEffect {
     PutRandomDiscardCardOnTop, // Put a random card from discard on top of draw pile
     ConditionalEffect(Condition, Box<BattleEffect>), // Conditional effect that only triggers if condition is met
     ActivateCorruption, // Activates Corruption power for making skills cost 0 and exhaust them
+    ActivateEstablishment { amount: u32 }, // Whenever a card is retained, lower its cost by N this combat (Establishment)
     ActivateMetallicize { amount: u32 }, // Activates Metallicize power for end-of-turn block generation
     ActivateFlameBarrier { damage: u32 }, // Activates Flame Barrier for retaliation damage
     ActivateDemonForm { strength_per_turn: u32 }, // Activates Demon Form for turn-based Strength gain
     ActivateRage { block_per_attack: u32 }, // Activates Rage for gaining block when playing attacks
     AddRandomAttackToHand, // Add a random Attack card to hand
-    ActivateEvolve, // Activates Evolve for drawing cards when Status cards are drawn
+    ActivateEvolve { cards_per_status: u32 }, // Activates Evolve for drawing cards when Status cards are drawn
     ExhaustNonAttackCardsFromHand { block_per_card: u32 }, // Exhaust all non-Attack cards from hand, gain block per card
     ActivateRupture, // Activates Rupture for gaining Strength when losing HP
     EnterSelectCardToDuplicate { copies: u32 }, // Transition to SelectCardToDuplicate state to duplicate a card
@@ -90,12 +86,10 @@ pub enum BattleEffect {
     HealOnKill { amount: u32 }, // Heal specified amount if the target enemy dies from this attack
     AttackAllEnemiesAndHeal { amount: u32, num_attacks: u32 }, // Deal damage to all enemies and heal for unblocked damage
     ExhaustHandForDamage { damage_per_card: u32, target: Entity }, // Exhaust all cards in hand and deal damage per card exhausted
-    ActivateJuggernaut { damage_per_block: u32 }, // Activates Juggernaut for dealing damage when gaining block
-    AttackRandomEnemy { amount: u32, num_attacks: u32, strength_multiplier: u32 }, // Deal damage to a random enemy
+    ActivateJuggernaut { damage: u32 }, // Activates Juggernaut for dealing damage to a random enemy whenever block is gained
     AddFireBreathing { damage_per_status: u32 }, // Activates Fire Breathing for dealing damage when Status/Curse cards are drawn
     ShuffleDiscardIntoDraw, // Shuffle discard pile into draw pile
     GainRegen { amount: u32 }, // Gain regeneration (heals X HP at end of turn, decreases by 1 each turn)
-    AttackAllEnemiesForCurrentEnergy { amount_per_hit: u32 }, // Spend all energy and attack all enemies X times where X is energy spent
     AddStatusToDiscard { status_card: CardEnum }, // Add a status card to discard pile
     GainEnergyIfNoBlock { amount: u32 }, // Gain energy if player has no block
     ExhaustNonAttacksInHand, // Exhaust all non-Attack cards in hand
@@ -114,4 +108,129 @@ pub enum BattleEffect {
     AddRandomAttackCardsToHand { num_choices: u32, num_copies: u32, cost: u32 }, // Choose 1 of N random Attack cards to add to hand (M copies, cost X)
     AddRandomSkillCardsToHand { num_choices: u32, num_copies: u32, cost: u32 }, // Choose 1 of N random Skill cards to add to hand (M copies, cost X)
     ActivateGrantRitualNextTurn { amount: u32 }, // Activates listener to grant Ritual at start of next enemy turn
+    UpgradeAllCardsInCombat, // Upgrade every card in hand, draw, discard and exhaust piles for the rest of combat (Apotheosis)
+    ShuffleRandomCardsIntoDrawPile { card_type: CardType, count: u32 }, // Shuffle N random cards of a type into the draw pile, they cost 0 this combat
+    GainGoldOnKill { amount: u32 }, // Gain gold if the target enemy dies from this attack (Hand of Greed)
+    IncreaseDamageOnKill { amount: u32 }, // Permanently increase this card's damage if the target dies (Ritual Dagger)
+    ActivateMagnetism { cards_per_turn: u32 }, // Activates Magnetism listener for adding colorless cards at start of turn
+    ActivateMayhem { cards_per_turn: u32 }, // Activates Mayhem listener for playing the top card at start of turn
+    ActivatePanache { damage: u32 }, // Activates Panache listener for damage every 5 cards played in a turn
+    ActivateSadisticNature { damage: u32 }, // Activates Sadistic Nature listener for damage whenever a debuff is applied
+    ActivateTheBomb { damage: u32, turns: u32 }, // Activates The Bomb countdown
+    DealDamage { target: Entity, amount: u32 }, // Non-attack damage to a specific entity (ignores Strength, Weak and Vulnerable)
+    DealDamageToAllEnemies { amount: u32 }, // Non-attack damage to all enemies (ignores Strength, Weak and Vulnerable)
+    DealDamageToRandomEnemy { amount: u32 }, // Non-attack damage to one random living enemy (Juggernaut)
+    EndTurn, // End the player's turn once the card being played has resolved (Time Warp)
+    EnterSelectCardInDrawPile { card_type: CardType }, // Transition to SelectCardInDrawPile state to put a card of this type into hand
+    MoveRandomCardsFromDrawPileToHand { card_type: CardType, count: u32 }, // Put N random cards of a type from draw pile into hand (Violence)
+    AddRandomCardsToHand { num_choices: u32, num_copies: u32, cost: u32 }, // Choose 1 of N random Ironclad cards to add to hand (M copies, cost X this turn)
+    AddRandomColorlessCardsToHand { num_choices: u32, num_copies: u32, cost: u32 }, // Choose 1 of N random Colorless cards to add to hand (M copies, cost X this turn)
+    AddRandomColorlessCardToHand { count: u32 }, // Add N random Colorless cards to hand (Jack of All Trades, Magnetism)
+    ReduceHandCostToOne { this_combat: bool }, // Reduce the cost of cards in hand to 1 this turn or this combat (Enlightenment)
+    EnterSelectCardsInHandToPutOnBottom { count: u32 }, // Put up to N cards from hand on the bottom of the draw pile, they cost 0 until played (Forethought)
+    EnterSelectCardsInHandToExhaust { count: u32 }, // Exhaust up to N cards from hand (Purity)
+    EnterSelectCardsInHandToDiscardAndDraw, // Discard any number of cards from hand, then draw that many (Gambler's Brew)
+    MakeRandomCardInHandFree, // A random card in hand costs 0 for the rest of combat (Madness)
+    AttackToTargetWithDrawPileSize, // Deal damage equal to the number of cards in the draw pile (Mind Blast)
+    GainIntangible { amount: u32 }, // Reduce all damage and HP loss to 1 for N turns
+    PlayTopCardForFree, // Play the top card of the draw pile without spending energy (Mayhem)
+    AddStatusCard { status_card: CardEnum, count: u32, placement: StatusPlacement, upgraded: bool }, // Add N Status cards to the given pile (Sentry, Hexaghost, Chosen)
+    UpgradeStatusCards { status_card: CardEnum }, // Upgrade every copy of a Status card in hand, draw and discard piles (Hexaghost's Inferno)
+    LoseEnergy { amount: u32 }, // Lose energy, never going below 0 (Void)
+    RepeatX { effect: Box<BattleEffect>, extra: u32 }, // Repeat the effect X + extra times, X being the energy spent on an X-cost card (Whirlwind, Skewer, Malaise+)
+    XAmount { effect: Box<BattleEffect>, extra: u32 }, // Apply the effect with its amount set to X + extra, X as for RepeatX (Malaise, Doppelganger, Collect)
+    AddRandomColorlessCardFreeThisTurn { upgraded: bool }, // Add a random (upgraded) Colorless card to hand that costs 0 this turn (Transmutation)
+    GrantKeywordToHand { keyword: CardKeyword, card_type: Option<CardType> }, // Give a keyword to every card in hand, optionally only one card type (Runic Pyramid-style Retain)
+    GainThorns { amount: u32 }, // Deal N damage back whenever attacked (Caltrops)
+    GainBuffer { amount: u32 }, // Prevent the next N times HP would be lost (Buffer)
+    GainBlur { amount: u32 }, // Block is not removed at the start of the next N turns (Blur)
+    GainEnergyNextTurn { amount: u32 }, // Gain N energy at the start of the next turn (Energized)
+    ConserveEnergy, // Unspent energy carries over into the next turn (Conserve)
+    ActivateBarricade, // Block is not removed at the start of turn for the rest of combat (Barricade)
+    ApplyNoDraw, // The source cannot draw any more cards this turn (Battle Trance)
+    SpawnEnemy { enemy: Box<EnemyEnum>, slot: Option<usize>, minion: bool }, // Summon an enemy into a slot (a free one at the end if None), as a minion of the summoner if set
+    Targeted { selector: TargetSelector, effect: Box<BattleEffect> }, // Apply the effect to each entity the selector picks, instead of the chosen target (Cleave, Thunderclap, Juggernaut)
+}
+
+impl BattleEffect {
+    /// This effect applied to the entities `selector` picks
+    pub fn targeting(self, selector: TargetSelector) -> BattleEffect {
+        BattleEffect::Targeted { selector, effect: Box::new(self) }
+    }
+
+    /// Which entities this effect acts on, used to work out what a card can be played on
+    /// Effects that act on their source report `Source`
+    pub fn target_selector(&self) -> TargetSelector {
+        match self {
+            BattleEffect::Targeted { selector, .. } => *selector,
+            BattleEffect::RepeatX { effect, .. } | BattleEffect::XAmount { effect, .. } => effect.target_selector(),
+            BattleEffect::ConditionalEffect(_, effect) => effect.target_selector(),
+            BattleEffect::AttackToTarget { .. }
+            | BattleEffect::AttackToTargetWithBlock
+            | BattleEffect::AttackToTargetWithScaling { .. }
+            | BattleEffect::AttackToTargetWithDrawPileSize
+            | BattleEffect::PerfectedStrike { .. }
+            | BattleEffect::ApplyVulnerable { .. }
+            | BattleEffect::ApplyWeak { .. }
+            | BattleEffect::ApplyFrail { .. }
+            | BattleEffect::ApplyEntangled { .. }
+            | BattleEffect::LoseStrength(_)
+            | BattleEffect::LoseDexterity(_)
+            | BattleEffect::ApplyDamageReduction(_)
+            | BattleEffect::HealOnKill { .. }
+            | BattleEffect::GainGoldOnKill { .. }
+            | BattleEffect::IncreaseDamageOnKill { .. } => TargetSelector::ChosenTarget,
+            _ => TargetSelector::Source,
+        }
+    }
+
+    /// This effect with its amount (damage, block, stacks, duration, cards or energy) set to `amount`,
+    /// for `XAmount`. Effects without an amount are returned unchanged
+    pub fn with_amount(self, amount: u32) -> BattleEffect {
+        match self {
+            BattleEffect::AttackToTarget { num_attacks, strength_multiplier, .. } => BattleEffect::AttackToTarget { amount, num_attacks, strength_multiplier },
+            BattleEffect::GainDefense { .. } => BattleEffect::GainDefense { amount },
+            BattleEffect::ApplyVulnerable { .. } => BattleEffect::ApplyVulnerable { duration: amount },
+            BattleEffect::ApplyWeak { .. } => BattleEffect::ApplyWeak { duration: amount },
+            BattleEffect::ApplyFrail { .. } => BattleEffect::ApplyFrail { duration: amount },
+            BattleEffect::GainStrength { .. } => BattleEffect::GainStrength { amount },
+            BattleEffect::LoseStrength(_) => BattleEffect::LoseStrength(amount),
+            BattleEffect::GainDexterity { .. } => BattleEffect::GainDexterity { amount },
+            BattleEffect::LoseDexterity(_) => BattleEffect::LoseDexterity(amount),
+            BattleEffect::DrawCard { .. } => BattleEffect::DrawCard { count: amount },
+            BattleEffect::Heal(_) => BattleEffect::Heal(amount),
+            BattleEffect::LoseHp(_) => BattleEffect::LoseHp(amount),
+            BattleEffect::GainPlatedArmor(_) => BattleEffect::GainPlatedArmor(amount),
+            BattleEffect::GainEnergy { .. } => BattleEffect::GainEnergy { amount },
+            BattleEffect::GainEnergyNextTurn { .. } => BattleEffect::GainEnergyNextTurn { amount },
+            BattleEffect::GainIntangible { .. } => BattleEffect::GainIntangible { amount },
+            BattleEffect::GainThorns { .. } => BattleEffect::GainThorns { amount },
+            BattleEffect::GainBuffer { .. } => BattleEffect::GainBuffer { amount },
+            BattleEffect::AddRandomColorlessCardToHand { .. } => BattleEffect::AddRandomColorlessCardToHand { count: amount },
+            BattleEffect::Targeted { selector, effect } => BattleEffect::Targeted { selector, effect: Box::new(effect.with_amount(amount)) },
+            BattleEffect::ConditionalEffect(condition, effect) => BattleEffect::ConditionalEffect(condition, Box::new(effect.with_amount(amount))),
+            other => other,
+        }
+    }
+
+    /// Whether this effect deals attack damage, which makes an enemy move an attack intent
+    pub fn is_attack(&self) -> bool {
+        matches!(
+            self,
+            BattleEffect::AttackToTarget { .. }
+                | BattleEffect::AttackToTargetWithBlock
+                | BattleEffect::AttackToTargetWithScaling { .. }
+                | BattleEffect::AttackToTargetWithDrawPileSize
+                | BattleEffect::PerfectedStrike { .. }
+                | BattleEffect::AttackAllEnemiesAndHeal { .. }
+        ) || matches!(self, BattleEffect::Targeted { effect, .. } if effect.is_attack())
+    }
+}
+
+/// Where a generated Status card ends up in the player's piles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusPlacement {
+    ShuffleIntoDrawPile, // Random position in the draw pile (Chosen's Hex, Reckless Charge, Wild Strike)
+    Discard,             // Top of the discard pile (Sentry, Hexaghost)
+    TopOfDrawPile,       // Drawn next (Bronze Orb's Stasis-style effects)
 }
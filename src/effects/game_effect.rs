@@ -16,9 +16,21 @@ pub enum GameEffect {
     EnterSelectCardsToRemove { count: u32 },
     EnterSelectCardsToTransform { count: u32 },
 
+    // Deck modification (choose one of several new cards)
+    ChooseCard { pool: CardPool, num_choices: u32 },
+
     // Deck modification (automatic/random)
     UpgradeRandomCards { count: u32 },
 
     // Event transitions
     TriggerCombatEvent,
 }
+
+/// Which cards a `GameEffect::ChooseCard` draws its options from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardPool {
+    /// The player's class card reward pool
+    Class,
+    /// The Colorless card pool
+    Colorless,
+}
@@ -2,8 +2,10 @@ mod condition;
 mod effect;
 mod game_effect;
 mod base_effect;
+mod target_selector;
 
-pub use condition::Condition;
-pub use effect::{Effect, BattleEffect};
-pub use game_effect::GameEffect;
+pub use condition::{Condition, Quantity, Comparison};
+pub use effect::{Effect, BattleEffect, StatusPlacement};
+pub use game_effect::{GameEffect, CardPool};
 pub use base_effect::BaseEffect;
+pub use target_selector::TargetSelector;
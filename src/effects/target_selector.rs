@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+
+/// Which entities an effect applies to, carried by `BattleEffect::Targeted`
+/// The selected entities are passed as the inner effect's target, one at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TargetSelector {
+    ChosenTarget,  // The target picked when the card was played
+    Source,        // The entity that owns the effect (the player for cards)
+    AllEnemies,    // Every living enemy of the source
+    RandomEnemy,   // One random living enemy of the source, picked when the effect resolves
+    LowestHpEnemy, // The living enemy of the source with the least HP, the first one on ties
+    AllAllies,     // The source and every living character on its side
+}
+
+impl TargetSelector {
+    /// Whether the player has to pick a target when playing a card with this selector
+    pub fn needs_chosen_target(self) -> bool {
+        self == TargetSelector::ChosenTarget
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, utils::CategoricalDistribution};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, utils::CategoricalDistribution};
 use crate::battle::battle_events::{BattleEvent, EventListener};
 use crate::battle::target::Entity;
 use std::any::Any;
@@ -152,6 +152,13 @@ impl EnemyTrait for AcidSlimeL {
 
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move, global_info)
+        }))
+    }
 }
 
 #[cfg(test)]
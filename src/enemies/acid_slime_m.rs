@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, utils::CategoricalDistribution};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, utils::CategoricalDistribution};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -139,6 +139,13 @@ impl EnemyTrait for AcidSlimeM {
         
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move, global_info)
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -87,6 +87,10 @@ impl EnemyTrait for AcidSlimeS {
         let effects = self.get_move_effects(selected_move, global_info);
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::forced(self, global_info, rng))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 use crate::{
-    game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo},
+    game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo},
     utils::CategoricalDistribution,
     battle::{battle_events::{BattleEvent, EventListener}, target::Entity},
 };
@@ -127,6 +127,13 @@ impl EnemyTrait for Cultist {
         
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(global_info), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move)
+        }))
+    }
 }
 
 /// Listener that grants Ritual at the start of the next enemy turn
@@ -1,4 +1,4 @@
-use crate::{enemies::{red_louse::{RedLouse, RedLouseMove}, green_louse::{GreenLouse, GreenLouseMove}, jaw_worm::{JawWorm, JawWormMove}, cultist::{Cultist, CultistMove}, spike_slime_s::{SpikeSlimeS, SpikeSlimeSMove}, spike_slime_m::{SpikeSlimeM, SpikeSlimeMMove}, spike_slime_l::{SpikeSlimeL, SpikeSlimeLMove}, acid_slime_s::{AcidSlimeS, AcidSlimeSMove}, acid_slime_m::{AcidSlimeM, AcidSlimeMMove}, acid_slime_l::{AcidSlimeL, AcidSlimeLMove}, gremlin_nob::{GremlinNob, GremlinNobMove}, lagavulin::{Lagavulin, LagavulinMove}, sentry::{Sentry, SentryMove}, fat_gremlin::{FatGremlin, FatGremlinMove}, sneaky_gremlin::{SneakyGremlin, SneakyGremlinMove}, mad_gremlin::{MadGremlin, MadGremlinMove}, shield_gremlin::{ShieldGremlin, ShieldGremlinMove}, gremlin_wizard::{GremlinWizard, GremlinWizardMove}, looter::{Looter, LooterMove}, fungi_beast::{FungiBeast, FungiBeastMove}, blue_slaver::{BlueSlaver, BlueSlaverMove}, red_slaver::{RedSlaver, RedSlaverMove}}, game::{effect::BattleEffect, global_info::GlobalInfo, enemy::EnemyTrait}};
+use crate::{enemies::{red_louse::{RedLouse, RedLouseMove}, green_louse::{GreenLouse, GreenLouseMove}, jaw_worm::{JawWorm, JawWormMove}, cultist::{Cultist, CultistMove}, spike_slime_s::{SpikeSlimeS, SpikeSlimeSMove}, spike_slime_m::{SpikeSlimeM, SpikeSlimeMMove}, spike_slime_l::{SpikeSlimeL, SpikeSlimeLMove}, acid_slime_s::{AcidSlimeS, AcidSlimeSMove}, acid_slime_m::{AcidSlimeM, AcidSlimeMMove}, acid_slime_l::{AcidSlimeL, AcidSlimeLMove}, gremlin_nob::{GremlinNob, GremlinNobMove}, lagavulin::{Lagavulin, LagavulinMove}, sentry::{Sentry, SentryMove}, fat_gremlin::{FatGremlin, FatGremlinMove}, sneaky_gremlin::{SneakyGremlin, SneakyGremlinMove}, mad_gremlin::{MadGremlin, MadGremlinMove}, shield_gremlin::{ShieldGremlin, ShieldGremlinMove}, gremlin_wizard::{GremlinWizard, GremlinWizardMove}, looter::{Looter, LooterMove}, fungi_beast::{FungiBeast, FungiBeastMove}, blue_slaver::{BlueSlaver, BlueSlaverMove}, red_slaver::{RedSlaver, RedSlaverMove}}, game::{effect::BattleEffect, global_info::GlobalInfo, enemy::{EnemyTrait, MoveOutcome}}};
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -27,6 +27,19 @@ pub enum EnemyEnum {
     RedSlaver(RedSlaver),
 }
 
+/// One result of `EnemyEnum::sample_move_and_effects`: the enemy after choosing the move,
+/// the move, its effects and its probability
+pub type EnemyMoveOutcome = (EnemyEnum, EnemyMove, Vec<BattleEffect>, f64);
+
+fn wrap_move_outcomes<E: EnemyTrait>(
+    outcomes: Option<Vec<MoveOutcome<E>>>,
+    wrap_enemy: fn(E) -> EnemyEnum,
+    wrap_move: fn(E::MoveType) -> EnemyMove,
+) -> Option<Vec<EnemyMoveOutcome>> {
+    outcomes.map(|outcomes| outcomes.into_iter()
+        .map(|outcome| (wrap_enemy(outcome.enemy), wrap_move(outcome.selected_move), outcome.effects, outcome.probability))
+        .collect())
+}
 
 /// Enum to hold any enemy move type
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -151,6 +164,62 @@ impl EnemyEnum {
         }
     }
 
+    /// Every move the enemy can sample next, with the enemy after choosing it, the move's effects
+    /// and its probability (None when the enemy cannot list them)
+    pub fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<EnemyMoveOutcome>> {
+        match self {
+            EnemyEnum::RedLouse(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::RedLouse, EnemyMove::RedLouse),
+            EnemyEnum::GreenLouse(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::GreenLouse, EnemyMove::GreenLouse),
+            EnemyEnum::JawWorm(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::JawWorm, EnemyMove::JawWorm),
+            EnemyEnum::Cultist(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::Cultist, EnemyMove::Cultist),
+            EnemyEnum::SpikeSlimeS(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::SpikeSlimeS, EnemyMove::SpikeSlimeS),
+            EnemyEnum::SpikeSlimeM(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::SpikeSlimeM, EnemyMove::SpikeSlimeM),
+            EnemyEnum::SpikeSlimeL(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::SpikeSlimeL, EnemyMove::SpikeSlimeL),
+            EnemyEnum::AcidSlimeS(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::AcidSlimeS, EnemyMove::AcidSlimeS),
+            EnemyEnum::AcidSlimeM(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::AcidSlimeM, EnemyMove::AcidSlimeM),
+            EnemyEnum::AcidSlimeL(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::AcidSlimeL, EnemyMove::AcidSlimeL),
+            EnemyEnum::GremlinNob(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::GremlinNob, EnemyMove::GremlinNob),
+            EnemyEnum::Lagavulin(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::Lagavulin, EnemyMove::Lagavulin),
+            EnemyEnum::Sentry(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::Sentry, EnemyMove::Sentry),
+            EnemyEnum::FatGremlin(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::FatGremlin, EnemyMove::FatGremlin),
+            EnemyEnum::SneakyGremlin(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::SneakyGremlin, EnemyMove::SneakyGremlin),
+            EnemyEnum::MadGremlin(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::MadGremlin, EnemyMove::MadGremlin),
+            EnemyEnum::ShieldGremlin(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::ShieldGremlin, EnemyMove::ShieldGremlin),
+            EnemyEnum::GremlinWizard(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::GremlinWizard, EnemyMove::GremlinWizard),
+            EnemyEnum::Looter(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::Looter, EnemyMove::Looter),
+            EnemyEnum::FungiBeast(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::FungiBeast, EnemyMove::FungiBeast),
+            EnemyEnum::BlueSlaver(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::BlueSlaver, EnemyMove::BlueSlaver),
+            EnemyEnum::RedSlaver(enemy) => wrap_move_outcomes(enemy.move_outcomes(global_info, rng), EnemyEnum::RedSlaver, EnemyMove::RedSlaver),
+        }
+    }
+
+    /// Display name of the enemy
+    pub fn get_name(&self) -> String {
+        match self {
+            EnemyEnum::RedLouse(_) => RedLouse::get_name(),
+            EnemyEnum::GreenLouse(_) => GreenLouse::get_name(),
+            EnemyEnum::JawWorm(_) => JawWorm::get_name(),
+            EnemyEnum::Cultist(_) => Cultist::get_name(),
+            EnemyEnum::SpikeSlimeS(_) => SpikeSlimeS::get_name(),
+            EnemyEnum::SpikeSlimeM(_) => SpikeSlimeM::get_name(),
+            EnemyEnum::SpikeSlimeL(_) => SpikeSlimeL::get_name(),
+            EnemyEnum::AcidSlimeS(_) => AcidSlimeS::get_name(),
+            EnemyEnum::AcidSlimeM(_) => AcidSlimeM::get_name(),
+            EnemyEnum::AcidSlimeL(_) => AcidSlimeL::get_name(),
+            EnemyEnum::GremlinNob(_) => GremlinNob::get_name(),
+            EnemyEnum::Lagavulin(_) => Lagavulin::get_name(),
+            EnemyEnum::Sentry(_) => Sentry::get_name(),
+            EnemyEnum::FatGremlin(_) => FatGremlin::get_name(),
+            EnemyEnum::SneakyGremlin(_) => SneakyGremlin::get_name(),
+            EnemyEnum::MadGremlin(_) => MadGremlin::get_name(),
+            EnemyEnum::ShieldGremlin(_) => ShieldGremlin::get_name(),
+            EnemyEnum::GremlinWizard(_) => GremlinWizard::get_name(),
+            EnemyEnum::Looter(_) => Looter::get_name(),
+            EnemyEnum::FungiBeast(_) => FungiBeast::get_name(),
+            EnemyEnum::BlueSlaver(_) => BlueSlaver::get_name(),
+            EnemyEnum::RedSlaver(_) => RedSlaver::get_name(),
+        }
+    }
 
     /// Get the HP of the enemy
     pub fn get_hp(&self) -> u32 {
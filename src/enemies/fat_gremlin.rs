@@ -1,4 +1,4 @@
-use crate::game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo};
+use crate::game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -79,6 +79,10 @@ impl EnemyTrait for FatGremlin {
 
         (move_type, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::forced(self, global_info, rng))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, utils::CategoricalDistribution};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, utils::CategoricalDistribution};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -131,6 +131,13 @@ impl EnemyTrait for GreenLouse {
         
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(global_info), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move)
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, utils::CategoricalDistribution};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, utils::CategoricalDistribution};
 use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
 use serde::{Serialize, Deserialize};
 
@@ -219,6 +219,13 @@ impl EnemyTrait for GremlinNob {
         
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(global_info), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move, global_info)
+        }))
+    }
 }
 
 // EnrageListener implementation for GremlinNob
@@ -1,4 +1,4 @@
-use crate::game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo};
+use crate::game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -114,6 +114,10 @@ impl EnemyTrait for GremlinWizard {
         let effects = self.get_move_effects(move_type, global_info);
         (move_type, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::forced(self, global_info, rng))
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use crate::game::enemy::EnemyTrait;
 use crate::game::global_info::GlobalInfo;
-use crate::game::effect::Effect;
+use crate::game::effect::{Effect, StatusPlacement};
 use crate::battle::battle_events::{BattleEvent, EventListener};
 use crate::battle::target::Entity;
 
@@ -25,7 +25,7 @@ use crate::battle::target::Entity;
 /// - **Sear**: 6 damage + 1 Burn to discard pile
 /// - **Tackle**: 5×2 hits (6×2 at A4+)
 /// - **Inflame**: Gains 12 Block + 2 Strength (3 Strength at A19+)
-/// - **Inferno**: 2×6 hits (3×6 at A4+) + 3 Burns to discard + upgrades all existing Burns
+/// - **Inferno**: 2×6 hits (3×6 at A4+) + upgrades all existing Burns + 3 Burn+ to discard
 /// - **Divider**: (N+1)×6 damage where N = player HP ÷ 12 (rounded down)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Hexaghost {
@@ -149,8 +149,12 @@ impl Hexaghost {
                         num_attacks: 1,
                         strength_multiplier: 1,
                     },
-                    Effect::AddStatusToDiscard {
+                    // After the first Inferno, Sear creates Burn+ instead of Burn
+                    Effect::AddStatusCard {
                         status_card: crate::game::card_enum::CardEnum::Burn,
+                        count: 1,
+                        placement: StatusPlacement::Discard,
+                        upgraded: self.inferno_upgrades_active,
                     },
                 ]
             }
@@ -183,12 +187,16 @@ impl Hexaghost {
                     },
                 ];
 
-                // Add 3 Burns to discard
-                for _ in 0..3 {
-                    effects.push(Effect::AddStatusToDiscard {
-                        status_card: crate::game::card_enum::CardEnum::Burn,
-                    });
-                }
+                // Upgrade every existing Burn, then add 3 Burn+ to discard
+                effects.push(Effect::UpgradeStatusCards {
+                    status_card: crate::game::card_enum::CardEnum::Burn,
+                });
+                effects.push(Effect::AddStatusCard {
+                    status_card: crate::game::card_enum::CardEnum::Burn,
+                    count: 3,
+                    placement: StatusPlacement::Discard,
+                    upgraded: true,
+                });
 
                 effects
             }
@@ -361,13 +369,12 @@ mod tests {
         }
 
         // Check Burn addition effect
-        match &effects[1] {
-            Effect::AddStatusToDiscard { .. } => {
-                // Burn status card - we can't easily check the card type here
-                // but we can verify it's AddStatusToDiscard
-            }
-            _ => panic!("Expected AddStatusToDiscard effect"),
-        }
+        assert_eq!(effects[1], Effect::AddStatusCard {
+            status_card: crate::game::card_enum::CardEnum::Burn,
+            count: 1,
+            placement: StatusPlacement::Discard,
+            upgraded: false,
+        });
     }
 
     #[test]
@@ -442,10 +449,9 @@ mod tests {
         let effects_a0 = hexaghost_a0.get_move_effects(HexaghostMove::Inferno, 0);
         let effects_a4 = hexaghost_a4.get_move_effects(HexaghostMove::Inferno, 0);
 
-        // A0: Should have attack + 3 Burns = 4 effects
-        assert_eq!(effects_a0.len(), 4);
-        // A4: Should have attack + 3 Burns = 4 effects
-        assert_eq!(effects_a4.len(), 4);
+        // Attack + upgrade existing Burns + 3 Burn+
+        assert_eq!(effects_a0.len(), 3);
+        assert_eq!(effects_a4.len(), 3);
 
         // Check attack effects
         match &effects_a0[0] {
@@ -464,15 +470,15 @@ mod tests {
             _ => panic!("Expected AttackToTarget effect"),
         }
 
-        // Check Burn additions (3 burns)
-        for i in 1..4 {
-            match &effects_a0[i] {
-                Effect::AddStatusToDiscard { .. } => {
-                    // Expected Burn card
-                }
-                _ => panic!("Expected AddStatusToDiscard effect for Burn {}", i),
-            }
-        }
+        assert_eq!(effects_a0[1], Effect::UpgradeStatusCards {
+            status_card: crate::game::card_enum::CardEnum::Burn,
+        });
+        assert_eq!(effects_a0[2], Effect::AddStatusCard {
+            status_card: crate::game::card_enum::CardEnum::Burn,
+            count: 3,
+            placement: StatusPlacement::Discard,
+            upgraded: true,
+        });
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, utils::CategoricalDistribution};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, utils::CategoricalDistribution};
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -75,7 +75,7 @@ impl JawWorm {
         }
     }
 
-    fn record_move(&mut self, selected_move: JawWormMove) {
+    pub fn record_move(&mut self, selected_move: JawWormMove) {
         // Track consecutive Thrash usage
         if selected_move == JawWormMove::Thrash {
             if let Some(JawWormMove::Thrash) = self.last_move {
@@ -199,6 +199,13 @@ impl EnemyTrait for JawWorm {
         
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(global_info), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move, global_info)
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::game::enemy::EnemyTrait;
+use crate::game::enemy::{EnemyTrait, MoveOutcome};
 use crate::game::global_info::GlobalInfo;
 use crate::game::effect::BattleEffect;
 use crate::utils::CategoricalDistribution;
@@ -165,8 +165,8 @@ impl Lagavulin {
             LagavulinMove::SiphonSoul => {
                 let (dex_loss, str_loss) = self.get_siphon_amounts();
                 vec![
-                    BattleEffect::LoseDexterityTarget(dex_loss),
-                    BattleEffect::LoseStrengthTarget(str_loss),
+                    BattleEffect::LoseDexterity(dex_loss),
+                    BattleEffect::LoseStrength(str_loss),
                 ]
             }
         }
@@ -225,6 +225,13 @@ impl EnemyTrait for Lagavulin {
 
         (selected_move, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::categorical(self, &self.choose_next_move(global_info), |enemy, selected_move| {
+            enemy.record_move(selected_move);
+            enemy.get_move_effects(selected_move)
+        }))
+    }
 }
 
 /// Event listener for Lagavulin's mechanics
@@ -428,23 +435,23 @@ mod tests {
         // Base: -1 Dexterity, -1 Strength
         assert_eq!(effects_base.len(), 2);
         match &effects_base[0] {
-            BattleEffect::LoseDexterityTarget(amount) => assert_eq!(*amount, 1),
-            _ => panic!("Expected LoseDexterityTarget"),
+            BattleEffect::LoseDexterity(amount) => assert_eq!(*amount, 1),
+            _ => panic!("Expected LoseDexterity"),
         }
         match &effects_base[1] {
-            BattleEffect::LoseStrengthTarget(amount) => assert_eq!(*amount, 1),
-            _ => panic!("Expected LoseStrengthTarget"),
+            BattleEffect::LoseStrength(amount) => assert_eq!(*amount, 1),
+            _ => panic!("Expected LoseStrength"),
         }
 
         // A18+: -2 Dexterity, -2 Strength
         assert_eq!(effects_a18.len(), 2);
         match &effects_a18[0] {
-            BattleEffect::LoseDexterityTarget(amount) => assert_eq!(*amount, 2),
-            _ => panic!("Expected LoseDexterityTarget"),
+            BattleEffect::LoseDexterity(amount) => assert_eq!(*amount, 2),
+            _ => panic!("Expected LoseDexterity"),
         }
         match &effects_a18[1] {
-            BattleEffect::LoseStrengthTarget(amount) => assert_eq!(*amount, 2),
-            _ => panic!("Expected LoseStrengthTarget"),
+            BattleEffect::LoseStrength(amount) => assert_eq!(*amount, 2),
+            _ => panic!("Expected LoseStrength"),
         }
     }
 
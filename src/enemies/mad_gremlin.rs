@@ -1,4 +1,4 @@
-use crate::game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo};
+use crate::game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo};
 use crate::battle::{battle_events::{BattleEvent, EventListener}, target::Entity};
 use serde::{Serialize, Deserialize};
 
@@ -81,6 +81,10 @@ impl EnemyTrait for MadGremlin {
 
         (move_type, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::forced(self, global_info, rng))
+    }
 }
 
 // AngryListener implementation for MadGremlin
@@ -1,4 +1,4 @@
-use crate::{game::{effect::BattleEffect, enemy::EnemyTrait, global_info::GlobalInfo}, battle::{battle_events::{BattleEvent, EventListener}, target::Entity}};
+use crate::{game::{effect::BattleEffect, enemy::{EnemyTrait, MoveOutcome}, global_info::GlobalInfo}, battle::{battle_events::{BattleEvent, EventListener}, target::Entity}};
 use std::any::Any;
 use serde::{Serialize, Deserialize};
 
@@ -155,6 +155,10 @@ impl EnemyTrait for Sentry {
         let effects = self.get_move_effects(move_type);
         (move_type, effects)
     }
+
+    fn move_outcomes(&self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>> {
+        Some(MoveOutcome::forced(self, global_info, rng))
+    }
 }
 
 /// Event listener for Sentry enemies
@@ -11,6 +11,7 @@ mod world_of_goop;
 mod wing_statue;
 mod purifier;
 mod living_wall;
+mod the_library;
 
 use crate::game::effect::Effect;
 use crate::game::global_info::GlobalInfo;
@@ -38,6 +39,8 @@ pub enum MapEvent {
     Purifier,
     /// Living Wall - A mysterious wall offering deck improvements
     LivingWall,
+    /// The Library - Read a book to find a new card, or rest
+    TheLibrary,
 }
 
 /// Represents a choice the player can make in an event
@@ -82,6 +85,7 @@ impl MapEvent {
             MapEvent::WingStatue => wing_statue::wing_statue_choices(),
             MapEvent::Purifier => purifier::purifier_choices(),
             MapEvent::LivingWall => living_wall::living_wall_choices(),
+            MapEvent::TheLibrary => the_library::the_library_choices(80, 0),  // Default max HP, ascension 0
         }
     }
 
@@ -99,6 +103,7 @@ impl MapEvent {
             MapEvent::WingStatue => wing_statue::wing_statue_choices(),
             MapEvent::Purifier => purifier::purifier_choices(),
             MapEvent::LivingWall => living_wall::living_wall_choices(),
+            MapEvent::TheLibrary => the_library::the_library_choices(ctx.player_max_hp, ctx.ascension),
         }
     }
 
@@ -114,6 +119,7 @@ impl MapEvent {
             MapEvent::WingStatue => wing_statue::wing_statue_description(),
             MapEvent::Purifier => purifier::purifier_description(),
             MapEvent::LivingWall => living_wall::living_wall_description(),
+            MapEvent::TheLibrary => the_library::the_library_description(),
         }
     }
 }
@@ -131,6 +137,7 @@ pub fn sample_sls_event(_global_info: &GlobalInfo, rng: &mut impl rand::Rng) ->
         MapEvent::WingStatue,
         MapEvent::Purifier,
         MapEvent::LivingWall,
+        MapEvent::TheLibrary,
     ];
 
     // TODO: In the future, this could be expanded to:
@@ -0,0 +1,72 @@
+use crate::game::effect::{Effect, BattleEffect, GameEffect, CardPool};
+use crate::events::map_events::{EventChoice, EventOutcome};
+
+/// The Library event choices
+/// Based on: https://slay-the-spire.fandom.com/wiki/The_Library
+/// - Read: Choose 1 of 20 cards to add to your deck
+/// - Sleep: Heal 33% of max HP (20% at Ascension 15+)
+pub fn the_library_choices(player_max_hp: u32, ascension: u32) -> Vec<EventChoice> {
+    let heal_percent = if ascension >= 15 { 0.20 } else { 0.33 };
+    // Heal(0) means "heal 1/3 of max HP" to the event system, so always heal at least 1
+    let heal_amount = ((player_max_hp as f64 * heal_percent).round() as u32).max(1);
+
+    vec![
+        EventChoice {
+            text: "Read (Choose 1 of 20 cards to add to your deck)".to_string(),
+            outcome: EventOutcome::Effects(vec![
+                Effect::Game(GameEffect::ChooseCard { pool: CardPool::Class, num_choices: 20 }),
+            ]),
+        },
+        EventChoice {
+            text: format!("Sleep (Heal {} HP)", heal_amount),
+            outcome: EventOutcome::Effects(vec![
+                Effect::Battle(BattleEffect::Heal(heal_amount)),
+            ]),
+        },
+    ]
+}
+
+pub fn the_library_description() -> &'static str {
+    "You come across an ornate building which appears abandoned. \
+     Shelves upon shelves of books stretch into the darkness."
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_the_library_has_two_choices() {
+        let choices = the_library_choices(80, 0);
+        assert_eq!(choices.len(), 2);
+    }
+
+    #[test]
+    fn test_the_library_read_choice() {
+        let choices = the_library_choices(80, 0);
+
+        let read = &choices[0];
+        assert!(read.text.contains("Read"));
+        match &read.outcome {
+            EventOutcome::Effects(effects) => {
+                assert_eq!(effects, &vec![Effect::Game(GameEffect::ChooseCard { pool: CardPool::Class, num_choices: 20 })]);
+            }
+            _ => panic!("Expected Effects outcome"),
+        }
+    }
+
+    #[test]
+    fn test_the_library_sleep_heal_scales_with_ascension() {
+        let choices = the_library_choices(80, 0);
+        match &choices[1].outcome {
+            EventOutcome::Effects(effects) => assert_eq!(effects, &vec![Effect::Battle(BattleEffect::Heal(26))]),
+            _ => panic!("Expected Effects outcome"),
+        }
+
+        let choices = the_library_choices(80, 15);
+        match &choices[1].outcome {
+            EventOutcome::Effects(effects) => assert_eq!(effects, &vec![Effect::Battle(BattleEffect::Heal(16))]),
+            _ => panic!("Expected Effects outcome"),
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::game::card_type::CardType;
 use crate::game::card_enum::CardEnum;
+use crate::game::card_keyword::{CardKeyword, CardKeywords};
 use crate::game::effect::{BattleEffect, Condition};
 use serde::{Serialize, Deserialize};
+use std::hash::{Hash, Hasher};
 
 /// Card rarity for classification and reward generation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -10,6 +12,7 @@ pub enum Rarity {
     Common,     // Most frequent rewards (~75% of pool)
     Uncommon,   // Less frequent rewards (~20% of pool)
     Rare,       // Rare rewards (~5% of pool)
+    Special,    // Generated by events, relics or other cards - never in reward pools or shops
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -20,38 +23,85 @@ pub enum CardClass {
     Curse,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Equality and hashing ignore the instance id: two copies of a card that play the same are the
+/// same card, so game-identical states compare and hash equal (MCTS transpositions, chance outcomes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Card {
+    id: Option<u32>, // Unique instance id, assigned when the card first enters combat, used to match cards back to the master deck
     card_enum: CardEnum,
     cost: u32,
     card_class: CardClass, // Combines card type, rarity, and character class
     effects: Vec<BattleEffect>,
     upgrade_level: u32, // 0 = not upgraded, 1+ = upgraded level
     play_condition: Condition,
-    ethereal: bool,
+    keywords: CardKeywords, // Retain, Innate, Unplayable, Ethereal and Exhaust, can be granted or removed during combat
     on_exhaust: Option<Vec<BattleEffect>>, // Effects that trigger when this card is exhausted
     end_of_turn: Option<Vec<BattleEffect>>, // Effects that trigger at end of turn
+    on_draw: Option<Vec<BattleEffect>>, // Effects that trigger when this card is drawn (e.g., Void)
     is_removable: bool, // Whether this card can be removed from the deck (false for Ascender's Bane, Curse of the Bell, Necronomicurse)
-    is_innate: bool, // Whether this card is innate (starts in every hand and returns to hand after played)
     modified_cost_this_turn: Option<u32>, // Temporary cost override for this turn (e.g., from potions making cards cost 0)
+    cost_until_played: Option<u32>, // Cost override that lasts until the card is next played (e.g., Forethought)
+    x_cost: bool, // Whether this card costs X (spends all remaining energy, X is passed to its RepeatX and XAmount effects)
 }
 
-impl Card {
+impl PartialEq for Card {
+    fn eq(&self, other: &Self) -> bool {
+        self.card_enum == other.card_enum
+            && self.cost == other.cost
+            && self.card_class == other.card_class
+            && self.effects == other.effects
+            && self.upgrade_level == other.upgrade_level
+            && self.play_condition == other.play_condition
+            && self.keywords == other.keywords
+            && self.on_exhaust == other.on_exhaust
+            && self.end_of_turn == other.end_of_turn
+            && self.on_draw == other.on_draw
+            && self.is_removable == other.is_removable
+            && self.modified_cost_this_turn == other.modified_cost_this_turn
+            && self.cost_until_played == other.cost_until_played
+            && self.x_cost == other.x_cost
+    }
+}
 
+impl Eq for Card {}
+
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.card_enum.hash(state);
+        self.cost.hash(state);
+        self.card_class.hash(state);
+        self.effects.hash(state);
+        self.upgrade_level.hash(state);
+        self.play_condition.hash(state);
+        self.keywords.hash(state);
+        self.on_exhaust.hash(state);
+        self.end_of_turn.hash(state);
+        self.on_draw.hash(state);
+        self.is_removable.hash(state);
+        self.modified_cost_this_turn.hash(state);
+        self.cost_until_played.hash(state);
+        self.x_cost.hash(state);
+    }
+}
+
+impl Card {
     pub fn new(card_enum: CardEnum, cost: u32, card_class: CardClass, effects: Vec<BattleEffect>) -> Self {
         Card {
+            id: None,
             card_enum,
             cost,
             card_class,
             effects,
             upgrade_level: 0, // Default to not upgraded
             play_condition: Condition::True, // Default to playable
-            ethereal: false,
+            keywords: CardKeywords::new(),
             on_exhaust: None,
             end_of_turn: None,
+            on_draw: None,
             is_removable: true, // Default to removable
-            is_innate: false, // Default to not innate
             modified_cost_this_turn: None, // Default to no cost modification
+            cost_until_played: None,
+            x_cost: false,
         }
     }
 
@@ -63,7 +113,7 @@ impl Card {
 
     /// Builder pattern method to set whether the card is ethereal
     pub fn set_ethereal(mut self, ethereal: bool) -> Self {
-        self.ethereal = ethereal;
+        self.keywords.set(CardKeyword::Ethereal, ethereal);
         self
     }
 
@@ -85,6 +135,12 @@ impl Card {
         self
     }
 
+    /// Builder pattern method to set on-draw effects
+    pub fn set_on_draw(mut self, on_draw: Vec<BattleEffect>) -> Self {
+        self.on_draw = Some(on_draw);
+        self
+    }
+
     /// Convenience method to set upgradable from boolean
     pub fn set_upgraded(mut self, upgraded: bool) -> Self {
         if upgraded {
@@ -97,7 +153,10 @@ impl Card {
 
     /// Convenience method to set playable from boolean
     pub fn set_playable(mut self, playable: bool) -> Self {
-        self.play_condition = if playable { Condition::True } else { Condition::False };
+        if playable {
+            self.play_condition = Condition::True;
+        }
+        self.keywords.set(CardKeyword::Unplayable, !playable);
         self
     }
 
@@ -109,7 +168,25 @@ impl Card {
 
     /// Builder pattern method to set whether the card is innate
     pub fn set_innate(mut self, is_innate: bool) -> Self {
-        self.is_innate = is_innate;
+        self.keywords.set(CardKeyword::Innate, is_innate);
+        self
+    }
+
+    /// Builder pattern method to set whether the card is retained at the end of turn
+    pub fn set_retain(mut self, retain: bool) -> Self {
+        self.keywords.set(CardKeyword::Retain, retain);
+        self
+    }
+
+    /// Builder pattern method to set whether the card exhausts when played
+    pub fn set_exhaust(mut self, exhaust: bool) -> Self {
+        self.keywords.set(CardKeyword::Exhaust, exhaust);
+        self
+    }
+
+    /// Builder pattern method to mark the card as X-cost (e.g., Whirlwind, Transmutation)
+    pub fn set_x_cost(mut self, x_cost: bool) -> Self {
+        self.x_cost = x_cost;
         self
     }
 
@@ -119,6 +196,13 @@ impl Card {
         self
     }
 
+    /// Builder pattern method to set the card's cost for the rest of combat
+    /// (e.g., Chrysalis and Metamorphosis make their generated cards cost 0)
+    pub fn set_cost_for_combat(mut self, cost: u32) -> Self {
+        self.cost = cost;
+        self
+    }
+
     pub fn get_name(&self) -> String {
         if self.upgrade_level > 0 {
             self.card_enum.upgraded_name()
@@ -133,7 +217,9 @@ impl Card {
 
     pub fn get_cost(&self) -> u32 {
         // Return modified cost if set, otherwise return base cost
-        self.modified_cost_this_turn.unwrap_or(self.cost)
+        self.modified_cost_this_turn
+            .or(self.cost_until_played)
+            .unwrap_or(self.cost)
     }
 
     pub fn get_card_type(&self) -> CardType {
@@ -161,6 +247,10 @@ impl Card {
         self.end_of_turn.as_ref()
     }
 
+    pub fn get_on_draw(&self) -> Option<&Vec<BattleEffect>> {
+        self.on_draw.as_ref()
+    }
+
     pub fn get_rarity(&self) -> Rarity {
         match &self.card_class {
             CardClass::IronClad(rarity, _) => *rarity,
@@ -181,6 +271,8 @@ impl Card {
             return self; // Already upgraded
         }
 
+        // The upgraded card is still the same instance
+        let id = self.id;
 
         // Delegate to individual card upgrade functions and preserve rarity
         let upgraded_card = match self.card_enum {
@@ -232,7 +324,7 @@ impl Card {
             CardEnum::Metallicize => crate::cards::ironclad::metallicize::metallicize_upgraded(),
             CardEnum::FlameBarrier => crate::cards::ironclad::flame_barrier::flame_barrier_upgraded(),
             CardEnum::Rage => crate::cards::ironclad::rage::rage_upgraded(),
-            CardEnum::Rampage => self, // TODO: Implement rampage_upgraded()
+            CardEnum::Rampage => crate::cards::ironclad::rampage::upgrade_rampage(self),
             CardEnum::RecklessCharge => crate::cards::ironclad::reckless_charge::reckless_charge_upgraded(),
             CardEnum::SearingBlow => self.upgrade_searing_blow(),
             CardEnum::SeverSoul => crate::cards::ironclad::sever_soul::sever_soul_upgraded(),
@@ -245,6 +337,7 @@ impl Card {
             CardEnum::DemonForm => crate::cards::ironclad::demon_form::demon_form_upgraded(),
             CardEnum::SecondWind => crate::cards::ironclad::second_wind::second_wind_upgraded(),
             CardEnum::Rupture => crate::cards::ironclad::rupture::rupture_upgraded(),
+            CardEnum::Juggernaut => crate::cards::ironclad::juggernaut::juggernaut_upgraded(),
             CardEnum::DualWield => crate::cards::ironclad::dual_wield::dual_wield_upgraded(),
             CardEnum::DoubleTap => crate::cards::ironclad::double_tap::double_tap_upgraded(),
             CardEnum::Exhume => crate::cards::ironclad::exhume::exhume_upgraded(),
@@ -252,10 +345,13 @@ impl Card {
             CardEnum::Reaper => crate::cards::ironclad::reaper::reaper_upgraded(),
             CardEnum::FiendFire => crate::cards::ironclad::fiend_fire::fiend_fire_upgraded(),
             CardEnum::FireBreathing => crate::cards::ironclad::fire_breathing::fire_breathing_upgraded(),
+            CardEnum::Barricade => crate::cards::ironclad::barricade::barricade_upgraded(),
+            CardEnum::BattleTrance => crate::cards::ironclad::battle_trance::battle_trance_upgraded(),
             CardEnum::Slimed => self, // Status cards don't upgrade
             CardEnum::Wound => self, // Status cards don't upgrade
-            CardEnum::Burn => self, // Status cards don't upgrade
+            CardEnum::Burn => crate::cards::status::burn::burn_upgraded(),
             CardEnum::Dazed => self, // Status cards don't upgrade
+            CardEnum::Void => self, // Status cards don't upgrade
 
             // Colorless cards
             CardEnum::SwiftStrike => crate::cards::colorless::swift_strike::swift_strike_upgraded(),
@@ -272,14 +368,41 @@ impl Card {
             CardEnum::PanicButton => crate::cards::colorless::panic_button::panic_button_upgraded(),
             CardEnum::Panacea => crate::cards::colorless::panacea::panacea_upgraded(),
             CardEnum::DramaticEntrance => crate::cards::colorless::dramatic_entrance::dramatic_entrance_upgraded(),
+            CardEnum::Discovery => crate::cards::colorless::discovery::discovery_upgraded(),
+            CardEnum::Enlightenment => crate::cards::colorless::enlightenment::enlightenment_upgraded(),
+            CardEnum::Forethought => crate::cards::colorless::forethought::forethought_upgraded(),
+            CardEnum::JackOfAllTrades => crate::cards::colorless::jack_of_all_trades::jack_of_all_trades_upgraded(),
+            CardEnum::Madness => crate::cards::colorless::madness::madness_upgraded(),
+            CardEnum::MindBlast => crate::cards::colorless::mind_blast::mind_blast_upgraded(),
+            CardEnum::Purity => crate::cards::colorless::purity::purity_upgraded(),
+            CardEnum::Apotheosis => crate::cards::colorless::apotheosis::apotheosis_upgraded(),
+            CardEnum::Chrysalis => crate::cards::colorless::chrysalis::chrysalis_upgraded(),
+            CardEnum::HandOfGreed => crate::cards::colorless::hand_of_greed::hand_of_greed_upgraded(),
+            CardEnum::Magnetism => crate::cards::colorless::magnetism::magnetism_upgraded(),
+            CardEnum::Mayhem => crate::cards::colorless::mayhem::mayhem_upgraded(),
+            CardEnum::Metamorphosis => crate::cards::colorless::metamorphosis::metamorphosis_upgraded(),
+            CardEnum::Panache => crate::cards::colorless::panache::panache_upgraded(),
+            CardEnum::SadisticNature => crate::cards::colorless::sadistic_nature::sadistic_nature_upgraded(),
+            CardEnum::SecretTechnique => crate::cards::colorless::secret_technique::secret_technique_upgraded(),
+            CardEnum::SecretWeapon => crate::cards::colorless::secret_weapon::secret_weapon_upgraded(),
+            CardEnum::TheBomb => crate::cards::colorless::the_bomb::the_bomb_upgraded(),
+            CardEnum::ThinkingAhead => crate::cards::colorless::thinking_ahead::thinking_ahead_upgraded(),
+            CardEnum::Transmutation => crate::cards::colorless::transmutation::transmutation_upgraded(),
+            CardEnum::Violence => crate::cards::colorless::violence::violence_upgraded(),
+            CardEnum::Apparition => crate::cards::colorless::apparition::apparition_upgraded(),
+            CardEnum::Bite => crate::cards::colorless::bite::bite_upgraded(),
+            CardEnum::Jax => crate::cards::colorless::jax::jax_upgraded(),
+            CardEnum::RitualDagger => crate::cards::colorless::ritual_dagger::upgrade_ritual_dagger(self),
+            CardEnum::Shiv => crate::cards::colorless::shiv::shiv_upgraded(),
             CardEnum::AscendersCurse => crate::cards::curse::ascenders_curse(), // Curse cards don't have upgrades
             CardEnum::Injury => crate::cards::curse::injury(), // Curse cards don't have upgrades
             CardEnum::Clumsy => crate::cards::curse::clumsy(), // Curse cards don't have upgrades
             CardEnum::Regret => crate::cards::curse::regret(), // Curse cards don't have upgrades
             CardEnum::Writhe => crate::cards::curse::writhe(), // Curse cards don't have upgrades
+            CardEnum::Normality => crate::cards::curse::normality(), // Curse cards don't have upgrades
         };
 
-        upgraded_card
+        Card { id, ..upgraded_card }
     }
 
     /// Special upgrade method for Searing Blow that supports multiple upgrade levels
@@ -302,28 +425,49 @@ impl Card {
     /// Creates a new card with a specific upgrade level
     pub fn with_upgrade_level(self, upgrade_level: u32) -> Card {
         Card {
+            id: self.id,
             card_enum: self.card_enum,
             cost: self.cost,
             card_class: self.card_class,
             effects: self.effects,
             upgrade_level,
             play_condition: self.play_condition,
-            ethereal: self.ethereal,
+            keywords: self.keywords,
             on_exhaust: self.on_exhaust,
             end_of_turn: self.end_of_turn,
+            on_draw: self.on_draw,
             is_removable: self.is_removable,
-            is_innate: self.is_innate,
             modified_cost_this_turn: self.modified_cost_this_turn,
+            cost_until_played: self.cost_until_played,
+            x_cost: self.x_cost,
         }
     }
 
+    /// Gets the unique instance id of this card, if it has entered combat
+    pub fn get_id(&self) -> Option<u32> {
+        self.id
+    }
+
+    /// Gives this card its instance id
+    pub(crate) fn set_id(&mut self, id: u32) {
+        self.id = Some(id);
+    }
+
+    /// Creates a new copy of this card that does not share its instance id (e.g., Dual Wield, Duplicate)
+    pub fn new_copy(&self) -> Card {
+        Card { id: None, ..self.clone() }
+    }
+
     /// Gets the play condition for this card
-    pub fn get_play_condition(&self) -> Condition {
-        self.play_condition
+    pub fn get_play_condition(&self) -> &Condition {
+        &self.play_condition
     }
 
     /// Checks if this card is playable (for backward compatibility - always returns true for now since playability depends on context)
     pub fn is_playable(&self) -> bool {
+        if self.has_keyword(CardKeyword::Unplayable) {
+            return false;
+        }
         match self.play_condition {
             Condition::False => false,
             _ => true, // True, HandAllAttacks, etc. depend on context
@@ -332,7 +476,7 @@ impl Card {
 
     /// Checks if this card is ethereal (exhausts at end of turn if not played)
     pub fn is_ethereal(&self) -> bool {
-        self.ethereal
+        self.has_keyword(CardKeyword::Ethereal)
     }
 
     /// Checks if this card can be removed from the deck
@@ -346,10 +490,74 @@ impl Card {
         self.modified_cost_this_turn = None;
     }
 
+    /// Sets the temporary cost for this turn on a card that is already in a pile
+    pub fn set_modified_cost(&mut self, cost: u32) {
+        self.modified_cost_this_turn = Some(cost);
+    }
+
+    /// Sets the card's cost for the rest of combat on a card that is already in a pile
+    pub fn set_combat_cost(&mut self, cost: u32) {
+        self.cost = cost;
+    }
+
+    /// Grows the damage of this card's scaling attacks by their scaling, for the rest of combat (Rampage)
+    pub fn increase_scaling_damage(&mut self) {
+        for effect in &mut self.effects {
+            if let BattleEffect::AttackToTargetWithScaling { base_damage, scaling } = effect {
+                *base_damage += *scaling;
+            }
+        }
+    }
+
+    /// Sets a cost that lasts until the card is next played (e.g., Forethought)
+    pub fn set_cost_until_played(&mut self, cost: u32) {
+        self.cost_until_played = Some(cost);
+    }
+
+    /// Clears the cost that lasts until played (should be called when the card is played)
+    pub fn clear_cost_until_played(&mut self) {
+        self.cost_until_played = None;
+    }
+
+    /// Checks if this card costs X (playable with any amount of energy, including 0)
+    pub fn is_x_cost(&self) -> bool {
+        self.x_cost
+    }
+
     /// Checks if this card is innate
     /// Innate cards start in every hand and return to hand after being played
     pub fn is_innate(&self) -> bool {
-        self.is_innate
+        self.has_keyword(CardKeyword::Innate)
+    }
+
+    /// Checks if this card is retained at the end of turn instead of being discarded
+    pub fn is_retained(&self) -> bool {
+        self.has_keyword(CardKeyword::Retain)
+    }
+
+    /// Checks if this card exhausts when played, either from the Exhaust keyword or its Exhaust effect
+    pub fn exhausts(&self) -> bool {
+        self.has_keyword(CardKeyword::Exhaust) || self.effects.contains(&BattleEffect::Exhaust)
+    }
+
+    /// Checks if this card currently has a keyword
+    pub fn has_keyword(&self, keyword: CardKeyword) -> bool {
+        self.keywords.contains(keyword)
+    }
+
+    /// Gets all keywords currently on this card
+    pub fn get_keywords(&self) -> CardKeywords {
+        self.keywords
+    }
+
+    /// Grants a keyword to a card that is already in a pile (e.g., Retain from Well-Laid Plans)
+    pub fn add_keyword(&mut self, keyword: CardKeyword) {
+        self.keywords.insert(keyword);
+    }
+
+    /// Removes a keyword from a card that is already in a pile
+    pub fn remove_keyword(&mut self, keyword: CardKeyword) {
+        self.keywords.remove(keyword);
     }
 }
 
@@ -439,4 +647,29 @@ mod tests {
         let upgraded = innate_card.with_upgrade_level(1);
         assert!(upgraded.is_innate()); // Should preserve innate status
     }
+
+    #[test]
+    fn test_card_keywords_added_and_removed_in_combat() {
+        let mut card = Card::new(CardEnum::Strike, 1, CardClass::IronClad(Rarity::Basic, CardType::Attack), vec![BattleEffect::AttackToTarget { amount: 6, num_attacks: 1, strength_multiplier: 1 }]);
+        assert!(!card.is_retained());
+        assert!(!card.exhausts());
+
+        card.add_keyword(CardKeyword::Retain);
+        card.add_keyword(CardKeyword::Exhaust);
+        assert!(card.is_retained());
+        assert!(card.exhausts());
+
+        let upgraded = card.clone().with_upgrade_level(1);
+        assert!(upgraded.is_retained()); // Keywords gained in combat survive an in-combat upgrade
+
+        card.remove_keyword(CardKeyword::Exhaust);
+        assert!(!card.exhausts());
+    }
+
+    #[test]
+    fn test_card_unplayable_keyword() {
+        let card = Card::new(CardEnum::Wound, 0, CardClass::Status, vec![]).set_playable(false);
+        assert!(card.has_keyword(CardKeyword::Unplayable));
+        assert!(!card.is_playable());
+    }
 }
\ No newline at end of file
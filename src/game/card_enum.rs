@@ -67,6 +67,7 @@ pub enum CardEnum {
     DemonForm,
     SecondWind,
     Rupture,
+    Juggernaut,
     DualWield,
     DoubleTap,
     Exhume,
@@ -74,11 +75,14 @@ pub enum CardEnum {
     Reaper,
     FiendFire,
     FireBreathing,
+    Barricade,
+    BattleTrance,
     // Status Cards
     Slimed,
     Wound,
     Burn,
     Dazed,
+    Void,
 
     // Curse Cards
     AscendersCurse,
@@ -86,6 +90,7 @@ pub enum CardEnum {
     Clumsy,
     Regret,
     Writhe,
+    Normality,
 
     // Colorless Cards
     SwiftStrike,
@@ -102,6 +107,32 @@ pub enum CardEnum {
     PanicButton,
     Panacea,
     DramaticEntrance,
+    Discovery,
+    Enlightenment,
+    Forethought,
+    JackOfAllTrades,
+    Madness,
+    MindBlast,
+    Purity,
+    Apotheosis,
+    Chrysalis,
+    HandOfGreed,
+    Magnetism,
+    Mayhem,
+    Metamorphosis,
+    Panache,
+    SadisticNature,
+    SecretTechnique,
+    SecretWeapon,
+    TheBomb,
+    ThinkingAhead,
+    Transmutation,
+    Violence,
+    Apparition,
+    Bite,
+    Jax,
+    RitualDagger,
+    Shiv,
     // Add more cards as needed
 }
 
@@ -170,6 +201,7 @@ impl CardEnum {
             CardEnum::DemonForm => "Demon Form",
             CardEnum::SecondWind => "Second Wind",
             CardEnum::Rupture => "Rupture",
+            CardEnum::Juggernaut => "Juggernaut",
             CardEnum::DualWield => "Dual Wield",
             CardEnum::DoubleTap => "Double Tap",
             CardEnum::Exhume => "Exhume",
@@ -177,15 +209,19 @@ impl CardEnum {
             CardEnum::Reaper => "Reaper",
             CardEnum::FiendFire => "Fiend Fire",
             CardEnum::FireBreathing => "Fire Breathing",
+            CardEnum::Barricade => "Barricade",
+            CardEnum::BattleTrance => "Battle Trance",
             CardEnum::Slimed => "Slimed",
             CardEnum::Wound => "Wound",
             CardEnum::Burn => "Burn",
             CardEnum::Dazed => "Dazed",
+            CardEnum::Void => "Void",
             CardEnum::AscendersCurse => "Ascender's Curse",
             CardEnum::Injury => "Injury",
             CardEnum::Clumsy => "Clumsy",
             CardEnum::Regret => "Regret",
             CardEnum::Writhe => "Writhe",
+            CardEnum::Normality => "Normality",
             CardEnum::SwiftStrike => "Swift Strike",
             CardEnum::Finesse => "Finesse",
             CardEnum::FlashOfSteel => "Flash of Steel",
@@ -200,6 +236,32 @@ impl CardEnum {
             CardEnum::PanicButton => "Panic Button",
             CardEnum::Panacea => "Panacea",
             CardEnum::DramaticEntrance => "Dramatic Entrance",
+            CardEnum::Discovery => "Discovery",
+            CardEnum::Enlightenment => "Enlightenment",
+            CardEnum::Forethought => "Forethought",
+            CardEnum::JackOfAllTrades => "Jack of All Trades",
+            CardEnum::Madness => "Madness",
+            CardEnum::MindBlast => "Mind Blast",
+            CardEnum::Purity => "Purity",
+            CardEnum::Apotheosis => "Apotheosis",
+            CardEnum::Chrysalis => "Chrysalis",
+            CardEnum::HandOfGreed => "Hand of Greed",
+            CardEnum::Magnetism => "Magnetism",
+            CardEnum::Mayhem => "Mayhem",
+            CardEnum::Metamorphosis => "Metamorphosis",
+            CardEnum::Panache => "Panache",
+            CardEnum::SadisticNature => "Sadistic Nature",
+            CardEnum::SecretTechnique => "Secret Technique",
+            CardEnum::SecretWeapon => "Secret Weapon",
+            CardEnum::TheBomb => "The Bomb",
+            CardEnum::ThinkingAhead => "Thinking Ahead",
+            CardEnum::Transmutation => "Transmutation",
+            CardEnum::Violence => "Violence",
+            CardEnum::Apparition => "Apparition",
+            CardEnum::Bite => "Bite",
+            CardEnum::Jax => "J.A.X.",
+            CardEnum::RitualDagger => "Ritual Dagger",
+            CardEnum::Shiv => "Shiv",
         }
     }
 
@@ -223,6 +285,7 @@ impl CardEnum {
             CardRarity::Common => Some(Rarity::Common),
             CardRarity::Uncommon => Some(Rarity::Uncommon),
             CardRarity::Rare => Some(Rarity::Rare),
+            CardRarity::Special => None,
         }
     }
 
@@ -232,13 +295,9 @@ impl CardEnum {
         self.rarity().is_some()
     }
 
-    /// Get all Ironclad Attack cards
-    /// Returns a vector of all CardEnums that are Ironclad Attack cards
-    pub fn all_ironclad_attacks() -> Vec<CardEnum> {
-        use crate::game::card_type::CardType;
-
-        // Get all Ironclad cards
-        let all_ironclad = vec![
+    /// Get all Ironclad cards (including Basic cards)
+    pub fn all_ironclad_cards() -> Vec<CardEnum> {
+        vec![
             CardEnum::Strike, CardEnum::Defend, CardEnum::Bash,
             CardEnum::BodySlam, CardEnum::Clash, CardEnum::Carnage,
             CardEnum::Cleave, CardEnum::Embrace, CardEnum::Flex,
@@ -261,11 +320,37 @@ impl CardEnum {
             CardEnum::Whirlwind, CardEnum::DemonForm, CardEnum::SecondWind,
             CardEnum::Rupture, CardEnum::DualWield, CardEnum::DoubleTap,
             CardEnum::Exhume, CardEnum::Feed, CardEnum::Reaper,
-            CardEnum::FiendFire, CardEnum::FireBreathing,
-        ];
+            CardEnum::FiendFire, CardEnum::FireBreathing, CardEnum::Barricade,
+            CardEnum::BattleTrance, CardEnum::Juggernaut,
+        ]
+    }
+
+    /// Get all Colorless cards that can appear in shops and colorless rewards
+    /// Special cards (Shiv, Bite, J.A.X., ...) are only generated by other cards and events
+    pub fn all_colorless_cards() -> Vec<CardEnum> {
+        vec![
+            CardEnum::SwiftStrike, CardEnum::Finesse, CardEnum::FlashOfSteel,
+            CardEnum::Blind, CardEnum::Trip, CardEnum::GoodInstincts,
+            CardEnum::BandageUp, CardEnum::DeepBreath, CardEnum::DarkShackles,
+            CardEnum::Impatience, CardEnum::PanicButton, CardEnum::Panacea,
+            CardEnum::DramaticEntrance, CardEnum::Discovery, CardEnum::Enlightenment,
+            CardEnum::Forethought, CardEnum::JackOfAllTrades, CardEnum::Madness,
+            CardEnum::MindBlast, CardEnum::Purity, CardEnum::MasterOfStrategy,
+            CardEnum::Apotheosis, CardEnum::Chrysalis, CardEnum::HandOfGreed,
+            CardEnum::Magnetism, CardEnum::Mayhem, CardEnum::Metamorphosis,
+            CardEnum::Panache, CardEnum::SadisticNature, CardEnum::SecretTechnique,
+            CardEnum::SecretWeapon, CardEnum::TheBomb, CardEnum::ThinkingAhead,
+            CardEnum::Transmutation, CardEnum::Violence,
+        ]
+    }
+
+    /// Get all Ironclad Attack cards
+    /// Returns a vector of all CardEnums that are Ironclad Attack cards
+    pub fn all_ironclad_attacks() -> Vec<CardEnum> {
+        use crate::game::card_type::CardType;
 
         // Filter to only Attack cards
-        all_ironclad.into_iter()
+        Self::all_ironclad_cards().into_iter()
             .filter(|card_enum| {
                 let card = card_enum.to_card();
                 card.get_card_type() == CardType::Attack
@@ -276,35 +361,8 @@ impl CardEnum {
     pub fn all_ironclad_skills() -> Vec<CardEnum> {
         use crate::game::card_type::CardType;
 
-        // Get all Ironclad cards
-        let all_ironclad = vec![
-            CardEnum::Strike, CardEnum::Defend, CardEnum::Bash,
-            CardEnum::BodySlam, CardEnum::Clash, CardEnum::Carnage,
-            CardEnum::Cleave, CardEnum::Embrace, CardEnum::Flex,
-            CardEnum::Inflame, CardEnum::Immolate, CardEnum::IronWave,
-            CardEnum::PommelStrike, CardEnum::PowerThrough, CardEnum::ShrugItOff,
-            CardEnum::TwinStrike, CardEnum::Clothesline, CardEnum::HeavyBlade,
-            CardEnum::PerfectedStrike, CardEnum::Thunderclap, CardEnum::WildStrike,
-            CardEnum::Combust, CardEnum::Disarm, CardEnum::Dropkick,
-            CardEnum::FeelNoPain, CardEnum::Entrench, CardEnum::Bludgeon,
-            CardEnum::Anger, CardEnum::SwordBoomerang, CardEnum::Hemokinesis,
-            CardEnum::Armaments, CardEnum::Impervious, CardEnum::Brutality,
-            CardEnum::Offering, CardEnum::Shockwave, CardEnum::Uppercut,
-            CardEnum::Intimidate, CardEnum::SeeingRed, CardEnum::GhostlyArmor,
-            CardEnum::Havoc, CardEnum::Headbutt, CardEnum::TrueGrit,
-            CardEnum::Warcry, CardEnum::Corruption, CardEnum::LimitBreak,
-            CardEnum::Metallicize, CardEnum::FlameBarrier, CardEnum::Rage,
-            CardEnum::Rampage, CardEnum::RecklessCharge, CardEnum::SearingBlow,
-            CardEnum::SeverSoul, CardEnum::SpotWeakness, CardEnum::Pummel,
-            CardEnum::InfernalBlade, CardEnum::Evolve, CardEnum::Sentinel,
-            CardEnum::Whirlwind, CardEnum::DemonForm, CardEnum::SecondWind,
-            CardEnum::Rupture, CardEnum::DualWield, CardEnum::DoubleTap,
-            CardEnum::Exhume, CardEnum::Feed, CardEnum::Reaper,
-            CardEnum::FiendFire, CardEnum::FireBreathing,
-        ];
-
         // Filter to only Skill cards
-        all_ironclad.into_iter()
+        Self::all_ironclad_cards().into_iter()
             .filter(|card_enum| {
                 let card = card_enum.to_card();
                 card.get_card_type() == CardType::Skill
@@ -377,6 +435,7 @@ impl CardEnum {
             CardEnum::DemonForm => crate::cards::ironclad::demon_form::demon_form(),
             CardEnum::SecondWind => crate::cards::ironclad::second_wind::second_wind(),
             CardEnum::Rupture => crate::cards::ironclad::rupture::rupture(),
+            CardEnum::Juggernaut => crate::cards::ironclad::juggernaut::juggernaut(),
             CardEnum::DualWield => crate::cards::ironclad::dual_wield::dual_wield(),
             CardEnum::DoubleTap => crate::cards::ironclad::double_tap::double_tap(),
             CardEnum::Exhume => crate::cards::ironclad::exhume::exhume(),
@@ -384,12 +443,15 @@ impl CardEnum {
             CardEnum::Reaper => crate::cards::ironclad::reaper::reaper(),
             CardEnum::FiendFire => crate::cards::ironclad::fiend_fire::fiend_fire(),
             CardEnum::FireBreathing => crate::cards::ironclad::fire_breathing::fire_breathing(),
+            CardEnum::Barricade => crate::cards::ironclad::barricade::barricade(),
+            CardEnum::BattleTrance => crate::cards::ironclad::battle_trance::battle_trance(),
 
             // Status Cards
             CardEnum::Slimed => crate::cards::status::slimed::slimed(),
             CardEnum::Wound => crate::cards::status::wound::wound(),
             CardEnum::Burn => crate::cards::status::burn::burn(),
             CardEnum::Dazed => crate::cards::status::dazed::dazed(),
+            CardEnum::Void => crate::cards::status::void::void(),
 
             // Curse Cards
             CardEnum::AscendersCurse => crate::cards::curse::ascenders_curse(),
@@ -397,6 +459,7 @@ impl CardEnum {
             CardEnum::Clumsy => crate::cards::curse::clumsy(),
             CardEnum::Regret => crate::cards::curse::regret(),
             CardEnum::Writhe => crate::cards::curse::writhe(),
+            CardEnum::Normality => crate::cards::curse::normality(),
 
             // Colorless Cards
             CardEnum::SwiftStrike => crate::cards::colorless::swift_strike::swift_strike(),
@@ -413,6 +476,32 @@ impl CardEnum {
             CardEnum::PanicButton => crate::cards::colorless::panic_button::panic_button(),
             CardEnum::Panacea => crate::cards::colorless::panacea::panacea(),
             CardEnum::DramaticEntrance => crate::cards::colorless::dramatic_entrance::dramatic_entrance(),
+            CardEnum::Discovery => crate::cards::colorless::discovery::discovery(),
+            CardEnum::Enlightenment => crate::cards::colorless::enlightenment::enlightenment(),
+            CardEnum::Forethought => crate::cards::colorless::forethought::forethought(),
+            CardEnum::JackOfAllTrades => crate::cards::colorless::jack_of_all_trades::jack_of_all_trades(),
+            CardEnum::Madness => crate::cards::colorless::madness::madness(),
+            CardEnum::MindBlast => crate::cards::colorless::mind_blast::mind_blast(),
+            CardEnum::Purity => crate::cards::colorless::purity::purity(),
+            CardEnum::Apotheosis => crate::cards::colorless::apotheosis::apotheosis(),
+            CardEnum::Chrysalis => crate::cards::colorless::chrysalis::chrysalis(),
+            CardEnum::HandOfGreed => crate::cards::colorless::hand_of_greed::hand_of_greed(),
+            CardEnum::Magnetism => crate::cards::colorless::magnetism::magnetism(),
+            CardEnum::Mayhem => crate::cards::colorless::mayhem::mayhem(),
+            CardEnum::Metamorphosis => crate::cards::colorless::metamorphosis::metamorphosis(),
+            CardEnum::Panache => crate::cards::colorless::panache::panache(),
+            CardEnum::SadisticNature => crate::cards::colorless::sadistic_nature::sadistic_nature(),
+            CardEnum::SecretTechnique => crate::cards::colorless::secret_technique::secret_technique(),
+            CardEnum::SecretWeapon => crate::cards::colorless::secret_weapon::secret_weapon(),
+            CardEnum::TheBomb => crate::cards::colorless::the_bomb::the_bomb(),
+            CardEnum::ThinkingAhead => crate::cards::colorless::thinking_ahead::thinking_ahead(),
+            CardEnum::Transmutation => crate::cards::colorless::transmutation::transmutation(),
+            CardEnum::Violence => crate::cards::colorless::violence::violence(),
+            CardEnum::Apparition => crate::cards::colorless::apparition::apparition(),
+            CardEnum::Bite => crate::cards::colorless::bite::bite(),
+            CardEnum::Jax => crate::cards::colorless::jax::jax(),
+            CardEnum::RitualDagger => crate::cards::colorless::ritual_dagger::ritual_dagger(),
+            CardEnum::Shiv => crate::cards::colorless::shiv::shiv(),
         }
     }
 }
@@ -442,4 +531,22 @@ mod tests {
                       "{} should be an Attack card", attack_enum.name());
         }
     }
+
+    #[test]
+    fn test_all_colorless_cards() {
+        let colorless = CardEnum::all_colorless_cards();
+
+        // Every colorless pool card is rewardable and actually colorless
+        for card_enum in &colorless {
+            let card = card_enum.to_card();
+            assert!(matches!(card.get_card_class(), crate::game::card::CardClass::Colorless(_, _)),
+                    "{} should be a Colorless card", card_enum.name());
+            assert!(card_enum.is_rewardable(), "{} should be rewardable", card_enum.name());
+        }
+
+        // Special cards never show up in the pool
+        assert!(!colorless.contains(&CardEnum::Shiv));
+        assert!(!colorless.contains(&CardEnum::RitualDagger));
+        assert!(!CardEnum::Shiv.is_rewardable());
+    }
 }
@@ -0,0 +1,101 @@
+use serde::{Serialize, Deserialize};
+
+/// Keywords that change how a card behaves in combat, independently of its effects
+/// They live on each card instance, so effects can grant or remove them during combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardKeyword {
+    Retain,     // Not discarded at the end of turn
+    Innate,     // Starts each combat in the opening hand
+    Unplayable, // Can never be played
+    Ethereal,   // Exhausted if still in hand at the end of turn
+    Exhaust,    // Exhausted when played
+}
+
+impl CardKeyword {
+    fn bit(self) -> u8 {
+        match self {
+            CardKeyword::Retain => 1 << 0,
+            CardKeyword::Innate => 1 << 1,
+            CardKeyword::Unplayable => 1 << 2,
+            CardKeyword::Ethereal => 1 << 3,
+            CardKeyword::Exhaust => 1 << 4,
+        }
+    }
+}
+
+/// Set of keywords on a card instance, stored as a bitset so cards stay cheap to clone and hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct CardKeywords(u8);
+
+impl CardKeywords {
+    pub fn new() -> Self {
+        CardKeywords(0)
+    }
+
+    pub fn contains(&self, keyword: CardKeyword) -> bool {
+        self.0 & keyword.bit() != 0
+    }
+
+    pub fn insert(&mut self, keyword: CardKeyword) {
+        self.0 |= keyword.bit();
+    }
+
+    pub fn remove(&mut self, keyword: CardKeyword) {
+        self.0 &= !keyword.bit();
+    }
+
+    /// Insert the keyword if `present` is true, remove it otherwise
+    pub fn set(&mut self, keyword: CardKeyword, present: bool) {
+        if present {
+            self.insert(keyword);
+        } else {
+            self.remove(keyword);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_remove_keywords() {
+        let mut keywords = CardKeywords::new();
+        assert!(keywords.is_empty());
+
+        keywords.insert(CardKeyword::Retain);
+        keywords.insert(CardKeyword::Ethereal);
+        assert!(keywords.contains(CardKeyword::Retain));
+        assert!(keywords.contains(CardKeyword::Ethereal));
+        assert!(!keywords.contains(CardKeyword::Innate));
+
+        keywords.remove(CardKeyword::Retain);
+        assert!(!keywords.contains(CardKeyword::Retain));
+        assert!(keywords.contains(CardKeyword::Ethereal));
+
+        keywords.set(CardKeyword::Ethereal, false);
+        assert!(keywords.is_empty());
+    }
+
+    #[test]
+    fn test_keywords_are_independent() {
+        let all = [
+            CardKeyword::Retain,
+            CardKeyword::Innate,
+            CardKeyword::Unplayable,
+            CardKeyword::Ethereal,
+            CardKeyword::Exhaust,
+        ];
+        for keyword in all {
+            let mut keywords = CardKeywords::new();
+            keywords.insert(keyword);
+            for other in all {
+                assert_eq!(keywords.contains(other), other == keyword);
+            }
+        }
+    }
+}
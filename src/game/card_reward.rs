@@ -115,6 +115,7 @@ impl CardRewardPool {
             CardEnum::DemonForm,
             CardEnum::SecondWind,
             CardEnum::Rupture,
+            CardEnum::Juggernaut,
             CardEnum::DualWield,
             CardEnum::DoubleTap,
             CardEnum::Exhume,
@@ -122,17 +123,21 @@ impl CardRewardPool {
             CardEnum::Reaper,
             CardEnum::FiendFire,
             CardEnum::FireBreathing,
+            CardEnum::Barricade,
+            CardEnum::BattleTrance,
             // Status Cards
             CardEnum::Slimed,
             CardEnum::Wound,
             CardEnum::Burn,
             CardEnum::Dazed,
+            CardEnum::Void,
             // Curse Cards
             CardEnum::AscendersCurse,
             CardEnum::Injury,
             CardEnum::Clumsy,
             CardEnum::Regret,
             CardEnum::Writhe,
+            CardEnum::Normality,
             // Colorless Cards
             CardEnum::SwiftStrike,
             CardEnum::Finesse,
@@ -328,6 +333,23 @@ impl CardRewardPool {
         options
     }
 
+    /// Generate `count` distinct card options drawn uniformly from the whole pool
+    /// Used by events such as The Library; does not affect the rare offset
+    pub fn generate_distinct_options(&self, rng: &mut impl rand::Rng, count: usize) -> Vec<Card> {
+        use rand::seq::IndexedRandom;
+
+        let all_cards: Vec<CardEnum> = self.common_pool.iter()
+            .chain(self.uncommon_pool.iter())
+            .chain(self.rare_pool.iter())
+            .copied()
+            .collect();
+
+        all_cards
+            .choose_multiple(rng, count)
+            .filter_map(|card_enum| self.try_create_card_from_enum(*card_enum))
+            .collect()
+    }
+
     /// Try to create a card from a CardEnum, returning None if not implemented
     fn try_create_card_from_enum(&self, card_enum: CardEnum) -> Option<Card> {
         // Use catch_unwind to handle any panics from unimplemented cards
@@ -377,12 +399,15 @@ impl CardRewardPool {
             CardEnum::Whirlwind => crate::cards::ironclad::whirlwind::whirlwind(),
             CardEnum::SecondWind => crate::cards::ironclad::second_wind::second_wind(),
             CardEnum::Rupture => crate::cards::ironclad::rupture::rupture(),
+            CardEnum::Juggernaut => crate::cards::ironclad::juggernaut::juggernaut(),
             CardEnum::DualWield => crate::cards::ironclad::dual_wield::dual_wield(),
             CardEnum::DoubleTap => crate::cards::ironclad::double_tap::double_tap(),
             CardEnum::Feed => crate::cards::ironclad::feed::feed(),
             CardEnum::Reaper => crate::cards::ironclad::reaper::reaper(),
             CardEnum::FiendFire => crate::cards::ironclad::fiend_fire::fiend_fire(),
             CardEnum::FireBreathing => crate::cards::ironclad::fire_breathing::fire_breathing(),
+            CardEnum::Barricade => crate::cards::ironclad::barricade::barricade(),
+            CardEnum::BattleTrance => crate::cards::ironclad::battle_trance::battle_trance(),
 
             // Ironclad Uncommon Cards
             CardEnum::Armaments => crate::cards::ironclad::armaments::armaments(),
@@ -436,17 +461,47 @@ impl CardRewardPool {
             CardEnum::Impatience => crate::cards::colorless::impatience::impatience(),
             CardEnum::PanicButton => crate::cards::colorless::panic_button::panic_button(),
             CardEnum::Panacea => crate::cards::colorless::panacea::panacea(),
+            CardEnum::Discovery => crate::cards::colorless::discovery::discovery(),
+            CardEnum::Enlightenment => crate::cards::colorless::enlightenment::enlightenment(),
+            CardEnum::Forethought => crate::cards::colorless::forethought::forethought(),
+            CardEnum::JackOfAllTrades => crate::cards::colorless::jack_of_all_trades::jack_of_all_trades(),
+            CardEnum::Madness => crate::cards::colorless::madness::madness(),
+            CardEnum::MindBlast => crate::cards::colorless::mind_blast::mind_blast(),
+            CardEnum::Purity => crate::cards::colorless::purity::purity(),
+            CardEnum::Apotheosis => crate::cards::colorless::apotheosis::apotheosis(),
+            CardEnum::Chrysalis => crate::cards::colorless::chrysalis::chrysalis(),
+            CardEnum::HandOfGreed => crate::cards::colorless::hand_of_greed::hand_of_greed(),
+            CardEnum::Magnetism => crate::cards::colorless::magnetism::magnetism(),
+            CardEnum::Mayhem => crate::cards::colorless::mayhem::mayhem(),
+            CardEnum::Metamorphosis => crate::cards::colorless::metamorphosis::metamorphosis(),
+            CardEnum::Panache => crate::cards::colorless::panache::panache(),
+            CardEnum::SadisticNature => crate::cards::colorless::sadistic_nature::sadistic_nature(),
+            CardEnum::SecretTechnique => crate::cards::colorless::secret_technique::secret_technique(),
+            CardEnum::SecretWeapon => crate::cards::colorless::secret_weapon::secret_weapon(),
+            CardEnum::TheBomb => crate::cards::colorless::the_bomb::the_bomb(),
+            CardEnum::ThinkingAhead => crate::cards::colorless::thinking_ahead::thinking_ahead(),
+            CardEnum::Transmutation => crate::cards::colorless::transmutation::transmutation(),
+            CardEnum::Violence => crate::cards::colorless::violence::violence(),
+
+            // Special Colorless Cards (only created by other cards, relics and events)
+            CardEnum::Apparition => crate::cards::colorless::apparition::apparition(),
+            CardEnum::Bite => crate::cards::colorless::bite::bite(),
+            CardEnum::Jax => crate::cards::colorless::jax::jax(),
+            CardEnum::RitualDagger => crate::cards::colorless::ritual_dagger::ritual_dagger(),
+            CardEnum::Shiv => crate::cards::colorless::shiv::shiv(),
 
             // Status/Curse Cards (should not be in reward pools but included for completeness)
             CardEnum::Slimed => crate::cards::status::slimed::slimed(),
             CardEnum::Wound => crate::cards::status::wound::wound(),
             CardEnum::Burn => crate::cards::status::burn::burn(),
             CardEnum::Dazed => crate::cards::status::dazed::dazed(),
+            CardEnum::Void => crate::cards::status::void::void(),
             CardEnum::AscendersCurse => crate::cards::curse::ascenders_curse(), // For completeness, though not used in rewards
             CardEnum::Injury => crate::cards::curse::injury(), // For completeness, though not used in rewards
             CardEnum::Clumsy => crate::cards::curse::clumsy(), // For completeness, though not used in rewards
             CardEnum::Regret => crate::cards::curse::regret(), // For completeness, though not used in rewards
             CardEnum::Writhe => crate::cards::curse::writhe(), // For completeness, though not used in rewards
+            CardEnum::Normality => crate::cards::curse::normality(), // For completeness, though not used in rewards
             CardEnum::DramaticEntrance => crate::cards::colorless::dramatic_entrance::dramatic_entrance(),
         }
     }
@@ -458,6 +513,87 @@ impl Default for CardRewardPool {
     }
 }
 
+/// Pool of Colorless cards offered by the shop, the Colorless Potion and events
+/// Colorless cards only come in Uncommon and Rare; Special cards are never offered
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ColorlessCardPool {
+    /// Uncommon colorless cards - drawn with equal probability
+    uncommon_pool: Vec<CardEnum>,
+    /// Rare colorless cards - drawn with equal probability
+    rare_pool: Vec<CardEnum>,
+}
+
+impl ColorlessCardPool {
+    /// Create a new colorless card pool from all rewardable colorless cards
+    pub fn new() -> Self {
+        let mut uncommon_pool = Vec::new();
+        let mut rare_pool = Vec::new();
+
+        for card in CardEnum::all_colorless_cards() {
+            match card.rarity() {
+                Some(Rarity::Rare) => rare_pool.push(card),
+                Some(_) => uncommon_pool.push(card),
+                None => {} // Special cards are never offered
+            }
+        }
+
+        Self {
+            uncommon_pool,
+            rare_pool,
+        }
+    }
+
+    /// Get the number of cards in the pool
+    pub fn pool_size(&self) -> usize {
+        self.uncommon_pool.len() + self.rare_pool.len()
+    }
+
+    pub fn get_uncommon_cards(&self) -> Vec<CardEnum> {
+        self.uncommon_pool.clone()
+    }
+
+    pub fn get_rare_cards(&self) -> Vec<CardEnum> {
+        self.rare_pool.clone()
+    }
+
+    /// Get every card in the pool regardless of rarity
+    pub fn get_all_cards(&self) -> Vec<CardEnum> {
+        self.uncommon_pool.iter().chain(self.rare_pool.iter()).copied().collect()
+    }
+
+    /// Draw a random colorless card of the given rarity
+    /// Common falls back to Uncommon since there are no common colorless cards
+    pub fn sample_card(&self, rng: &mut impl rand::Rng, rarity: Rarity) -> Option<Card> {
+        let pool = match rarity {
+            Rarity::Common | Rarity::Uncommon => &self.uncommon_pool,
+            Rarity::Rare => &self.rare_pool,
+        };
+
+        if pool.is_empty() {
+            return None;
+        }
+
+        let card_dist = CategoricalDistribution::uniform(pool.clone());
+        Some(card_dist.sample_owned(rng).to_card())
+    }
+
+    /// Generate `count` distinct colorless cards drawn uniformly from the whole pool
+    pub fn generate_options(&self, rng: &mut impl rand::Rng, count: usize) -> Vec<Card> {
+        use rand::seq::IndexedRandom;
+
+        self.get_all_cards()
+            .choose_multiple(rng, count)
+            .map(|card_enum| card_enum.to_card())
+            .collect()
+    }
+}
+
+impl Default for ColorlessCardPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -673,4 +809,37 @@ mod tests {
         assert!(!all_card_enums.contains(&CardEnum::Dazed), "Dazed should not be in reward pool");
         assert!(!all_card_enums.contains(&CardEnum::Burn), "Burn should not be in reward pool");
     }
+
+    #[test]
+    fn test_colorless_pool_contents() {
+        use crate::game::card::CardClass;
+
+        let pool = ColorlessCardPool::new();
+        assert_eq!(pool.pool_size(), CardEnum::all_colorless_cards().len());
+        assert!(pool.get_rare_cards().contains(&CardEnum::Apotheosis));
+        assert!(pool.get_uncommon_cards().contains(&CardEnum::Discovery));
+
+        for card_enum in pool.get_all_cards() {
+            assert!(matches!(card_enum.to_card().get_card_class(), CardClass::Colorless(_, _)));
+        }
+
+        // Special cards are only created by other effects
+        assert!(!pool.get_all_cards().contains(&CardEnum::Shiv));
+        assert!(!pool.get_all_cards().contains(&CardEnum::Apparition));
+    }
+
+    #[test]
+    fn test_colorless_pool_sampling() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let pool = ColorlessCardPool::new();
+
+        let rare = pool.sample_card(&mut rng, Rarity::Rare).unwrap();
+        assert!(pool.get_rare_cards().contains(&rare.get_card_enum()));
+
+        let options = pool.generate_options(&mut rng, 3);
+        assert_eq!(options.len(), 3);
+        assert_ne!(options[0].get_card_enum(), options[1].get_card_enum());
+        assert_ne!(options[1].get_card_enum(), options[2].get_card_enum());
+        assert_ne!(options[0].get_card_enum(), options[2].get_card_enum());
+    }
 }
\ No newline at end of file
@@ -73,4 +73,36 @@ impl Deck {
     pub fn get_card(&self, index: usize) -> Option<&Card> {
         self.cards.get(index)
     }
+
+    /// The instance id after the highest one used in this deck
+    pub fn next_card_id(&self) -> u32 {
+        self.cards.iter()
+            .filter_map(|card| card.get_id())
+            .max()
+            .map_or(0, |id| id + 1)
+    }
+
+    /// Give an instance id to every card that does not have one yet
+    /// Returns the next unused id
+    pub fn assign_card_ids(&mut self) -> u32 {
+        let mut next_id = self.next_card_id();
+        for card in self.cards.iter_mut().filter(|card| card.get_id().is_none()) {
+            card.set_id(next_id);
+            next_id += 1;
+        }
+        next_id
+    }
+
+    /// Replace the card with the given instance id by `update(card)`, returns false if no card matches
+    pub fn update_card_by_id(&mut self, id: u32, update: impl FnOnce(Card) -> Card) -> bool {
+        match self.cards.iter_mut().find(|c| c.get_id() == Some(id)) {
+            Some(existing) => {
+                let mut updated = update(existing.clone());
+                updated.set_id(id);
+                *existing = updated;
+                true
+            }
+            None => false,
+        }
+    }
 }
\ No newline at end of file
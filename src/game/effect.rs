@@ -1,2 +1,2 @@
 // Re-export effects from the effects module
-pub use crate::effects::{Condition, Effect, BattleEffect, GameEffect, BaseEffect};
+pub use crate::effects::{Condition, Quantity, Comparison, Effect, BattleEffect, StatusPlacement, GameEffect, CardPool, BaseEffect, TargetSelector};
@@ -1,4 +1,5 @@
 use crate::game::{effect::BattleEffect, global_info::GlobalInfo};
+use crate::utils::CategoricalDistribution;
 
 pub trait EnemyTrait {
     type MoveType;
@@ -6,4 +7,52 @@ pub trait EnemyTrait {
     fn get_name() -> String;
     fn get_hp(&self) -> u32;
     fn choose_move_and_effects(&mut self, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> (Self::MoveType, Vec<BattleEffect>);
-}
\ No newline at end of file
+
+    /// Every result `choose_move_and_effects` can have, with its probability
+    /// None when the enemy cannot list them (the move is then only known by sampling)
+    fn move_outcomes(&self, _global_info: &GlobalInfo, _rng: &mut impl rand::Rng) -> Option<Vec<MoveOutcome<Self>>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// One possible result of `EnemyTrait::choose_move_and_effects`
+#[derive(Debug, Clone)]
+pub struct MoveOutcome<E: EnemyTrait> {
+    /// The enemy after choosing the move (with its move history updated)
+    pub enemy: E,
+    pub selected_move: E::MoveType,
+    pub effects: Vec<BattleEffect>,
+    pub probability: f64,
+}
+
+impl<E: EnemyTrait + Clone> MoveOutcome<E>
+where
+    E::MoveType: Clone,
+{
+    /// The outcomes of drawing the move from `distribution`
+    /// `commit` records the move on the enemy as `choose_move_and_effects` does and returns its effects
+    pub fn categorical(
+        enemy: &E,
+        distribution: &CategoricalDistribution<E::MoveType>,
+        mut commit: impl FnMut(&mut E, E::MoveType) -> Vec<BattleEffect>,
+    ) -> Vec<Self> {
+        distribution.outcomes().iter()
+            .zip(distribution.probabilities())
+            .map(|(selected_move, probability)| {
+                let mut enemy = enemy.clone();
+                let effects = commit(&mut enemy, selected_move.clone());
+                MoveOutcome { enemy, selected_move: selected_move.clone(), effects, probability }
+            })
+            .collect()
+    }
+
+    /// The only outcome of an enemy whose move choice does not use the rng
+    pub fn forced(enemy: &E, global_info: &GlobalInfo, rng: &mut impl rand::Rng) -> Vec<Self> {
+        let mut enemy = enemy.clone();
+        let (selected_move, effects) = enemy.choose_move_and_effects(global_info, rng);
+        vec![MoveOutcome { enemy, selected_move, effects, probability: 1.0 }]
+    }
+}
@@ -31,6 +31,9 @@ impl Game {
                                         self.player_max_hp = final_state.max_hp;
                                         self.gold = final_state.gold;
                                         self.potions = final_state.potions;
+                                        if let Some(deck) = final_state.deck {
+                                            self.deck = deck;
+                                        }
                                         // Note: relics remain unchanged as they are static during battle
                                     }
 
@@ -63,6 +66,9 @@ impl Game {
                                         self.player_max_hp = final_state.max_hp;
                                         self.gold = final_state.gold;
                                         self.potions = final_state.potions;
+                                        if let Some(deck) = final_state.deck {
+                                            self.deck = deck;
+                                        }
                                     }
                                     self.battle = None;
                                     self.current_battle_is_boss = false; // Reset flag
@@ -220,8 +226,10 @@ impl Game {
                     _ => return Err(GameError::InvalidState),
                 };
 
-                // Add gold to player
-                self.gold += reward_state.gold_reward;
+                // Add gold to player, unless Ectoplasm forbids it
+                if crate::relics::ectoplasm::can_gain_gold(&self.relics) {
+                    self.gold += reward_state.gold_reward;
+                }
                 info!("Claimed {} gold from combat reward", reward_state.gold_reward);
 
                 // Mark gold as claimed
@@ -359,13 +367,15 @@ impl Game {
                 let choice = choices.remove(choice_index);
                 match choice.outcome {
                     crate::events::map_events::EventOutcome::Effects(effects) => {
+                        // Event is complete, return to map before applying effects
+                        // so effects that open a selection (e.g. choosing a card) can take over
+                        self.set_game_state(GameState::OnMap);
+
                         // Apply all effects from the event choice
                         for effect in effects {
                             self.eval_effect(effect, rng);
                         }
 
-                        // Event is complete, return to map
-                        self.set_game_state(GameState::OnMap);
                         Ok(GameResult { outcome: GameOutcome::Continue, game_events: Vec::new() })
                     },
                     crate::events::map_events::EventOutcome::NextChoices(new_choices) => {
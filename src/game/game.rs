@@ -311,7 +311,7 @@ impl Game {
 
     /// Start shop visit with 5 random cards for sale
     pub fn start_shop(&mut self, rng: &mut impl rand::Rng) {
-        let shop_state = crate::game::shop::ShopState::new(5, rng);
+        let shop_state = crate::game::shop::ShopState::new_with_colorless(5, rng);
         info!("Started shop with {} cards for sale", shop_state.card_count());
         for (i, card) in shop_state.cards_for_sale.iter().enumerate() {
             if let Some(price) = shop_state.get_card_price(i) {
@@ -331,7 +331,8 @@ impl Game {
 
     /// Evaluate a single effect and apply it to the player/game
     pub(crate) fn eval_effect(&mut self, effect: crate::game::effect::Effect, rng: &mut impl rand::Rng) {
-        use crate::game::effect::{Effect, BattleEffect, GameEffect};
+        use crate::game::effect::{Effect, BattleEffect, GameEffect, CardPool};
+        use crate::game::card_reward::ColorlessCardPool;
 
         match effect {
             Effect::Battle(battle_effect) => {
@@ -383,6 +384,10 @@ impl Game {
             Effect::Game(game_effect) => {
                 match game_effect {
                     GameEffect::GainGold { amount } => {
+                        if !crate::relics::ectoplasm::can_gain_gold(&self.relics) {
+                            info!("Ectoplasm prevents gaining {} gold", amount);
+                            return;
+                        }
                         self.gold += amount;
                         info!("Gained {} gold", amount);
                     },
@@ -416,6 +421,14 @@ impl Game {
                         // TODO: Implement card transformation
                         info!("Enter card transform selection (count: {}) - not yet implemented", count);
                     },
+                    GameEffect::ChooseCard { pool, num_choices } => {
+                        let options = match pool {
+                            CardPool::Class => self.card_reward_pool.generate_distinct_options(rng, num_choices as usize),
+                            CardPool::Colorless => ColorlessCardPool::new().generate_options(rng, num_choices as usize),
+                        };
+                        info!("Choose 1 of {} {:?} cards", options.len(), pool);
+                        self.push_state(GameState::CardRewardSelection(options));
+                    },
                     GameEffect::UpgradeRandomCards { count } => {
                         let mut upgradeable_indices: Vec<usize> = self.deck.get_cards()
                             .iter()
@@ -964,6 +977,29 @@ mod tests {
         assert!(game.get_current_event_choices().is_empty());
     }
 
+    #[test]
+    fn test_choose_event_library_read() {
+        let deck = starter_deck();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let (map, _) = create_test_map();
+        let mut game = Game::new(deck, global_info, map, 80, 80);
+        let mut rng = rand::rng();
+
+        game.start_event(MapEvent::TheLibrary);
+        let initial_deck_size = game.deck.size();
+
+        // Read: choose 1 of 20 cards
+        let result = game.eval_action(GameAction::ChooseEvent(0), &mut rng);
+        assert!(result.is_ok());
+        assert_eq!(game.get_card_reward_options().len(), 20);
+
+        let result = game.eval_action(GameAction::SelectCardReward(3), &mut rng);
+        assert!(result.is_ok());
+
+        assert_eq!(game.deck.size(), initial_deck_size + 1);
+        assert_eq!(game.get_game_state(), &GameState::OnMap);
+    }
+
     #[test]
     fn test_choose_event_invalid_state() {
         let deck = starter_deck();
@@ -1064,6 +1100,24 @@ mod tests {
         assert_eq!(game.get_game_state(), &GameState::SelectingCardFromDeck(crate::game::game_state::CardFromDeckTo::Upgrade));
     }
 
+    #[test]
+    fn test_rest_site_lists_rest_and_upgrade() {
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let mut rest_map = Map::new();
+        rest_map.add_node(MapNode::new(0, 0, NodeType::Start));
+        rest_map.add_node(MapNode::new(1, 0, NodeType::RestSite));
+        rest_map.add_edge((0, 0), (1, 0)).unwrap();
+        rest_map.set_starting_position((0, 0)).unwrap();
+
+        let mut game = Game::new(starter_deck(), global_info, rest_map, 80, 80);
+        game.eval_action(GameAction::ChoosePath(0), &mut rand::rng()).unwrap();
+
+        assert_eq!(game.list_available_actions(), vec![
+            GameAction::RestSiteChoice(RestSiteAction::Rest),
+            GameAction::RestSiteChoice(RestSiteAction::Upgrade),
+        ]);
+    }
+
     #[test]
     fn test_select_card_to_upgrade_valid() {
         let deck = starter_deck();
@@ -1752,48 +1806,23 @@ impl crate::agents::ForwardSimulation for Game {
     }
 
     fn evaluate(&self) -> f32 {
-        // Terminal: player dead
-        if !self.is_player_alive() {
-            return 0.0;
-        }
-
-        // Terminal: BOSS BEATEN - very high reward!
-        if matches!(self.get_game_state(), GameState::BossBeaten) {
-            return 50.0 + self.state_score();
-        }
-
-        // Non-terminal: state score
-        self.state_score()
+        // The default weights of the run heuristic, other objectives plug their own evaluator into MCTS
+        use crate::agents::Evaluator;
+        crate::agents::GameEvaluator::default().evaluate(self)
     }
 }
 
-// Helper method to compute the state score (private to Game impl)
-impl Game {
-    fn state_score(&self) -> f32 {
-        // Heuristic evaluation for game state
-        // Consider HP, gold, deck quality, and position
+impl crate::agents::Determinize for Game {
+    type Observation = super::observation::GameObservation;
 
-        // HP ratio (0-1)
-        let hp_ratio = if self.player_max_hp > 0 {
-            self.player_hp as f32 / self.player_max_hp as f32
-        } else {
-            0.0
-        };
-
-        // Gold (normalized, assume 500 is a lot)
-        let gold_score = (self.gold as f32 / 500.0).min(1.0);
-
-        // Deck size (normalize around 15 cards)
-        let deck_size = self.deck.size() as f32;
-        let deck_score = 1.0 - ((deck_size - 15.0).abs() / 15.0).min(1.0);
-
-        // Relics (normalize around 10 relics)
-        let relic_score = (self.relics.len() as f32 / 10.0).min(1.0);
-
-        // Potions (normalize around 3 potions)
-        let potion_score = (self.potions.potion_count() as f32 / 3.0).min(1.0);
+    /// Only the battle in progress hides anything (see `Battle::determinize`)
+    fn observation(&self) -> Self::Observation {
+        self.player_observation()
+    }
 
-        // Combine scores with weights
-        0.4 * hp_ratio + 0.2 * gold_score + 0.15 * deck_score + 0.15 * relic_score + 0.1 * potion_score
+    fn determinize(&self, rng: &mut impl rand::Rng) -> Self {
+        let mut game = self.clone();
+        game.battle = self.battle.as_ref().map(|battle| battle.determinize(rng));
+        game
     }
 }
@@ -1,4 +1,4 @@
-use crate::game::{game::Game, action::GameAction, game_state::GameState};
+use crate::game::{game::Game, action::{GameAction, RestSiteAction}, game_state::GameState};
 
 impl Game {
     pub(super) fn list_available_actions(&self) -> Vec<GameAction> {
@@ -51,6 +51,11 @@ impl Game {
                     actions.push(GameAction::ClaimRelic);
                 }
 
+                // Can look at the card choices if not picked yet
+                if reward_state.card_selection_available {
+                    actions.push(GameAction::RequestCardSelection);
+                }
+
                 // Can skip
                 actions.push(GameAction::Skip);
 
@@ -69,9 +74,12 @@ impl Game {
                     .collect()
             }
             GameState::RestSite => {
-                // Rest site actions
-                vec![
-                ]
+                // Rest, or upgrade when some card can still be upgraded
+                let mut actions = vec![GameAction::RestSiteChoice(RestSiteAction::Rest)];
+                if self.has_upgradeable_cards() {
+                    actions.push(GameAction::RestSiteChoice(RestSiteAction::Upgrade));
+                }
+                actions
             }
             GameState::SelectingCardFromDeck(_) => {
                 // Select from deck
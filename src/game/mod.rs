@@ -3,6 +3,7 @@ pub mod effect;
 pub mod enemy;
 pub mod deck;
 pub mod card_type;
+pub mod card_keyword;
 pub mod card_enum;
 pub mod global_info;
 pub mod game;
@@ -18,6 +19,7 @@ pub mod shop;
 pub mod player_run_state;
 pub mod list_available_actions;
 pub mod eval_action;
+pub mod observation;
 
 // Re-export commonly used types for easier access
 pub use game_state::GameState;
@@ -0,0 +1,109 @@
+use crate::battle::observation::BattleObservation;
+use crate::game::{card::Card, game::Game, game_state::GameState};
+use crate::map::MapNode;
+use crate::potion::PotionInventory;
+use crate::relics::Relic;
+use serde::{Serialize, Deserialize};
+
+/// What the player can see of a run, and nothing more
+///
+/// Leaves out the card reward and potion pools, the event history and the hidden side of the
+/// battle in progress (see `BattleObservation`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameObservation {
+    pub ascension: u32,
+    pub floor: u32,
+    pub state: GameState,
+    pub current_hp: u32,
+    pub max_hp: u32,
+    pub gold: u32,
+    pub deck: Vec<Card>,
+    pub relics: Vec<Relic>,
+    pub potions: PotionInventory,
+    /// Every node of the map with the nodes it leads to
+    pub map: Vec<MapNodeObservation>,
+    pub current_node_position: (u32, u32),
+    pub current_battle_is_boss: bool,
+    pub battle: Option<BattleObservation>,
+}
+
+/// A map node and its edges, the map itself is keyed by position tuples which JSON cannot hold
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MapNodeObservation {
+    pub node: MapNode,
+    pub next: Vec<(u32, u32)>,
+}
+
+impl Game {
+    /// The run as the player sees it, see `GameObservation`
+    pub fn player_observation(&self) -> GameObservation {
+        // Sorted by position so the same map always serializes the same way
+        let mut map: Vec<MapNodeObservation> = self.map.get_all_nodes().into_iter()
+            .map(|node| {
+                let mut next = self.map.get_neighbors((node.floor, node.position));
+                next.sort();
+                MapNodeObservation { node: node.clone(), next }
+            })
+            .collect();
+        map.sort_by_key(|observation| (observation.node.floor, observation.node.position));
+
+        GameObservation {
+            ascension: self.global_info.ascention,
+            floor: self.global_info.current_floor,
+            state: self.get_game_state().clone(),
+            current_hp: self.player_hp,
+            max_hp: self.player_max_hp,
+            gold: self.gold,
+            deck: self.deck.get_cards().clone(),
+            relics: self.relics.clone(),
+            potions: self.potions.clone(),
+            map,
+            current_node_position: self.current_node_position,
+            current_battle_is_boss: self.current_battle_is_boss,
+            battle: self.battle.as_ref().map(|battle| battle.player_observation()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::ironclad::starter_deck::starter_deck;
+    use crate::game::{action::GameAction, global_info::GlobalInfo};
+    use crate::map::debug_map;
+
+    fn game() -> Game {
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        Game::new(starter_deck(), global_info, debug_map(), 80, 80)
+    }
+
+    #[test]
+    fn test_game_observation_shows_the_map() {
+        let observation = game().player_observation();
+
+        assert_eq!(observation.state, GameState::OnMap);
+        assert_eq!(observation.map.len(), 6);
+        let start = observation.map.iter().find(|node| node.node.floor == 0).unwrap();
+        assert_eq!(start.next.len(), 5);
+        assert!(observation.battle.is_none());
+
+        // Nodes and their edges come in position order, so the JSON of a map is always the same
+        let positions: Vec<(u32, u32)> = observation.map.iter().map(|node| (node.node.floor, node.node.position)).collect();
+        assert!(positions.is_sorted());
+        assert!(start.next.is_sorted());
+        assert_eq!(serde_json::to_string(&observation).unwrap(), serde_json::to_string(&game().player_observation()).unwrap());
+    }
+
+    #[test]
+    fn test_game_observation_round_trips_through_json_in_battle() {
+        let mut game = game();
+        game.eval_action(GameAction::ChoosePath(0), &mut rand::rng()).unwrap();
+
+        let observation = game.player_observation();
+        assert!(observation.battle.is_some());
+
+        let json = serde_json::to_string(&observation).expect("Should serialize");
+        let deserialized: GameObservation = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(deserialized, observation);
+    }
+}
@@ -1,5 +1,5 @@
 /// Player state that persists across battles during a run
-/// This includes HP, gold, relics, and potions
+/// This includes HP, gold, relics, potions and, after a battle, the master deck
 #[derive(Debug, Clone)]
 pub struct PlayerRunState {
     /// Current HP
@@ -12,6 +12,8 @@ pub struct PlayerRunState {
     pub relics: Vec<crate::relics::Relic>,
     /// Potion inventory
     pub potions: crate::potion::PotionInventory,
+    /// Master deck with permanent card modifications, set when leaving a battle
+    pub deck: Option<crate::game::deck::Deck>,
 }
 
 impl PlayerRunState {
@@ -23,6 +25,7 @@ impl PlayerRunState {
             gold,
             relics: Vec::new(),
             potions: crate::potion::PotionInventory::default(),
+            deck: None,
         }
     }
 
@@ -34,6 +37,7 @@ impl PlayerRunState {
             gold,
             relics,
             potions: crate::potion::PotionInventory::default(),
+            deck: None,
         }
     }
 
@@ -51,6 +55,7 @@ impl PlayerRunState {
             gold,
             relics,
             potions,
+            deck: None,
         }
     }
 }
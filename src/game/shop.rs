@@ -1,4 +1,5 @@
 use crate::game::card::Card;
+use crate::game::card_reward::{ColorlessCardPool, Rarity};
 use serde::{Serialize, Deserialize};
 
 /// State for a shop visit
@@ -28,6 +29,21 @@ impl ShopState {
         }
     }
 
+    /// Create a new shop that also stocks one Uncommon and one Rare Colorless card
+    pub fn new_with_colorless(num_cards: usize, rng: &mut impl rand::Rng) -> Self {
+        let mut shop = Self::new(num_cards, rng);
+
+        let colorless_pool = ColorlessCardPool::new();
+        for rarity in [Rarity::Uncommon, Rarity::Rare] {
+            if let Some(card) = colorless_pool.sample_card(rng, rarity) {
+                shop.card_prices.push(calculate_colorless_card_price(rarity, rng));
+                shop.cards_for_sale.push(card);
+            }
+        }
+
+        shop
+    }
+
     /// Get the number of cards available for sale
     pub fn card_count(&self) -> usize {
         self.cards_for_sale.len()
@@ -64,6 +80,18 @@ impl ShopState {
     }
 }
 
+/// Colorless cards are sold at a markup over class cards of the same rarity
+fn calculate_colorless_card_price(rarity: Rarity, rng: &mut impl rand::Rng) -> u32 {
+    let base_price: u32 = match rarity {
+        Rarity::Common | Rarity::Uncommon => 90,
+        Rarity::Rare => 180,
+    };
+
+    // Add some randomness (±10%)
+    let variance = base_price / 10;
+    rng.random_range((base_price - variance)..=(base_price + variance))
+}
+
 /// Pool of cards that can appear in the shop
 pub struct ShopCardPool {
     available_cards: Vec<Card>,
@@ -272,4 +300,26 @@ mod tests {
         assert_eq!(shop1.card_removal_cost, 75);
         assert_eq!(shop2.card_removal_cost, 75);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shop_with_colorless_cards() {
+        use crate::game::card::CardClass;
+
+        let mut rng = rand::rng();
+        let shop = ShopState::new_with_colorless(5, &mut rng);
+
+        assert_eq!(shop.card_count(), 7);
+        assert_eq!(shop.card_prices.len(), 7);
+
+        let colorless: Vec<_> = shop.cards_for_sale.iter()
+            .filter(|card| matches!(card.get_card_class(), CardClass::Colorless(_, _)))
+            .collect();
+        assert_eq!(colorless.len(), 2);
+
+        // Uncommon then Rare, priced at a markup
+        let uncommon_price = shop.get_card_price(5).unwrap();
+        let rare_price = shop.get_card_price(6).unwrap();
+        assert!((81..=99).contains(&uncommon_price));
+        assert!((162..=198).contains(&rare_price));
+    }
+}
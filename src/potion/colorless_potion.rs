@@ -0,0 +1,77 @@
+//! Colorless Potion implementation
+//!
+//! The Colorless Potion allows the player to choose 1 of 3 random Colorless cards
+//! to add to their hand. The chosen card is added and costs 0 this turn.
+
+use crate::game::effect::BattleEffect;
+use crate::battle::target::Entity;
+
+/// Colorless Potion: Choose 1 of 3 random Colorless cards to add to your hand. It costs 0 this turn.
+pub const COLORLESS_POTION_NAME: &str = "Colorless Potion";
+pub const COLORLESS_POTION_DESCRIPTION: &str = "Choose 1 of 3 random Colorless cards to add to your hand. It costs 0 this turn.";
+
+/// Get the effects for the Colorless Potion
+/// Returns a tuple of (target, effects)
+pub fn get_colorless_potion_effects() -> (Option<Entity>, Vec<BattleEffect>) {
+    (Some(Entity::Player), vec![
+        BattleEffect::AddRandomColorlessCardsToHand {
+            num_choices: 3,
+            num_copies: 1,
+            cost: 0
+        }
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::Battle;
+    use crate::battle::battle_action::BattleAction;
+    use crate::battle::battle_state::BattleState;
+    use crate::battle::enemy_in_battle::EnemyInBattle;
+    use crate::cards::ironclad::starter_deck::starter_deck;
+    use crate::enemies::{red_louse::RedLouse, enemy_enum::EnemyEnum};
+    use crate::game::{global_info::GlobalInfo, PlayerRunState, enemy::EnemyTrait, card::CardClass};
+    use crate::potion::Potion;
+
+    #[test]
+    fn test_colorless_potion_name_and_description() {
+        assert_eq!(Potion::ColorlessPotion.name(), "Colorless Potion");
+        assert_eq!(Potion::ColorlessPotion.description(), COLORLESS_POTION_DESCRIPTION);
+    }
+
+    #[test]
+    fn test_colorless_potion_adds_colorless_card() {
+        let deck = starter_deck();
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let red_louse = RedLouse::instantiate(&mut rng, &global_info);
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::RedLouse(red_louse))];
+
+        let mut player_state = PlayerRunState::new(80, 80, 0);
+        player_state.potions.add_potion(Potion::ColorlessPotion);
+
+        let mut battle = Battle::new(deck, global_info, player_state, enemies, &mut rng);
+        let initial_hand_size = battle.cards.hand_size();
+
+        battle.eval_action(BattleAction::UsePotion(0, None), &mut rng).unwrap();
+
+        match &battle.battle_state {
+            BattleState::SelectCardFromChoices { choices, num_copies, cost_override } => {
+                assert_eq!(choices.len(), 3, "Should have 3 choices");
+                assert_eq!(*num_copies, 1);
+                assert_eq!(*cost_override, Some(0));
+            },
+            _ => panic!("Should be in SelectCardFromChoices state"),
+        }
+
+        battle.eval_action(BattleAction::SelectCardFromChoices(0), &mut rng).unwrap();
+        assert_eq!(battle.battle_state, BattleState::PlayerTurn);
+
+        let hand = battle.cards.get_hand();
+        assert_eq!(hand.len(), initial_hand_size + 1);
+        let new_card = &hand[initial_hand_size];
+        assert!(matches!(new_card.get_card_class(), CardClass::Colorless(_, _)));
+        assert_eq!(new_card.get_cost(), 0);
+    }
+}
@@ -1,7 +1,8 @@
 pub mod attack_potion;
 pub mod skill_potion;
+pub mod colorless_potion;
 
-use crate::game::effect::BattleEffect;
+use crate::game::effect::{BattleEffect, TargetSelector};
 use crate::battle::target::Entity;
 use serde::{Serialize, Deserialize};
 use ordered_float::NotNan;
@@ -39,6 +40,8 @@ pub enum Potion {
     AttackPotion,
     /// Skill Potion: Choose 1 of 3 random Skill cards to add to your hand. It costs 0 this turn.
     SkillPotion,
+    /// Colorless Potion: Choose 1 of 3 random Colorless cards to add to your hand. It costs 0 this turn.
+    ColorlessPotion,
     /// Cultist Potion: Gain 1 Ritual
     CultistPotion,
     /// Distilled Chaos: Play the top 3 cards of your draw pile
@@ -47,6 +50,8 @@ pub enum Potion {
     BlessingOfTheForge,
     /// Fruit Juice: Gain 5 Max HP
     FruitJuice,
+    /// Gambler's Brew: Discard any number of cards, then draw that many
+    GamblersBrew,
 }
 
 impl Potion {
@@ -68,10 +73,12 @@ impl Potion {
             Potion::EssenceOfSteelPotion => "Essence of Steel",
             Potion::AttackPotion => attack_potion::ATTACK_POTION_NAME,
             Potion::SkillPotion => skill_potion::SKILL_POTION_NAME,
+            Potion::ColorlessPotion => colorless_potion::COLORLESS_POTION_NAME,
             Potion::CultistPotion => "Cultist Potion",
             Potion::DistilledChaos => "Distilled Chaos",
             Potion::BlessingOfTheForge => "Blessing of the Forge",
             Potion::FruitJuice => "Fruit Juice",
+            Potion::GamblersBrew => "Gambler's Brew",
         }
     }
 
@@ -93,10 +100,12 @@ impl Potion {
             Potion::EssenceOfSteelPotion => "Gain 4 Plated Armor",
             Potion::AttackPotion => attack_potion::ATTACK_POTION_DESCRIPTION,
             Potion::SkillPotion => skill_potion::SKILL_POTION_DESCRIPTION,
+            Potion::ColorlessPotion => colorless_potion::COLORLESS_POTION_DESCRIPTION,
             Potion::CultistPotion => "Gain 1 Ritual",
             Potion::DistilledChaos => "Play the top 3 cards of your draw pile",
             Potion::BlessingOfTheForge => "Upgrade all cards in your hand",
             Potion::FruitJuice => "Gain 5 Max HP",
+            Potion::GamblersBrew => "Discard any number of cards, then draw that many",
         }
     }
 
@@ -131,7 +140,7 @@ impl Potion {
                 // For now, we'll use AttackToTarget since Fire potion should hit all enemies
                 // This would need special handling in the battle system
                 (None, vec![
-                    BattleEffect::AttackAllEnemies { amount: 20, num_attacks: 1 }
+                    BattleEffect::AttackToTarget { amount: 20, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)
                 ])
             }
             Potion::SwiftPotion => {
@@ -149,7 +158,7 @@ impl Potion {
             Potion::ExplosivePotion => {
                 // Explosive potion hits all enemies
                 (None, vec![
-                    BattleEffect::AttackAllEnemies { amount: 10, num_attacks: 1 }
+                    BattleEffect::AttackToTarget { amount: 10, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)
                 ])
             }
             Potion::FearPotion => {
@@ -184,6 +193,7 @@ impl Potion {
             }
             Potion::AttackPotion => attack_potion::get_attack_potion_effects(),
             Potion::SkillPotion => skill_potion::get_skill_potion_effects(),
+            Potion::ColorlessPotion => colorless_potion::get_colorless_potion_effects(),
             Potion::CultistPotion => {
                 (Some(Entity::Player), vec![
                     BattleEffect::GainRitual(1)
@@ -206,6 +216,11 @@ impl Potion {
                     BattleEffect::HealAndIncreaseMaxHp(5)
                 ])
             }
+            Potion::GamblersBrew => {
+                (Some(Entity::Player), vec![
+                    BattleEffect::EnterSelectCardsInHandToDiscardAndDraw
+                ])
+            }
         }
     }
 
@@ -392,6 +407,7 @@ impl PotionPool {
             Potion::WeakPotion,
             Potion::AttackPotion,
             Potion::SkillPotion,
+            Potion::ColorlessPotion,
         ];
 
         let uncommon_potions = vec![
@@ -401,6 +417,7 @@ impl PotionPool {
             Potion::RegenPotion,
             Potion::EssenceOfSteelPotion,
             Potion::CultistPotion,
+            Potion::GamblersBrew,
         ];
 
         let rare_potions = vec![
@@ -26,7 +26,7 @@ impl EventListener for AkabekoRelic {
                 self.triggered = false;
                 vec![]
             }
-            BattleEvent::CardPlayed { source, card_type } if *source == self.owner && !self.triggered => {
+            BattleEvent::CardPlayed { source, card_type, .. } if *source == self.owner && !self.triggered => {
                 use crate::game::card_type::CardType;
                 if *card_type == CardType::Attack {
                     self.triggered = true;
@@ -87,6 +87,7 @@ mod tests {
         let effects = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
 
         assert_eq!(effects.len(), 1);
@@ -106,6 +107,7 @@ mod tests {
         let effects1 = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects1.len(), 1);
         assert!(akabeko.triggered);
@@ -114,6 +116,7 @@ mod tests {
         let effects2 = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects2.len(), 0);
     }
@@ -129,6 +132,7 @@ mod tests {
         let _ = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert!(akabeko.triggered);
 
@@ -142,6 +146,7 @@ mod tests {
         let effects = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects.len(), 1);
     }
@@ -157,6 +162,7 @@ mod tests {
         let effects = akabeko.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
 
         assert_eq!(effects.len(), 0);
@@ -39,7 +39,7 @@ impl EventListener for ArtOfWarRelic {
                     vec![]
                 }
             }
-            BattleEvent::CardPlayed { source, card_type } if *source == self.owner => {
+            BattleEvent::CardPlayed { source, card_type, .. } if *source == self.owner => {
                 if *card_type == CardType::Attack {
                     self.played_attack_this_turn = true;
                 }
@@ -127,6 +127,7 @@ mod tests {
         let _ = art.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert!(art.played_attack_this_turn);
 
@@ -148,6 +149,7 @@ mod tests {
         let _ = art.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert!(art.played_attack_this_turn);
 
@@ -175,10 +177,12 @@ mod tests {
         let _ = art.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         let _ = art.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Power,
+            x_value: None,
         });
 
         // Should not have played attack
@@ -198,6 +202,7 @@ mod tests {
         let _ = art.on_event(&BattleEvent::CardPlayed {
             source: Entity::Enemy(0),
             card_type: CardType::Attack,
+            x_value: None,
         });
 
         assert!(!art.played_attack_this_turn);
@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use crate::battle::battle_events::{BattleEvent, EventListener};
 use crate::battle::target::Entity;
-use crate::game::effect::BattleEffect;
+use crate::game::effect::{BattleEffect, TargetSelector};
 
 /// Bag of Marbles relic
 /// At the start of each combat, apply 1 Vulnerable to ALL enemies
@@ -26,7 +26,7 @@ impl EventListener for BagOfMarblesRelic {
             BattleEvent::CombatStart { player } if !self.used && *player == self.owner => {
                 self.used = true;
                 // Apply 1 Vulnerable to ALL enemies
-                vec![BattleEffect::ApplyVulnerableAll { duration: 1 }]
+                vec![BattleEffect::ApplyVulnerable { duration: 1 }.targeting(TargetSelector::AllEnemies)]
             }
             _ => vec![]
         }
@@ -72,7 +72,7 @@ mod tests {
         let effects = bag.on_event(&combat_start_event);
 
         assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::ApplyVulnerableAll { duration: 1 });
+        assert_eq!(effects[0], BattleEffect::ApplyVulnerable { duration: 1 }.targeting(TargetSelector::AllEnemies));
         assert!(!bag.is_active()); // Used up for this combat
     }
 
@@ -152,7 +152,7 @@ mod tests {
         let player_combat_start = BattleEvent::CombatStart { player };
         let effects = bag.on_event(&player_combat_start);
         assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::ApplyVulnerableAll { duration: 1 });
+        assert_eq!(effects[0], BattleEffect::ApplyVulnerable { duration: 1 }.targeting(TargetSelector::AllEnemies));
         assert!(!bag.is_active());
     }
 
@@ -165,10 +165,11 @@ mod tests {
         let effects = bag.on_event(&combat_start_event);
 
         assert_eq!(effects.len(), 1);
-        if let BattleEffect::ApplyVulnerableAll { duration } = effects[0] {
-            assert_eq!(duration, 1, "Vulnerable should last 1 turn");
+        if let BattleEffect::Targeted { selector, effect } = &effects[0] {
+            assert_eq!(*selector, TargetSelector::AllEnemies);
+            assert_eq!(**effect, BattleEffect::ApplyVulnerable { duration: 1 }, "Vulnerable should last 1 turn");
         } else {
-            panic!("Expected ApplyVulnerableAll effect");
+            panic!("Expected Vulnerable applied to all enemies");
         }
     }
 }
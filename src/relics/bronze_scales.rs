@@ -3,7 +3,7 @@ use crate::battle::battle_events::{BattleEvent, EventListener};
 use crate::game::effect::BattleEffect;
 use crate::battle::target::Entity;
 
-/// Bronze Scales - Whenever you take damage, deal 3 damage back (Thorns)
+/// Bronze Scales - Start each combat with 3 Thorns
 #[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BronzeScalesRelic {
     owner: Entity,
@@ -18,13 +18,9 @@ impl BronzeScalesRelic {
 impl EventListener for BronzeScalesRelic {
     fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
         match event {
-            BattleEvent::DamageTaken { target, amount, .. } if *target == self.owner && *amount > 0 => {
-                // Deal 3 damage back to the source
-                vec![BattleEffect::AttackToTarget {
-                    amount: 3,
-                    num_attacks: 1,
-                    strength_multiplier: 0,
-                }]
+            BattleEvent::CombatStart { player } if *player == self.owner => {
+                // Thorns deals the damage back whenever the owner is attacked
+                vec![BattleEffect::GainThorns { amount: 3 }]
             }
             _ => vec![]
         }
@@ -59,26 +55,21 @@ mod tests {
     }
 
     #[test]
-    fn test_bronze_scales_deals_damage_when_player_takes_damage() {
+    fn test_bronze_scales_grants_thorns_at_combat_start() {
         let mut scales = BronzeScalesRelic::new(Entity::Player);
 
-        let effects = scales.on_event(&BattleEvent::DamageTaken {
-            target: Entity::Player,
-            amount: 5,
-            source: Entity::Enemy(0),
-        });
+        let effects = scales.on_event(&BattleEvent::CombatStart { player: Entity::Player });
 
-        assert_eq!(effects.len(), 1);
-        assert!(matches!(effects[0], BattleEffect::AttackToTarget { amount: 3, num_attacks: 1, strength_multiplier: 0 }));
+        assert_eq!(effects, vec![BattleEffect::GainThorns { amount: 3 }]);
     }
 
     #[test]
-    fn test_bronze_scales_no_trigger_when_zero_damage() {
+    fn test_bronze_scales_no_trigger_when_player_takes_damage() {
         let mut scales = BronzeScalesRelic::new(Entity::Player);
 
         let effects = scales.on_event(&BattleEvent::DamageTaken {
             target: Entity::Player,
-            amount: 0,
+            amount: 5,
             source: Entity::Enemy(0),
         });
 
@@ -86,15 +77,25 @@ mod tests {
     }
 
     #[test]
-    fn test_bronze_scales_no_trigger_when_enemy_takes_damage() {
-        let mut scales = BronzeScalesRelic::new(Entity::Player);
-
-        let effects = scales.on_event(&BattleEvent::DamageTaken {
-            target: Entity::Enemy(0),
-            amount: 5,
-            source: Entity::Player,
+    fn test_bronze_scales_thorns_hits_attacker() {
+        use crate::battle::Battle;
+        use crate::battle::enemy_in_battle::EnemyInBattle;
+        use crate::enemies::{jaw_worm::JawWorm, enemy_enum::EnemyEnum};
+        use crate::game::{PlayerRunState, deck::Deck, effect::BaseEffect, enemy::EnemyTrait, global_info::GlobalInfo};
+        use crate::relics::Relic;
+
+        let mut rng = rand::rng();
+        let global_info = GlobalInfo { ascention: 0, current_floor: 1 };
+        let enemies = vec![EnemyInBattle::new(EnemyEnum::JawWorm(JawWorm::instantiate(&mut rng, &global_info)))];
+        let player_state = PlayerRunState::new_with_relics(50, 80, 0, vec![Relic::BronzeScales]);
+        let mut battle = Battle::new(Deck::new(vec![]), global_info, player_state, enemies, &mut rng);
+        let enemy_hp = battle.get_enemies()[0].battle_info.get_hp();
+
+        battle.eval_base_effect(&BaseEffect::AttackToTarget {
+            source: Entity::Enemy(0), target: Entity::Player, amount: 6, num_attacks: 2, strength_multiplier: 1,
         });
+        battle.process_effect_queue();
 
-        assert_eq!(effects.len(), 0);
+        assert_eq!(battle.get_enemies()[0].battle_info.get_hp(), enemy_hp - 6);
     }
 }
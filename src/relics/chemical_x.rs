@@ -0,0 +1,37 @@
+use super::Relic;
+
+/// Chemical X - The effects of your X-cost cards are increased by 2
+/// Chemical X has no battle listener: it is read when an X-cost card is played
+pub const CHEMICAL_X_BONUS: u32 = 2;
+
+/// Compute X for an X-cost card from the energy spent on it and the player's relics
+pub fn x_value(energy_spent: u32, relics: &[Relic]) -> u32 {
+    if relics.contains(&Relic::ChemicalX) {
+        energy_spent + CHEMICAL_X_BONUS
+    } else {
+        energy_spent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x_value_without_chemical_x() {
+        assert_eq!(x_value(0, &[]), 0);
+        assert_eq!(x_value(3, &[Relic::Anchor]), 3);
+    }
+
+    #[test]
+    fn test_x_value_with_chemical_x() {
+        assert_eq!(x_value(0, &[Relic::ChemicalX]), 2);
+        assert_eq!(x_value(3, &[Relic::Anchor, Relic::ChemicalX]), 5);
+    }
+
+    #[test]
+    fn test_chemical_x_name() {
+        assert_eq!(Relic::ChemicalX.name(), "Chemical X");
+        assert!(Relic::ChemicalX.to_battle_event_listener().is_none());
+    }
+}
@@ -0,0 +1,26 @@
+use super::Relic;
+
+/// Ectoplasm - Gain 1 Energy at the start of each turn. You can no longer gain Gold.
+/// Ectoplasm has no battle listener: its energy is part of `Relic::energy_per_turn_bonus`
+/// and every place that hands out gold checks `can_gain_gold`
+pub fn can_gain_gold(relics: &[Relic]) -> bool {
+    !relics.contains(&Relic::Ectoplasm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_gain_gold() {
+        assert!(can_gain_gold(&[]));
+        assert!(!can_gain_gold(&[Relic::Anchor, Relic::Ectoplasm]));
+    }
+
+    #[test]
+    fn test_ectoplasm_name() {
+        assert_eq!(Relic::Ectoplasm.name(), "Ectoplasm");
+        assert_eq!(Relic::Ectoplasm.energy_per_turn_bonus(), 1);
+        assert!(Relic::Ectoplasm.to_battle_event_listener().is_none());
+    }
+}
@@ -17,6 +17,11 @@ impl HappyFlowerRelic {
             owner,
         }
     }
+
+    /// Turns counted towards the next energy, shown on the relic
+    pub fn get_counter(&self) -> u32 {
+        self.turn_count
+    }
 }
 
 impl EventListener for HappyFlowerRelic {
@@ -0,0 +1,20 @@
+use super::Relic;
+
+/// Ice Cream - Energy is now conserved between turns.
+/// Ice Cream has no battle listener: it is read when the player's energy is refilled at the start of each turn
+pub fn conserves_energy(relics: &[Relic]) -> bool {
+    relics.contains(&Relic::IceCream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ice_cream_name() {
+        assert_eq!(Relic::IceCream.name(), "Ice Cream");
+        assert!(conserves_energy(&[Relic::Anchor, Relic::IceCream]));
+        assert!(!conserves_energy(&[Relic::Anchor]));
+        assert!(Relic::IceCream.to_battle_event_listener().is_none());
+    }
+}
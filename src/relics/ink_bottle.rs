@@ -17,6 +17,11 @@ impl InkBottleRelic {
             owner,
         }
     }
+
+    /// Cards played towards the next draw, shown on the relic
+    pub fn get_counter(&self) -> u32 {
+        self.card_count
+    }
 }
 
 impl EventListener for InkBottleRelic {
@@ -82,6 +87,7 @@ mod tests {
             let effects = bottle.on_event(&BattleEvent::CardPlayed {
                 source: Entity::Player,
                 card_type: CardType::Attack,
+                x_value: None,
             });
             if i == 10 {
                 assert_eq!(effects.len(), 1);
@@ -104,6 +110,7 @@ mod tests {
             let _ = bottle.on_event(&BattleEvent::CardPlayed {
                 source: Entity::Player,
                 card_type: CardType::Skill,
+                x_value: None,
             });
         }
         assert_eq!(bottle.card_count, 5);
@@ -127,18 +134,21 @@ mod tests {
         let _ = bottle.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(bottle.card_count, 1);
 
         let _ = bottle.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(bottle.card_count, 2);
 
         let _ = bottle.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Power,
+            x_value: None,
         });
         assert_eq!(bottle.card_count, 3);
     }
@@ -151,6 +161,7 @@ mod tests {
         let effects = bottle.on_event(&BattleEvent::CardPlayed {
             source: Entity::Enemy(0),
             card_type: CardType::Attack,
+            x_value: None,
         });
 
         assert_eq!(effects.len(), 0);
@@ -171,6 +182,7 @@ mod tests {
             let effects = bottle.on_event(&BattleEvent::CardPlayed {
                 source: Entity::Player,
                 card_type: CardType::Attack,
+                x_value: None,
             });
             if effects.len() > 0 {
                 trigger_count += 1;
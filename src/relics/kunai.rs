@@ -18,6 +18,11 @@ impl KunaiRelic {
             owner,
         }
     }
+
+    /// Attacks played this turn towards the next Dexterity, shown on the relic
+    pub fn get_counter(&self) -> u32 {
+        self.attacks_this_turn
+    }
 }
 
 impl EventListener for KunaiRelic {
@@ -32,7 +37,7 @@ impl EventListener for KunaiRelic {
                 self.attacks_this_turn = 0;
                 vec![]
             }
-            BattleEvent::CardPlayed { source, card_type } if *source == self.owner => {
+            BattleEvent::CardPlayed { source, card_type, .. } if *source == self.owner => {
                 if *card_type == CardType::Attack {
                     self.attacks_this_turn += 1;
                     // Check if we've played 3, 6, 9, etc. attacks this turn
@@ -93,18 +98,21 @@ mod tests {
         let effects1 = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects1.len(), 0);
 
         let effects2 = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects2.len(), 0);
 
         let effects3 = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects3.len(), 1);
         assert!(matches!(effects3[0], BattleEffect::GainDexterity { amount: 1 }));
@@ -124,10 +132,12 @@ mod tests {
         let _ = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         let _ = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(kunai.attacks_this_turn, 2);
 
@@ -149,6 +159,7 @@ mod tests {
         let effects1 = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(effects1.len(), 0);
         assert_eq!(kunai.attacks_this_turn, 0);
@@ -156,6 +167,7 @@ mod tests {
         let effects2 = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Power,
+            x_value: None,
         });
         assert_eq!(effects2.len(), 0);
         assert_eq!(kunai.attacks_this_turn, 0);
@@ -176,6 +188,7 @@ mod tests {
             let effects = kunai.on_event(&BattleEvent::CardPlayed {
                 source: Entity::Player,
                 card_type: CardType::Attack,
+                x_value: None,
             });
             if effects.len() > 0 {
                 trigger_count += 1;
@@ -198,6 +211,7 @@ mod tests {
         let effects = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Enemy(0),
             card_type: CardType::Attack,
+            x_value: None,
         });
 
         assert_eq!(effects.len(), 0);
@@ -217,6 +231,7 @@ mod tests {
         let _ = kunai.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(kunai.attacks_this_turn, 1);
 
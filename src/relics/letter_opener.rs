@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::battle::battle_events::{BattleEvent, EventListener};
-use crate::game::effect::BattleEffect;
+use crate::game::effect::{BattleEffect, TargetSelector};
 use crate::battle::target::Entity;
 use crate::game::card_type::CardType;
 
@@ -18,6 +18,11 @@ impl LetterOpenerRelic {
             owner,
         }
     }
+
+    /// Skills played this turn towards the next damage, shown on the relic
+    pub fn get_counter(&self) -> u32 {
+        self.skills_this_turn
+    }
 }
 
 impl EventListener for LetterOpenerRelic {
@@ -32,15 +37,16 @@ impl EventListener for LetterOpenerRelic {
                 self.skills_this_turn = 0;
                 vec![]
             }
-            BattleEvent::CardPlayed { source, card_type } if *source == self.owner => {
+            BattleEvent::CardPlayed { source, card_type, .. } if *source == self.owner => {
                 if *card_type == CardType::Skill {
                     self.skills_this_turn += 1;
                     // Check if we've played 3, 6, 9, etc. skills this turn
                     if self.skills_this_turn % 3 == 0 && self.skills_this_turn > 0 {
-                        vec![BattleEffect::AttackAllEnemies {
+                        vec![BattleEffect::AttackToTarget {
                             amount: 5,
                             num_attacks: 1,
-                        }]
+                            strength_multiplier: 1,
+                        }.targeting(TargetSelector::AllEnemies)]
                     } else {
                         vec![]
                     }
@@ -96,21 +102,24 @@ mod tests {
         let effects1 = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(effects1.len(), 0);
 
         let effects2 = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(effects2.len(), 0);
 
         let effects3 = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(effects3.len(), 1);
-        assert!(matches!(effects3[0], BattleEffect::AttackAllEnemies { amount: 5, num_attacks: 1 }));
+        assert_eq!(effects3[0], BattleEffect::AttackToTarget { amount: 5, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
     }
 
     #[test]
@@ -127,10 +136,12 @@ mod tests {
         let _ = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         let _ = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(opener.skills_this_turn, 2);
 
@@ -152,6 +163,7 @@ mod tests {
         let effects1 = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Attack,
+            x_value: None,
         });
         assert_eq!(effects1.len(), 0);
         assert_eq!(opener.skills_this_turn, 0);
@@ -159,6 +171,7 @@ mod tests {
         let effects2 = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Power,
+            x_value: None,
         });
         assert_eq!(effects2.len(), 0);
         assert_eq!(opener.skills_this_turn, 0);
@@ -179,6 +192,7 @@ mod tests {
             let effects = opener.on_event(&BattleEvent::CardPlayed {
                 source: Entity::Player,
                 card_type: CardType::Skill,
+                x_value: None,
             });
             if effects.len() > 0 {
                 trigger_count += 1;
@@ -201,6 +215,7 @@ mod tests {
         let effects = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Enemy(0),
             card_type: CardType::Skill,
+            x_value: None,
         });
 
         assert_eq!(effects.len(), 0);
@@ -220,6 +235,7 @@ mod tests {
         let _ = opener.on_event(&BattleEvent::CardPlayed {
             source: Entity::Player,
             card_type: CardType::Skill,
+            x_value: None,
         });
         assert_eq!(opener.skills_this_turn, 1);
 
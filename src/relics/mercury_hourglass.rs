@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::battle::battle_events::{BattleEvent, EventListener};
-use crate::game::effect::BattleEffect;
+use crate::game::effect::{BattleEffect, TargetSelector};
 use crate::battle::target::Entity;
 
 /// Mercury Hourglass - At the start of your turn, deal 3 damage to ALL enemies
@@ -20,7 +20,7 @@ impl EventListener for MercuryHourglassRelic {
         match event {
             BattleEvent::StartOfPlayerTurn => {
                 // Deal 3 damage to ALL enemies
-                vec![BattleEffect::AttackAllEnemies { amount: 3, num_attacks: 1 }]
+                vec![BattleEffect::AttackToTarget { amount: 3, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies)]
             }
             _ => vec![]
         }
@@ -61,7 +61,7 @@ mod tests {
         let effects = hourglass.on_event(&BattleEvent::StartOfPlayerTurn);
 
         assert_eq!(effects.len(), 1);
-        assert!(matches!(effects[0], BattleEffect::AttackAllEnemies { amount: 3, num_attacks: 1 }));
+        assert_eq!(effects[0], BattleEffect::AttackToTarget { amount: 3, num_attacks: 1, strength_multiplier: 1 }.targeting(TargetSelector::AllEnemies));
     }
 
     #[test]
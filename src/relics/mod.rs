@@ -27,6 +27,16 @@ pub mod letter_opener;
 pub mod shuriken;
 pub mod ornamental_fan;
 pub mod red_mask;
+pub mod chemical_x;
+pub mod torii;
+pub mod tungsten_rod;
+pub mod runic_pyramid;
+pub mod snecko_eye;
+pub mod ice_cream;
+pub mod philosophers_stone;
+pub mod ectoplasm;
+pub mod velvet_choker;
+pub mod runic_dome;
 
 pub use burning_blood::BurningBloodRelic;
 pub use anchor::AnchorRelic;
@@ -44,12 +54,10 @@ pub use oddly_smooth_stone::OddlySmoothStoneRelic;
 pub use mango::MangoRelic;
 pub use centennial_puzzle::CentennialPuzzleRelic;
 pub use orichalcum::OrichalcumRelic;
-pub use nunchaku::NunchakuRelic;
 pub use akabeko::AkabekoRelic;
 pub use the_boot::TheBootRelic;
 pub use gremlin_horn::GremlinHornRelic;
 pub use happy_flower::HappyFlowerRelic;
-pub use pen_nib::PenNibRelic;
 pub use art_of_war::ArtOfWarRelic;
 pub use ink_bottle::InkBottleRelic;
 pub use kunai::KunaiRelic;
@@ -91,6 +99,16 @@ pub enum Relic {
     Shuriken,
     OrnamentalFan,
     RedMask,
+    ChemicalX,
+    Torii,
+    TungstenRod,
+    RunicPyramid,
+    SneckoEye,
+    IceCream,
+    PhilosophersStone,
+    Ectoplasm,
+    VelvetChoker,
+    RunicDome,
 }
 
 impl Relic {
@@ -126,6 +144,24 @@ impl Relic {
             Relic::Shuriken => "Shuriken",
             Relic::OrnamentalFan => "Ornamental Fan",
             Relic::RedMask => "Red Mask",
+            Relic::ChemicalX => "Chemical X",
+            Relic::Torii => "Torii",
+            Relic::TungstenRod => "Tungsten Rod",
+            Relic::RunicPyramid => "Runic Pyramid",
+            Relic::SneckoEye => "Snecko Eye",
+            Relic::IceCream => "Ice Cream",
+            Relic::PhilosophersStone => "Philosopher's Stone",
+            Relic::Ectoplasm => "Ectoplasm",
+            Relic::VelvetChoker => "Velvet Choker",
+            Relic::RunicDome => "Runic Dome",
+        }
+    }
+
+    /// Extra energy this relic gives at the start of every turn (boss energy relics)
+    pub fn energy_per_turn_bonus(&self) -> u32 {
+        match self {
+            Relic::PhilosophersStone | Relic::Ectoplasm | Relic::VelvetChoker | Relic::RunicDome => 1,
+            _ => 0,
         }
     }
 
@@ -160,12 +196,10 @@ impl Relic {
             Relic::OddlySmoothStone => Some(EventListenerEnum::OddlySmoothStone(OddlySmoothStoneRelic::new(Entity::Player))),
             Relic::CentennialPuzzle => Some(EventListenerEnum::CentennialPuzzle(CentennialPuzzleRelic::new(Entity::Player))),
             Relic::Orichalcum => Some(EventListenerEnum::Orichalcum(OrichalcumRelic::new(Entity::Player))),
-            Relic::Nunchaku => Some(EventListenerEnum::Nunchaku(NunchakuRelic::new(Entity::Player))),
             Relic::Akabeko => Some(EventListenerEnum::Akabeko(AkabekoRelic::new(Entity::Player))),
             Relic::TheBoot => Some(EventListenerEnum::TheBoot(TheBootRelic::new(Entity::Player))),
             Relic::GremlinHorn => Some(EventListenerEnum::GremlinHorn(GremlinHornRelic::new(Entity::Player))),
             Relic::HappyFlower => Some(EventListenerEnum::HappyFlower(HappyFlowerRelic::new(Entity::Player))),
-            Relic::PenNib => Some(EventListenerEnum::PenNib(PenNibRelic::new(Entity::Player))),
             Relic::ArtOfWar => Some(EventListenerEnum::ArtOfWar(ArtOfWarRelic::new(Entity::Player))),
             Relic::InkBottle => Some(EventListenerEnum::InkBottle(InkBottleRelic::new(Entity::Player))),
             Relic::Kunai => Some(EventListenerEnum::Kunai(KunaiRelic::new(Entity::Player))),
@@ -202,7 +236,7 @@ impl Relic {
             Relic::OrnamentalFan,
         ];
 
-        const RARE_RELICS: [Relic; 10] = [
+        const RARE_RELICS: [Relic; 12] = [
             Relic::Akabeko,
             Relic::ArtOfWar,
             Relic::CentennialPuzzle,
@@ -213,6 +247,8 @@ impl Relic {
             Relic::LetterOpener,
             Relic::Nunchaku,
             Relic::Orichalcum,
+            Relic::Torii,
+            Relic::TungstenRod,
         ];
 
         let relics = match rarity {
@@ -1,159 +1,44 @@
-use serde::{Serialize, Deserialize};
-use crate::battle::battle_events::{BattleEvent, EventListener};
-use crate::game::effect::BattleEffect;
-use crate::battle::target::Entity;
+use super::Relic;
 
 /// Nunchaku - Every time you play 10 Attacks, gain 1 Energy
-#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct NunchakuRelic {
-    attack_count: u8,
-    owner: Entity,
-}
+/// Nunchaku has no battle listener: it reads the combat's Attack counter whenever an Attack is played
+pub const NUNCHAKU_ATTACKS: u32 = 10;
 
-impl NunchakuRelic {
-    pub fn new(owner: Entity) -> Self {
-        Self {
-            attack_count: 0,
-            owner,
-        }
-    }
+/// Energy the relics give for the Attack that brought the combat's Attack count to `attacks_played`
+pub fn energy_for_attack(relics: &[Relic], attacks_played: u32) -> u32 {
+    let completes_ten = attacks_played > 0 && attacks_played % NUNCHAKU_ATTACKS == 0;
+    (relics.contains(&Relic::Nunchaku) && completes_ten) as u32
 }
 
-impl EventListener for NunchakuRelic {
-    fn on_event(&mut self, event: &BattleEvent) -> Vec<BattleEffect> {
-        match event {
-            BattleEvent::CombatStart { player } if *player == self.owner => {
-                self.attack_count = 0;
-                vec![]
-            }
-            BattleEvent::CardPlayed { source, card_type } if *source == self.owner => {
-                use crate::game::card_type::CardType;
-                if *card_type == CardType::Attack {
-                    self.attack_count += 1;
-                    if self.attack_count >= 10 {
-                        self.attack_count = 0;
-                        vec![BattleEffect::GainEnergy { amount: 1 }]
-                    } else {
-                        vec![]
-                    }
-                } else {
-                    vec![]
-                }
-            }
-            _ => vec![]
-        }
-    }
-
-    fn is_active(&self) -> bool {
-        true
-    }
-
-    fn get_owner(&self) -> Entity {
-        self.owner
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-
-    fn hash_to(&self, state: &mut std::collections::hash_map::DefaultHasher) {
-        use std::hash::Hash;
-        self.hash(state);
-    }
+/// Attacks played towards the next Energy, shown on the relic
+pub fn counter(attacks_played: u32) -> u32 {
+    attacks_played % NUNCHAKU_ATTACKS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::card_type::CardType;
-
-    #[test]
-    fn test_nunchaku_creation() {
-        let nunchaku = NunchakuRelic::new(Entity::Player);
-        assert_eq!(nunchaku.attack_count, 0);
-    }
-
-    #[test]
-    fn test_nunchaku_resets_on_combat_start() {
-        let mut nunchaku = NunchakuRelic::new(Entity::Player);
-        nunchaku.attack_count = 5;
-
-        let _ = nunchaku.on_event(&BattleEvent::CombatStart {
-            player: Entity::Player,
-        });
-
-        assert_eq!(nunchaku.attack_count, 0);
-    }
 
     #[test]
-    fn test_nunchaku_counts_attacks() {
-        let mut nunchaku = NunchakuRelic::new(Entity::Player);
-
-        for i in 1..=9 {
-            let effects = nunchaku.on_event(&BattleEvent::CardPlayed {
-                source: Entity::Player,
-                card_type: CardType::Attack,
-            });
-            assert_eq!(effects.len(), 0);
-            assert_eq!(nunchaku.attack_count, i as u8);
-        }
+    fn test_energy_every_ten_attacks() {
+        let relics = [Relic::Nunchaku];
+        assert_eq!(energy_for_attack(&relics, 0), 0);
+        assert_eq!(energy_for_attack(&relics, 9), 0);
+        assert_eq!(energy_for_attack(&relics, 10), 1);
+        assert_eq!(energy_for_attack(&relics, 20), 1);
+        assert_eq!(energy_for_attack(&[], 10), 0);
     }
 
     #[test]
-    fn test_nunchaku_gives_energy_every_10_attacks() {
-        let mut nunchaku = NunchakuRelic::new(Entity::Player);
-
-        // Play 10 attacks
-        for _ in 0..9 {
-            let effects = nunchaku.on_event(&BattleEvent::CardPlayed {
-                source: Entity::Player,
-                card_type: CardType::Attack,
-            });
-            assert_eq!(effects.len(), 0);
-        }
-
-        // 10th attack should trigger
-        let effects = nunchaku.on_event(&BattleEvent::CardPlayed {
-            source: Entity::Player,
-            card_type: CardType::Attack,
-        });
-
-        assert_eq!(effects.len(), 1);
-        assert!(matches!(effects[0], BattleEffect::GainEnergy { amount: 1 }));
-        assert_eq!(nunchaku.attack_count, 0); // Reset after triggering
+    fn test_counter_wraps_after_ten() {
+        assert_eq!(counter(9), 9);
+        assert_eq!(counter(10), 0);
+        assert_eq!(counter(13), 3);
     }
 
     #[test]
-    fn test_nunchaku_only_counts_attacks() {
-        let mut nunchaku = NunchakuRelic::new(Entity::Player);
-
-        // Play a skill (should not count)
-        let effects1 = nunchaku.on_event(&BattleEvent::CardPlayed {
-            source: Entity::Player,
-            card_type: CardType::Skill,
-        });
-        assert_eq!(effects1.len(), 0);
-        assert_eq!(nunchaku.attack_count, 0);
-
-        // Play an attack (should count)
-        let effects2 = nunchaku.on_event(&BattleEvent::CardPlayed {
-            source: Entity::Player,
-            card_type: CardType::Attack,
-        });
-        assert_eq!(effects2.len(), 0);
-        assert_eq!(nunchaku.attack_count, 1);
-    }
-
-    #[test]
-    fn test_nunchaku_no_trigger_for_enemy_cards() {
-        let mut nunchaku = NunchakuRelic::new(Entity::Player);
-
-        let effects = nunchaku.on_event(&BattleEvent::CardPlayed {
-            source: Entity::Enemy(0),
-            card_type: CardType::Attack,
-        });
-
-        assert_eq!(effects.len(), 0);
-        assert_eq!(nunchaku.attack_count, 0);
+    fn test_nunchaku_name() {
+        assert_eq!(Relic::Nunchaku.name(), "Nunchaku");
+        assert!(Relic::Nunchaku.to_battle_event_listener().is_none());
     }
 }
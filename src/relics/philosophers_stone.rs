@@ -0,0 +1,33 @@
+use super::Relic;
+
+/// Philosopher's Stone - Gain 1 Energy at the start of each turn. ALL enemies start with 1 Strength.
+/// Philosopher's Stone has no battle listener: its energy is part of `Relic::energy_per_turn_bonus`
+/// and its Strength is given to enemies when the battle starts and when an enemy is spawned
+pub const PHILOSOPHERS_STONE_ENEMY_STRENGTH: u32 = 1;
+
+/// Strength every enemy starts combat with
+pub fn enemy_starting_strength(relics: &[Relic]) -> u32 {
+    if relics.contains(&Relic::PhilosophersStone) {
+        PHILOSOPHERS_STONE_ENEMY_STRENGTH
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enemy_starting_strength() {
+        assert_eq!(enemy_starting_strength(&[]), 0);
+        assert_eq!(enemy_starting_strength(&[Relic::Anchor, Relic::PhilosophersStone]), 1);
+    }
+
+    #[test]
+    fn test_philosophers_stone_name() {
+        assert_eq!(Relic::PhilosophersStone.name(), "Philosopher's Stone");
+        assert_eq!(Relic::PhilosophersStone.energy_per_turn_bonus(), 1);
+        assert!(Relic::PhilosophersStone.to_battle_event_listener().is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use crate::battle::battle_events::{BattleEvent, EventListener};
 use crate::battle::target::Entity;
-use crate::game::effect::BattleEffect;
+use crate::game::effect::{BattleEffect, TargetSelector};
 
 /// Red Mask relic (Event relic)
 /// At the start of each combat, apply 1 Weak to ALL enemies
@@ -26,7 +26,7 @@ impl EventListener for RedMaskRelic {
             BattleEvent::CombatStart { player } if !self.used && *player == self.owner => {
                 self.used = true;
                 // Apply 1 Weak to ALL enemies
-                vec![BattleEffect::ApplyWeakAll { duration: 1 }]
+                vec![BattleEffect::ApplyWeak { duration: 1 }.targeting(TargetSelector::AllEnemies)]
             }
             _ => vec![]
         }
@@ -71,7 +71,7 @@ mod tests {
         let effects = mask.on_event(&combat_start_event);
 
         assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::ApplyWeakAll { duration: 1 });
+        assert_eq!(effects[0], BattleEffect::ApplyWeak { duration: 1 }.targeting(TargetSelector::AllEnemies));
         assert!(!mask.is_active()); // Used up for this combat
     }
 
@@ -109,7 +109,7 @@ mod tests {
         let player_combat_start = BattleEvent::CombatStart { player };
         let effects = mask.on_event(&player_combat_start);
         assert_eq!(effects.len(), 1);
-        assert_eq!(effects[0], BattleEffect::ApplyWeakAll { duration: 1 });
+        assert_eq!(effects[0], BattleEffect::ApplyWeak { duration: 1 }.targeting(TargetSelector::AllEnemies));
         assert!(!mask.is_active());
     }
 
@@ -122,10 +122,11 @@ mod tests {
         let effects = mask.on_event(&combat_start_event);
 
         assert_eq!(effects.len(), 1);
-        if let BattleEffect::ApplyWeakAll { duration } = effects[0] {
-            assert_eq!(duration, 1, "Weak should last 1 turn");
+        if let BattleEffect::Targeted { selector, effect } = &effects[0] {
+            assert_eq!(*selector, TargetSelector::AllEnemies);
+            assert_eq!(**effect, BattleEffect::ApplyWeak { duration: 1 }, "Weak should last 1 turn");
         } else {
-            panic!("Expected ApplyWeakAll effect");
+            panic!("Expected Weak applied to all enemies");
         }
     }
 
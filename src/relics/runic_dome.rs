@@ -0,0 +1,26 @@
+use super::Relic;
+
+/// Runic Dome - Gain 1 Energy at the start of each turn. You can no longer see enemy Intents.
+/// Runic Dome has no battle listener: its energy is part of `Relic::energy_per_turn_bonus`
+/// and the player's observation of the battle checks `hides_intents`
+pub fn hides_intents(relics: &[Relic]) -> bool {
+    relics.contains(&Relic::RunicDome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hides_intents_only_with_runic_dome() {
+        assert!(hides_intents(&[Relic::Anchor, Relic::RunicDome]));
+        assert!(!hides_intents(&[Relic::Anchor]));
+    }
+
+    #[test]
+    fn test_runic_dome_name() {
+        assert_eq!(Relic::RunicDome.name(), "Runic Dome");
+        assert_eq!(Relic::RunicDome.energy_per_turn_bonus(), 1);
+        assert!(Relic::RunicDome.to_battle_event_listener().is_none());
+    }
+}
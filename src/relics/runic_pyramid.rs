@@ -0,0 +1,24 @@
+use super::Relic;
+
+/// Runic Pyramid - At the end of your turn, you no longer discard your hand
+/// Runic Pyramid has no battle listener: it is read when the player's turn ends
+pub fn keeps_hand(relics: &[Relic]) -> bool {
+    relics.contains(&Relic::RunicPyramid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_hand_only_with_runic_pyramid() {
+        assert!(keeps_hand(&[Relic::Anchor, Relic::RunicPyramid]));
+        assert!(!keeps_hand(&[Relic::Anchor]));
+    }
+
+    #[test]
+    fn test_runic_pyramid_name() {
+        assert_eq!(Relic::RunicPyramid.name(), "Runic Pyramid");
+        assert!(Relic::RunicPyramid.to_battle_event_listener().is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use super::Relic;
+
+/// Snecko Eye - Draw 2 additional cards each turn. Start each combat Confused.
+/// Snecko Eye has no battle listener: it is read when the battle starts and when the player draws their hand
+pub const SNECKO_EYE_EXTRA_DRAW: usize = 2;
+
+/// Number of cards the player draws at the start of each turn
+pub fn cards_per_turn(base: usize, relics: &[Relic]) -> usize {
+    if relics.contains(&Relic::SneckoEye) {
+        base + SNECKO_EYE_EXTRA_DRAW
+    } else {
+        base
+    }
+}
+
+/// Whether the player starts combat Confused
+pub fn starts_confused(relics: &[Relic]) -> bool {
+    relics.contains(&Relic::SneckoEye)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cards_per_turn() {
+        assert_eq!(cards_per_turn(5, &[]), 5);
+        assert_eq!(cards_per_turn(5, &[Relic::Anchor, Relic::SneckoEye]), 7);
+    }
+
+    #[test]
+    fn test_snecko_eye_name() {
+        assert_eq!(Relic::SneckoEye.name(), "Snecko Eye");
+        assert!(starts_confused(&[Relic::SneckoEye]));
+        assert!(!starts_confused(&[Relic::Anchor]));
+    }
+}
@@ -0,0 +1,35 @@
+use super::Relic;
+use crate::battle::damage_type::DamageType;
+
+/// Torii - Whenever you would receive 5 or less unblocked attack damage, reduce it to 1
+/// Torii has no battle listener: it is read when the player takes damage
+pub const TORII_THRESHOLD: u32 = 5;
+
+/// Unblocked damage the player actually receives after Torii
+pub fn reduce_unblocked_damage(unblocked_damage: u32, damage_type: DamageType, relics: &[Relic]) -> u32 {
+    let applies = damage_type == DamageType::Normal
+        && unblocked_damage > 1
+        && unblocked_damage <= TORII_THRESHOLD
+        && relics.contains(&Relic::Torii);
+    if applies { 1 } else { unblocked_damage }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_torii_reduces_small_attack_damage() {
+        assert_eq!(reduce_unblocked_damage(5, DamageType::Normal, &[Relic::Torii]), 1);
+        assert_eq!(reduce_unblocked_damage(2, DamageType::Normal, &[Relic::Torii]), 1);
+        assert_eq!(reduce_unblocked_damage(6, DamageType::Normal, &[Relic::Torii]), 6);
+        assert_eq!(reduce_unblocked_damage(0, DamageType::Normal, &[Relic::Torii]), 0);
+    }
+
+    #[test]
+    fn test_torii_ignores_non_attack_damage() {
+        assert_eq!(reduce_unblocked_damage(3, DamageType::Thorns, &[Relic::Torii]), 3);
+        assert_eq!(reduce_unblocked_damage(3, DamageType::HpLoss, &[Relic::Torii]), 3);
+        assert_eq!(reduce_unblocked_damage(3, DamageType::Normal, &[]), 3);
+    }
+}
@@ -0,0 +1,27 @@
+use super::Relic;
+
+/// Tungsten Rod - Whenever you would lose HP, lose 1 less
+/// Tungsten Rod has no battle listener: it is read when the player loses HP
+pub const TUNGSTEN_ROD_REDUCTION: u32 = 1;
+
+/// HP the player actually loses after Tungsten Rod
+pub fn reduce_hp_loss(hp_loss: u32, relics: &[Relic]) -> u32 {
+    if relics.contains(&Relic::TungstenRod) {
+        hp_loss.saturating_sub(TUNGSTEN_ROD_REDUCTION)
+    } else {
+        hp_loss
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tungsten_rod_reduces_hp_loss() {
+        assert_eq!(reduce_hp_loss(5, &[Relic::TungstenRod]), 4);
+        assert_eq!(reduce_hp_loss(1, &[Relic::TungstenRod]), 0);
+        assert_eq!(reduce_hp_loss(0, &[Relic::TungstenRod]), 0);
+        assert_eq!(reduce_hp_loss(5, &[Relic::Anchor]), 5);
+    }
+}
@@ -0,0 +1,29 @@
+use super::Relic;
+
+/// Velvet Choker - Gain 1 Energy at the start of each turn. You cannot play more than 6 cards per turn.
+/// Velvet Choker has no battle listener: its energy is part of `Relic::energy_per_turn_bonus`
+/// and its limit is checked with the turn's card counter before a card is played
+pub const VELVET_CHOKER_CARD_LIMIT: u32 = 6;
+
+/// Most cards the relics allow the player to play each turn
+pub fn card_limit(relics: &[Relic]) -> Option<u32> {
+    relics.contains(&Relic::VelvetChoker).then_some(VELVET_CHOKER_CARD_LIMIT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_limit() {
+        assert_eq!(card_limit(&[]), None);
+        assert_eq!(card_limit(&[Relic::VelvetChoker]), Some(6));
+    }
+
+    #[test]
+    fn test_velvet_choker_name() {
+        assert_eq!(Relic::VelvetChoker.name(), "Velvet Choker");
+        assert_eq!(Relic::VelvetChoker.energy_per_turn_bonus(), 1);
+        assert!(Relic::VelvetChoker.to_battle_event_listener().is_none());
+    }
+}